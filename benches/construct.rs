@@ -39,3 +39,27 @@ fn map_stride(bench: &mut test::Bencher)
     let av = a.slice(s![.., ..;2]);
     bench.iter(|| av.map(|&x| 2. * x));
 }
+
+#[bench]
+fn tile_16x16_by_16x16(bench: &mut Bencher)
+{
+    let a = Array::from_shape_fn((16, 16), |(i, j)| (i * 16 + j) as f64);
+    bench.iter(|| a.tile(&[16, 16]));
+}
+
+/// The same as `a.tile(&[16, 16])` by broadcasting to four axes and copying
+/// to a new array.
+#[bench]
+fn tile_broadcast_16x16_by_16x16(bench: &mut Bencher)
+{
+    let a = Array::from_shape_fn((16, 16), |(i, j)| (i * 16 + j) as f64);
+    bench.iter(|| {
+        a.view()
+            .insert_axis(Axis(1))
+            .broadcast((16, 16, 16, 16))
+            .unwrap()
+            .to_owned()
+            .into_shape_with_order((256, 256))
+            .unwrap()
+    });
+}
@@ -4,6 +4,7 @@ extern crate test;
 use test::Bencher;
 
 use ndarray::prelude::*;
+use ndarray::Order;
 
 const N: usize = 1024;
 const X: usize = 64;
@@ -30,3 +31,168 @@ fn clip(bench: &mut Bencher)
         })
     });
 }
+
+#[bench]
+fn median_1e6(bench: &mut Bencher)
+{
+    let a = Array::from_iter((0..1_000_000u64).map(|i| (i * 7919 % 1_000_003) as f64));
+    bench.iter(|| a.median());
+}
+
+#[bench]
+fn sort_1e6(bench: &mut Bencher)
+{
+    let a = Array::from_iter((0..1_000_000u64).map(|i| (i * 7919 % 1_000_003) as f64));
+    bench.iter(|| {
+        let mut v = a.to_vec();
+        v.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        v[v.len() / 2]
+    });
+}
+
+#[bench]
+fn ptp_1e6(bench: &mut Bencher)
+{
+    let a = Array::from_iter((0..1_000_000u64).map(|i| (i * 7919 % 1_000_003) as f64));
+    bench.iter(|| a.ptp());
+}
+
+#[bench]
+fn max_minus_min_1e6(bench: &mut Bencher)
+{
+    let a = Array::from_iter((0..1_000_000u64).map(|i| (i * 7919 % 1_000_003) as f64));
+    bench.iter(|| {
+        a.max()
+            .copied()
+            .zip(a.min().copied())
+            .map(|(max, min)| max - min)
+    });
+}
+
+#[bench]
+fn min_max_1e6(bench: &mut Bencher)
+{
+    let a = Array::from_iter((0..1_000_000u64).map(|i| (i * 7919 % 1_000_003) as f64));
+    bench.iter(|| a.min_max());
+}
+
+#[bench]
+fn min_then_max_1e6(bench: &mut Bencher)
+{
+    let a = Array::from_iter((0..1_000_000u64).map(|i| (i * 7919 % 1_000_003) as f64));
+    bench.iter(|| a.min().zip(a.max()));
+}
+
+#[bench]
+fn ptp_axis_f_order(bench: &mut Bencher)
+{
+    let a = Array::linspace(0., 127., N * 2)
+        .into_shape_with_order(([X * 2, Y], Order::ColumnMajor))
+        .unwrap();
+    bench.iter(|| a.ptp_axis(Axis(1)));
+}
+
+#[bench]
+fn sum_f64_1e6(bench: &mut Bencher)
+{
+    let a = Array::from_iter((0..1_000_000u32).map(|i| (i % 1000) as f64 * 0.1));
+    bench.iter(|| a.sum());
+}
+
+#[bench]
+fn sum_f32_1e6(bench: &mut Bencher)
+{
+    let a = Array::from_iter((0..1_000_000u32).map(|i| (i % 1000) as f32 * 0.1));
+    bench.iter(|| a.sum());
+}
+
+#[cfg(feature = "std")]
+#[bench]
+fn sum_compensated_f32_1e6(bench: &mut Bencher)
+{
+    let a = Array::from_iter((0..1_000_000u32).map(|i| (i % 1000) as f32 * 0.1));
+    bench.iter(|| a.sum_compensated());
+}
+
+#[cfg(feature = "std")]
+#[bench]
+fn sum_of_squares_f64_1e6(bench: &mut Bencher)
+{
+    let a = Array::from_iter((0..1_000_000u32).map(|i| (i % 1000) as f64 * 0.1));
+    bench.iter(|| a.sum_of_squares());
+}
+
+#[bench]
+fn sum_of_squares_mapv_f64_1e6(bench: &mut Bencher)
+{
+    let a = Array::from_iter((0..1_000_000u32).map(|i| (i % 1000) as f64 * 0.1));
+    bench.iter(|| a.mapv(|x| x * x).sum());
+}
+
+#[cfg(feature = "std")]
+#[bench]
+fn interp_sorted_1e6(bench: &mut Bencher)
+{
+    let xp = Array::linspace(0., 1., 1000).mapv(|x: f64| x * x);
+    let fp = xp.mapv(f64::sqrt);
+    let x = Array::linspace(-0.1, 1.1, 1_000_000);
+    bench.iter(|| x.interp(&xp, &fp, None, None));
+}
+
+#[cfg(feature = "std")]
+#[bench]
+fn interp_unsorted_1e6(bench: &mut Bencher)
+{
+    let xp = Array::linspace(0., 1., 1000).mapv(|x: f64| x * x);
+    let fp = xp.mapv(f64::sqrt);
+    let x = Array::from_iter((0..1_000_000u64).map(|i| (i * 7919 % 1_000_003) as f64 / 1e6));
+    bench.iter(|| x.interp(&xp, &fp, None, None));
+}
+
+#[cfg(feature = "std")]
+#[bench]
+fn mul_add_f64_1e6(bench: &mut Bencher)
+{
+    let x = Array::from_iter((0..1_000_000u32).map(|i| (i % 1000) as f64 * 0.1));
+    let a = x.mapv(|x| x + 1.);
+    let b = x.mapv(|x| x - 1.);
+    bench.iter(|| x.mul_add(&a, &b));
+}
+
+/// The same as `x.mul_add(&a, &b)` with the operators, which allocates an
+/// intermediate array for the products.
+#[bench]
+fn mul_add_operators_f64_1e6(bench: &mut Bencher)
+{
+    let x = Array::from_iter((0..1_000_000u32).map(|i| (i % 1000) as f64 * 0.1));
+    let a = x.mapv(|x| x + 1.);
+    let b = x.mapv(|x| x - 1.);
+    bench.iter(|| &x * &a + &b);
+}
+
+#[bench]
+fn mul_add_i64_1e6(bench: &mut Bencher)
+{
+    let x = Array::from_iter(0..1_000_000i64);
+    let a = x.mapv(|x| x % 7);
+    let b = x.mapv(|x| -x);
+    bench.iter(|| x.mul_add(&a, &b));
+}
+
+#[bench]
+fn add_promote_f32_f64_1e6(bench: &mut Bencher)
+{
+    let a = Array::from_iter((0..1_000_000u32).map(|i| (i % 1000) as f32 * 0.1));
+    let b = Array::from_iter((0..1_000_000u32).map(|i| (i % 777) as f64 * 0.3));
+    bench.iter(|| a.add_promote(&b));
+}
+
+/// The same as `a.add_promote(&b)` with a cast of `a` first, which allocates
+/// a converted copy of `a`.
+#[bench]
+fn cast_then_add_f32_f64_1e6(bench: &mut Bencher)
+{
+    let a = Array::from_iter((0..1_000_000u32).map(|i| (i % 1000) as f32 * 0.1));
+    let b = Array::from_iter((0..1_000_000u32).map(|i| (i % 777) as f64 * 0.3));
+    bench.iter(|| a.cast::<f64>() + &b);
+}
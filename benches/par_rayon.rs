@@ -8,6 +8,7 @@ extern crate test;
 use test::Bencher;
 
 use ndarray::Zip;
+use ndarray_gen::array_builder::{ArrayBuilder, ElementGenerator};
 
 const EXP_N: usize = 256;
 const ADDN: usize = 512;
@@ -180,3 +181,36 @@ fn array_f64_collect(bench: &mut test::Bencher)
     let v = Array::from_elem((COLL_F64_N, COLL_F64_N), 1.);
     bench.iter(|| Zip::from(&v).par_map_collect(|s| s + 1.));
 }
+
+const SORT_ROWS: usize = 1000;
+const SORT_COLS: usize = 10000;
+
+fn sort_input() -> Array2<f64>
+{
+    ArrayBuilder::new((SORT_ROWS, SORT_COLS))
+        .generator(ElementGenerator::Random(1))
+        .build()
+}
+
+#[bench]
+fn sort_axis(bench: &mut Bencher)
+{
+    let a = sort_input();
+    bench.iter(|| {
+        let mut b = a.clone();
+        b.sort_axis_by(Axis(1), |x, y| x.total_cmp(y));
+        b
+    });
+}
+
+#[bench]
+fn rayon_sort_axis(bench: &mut Bencher)
+{
+    set_threads();
+    let a = sort_input();
+    bench.iter(|| {
+        let mut b = a.clone();
+        b.par_sort_axis_by(Axis(1), |x, y| x.total_cmp(y));
+        b
+    });
+}
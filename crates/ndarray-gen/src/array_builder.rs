@@ -28,6 +28,10 @@ pub enum ElementGenerator
     Sequential,
     Checkerboard,
     Zero,
+    /// Pseudo-random integers in `0..65536` from a linear congruential
+    /// generator with the given seed; the same seed gives the same elements
+    /// for every element type.
+    Random(u32),
 }
 
 impl<D: Dimension> Default for ArrayBuilder<D>
@@ -72,6 +76,18 @@ where D: Dimension
                 Array::from_iter(core::iter::successors(Some(zero), |elt| Some(elt.clone() + T::one())).take(size)),
             ElementGenerator::Checkerboard => Array::from_iter([T::one(), zero].iter().cycle().take(size).cloned()),
             ElementGenerator::Zero => Array::zeros(size),
+            ElementGenerator::Random(seed) => {
+                // `Num` has no conversion from integers, so make each element
+                // from two bytes and the elements `0..=256`
+                let bytes =
+                    Array::from_iter(core::iter::successors(Some(zero), |elt| Some(elt.clone() + T::one())).take(257));
+                let mut state = seed;
+                Array::from_iter((0..size).map(|_| {
+                    state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+                    let (high, low) = ((state >> 24) as usize, ((state >> 16) & 0xff) as usize);
+                    bytes[high].clone() * bytes[256].clone() + bytes[low].clone()
+                }))
+            }
         })
         .into_shape_with_order((self.dim, self.memory_order))
         .unwrap()
@@ -94,3 +110,21 @@ fn test_order()
     assert_eq!(c.strides(), &[n as isize, 1]);
     assert_eq!(f.strides(), &[1, m as isize]);
 }
+
+#[test]
+fn test_random()
+{
+    let a = ArrayBuilder::new((7, 9))
+        .generator(ElementGenerator::Random(3))
+        .build::<i32>();
+    let b = ArrayBuilder::new((7, 9))
+        .generator(ElementGenerator::Random(3))
+        .build::<f64>();
+    let c = ArrayBuilder::new((7, 9))
+        .generator(ElementGenerator::Random(4))
+        .build::<i32>();
+
+    assert_eq!(a.mapv(f64::from), b);
+    assert_ne!(a, c);
+    assert!(a.iter().all(|&x| (0..65536).contains(&x)));
+}
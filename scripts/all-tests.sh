@@ -15,6 +15,7 @@ cargo build -v --no-default-features
 cargo test -p ndarray -v --no-default-features
 # ndarray with no_std-compatible features
 cargo test -p ndarray -v --no-default-features --features approx
+cargo test -p ndarray -v --no-default-features --features libm
 # all with features
 cargo test -v --features "$FEATURES" $QC_FEAT
 # all with features and release (ignore test crates which is already optimized)
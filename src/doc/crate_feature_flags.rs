@@ -9,7 +9,13 @@
 //!     default `std` feature. To do so, use `default-features = false` in
 //!     your `Cargo.toml`.
 //!   - The `geomspace` `linspace` `logspace` `range` `std` `var` `var_axis`
-//!     and `std_axis` methods are only available when `std` is enabled.
+//!     and `std_axis` methods are only available when `std` is enabled,
+//!     except for the last four, see `libm`.
+//!
+//! ## `libm`
+//!   - Use the [`libm`] crate for float math without the standard library.
+//!   - Makes the `var` `std` `var_axis` and `std_axis` methods, and their
+//!     `try_` variants, available in `no_std` builds.
 //!
 //! ## `serde`
 //!   - Enables serialization support for serde 1.x
@@ -30,6 +36,7 @@
 //!   - Enable the ``threading`` feature in the matrixmultiply package
 //!
 //! [`parallel`]: crate::parallel
+//! [`libm`]: https://crates.io/crates/libm
 
 #[cfg(doc)]
 use crate::parallel::par_azip;
@@ -6,6 +6,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 use super::Dimension;
+use crate::{Ix, IxDyn};
 #[cfg(feature = "std")]
 use std::error::Error;
 use std::fmt;
@@ -115,3 +116,251 @@ where
 {
     from_kind(ErrorKind::IncompatibleShape)
 }
+
+/// An error from a statistical method, such as a weighted mean.
+///
+/// This enumeration is not exhaustive.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub enum StatsError
+{
+    /// the shapes of the inputs are incompatible
+    Shape(ShapeError),
+    /// the weights sum to zero
+    ZeroWeight,
+    /// the bin edges are not in increasing order
+    UnsortedEdges,
+    /// the delta degrees of freedom `ddof` are less than zero or greater than
+    /// the number of elements `n`
+    DdofOutOfRange
+    {
+        /// the delta degrees of freedom
+        ddof: f64,
+        /// the number of elements
+        n: usize,
+    },
+    /// the input has no elements
+    EmptyInput,
+    /// the smoothing factor `alpha` is not in the interval `(0, 1]`
+    AlphaOutOfRange
+    {
+        /// the smoothing factor
+        alpha: f64,
+    },
+}
+
+impl From<ShapeError> for StatsError
+{
+    fn from(error: ShapeError) -> Self
+    {
+        StatsError::Shape(error)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl Error for StatsError
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)>
+    {
+        match self {
+            StatsError::Shape(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for StatsError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            StatsError::Shape(error) => write!(f, "StatsError/Shape: {}", error),
+            StatsError::ZeroWeight => write!(f, "StatsError/ZeroWeight: the weights sum to zero"),
+            StatsError::UnsortedEdges => {
+                write!(f, "StatsError/UnsortedEdges: the bin edges are not in increasing order")
+            }
+            StatsError::DdofOutOfRange { ddof, n } => write!(
+                f,
+                "StatsError/DdofOutOfRange: `ddof` must not be less than zero or greater than the length {}, but is {}",
+                n, ddof
+            ),
+            StatsError::EmptyInput => write!(f, "StatsError/EmptyInput: the input has no elements"),
+            StatsError::AlphaOutOfRange { alpha } => write!(
+                f,
+                "StatsError/AlphaOutOfRange: `alpha` must be in the interval (0, 1], but is {}",
+                alpha
+            ),
+        }
+    }
+}
+
+/// An error from a checked reduction, such as
+/// [`try_sum`](crate::ArrayRef::try_sum), when the result overflows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OverflowError
+{
+    index: IxDyn,
+}
+
+impl OverflowError
+{
+    pub(crate) fn new(index: IxDyn) -> Self
+    {
+        OverflowError { index }
+    }
+
+    /// Return the index of the element of the input array at which the
+    /// overflow happened.
+    pub fn index(&self) -> &[Ix]
+    {
+        self.index.slice()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl Error for OverflowError {}
+
+impl fmt::Display for OverflowError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "OverflowError: arithmetic overflow at index {:?}", self.index())
+    }
+}
+
+/// An error from [`try_cast`](crate::ArrayRef::try_cast) when an element
+/// can not be represented in the target type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CastError
+{
+    index: IxDyn,
+}
+
+impl CastError
+{
+    pub(crate) fn new(index: IxDyn) -> Self
+    {
+        CastError { index }
+    }
+
+    /// Return the index of the first element of the input array, in logical
+    /// order, that could not be converted.
+    pub fn index(&self) -> &[Ix]
+    {
+        self.index.slice()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl Error for CastError {}
+
+impl fmt::Display for CastError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "CastError: the element at index {:?} can not be represented in the target type", self.index())
+    }
+}
+
+/// The operation of an [`ArithmeticError`].
+///
+/// This enumeration is not exhaustive.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ArithmeticOp
+{
+    /// addition, `+`
+    Add,
+    /// subtraction, `-`
+    Sub,
+    /// multiplication, `*`
+    Mul,
+    /// division, `/`
+    Div,
+}
+
+/// An error from a checked elementwise operation, such as
+/// [`checked_add`](crate::ArrayRef::checked_add), when the result of an
+/// element overflows or is a division by zero.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArithmeticError
+{
+    index: IxDyn,
+    op: ArithmeticOp,
+}
+
+impl ArithmeticError
+{
+    pub(crate) fn new(index: IxDyn, op: ArithmeticOp) -> Self
+    {
+        ArithmeticError { index, op }
+    }
+
+    /// Return the index of the first element of the result, in logical
+    /// order, that could not be computed.
+    pub fn index(&self) -> &[Ix]
+    {
+        self.index.slice()
+    }
+
+    /// Return the operation that failed.
+    pub fn op(&self) -> ArithmeticOp
+    {
+        self.op
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl Error for ArithmeticError {}
+
+impl fmt::Display for ArithmeticError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let op = match self.op {
+            ArithmeticOp::Add => "add with overflow",
+            ArithmeticOp::Sub => "subtract with overflow",
+            ArithmeticOp::Mul => "multiply with overflow",
+            ArithmeticOp::Div => "divide with overflow or by zero",
+        };
+        write!(f, "ArithmeticError: attempt to {} at index {:?}", op, self.index())
+    }
+}
+
+/// An error from [`try_mean`](crate::ArrayRef::try_mean) when the number of
+/// elements can not be converted to the element type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MeanError
+{
+    count: usize,
+}
+
+impl MeanError
+{
+    pub(crate) fn new(count: usize) -> Self
+    {
+        MeanError { count }
+    }
+
+    /// Return the number of elements that could not be converted.
+    pub fn count(&self) -> usize
+    {
+        self.count
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl Error for MeanError {}
+
+impl fmt::Display for MeanError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "MeanError: the number of elements {} can not be converted to the element type", self.count)
+    }
+}
@@ -7,6 +7,9 @@
 // except according to those terms.
 
 //! Methods for two-dimensional arrays.
+use num_traits::Zero;
+use std::ops::Add;
+
 use crate::imp_prelude::*;
 
 /// # Methods For 2-D Arrays
@@ -42,6 +45,24 @@ impl<A> ArrayRef<A, Ix2>
     {
         self.index_axis_mut(Axis(0), index)
     }
+
+    /// Return a view of the array rotated by `k` times 90 degrees
+    /// counterclockwise, or clockwise for a negative `k`.
+    ///
+    /// This is [`rot90_in_plane`](ArrayRef::rot90_in_plane) in the plane of
+    /// the rows and columns, and the result is always a view.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// let array = array![[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(array.rot90(1), array![[3, 6], [2, 5], [1, 4]]);
+    /// assert_eq!(array.rot90(2), array![[6, 5, 4], [3, 2, 1]]);
+    /// assert_eq!(array.rot90(-1), array![[4, 1], [5, 2], [6, 3]]);
+    /// ```
+    pub fn rot90(&self, k: isize) -> ArrayView2<'_, A>
+    {
+        self.rot90_in_plane(k, (Axis(0), Axis(1)))
+    }
 }
 
 impl<A> LayoutRef<A, Ix2>
@@ -101,6 +122,49 @@ impl<A> ArrayRef<A, Ix2>
     {
         self.index_axis_mut(Axis(1), index)
     }
+
+    /// Return the sum of the elements on the main diagonal.
+    ///
+    /// For a non-square matrix, this is the sum over the shorter diagonal
+    /// starting at the top left element.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// let array = array![[1., 2., 3.], [4., 5., 6.]];
+    /// assert_eq!(array.trace(), 6.);
+    /// ```
+    pub fn trace(&self) -> A
+    where A: Clone + Zero + Add<Output = A>
+    {
+        self.diag().sum()
+    }
+
+    /// Return the sum of the elements on the `k`-th diagonal.
+    ///
+    /// Like NumPy's `trace` with an `offset`, positive `k` selects a diagonal
+    /// above the main diagonal and negative `k` one below it. The sum is zero
+    /// if the diagonal lies outside the matrix.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// let array = array![[1., 2., 3.], [4., 5., 6.]];
+    /// assert_eq!(array.trace_offset(1), 8.);
+    /// assert_eq!(array.trace_offset(-1), 4.);
+    /// assert_eq!(array.trace_offset(3), 0.);
+    /// ```
+    pub fn trace_offset(&self, k: isize) -> A
+    where A: Clone + Zero + Add<Output = A>
+    {
+        let (m, n) = self.dim();
+        let offset = k.unsigned_abs();
+        if k >= 0 && offset < n {
+            self.slice(s![.., offset..]).diag().sum()
+        } else if k < 0 && offset < m {
+            self.slice(s![offset.., ..]).diag().sum()
+        } else {
+            A::zero()
+        }
+    }
 }
 
 impl<A> LayoutRef<A, Ix2>
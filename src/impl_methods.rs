@@ -2561,6 +2561,115 @@ impl<A, D: Dimension> ArrayRef<A, D>
     {
         self.view().reversed_axes()
     }
+
+    /// Return a view of the array rotated by `k` times 90 degrees in the
+    /// plane of `axes`.
+    ///
+    /// Like NumPy's `rot90`, the rotation is from the first axis towards the
+    /// second, and a negative `k` rotates the other way. Every rotation is a
+    /// view, made by inverting and swapping `axes`, so no elements are copied.
+    ///
+    /// **Panics** if the two axes are the same or out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[[1, 2],
+    ///                 [3, 4]]];
+    /// assert_eq!(a.rot90_in_plane(1, (Axis(1), Axis(2))), array![[[2, 4],
+    ///                                                             [1, 3]]]);
+    /// assert_eq!(a.rot90_in_plane(-1, (Axis(1), Axis(2))), array![[[3, 1],
+    ///                                                              [4, 2]]]);
+    /// ```
+    #[track_caller]
+    pub fn rot90_in_plane(&self, k: isize, axes: (Axis, Axis)) -> ArrayView<'_, A, D>
+    {
+        let (a, b) = axes;
+        assert!(
+            a != b && a.index() < self.ndim() && b.index() < self.ndim(),
+            "rot90_in_plane: the axes must be distinct and in bounds"
+        );
+        let mut view = self.view();
+        match k.rem_euclid(4) {
+            1 => {
+                view.invert_axis(b);
+                view.swap_axes(a.index(), b.index());
+            }
+            2 => {
+                view.invert_axis(a);
+                view.invert_axis(b);
+            }
+            3 => {
+                view.swap_axes(a.index(), b.index());
+                view.invert_axis(b);
+            }
+            _ => {}
+        }
+        view
+    }
+
+    /// Return a view of the array with `axis` reversed.
+    ///
+    /// This is a shorthand for inverting `axis` of `self.view()` with
+    /// [`.invert_axis()`](LayoutRef::invert_axis), for use in expressions.
+    ///
+    /// **Panics** if the axis is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.flipped(Axis(1)), array![[3, 2, 1],
+    ///                                      [6, 5, 4]]);
+    /// assert_eq!(&a.flipped(Axis(0)) - &a, array![[3, 3, 3],
+    ///                                             [-3, -3, -3]]);
+    /// ```
+    #[track_caller]
+    pub fn flipped(&self, axis: Axis) -> ArrayView<'_, A, D>
+    {
+        let mut view = self.view();
+        view.invert_axis(axis);
+        view
+    }
+
+    /// Return a mutable view of the array with `axis` reversed.
+    ///
+    /// **Panics** if the axis is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![1, 2, 3];
+    /// a.flipped_mut(Axis(0))[0] = 0;
+    /// assert_eq!(a, array![1, 2, 0]);
+    /// ```
+    #[track_caller]
+    pub fn flipped_mut(&mut self, axis: Axis) -> ArrayViewMut<'_, A, D>
+    {
+        let mut view = self.view_mut();
+        view.invert_axis(axis);
+        view
+    }
+
+    /// Return a view of the array with every axis reversed.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.flipped_all(), array![[6, 5, 4],
+    ///                                   [3, 2, 1]]);
+    /// ```
+    pub fn flipped_all(&self) -> ArrayView<'_, A, D>
+    {
+        let mut view = self.view();
+        for i in 0..view.ndim() {
+            view.invert_axis(Axis(i));
+        }
+        view
+    }
 }
 
 impl<A, D: Dimension> LayoutRef<A, D>
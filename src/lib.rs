@@ -144,7 +144,16 @@ pub use crate::dimension::{DimAdd, DimMax};
 
 pub use crate::dimension::IxDynImpl;
 pub use crate::dimension::NdIndex;
-pub use crate::error::{ErrorKind, ShapeError};
+pub use crate::error::{
+    ArithmeticError,
+    ArithmeticOp,
+    CastError,
+    ErrorKind,
+    MeanError,
+    OverflowError,
+    ShapeError,
+    StatsError,
+};
 pub use crate::indexes::{indices, indices_of};
 pub use crate::order::Order;
 pub use crate::slice::{MultiSliceArg, NewAxis, Slice, SliceArg, SliceInfo, SliceInfoElem, SliceNextDim};
@@ -1800,6 +1809,24 @@ mod impl_2d;
 mod impl_dyn;
 
 mod numeric;
+#[cfg(feature = "std")]
+pub use crate::numeric::NormOrd;
+#[cfg(feature = "std")]
+pub use crate::numeric::RankMethod;
+#[cfg(feature = "std")]
+pub use crate::numeric::{histogram2d, Bins};
+pub use crate::numeric::{
+    polyval,
+    polyval_inplace,
+    select_where,
+    select_where_scalar,
+    ConvMode,
+    DiffMode,
+    Interpolate,
+    Promote,
+    Rolling,
+    RoundMode,
+};
 
 pub mod linalg;
 
@@ -1829,3 +1856,13 @@ pub(crate) fn is_aligned<T>(ptr: *const T) -> bool
 
 // Triangular constructors
 mod tri;
+
+// Tiling, repeating and moving elements
+mod manipulation;
+
+// Distinct elements
+mod unique;
+pub use crate::unique::Unique;
+
+// Sorting along an axis
+mod sort;
@@ -0,0 +1,448 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use std::ops::Range;
+
+use crate::dimension::size_of_shape_checked;
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+use crate::{Slice, Zip};
+
+/// # Rearranging Elements
+///
+/// These methods build new arrays from the elements of an array, repeated
+/// or moved to other positions, like NumPy's array manipulation functions.
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Return the array repeated `reps[i]` times along each axis `i`.
+    ///
+    /// Like NumPy's `tile`, if `reps` has more elements than the array has
+    /// axes, the array is first extended with leading axes of length one,
+    /// and if it has fewer, `reps` is extended with leading ones. The result
+    /// has the larger of the two numbers of axes, and zero repetitions give
+    /// an axis of length zero.
+    ///
+    /// **Panics** if the number of elements of the result would overflow
+    /// `isize`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1, 2];
+    /// assert_eq!(a.tile(&[3]), array![1, 2, 1, 2, 1, 2].into_dyn());
+    /// assert_eq!(a.tile(&[2, 2]), array![[1, 2, 1, 2],
+    ///                                    [1, 2, 1, 2]].into_dyn());
+    ///
+    /// let b = array![[0, 1],
+    ///                [1, 0]];
+    /// assert_eq!(b.tile(&[1, 2]), array![[0, 1, 0, 1],
+    ///                                    [1, 0, 1, 0]].into_dyn());
+    /// assert_eq!(b.tile(&[0]).shape(), &[2, 0]);
+    /// ```
+    #[track_caller]
+    pub fn tile(&self, reps: &[usize]) -> Array<A, IxDyn>
+    where A: Clone
+    {
+        let ndim = self.ndim().max(reps.len());
+        let mut shape = vec![1; ndim];
+        shape[ndim - self.ndim()..].copy_from_slice(self.shape());
+        let mut counts = vec![1; ndim];
+        counts[ndim - reps.len()..].copy_from_slice(reps);
+        let out_shape = shape
+            .iter()
+            .zip(&counts)
+            .map(|(&n, &r)| n.checked_mul(r))
+            .collect::<Option<Vec<_>>>()
+            .map(|shape| IxDyn(&shape))
+            .filter(|dim| size_of_shape_checked(dim).is_ok())
+            .expect("tile: the shape of the result is too large");
+
+        let mut src = self.view().into_dyn();
+        while src.ndim() < ndim {
+            src.insert_axis_inplace(Axis(0));
+        }
+        let mut out = Array::uninit(out_shape);
+        if out.is_empty() {
+            // Safety: there are no elements to initialize
+            return unsafe { out.assume_init() };
+        }
+        src.assign_to(out.slice_each_axis_mut(|ax| Slice::from(..shape[ax.axis.index()])));
+        // Copy the block that is filled so far along each axis, starting
+        // with the last, so that every copy is of a large block.
+        for k in (0..ndim).rev() {
+            let mut filled = out.view_mut();
+            for (i, &n) in shape[..k].iter().enumerate() {
+                filled.slice_axis_inplace(Axis(i), Slice::from(..n));
+            }
+            let (head, mut tail) = filled.split_at(Axis(k), shape[k]);
+            for mut chunk in tail.axis_chunks_iter_mut(Axis(k), shape[k]) {
+                Zip::from(&mut chunk).and(&head).for_each(|to, from| {
+                    // Safety: the elements of `head` are initialized
+                    to.write(unsafe { from.assume_init_ref() }.clone());
+                });
+            }
+        }
+        // Safety: each block of the result is initialized above
+        unsafe { out.assume_init() }
+    }
+
+    /// Return the array with each subview along `axis` repeated `repeats`
+    /// times in a row.
+    ///
+    /// Unlike [`tile`](Self::tile), which repeats the whole array, this
+    /// repeats each element separately, like NumPy's `repeat`.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if the number of elements of
+    /// the result would overflow `isize`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.repeat(2, Axis(1)), array![[1, 1, 2, 2, 3, 3],
+    ///                                         [4, 4, 5, 5, 6, 6]]);
+    /// assert_eq!(a.repeat(2, Axis(0)), array![[1, 2, 3],
+    ///                                         [1, 2, 3],
+    ///                                         [4, 5, 6],
+    ///                                         [4, 5, 6]]);
+    /// ```
+    #[track_caller]
+    pub fn repeat(&self, repeats: usize, axis: Axis) -> Array<A, D>
+    where A: Clone
+    {
+        let len = self.len_of(axis);
+        repeat_impl(self, axis, len.checked_mul(repeats), |_| repeats)
+    }
+
+    /// Return the array with each subview `i` along `axis` repeated
+    /// `counts[i]` times in a row.
+    ///
+    /// This expands run-length encoded data, with the values in the array
+    /// and the lengths of the runs in `counts`.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the length of `counts` is not
+    /// the length of `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if the number of elements of
+    /// the result would overflow `isize`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let values = array![7, 0, 3];
+    /// let counts = array![2, 0, 3];
+    /// assert_eq!(values.repeat_counts(&counts, Axis(0)), Ok(array![7, 7, 3, 3, 3]));
+    /// ```
+    #[track_caller]
+    pub fn repeat_counts(&self, counts: &ArrayRef<usize, Ix1>, axis: Axis) -> Result<Array<A, D>, ShapeError>
+    where A: Clone
+    {
+        if counts.len() != self.len_of(axis) {
+            return Err(from_kind(ErrorKind::IncompatibleShape));
+        }
+        let total = counts.iter().try_fold(0usize, |sum, &n| sum.checked_add(n));
+        Ok(repeat_impl(self, axis, total, |i| counts[i]))
+    }
+
+    /// Return the array with the elements shifted circularly by `shift`
+    /// along `axis`.
+    ///
+    /// The subview at index `i` along `axis` moves to index `i + shift`,
+    /// modulo the length of the axis, so a negative `shift` moves the
+    /// elements to lower indices. Rolling an axis of length zero gives a
+    /// copy of the array.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.roll(1, Axis(1)), array![[3, 1, 2],
+    ///                                       [6, 4, 5]]);
+    /// assert_eq!(a.roll(-4, Axis(1)), array![[2, 3, 1],
+    ///                                        [5, 6, 4]]);
+    /// assert_eq!(a.roll(1, Axis(0)), array![[4, 5, 6],
+    ///                                       [1, 2, 3]]);
+    /// ```
+    #[track_caller]
+    pub fn roll(&self, shift: isize, axis: Axis) -> Array<A, D>
+    where A: Clone
+    {
+        let len = self.len_of(axis);
+        if len == 0 {
+            return self.to_owned();
+        }
+        let s = roll_amount(shift, len);
+        let mut out = Array::uninit(self.raw_dim());
+        self.slice_axis(axis, Slice::from(..len - s))
+            .assign_to(out.slice_axis_mut(axis, Slice::from(s..)));
+        self.slice_axis(axis, Slice::from(len - s..))
+            .assign_to(out.slice_axis_mut(axis, Slice::from(..s)));
+        // Safety: the two parts of the result are initialized above
+        unsafe { out.assume_init() }
+    }
+
+    /// Shift the elements circularly by `shift` along `axis`, in place.
+    ///
+    /// This is [`roll`](Self::roll) without allocating a new array: the
+    /// subviews along `axis` are reversed as a whole and then in two parts,
+    /// which moves each element twice.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// // move the zero frequency to the center, like an FFT shift
+    /// let mut spectrum = array![0, 1, 2, 3, -3, -2, -1];
+    /// spectrum.roll_inplace(3, Axis(0));
+    /// assert_eq!(spectrum, array![-3, -2, -1, 0, 1, 2, 3]);
+    /// ```
+    #[track_caller]
+    pub fn roll_inplace(&mut self, shift: isize, axis: Axis)
+    {
+        let len = self.len_of(axis);
+        if len == 0 {
+            return;
+        }
+        let s = roll_amount(shift, len);
+        if s == 0 {
+            return;
+        }
+        reverse_axis_range(self.view_mut(), axis, 0, len);
+        reverse_axis_range(self.view_mut(), axis, 0, s);
+        reverse_axis_range(self.view_mut(), axis, s, len);
+    }
+
+    /// Return the array with the elements shifted circularly by `shifts[i]`
+    /// along each axis `axes[i]`.
+    ///
+    /// This gives the same result as calling [`roll`](Self::roll) once for
+    /// each axis, but allocates only the result and copies each element
+    /// once. The shifts of an axis that appears more than once add up.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if `shifts` and `axes` have
+    /// different lengths.
+    ///
+    /// **Panics** if any axis is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.roll_multi(&[1, 1], &[Axis(0), Axis(1)]),
+    ///            Ok(array![[6, 4, 5],
+    ///                      [3, 1, 2]]));
+    /// assert_eq!(a.roll_multi(&[1, 1], &[Axis(1), Axis(1)]), Ok(a.roll(2, Axis(1))));
+    ///
+    /// // an FFT shift over all axes
+    /// let b = array![[0, 1, -1],
+    ///                [1, 2, 0]];
+    /// assert_eq!(b.roll_multi(&[1, 1], &[Axis(0), Axis(1)]),
+    ///            Ok(array![[0, 1, 2],
+    ///                      [-1, 0, 1]]));
+    /// ```
+    #[track_caller]
+    pub fn roll_multi(&self, shifts: &[isize], axes: &[Axis]) -> Result<Array<A, D>, ShapeError>
+    where A: Clone
+    {
+        if shifts.len() != axes.len() {
+            return Err(from_kind(ErrorKind::IncompatibleShape));
+        }
+        let mut amount = vec![0; self.ndim()];
+        for (&shift, &axis) in shifts.iter().zip(axes) {
+            let len = self.len_of(axis);
+            if len > 0 {
+                let s = &mut amount[axis.index()];
+                *s = (*s + roll_amount(shift, len)) % len;
+            }
+        }
+        if self.is_empty() {
+            return Ok(self.to_owned());
+        }
+        // A rolled axis has a length of at least two, so there are fewer
+        // rolled axes than bits in `usize`.
+        let rolled = (0..self.ndim())
+            .filter(|&k| amount[k] != 0)
+            .collect::<Vec<_>>();
+        let mut out = Array::uninit(self.raw_dim());
+        // Each bit of `part` picks one of the two parts of a rolled axis,
+        // which together copy the array one block at a time.
+        for part in 0..1usize << rolled.len() {
+            let mut from = self.view();
+            let mut to = out.view_mut();
+            for (bit, &k) in rolled.iter().enumerate() {
+                let (len, s) = (self.len_of(Axis(k)), amount[k]);
+                if part & (1 << bit) == 0 {
+                    from.slice_axis_inplace(Axis(k), Slice::from(..len - s));
+                    to.slice_axis_inplace(Axis(k), Slice::from(s..));
+                } else {
+                    from.slice_axis_inplace(Axis(k), Slice::from(len - s..));
+                    to.slice_axis_inplace(Axis(k), Slice::from(..s));
+                }
+            }
+            from.assign_to(to);
+        }
+        // Safety: the blocks of the result cover it and are initialized above
+        Ok(unsafe { out.assume_init() })
+    }
+
+    /// Return the array with the elements moved by `offset` along `axis`,
+    /// with `fill` in the positions they leave.
+    ///
+    /// Unlike [`roll`](Self::roll), elements moved past either end of the
+    /// axis are dropped instead of wrapping around, like lagging a time
+    /// series with pandas' `shift`. If `offset` is at least the length of the
+    /// axis, every element is `fill`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 2., 3.],
+    ///                [4., 5., 6.]];
+    /// assert_eq!(a.shift(1, Axis(1), 0.), array![[0., 1., 2.],
+    ///                                           [0., 4., 5.]]);
+    /// assert_eq!(a.shift(-1, Axis(0), f64::NAN).row(0), array![4., 5., 6.]);
+    /// assert_eq!(a.shift(3, Axis(1), 0.), array![[0., 0., 0.],
+    ///                                           [0., 0., 0.]]);
+    /// ```
+    #[track_caller]
+    pub fn shift(&self, offset: isize, axis: Axis, fill: A) -> Array<A, D>
+    where A: Clone
+    {
+        let (from, to, _) = shift_ranges(offset, self.len_of(axis));
+        let mut out = Array::from_elem(self.raw_dim(), fill);
+        out.slice_axis_mut(axis, Slice::from(to))
+            .assign(&self.slice_axis(axis, Slice::from(from)));
+        out
+    }
+
+    /// Move the elements by `offset` along `axis`, in place, with `fill` in
+    /// the positions they leave.
+    ///
+    /// This is [`shift`](Self::shift) without allocating a new array. Lanes
+    /// along `axis` that are contiguous are moved as slices.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut prices = array![10, 11, 13, 12];
+    /// prices.shift_inplace(-2, Axis(0), 0);
+    /// assert_eq!(prices, array![13, 12, 0, 0]);
+    /// ```
+    #[track_caller]
+    pub fn shift_inplace(&mut self, offset: isize, axis: Axis, fill: A)
+    where A: Clone
+    {
+        let len = self.len_of(axis);
+        let (from, _, vacated) = shift_ranges(offset, len);
+        let s = len - from.len();
+        if s == 0 {
+            return;
+        }
+        for mut lane in self.lanes_mut(axis) {
+            if let Some(lane) = lane.as_slice_mut() {
+                if offset > 0 {
+                    lane.rotate_right(s);
+                } else {
+                    lane.rotate_left(s);
+                }
+                lane[vacated.clone()].fill(fill.clone());
+            } else {
+                if offset > 0 {
+                    for i in (s..len).rev() {
+                        lane.swap(i, i - s);
+                    }
+                } else {
+                    for i in s..len {
+                        lane.swap(i - s, i);
+                    }
+                }
+                lane.slice_axis_mut(Axis(0), Slice::from(vacated.clone()))
+                    .fill(fill.clone());
+            }
+        }
+    }
+}
+
+/// Repeat each subview `i` of `a` along `axis` `count(i)` times, for a total
+/// length of `len` along `axis` or an overflow if `None`.
+#[track_caller]
+fn repeat_impl<A, D>(a: &ArrayRef<A, D>, axis: Axis, len: Option<usize>, count: impl Fn(usize) -> usize) -> Array<A, D>
+where
+    A: Clone,
+    D: Dimension,
+{
+    let mut dim = a.raw_dim();
+    let dim = len
+        .map(|len| {
+            dim[axis.index()] = len;
+            dim
+        })
+        .filter(|dim| size_of_shape_checked(dim).is_ok())
+        .expect("repeat: the shape of the result is too large");
+    let mut out = Array::uninit(dim);
+    let mut start = 0;
+    for i in 0..a.len_of(axis) {
+        let n = count(i);
+        let from = a.slice_axis(axis, Slice::from(i..i + 1));
+        Zip::from(out.slice_axis_mut(axis, Slice::from(start..start + n)))
+            .and_broadcast(&from)
+            .for_each(|to, from| {
+                to.write(from.clone());
+            });
+        start += n;
+    }
+    // Safety: the subviews along `axis` of the result are initialized in
+    // order above, for a total of its length along `axis`
+    unsafe { out.assume_init() }
+}
+
+/// Return `shift` modulo `len`, for `len > 0`.
+fn roll_amount(shift: isize, len: usize) -> usize
+{
+    // `len` fits in `isize`, as the length of an axis
+    shift.rem_euclid(len as isize) as usize
+}
+
+/// Return the ranges of an axis of length `len` that `offset` moves
+/// elements from and to, and the range it leaves to be filled.
+fn shift_ranges(offset: isize, len: usize) -> (Range<usize>, Range<usize>, Range<usize>)
+{
+    let s = offset.unsigned_abs().min(len);
+    if offset >= 0 {
+        (0..len - s, s..len, 0..s)
+    } else {
+        (s..len, 0..len - s, len - s..len)
+    }
+}
+
+/// Reverse the order of the subviews of `a` along `axis` in `lo..hi`.
+fn reverse_axis_range<A, D>(mut a: ArrayViewMut<'_, A, D>, axis: Axis, mut lo: usize, mut hi: usize)
+where D: Dimension
+{
+    while lo + 1 < hi {
+        let (left, right) = a.view_mut().split_at(axis, hi - 1);
+        let left = left.slice_axis_move(axis, Slice::from(lo..lo + 1));
+        let right = right.slice_axis_move(axis, Slice::from(..1));
+        Zip::from(left).and(right).for_each(std::mem::swap);
+        lo += 1;
+        hi -= 1;
+    }
+}
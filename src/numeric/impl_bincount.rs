@@ -0,0 +1,110 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::{PrimInt, Unsigned, Zero};
+use std::ops::Add;
+
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// # Counting Non-negative Integers
+impl<A, D> ArrayRef<A, D>
+where
+    A: PrimInt + Unsigned,
+    D: Dimension,
+{
+    /// Return the number of occurrences of each value in the array.
+    ///
+    /// Element `i` of the result is the number of elements equal to `i`, and
+    /// the result has length one more than the largest element (or zero if
+    /// the array is empty).
+    ///
+    /// The elements are counted in a single pass, growing the result when a
+    /// larger value is found, so its size is determined by the largest value
+    /// rather than by the number of elements.
+    ///
+    /// **Panics** if an element can not be converted to `usize`, or if the
+    /// largest element is `usize::MAX`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[0u8, 1, 1], [3, 1, 0]];
+    /// assert_eq!(a.bincount(), array![2, 3, 0, 1]);
+    /// ```
+    #[track_caller]
+    pub fn bincount(&self) -> Array1<usize>
+    {
+        let mut counts = Vec::new();
+        for x in self.iter() {
+            *bin_mut(&mut counts, x) += 1;
+        }
+        Array1::from(counts)
+    }
+
+    /// Return the sum of the weights of each value in the array.
+    ///
+    /// Element `i` of the result is the sum of `weights` at the positions of
+    /// the elements equal to `i`. The result has length one more than the
+    /// largest element, or `minlength` if that is larger. Like
+    /// [`bincount`](Self::bincount), this is a single pass over the elements.
+    ///
+    /// ***Errors*** if `weights` does not have the same shape as the array.
+    ///
+    /// **Panics** if an element can not be converted to `usize`, or if the
+    /// largest element is `usize::MAX`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![0u32, 1, 1, 3];
+    /// let w = array![0.5, 1., 2., 0.25];
+    /// assert_eq!(a.bincount_weighted(&w, 0), Ok(array![0.5, 3., 0., 0.25]));
+    /// assert_eq!(a.bincount_weighted(&w, 6), Ok(array![0.5, 3., 0., 0.25, 0., 0.]));
+    /// ```
+    #[track_caller]
+    pub fn bincount_weighted<W>(&self, weights: &ArrayRef<W, D>, minlength: usize) -> Result<Array1<W>, ShapeError>
+    where W: Clone + Zero + Add<Output = W>
+    {
+        if self.shape() != weights.shape() {
+            return Err(from_kind(ErrorKind::IncompatibleShape));
+        }
+        let mut sums = vec![W::zero(); minlength];
+        Zip::from(self).and(weights).for_each(|x, w| {
+            let sum = bin_mut(&mut sums, x);
+            *sum = sum.clone() + w.clone();
+        });
+        Ok(Array1::from(sums))
+    }
+}
+
+/// Return the bin of `x` in `bins`, first extending `bins` with zeros if it
+/// is too short.
+///
+/// `Vec::resize` grows the capacity geometrically, so extending the bins one
+/// value at a time is still linear in the largest value.
+#[track_caller]
+fn bin_mut<'a, A, T>(bins: &'a mut Vec<T>, x: &A) -> &'a mut T
+where
+    A: PrimInt,
+    T: Clone + Zero,
+{
+    let bin = x
+        .to_usize()
+        .expect("Converting the elements to `usize` must not fail.");
+    if bin >= bins.len() {
+        let len = bin
+            .checked_add(1)
+            .expect("the largest element must be less than `usize::MAX`");
+        bins.resize(len, T::zero());
+    }
+    &mut bins[bin]
+}
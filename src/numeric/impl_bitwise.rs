@@ -0,0 +1,114 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::ops::{BitAnd, BitOr, BitXor, Shl, Shr};
+
+use crate::dimension::DimMax;
+use crate::error::ShapeError;
+use crate::imp_prelude::*;
+use crate::Zip;
+
+macro_rules! bitwise_ops {
+    ($($(#[$meta:meta])* fn $id:ident($trt:ident::$mth:ident))+) => {
+        $($(#[$meta])*
+        pub fn $id<B, E>(&self, rhs: &ArrayRef<B, E>) -> Result<Array<A, <D as DimMax<E>>::Output>, ShapeError>
+        where
+            A: $trt<B, Output = A>,
+            B: Clone,
+            E: Dimension,
+            D: DimMax<E>,
+        {
+            let (lhs, rhs) = self.broadcast_with(rhs)?;
+            Ok(Zip::from(&lhs)
+                .and(&rhs)
+                .map_collect(|a, b| a.clone().$mth(b.clone())))
+        })+
+    };
+}
+
+/// # Bitwise Operations
+///
+/// The operators `&`, `|`, `^`, `<<` and `>>` and the assignment operators
+/// `&=`, `|=`, `^=`, `<<=` and `>>=` apply to arrays of integers elementwise,
+/// with a scalar or an array as the right hand side, and `!` inverts each
+/// element; see the [arithmetic operations](ArrayBase#arithmetic-operations).
+/// Those operators panic if the shapes of the arrays can not be broadcast
+/// together. The methods here are their counterparts for arrays, which
+/// return an error instead, to be used with shapes that come from user data.
+///
+/// Shifts follow the rules of the integer types: shifting by at least the
+/// number of bits of the type panics in debug builds, and in release builds
+/// the shift amount is taken modulo the number of bits.
+///
+/// ```
+/// use ndarray::{array, Array, Axis};
+///
+/// // extract the bit planes of a small 8-bit image, least significant first
+/// let image = array![[0b1010_0001u8, 0b0000_0011],
+///                    [0b1111_1111, 0b0000_0000]];
+/// let shifts = Array::from_iter(0..8u8).into_shape_with_order((8, 1, 1)).unwrap();
+/// let planes = image.shr_elem(&shifts).unwrap() & 1;
+/// assert_eq!(planes.shape(), &[8, 2, 2]);
+/// assert_eq!(planes.index_axis(Axis(0), 0), array![[1, 1],
+///                                                  [1, 0]]);
+/// assert_eq!(planes.index_axis(Axis(0), 7), array![[1, 0],
+///                                                  [1, 0]]);
+///
+/// // the operators with a scalar
+/// let mut masked = &image & 0x0f;
+/// masked |= 0x10;
+/// assert_eq!(masked, array![[0x11, 0x13],
+///                           [0x1f, 0x10]]);
+/// ```
+impl<A, D> ArrayRef<A, D>
+where
+    A: Clone,
+    D: Dimension,
+{
+    bitwise_ops! {
+        /// Return the bitwise and of each element and the corresponding
+        /// element of `rhs`, after broadcasting both to their common shape.
+        ///
+        /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can
+        /// not be broadcast together.
+        fn bitand_elem(BitAnd::bitand)
+        /// Return the bitwise or of each element and the corresponding
+        /// element of `rhs`, after broadcasting both to their common shape.
+        ///
+        /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can
+        /// not be broadcast together.
+        fn bitor_elem(BitOr::bitor)
+        /// Return the bitwise exclusive or of each element and the
+        /// corresponding element of `rhs`, after broadcasting both to their
+        /// common shape.
+        ///
+        /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can
+        /// not be broadcast together.
+        fn bitxor_elem(BitXor::bitxor)
+        /// Return each element shifted left by the corresponding element of
+        /// `rhs`, after broadcasting both to their common shape.
+        ///
+        /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can
+        /// not be broadcast together.
+        ///
+        /// **Panics** in debug builds if a shift amount is at least the
+        /// number of bits of `A`.
+        fn shl_elem(Shl::shl)
+        /// Return each element shifted right by the corresponding element of
+        /// `rhs`, after broadcasting both to their common shape.
+        ///
+        /// Signed integers are shifted arithmetically, keeping their sign.
+        ///
+        /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can
+        /// not be broadcast together.
+        ///
+        /// **Panics** in debug builds if a shift amount is at least the
+        /// number of bits of `A`.
+        fn shr_elem(Shr::shr)
+    }
+}
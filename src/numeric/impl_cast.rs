@@ -0,0 +1,145 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::Float;
+use num_traits::{AsPrimitive, NumCast, ToPrimitive};
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use super::impl_float_maths::round_ties_even;
+use crate::error::CastError;
+use crate::imp_prelude::*;
+
+/// How [`cast_rounded`](ArrayRef::cast_rounded) rounds floats to integers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RoundMode
+{
+    /// To the nearest integer, with half-way cases away from zero, like
+    /// [`f64::round`].
+    Nearest,
+    /// To the nearest integer, with half-way cases to the nearest even
+    /// integer, like NumPy's `rint`.
+    TiesEven,
+    /// To the largest integer less than or equal to the value.
+    Floor,
+    /// To the smallest integer greater than or equal to the value.
+    Ceil,
+    /// Towards zero, like the `as` operator.
+    Trunc,
+}
+
+impl RoundMode
+{
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn round<A: Float>(self, x: A) -> A
+    {
+        match self {
+            RoundMode::Nearest => x.round(),
+            RoundMode::TiesEven => round_ties_even(x),
+            RoundMode::Floor => x.floor(),
+            RoundMode::Ceil => x.ceil(),
+            RoundMode::Trunc => x.trunc(),
+        }
+    }
+}
+
+/// # Element Type Conversions
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Return a new array with each element converted to `B` as with the
+    /// `as` operator.
+    ///
+    /// The conversion never fails, and it is lossy in the same ways as `as`:
+    /// floats are truncated towards zero when converted to integers, values
+    /// out of the range of an integer type saturate to its minimum or
+    /// maximum, `NaN` becomes zero, integers wrap around when converted to a
+    /// narrower integer type, and floats too large for `f32` become infinite.
+    /// Use [`try_cast`](Self::try_cast) to detect elements that can not be
+    /// represented instead.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[-1.5, 2.7],
+    ///                [1e20, f64::NAN]];
+    /// assert_eq!(a.cast::<i32>(), array![[-1, 2],
+    ///                                    [i32::MAX, 0]]);
+    /// assert_eq!(array![1u8, 255].cast::<f32>(), array![1., 255.]);
+    /// ```
+    pub fn cast<B>(&self) -> Array<B, D>
+    where
+        A: AsPrimitive<B>,
+        B: 'static + Copy,
+    {
+        self.map(|x| x.as_())
+    }
+
+    /// Return a new array with each element converted to `B` with
+    /// [`NumCast`], or an error if an element can not be represented in `B`.
+    ///
+    /// Conversions between integer types succeed if and only if the value
+    /// is in the range of `B`. Floats are truncated towards zero when
+    /// converted to integers, and the conversion fails if the truncated
+    /// value is out of range or if the float is `NaN` or infinite.
+    /// Conversions to floats do not fail, although they may round.
+    ///
+    /// ***Errors*** with a [`CastError`] with the index of the first element,
+    /// in logical order, that can not be converted.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[-1.5, 2.7],
+    ///                [1e20, f64::NAN]];
+    /// assert_eq!(a.row(0).try_cast::<i32>(), Ok(array![-1, 2]));
+    /// assert_eq!(a.try_cast::<i32>().unwrap_err().index(), &[1, 0]);
+    /// assert_eq!(array![-1i64, 3].try_cast::<u8>().unwrap_err().index(), &[0]);
+    /// ```
+    pub fn try_cast<B>(&self) -> Result<Array<B, D>, CastError>
+    where
+        A: Clone + ToPrimitive,
+        B: NumCast,
+    {
+        self.checked_map(|x| B::from(x.clone()))
+            .map_err(CastError::new)
+    }
+
+    /// Return a new array with each element rounded to an integer with
+    /// `mode` and converted to `B`, or an error if an element can not be
+    /// represented in `B`.
+    ///
+    /// This is [`try_cast`](Self::try_cast) with the rounding done first,
+    /// for example to quantize floats to `i8` or `u8`. Unlike `try_cast`
+    /// it also fails for non-finite elements when `B` is a float type.
+    ///
+    /// ***Errors*** with a [`CastError`] with the index of the first element,
+    /// in logical order, that is not finite or whose rounded value is out of
+    /// the range of `B`.
+    ///
+    /// ```
+    /// use ndarray::{array, RoundMode};
+    ///
+    /// let a = array![-2.5, -0.2, 0.5, 1.7];
+    /// assert_eq!(a.cast_rounded::<i8>(RoundMode::Nearest), Ok(array![-3, 0, 1, 2]));
+    /// assert_eq!(a.cast_rounded::<i8>(RoundMode::TiesEven), Ok(array![-2, 0, 0, 2]));
+    /// assert_eq!(a.cast_rounded::<i8>(RoundMode::Floor), Ok(array![-3, -1, 0, 1]));
+    /// assert_eq!(a.cast_rounded::<i8>(RoundMode::Ceil), Ok(array![-2, 0, 1, 2]));
+    /// assert_eq!(a.cast_rounded::<u8>(RoundMode::Trunc).unwrap_err().index(), &[0]);
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+    pub fn cast_rounded<B>(&self, mode: RoundMode) -> Result<Array<B, D>, CastError>
+    where
+        A: Float,
+        B: NumCast,
+    {
+        self.checked_map(|&x| if x.is_finite() { B::from(mode.round(x)) } else { None })
+            .map_err(CastError::new)
+    }
+}
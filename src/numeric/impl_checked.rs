@@ -0,0 +1,438 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use num_traits::{CheckedAdd, CheckedMul, One, PrimInt, Signed, Zero};
+
+use crate::dimension::DimMax;
+use crate::error::{ArithmeticError, ArithmeticOp, OverflowError};
+use crate::imp_prelude::*;
+use crate::{IntoDimension, Zip};
+
+macro_rules! checked_ops {
+    ($($(#[$meta:meta])* fn $id:ident / $scalar:ident($mth:ident, $op:ident, $desc:expr))+) => {
+        $($(#[$meta])*
+        #[track_caller]
+        pub fn $id<E>(&self, rhs: &ArrayRef<A, E>) -> Result<Array<A, <D as DimMax<E>>::Output>, ArithmeticError>
+        where
+            E: Dimension,
+            D: DimMax<E>,
+        {
+            let (lhs, rhs) = self.broadcast_with(rhs).unwrap();
+            checked_zip(&lhs, &rhs, ArithmeticOp::$op, |a, b| a.$mth(b))
+        }
+
+        #[doc = concat!("Return each element ", $desc, " `rhs`, or an error if any of the")]
+        /// results overflows.
+        ///
+        /// The index of the error is that of the first element, in logical
+        /// order, whose result overflows.
+        pub fn $scalar(&self, rhs: A) -> Result<Array<A, D>, ArithmeticError>
+        {
+            let rhs = Array::from_elem((), rhs);
+            let rhs = rhs.broadcast(self.raw_dim()).unwrap();
+            checked_zip(&self.view(), &rhs, ArithmeticOp::$op, |a, b| a.$mth(b))
+        })+
+    };
+}
+
+/// # Checked Reductions
+///
+/// These methods are for integer arrays, where the plain reductions wrap
+/// around in release builds and panic in debug builds on overflow. Instead,
+/// they return an [`OverflowError`] with the index of the element at which
+/// the overflow happened.
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Return the sum of all elements in the array, or an error if the sum
+    /// overflows.
+    ///
+    /// The elements are added one at a time in logical order, and the index
+    /// of the error is that of the first element whose addition overflows.
+    /// For signed integers this means that the sum can overflow even if the
+    /// final result would fit in `A`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[100u8, 100],
+    ///                [60, 10]];
+    /// assert_eq!(a.try_sum().unwrap_err().index(), &[1, 0]);
+    /// assert_eq!(a.slice(ndarray::s![.., 1]).try_sum(), Ok(110));
+    /// ```
+    pub fn try_sum(&self) -> Result<A, OverflowError>
+    where A: Clone + Zero + CheckedAdd
+    {
+        self.checked_fold(A::zero(), A::checked_add)
+    }
+
+    /// Return the sums along `axis`, or an error if any of the sums
+    /// overflows.
+    ///
+    /// Each lane is summed one element at a time, and the index of the error
+    /// is that of an element whose addition to the sum of its lane overflows.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[120i8, -100, 0],
+    ///                [5, -28, 127],
+    ///                [-5, -1, 0]];
+    /// assert_eq!(a.try_sum_axis(Axis(1)), Ok(array![20, 104, -6]));
+    /// assert_eq!(a.try_sum_axis(Axis(0)).unwrap_err().index(), &[2, 1]);
+    /// ```
+    #[track_caller]
+    pub fn try_sum_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, OverflowError>
+    where
+        A: Clone + Zero + CheckedAdd,
+        D: RemoveAxis,
+    {
+        self.checked_fold_axis(axis, A::zero, A::checked_add)
+    }
+
+    /// Return the product of all elements in the array, or an error if the
+    /// product overflows.
+    ///
+    /// The elements are multiplied one at a time in logical order, and the
+    /// index of the error is that of the first element whose multiplication
+    /// overflows. The product of an empty array is one.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::from_iter(1..=13i32);
+    /// assert_eq!(a.slice(ndarray::s![..12]).try_product(), Ok(479_001_600));
+    /// assert_eq!(a.try_product().unwrap_err().index(), &[12]);
+    /// ```
+    pub fn try_product(&self) -> Result<A, OverflowError>
+    where A: Clone + One + CheckedMul
+    {
+        self.checked_fold(A::one(), A::checked_mul)
+    }
+
+    /// Return the products along `axis`, or an error if any of the products
+    /// overflows.
+    ///
+    /// Each lane is multiplied one element at a time, and the index of the
+    /// error is that of an element whose multiplication with the product of
+    /// its lane overflows.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[16u8, 2],
+    ///                [17, 3],
+    ///                [1, 4]];
+    /// assert_eq!(a.try_product_axis(Axis(1)), Ok(array![32, 51, 4]));
+    /// assert_eq!(a.try_product_axis(Axis(0)).unwrap_err().index(), &[1, 0]);
+    /// ```
+    #[track_caller]
+    pub fn try_product_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, OverflowError>
+    where
+        A: Clone + One + CheckedMul,
+        D: RemoveAxis,
+    {
+        self.checked_fold_axis(axis, A::one, A::checked_mul)
+    }
+
+    /// Fold all elements in logical order with `f`, which returns `None` on
+    /// overflow.
+    fn checked_fold<F>(&self, init: A, f: F) -> Result<A, OverflowError>
+    where
+        A: Clone,
+        F: Fn(&A, &A) -> Option<A>,
+    {
+        let mut acc = init;
+        if let Some(slc) = self.as_slice() {
+            for (i, x) in slc.iter().enumerate() {
+                acc = f(&acc, x).ok_or_else(|| OverflowError::new(unravel_index(&self.dim, i)))?;
+            }
+        } else {
+            for (index, x) in self.indexed_iter() {
+                acc = f(&acc, x).ok_or_else(|| OverflowError::new(IxDyn(index.into_dimension().slice())))?;
+            }
+        }
+        Ok(acc)
+    }
+
+    /// Fold the lanes along `axis` with `f`, which returns `None` on
+    /// overflow, starting each lane from `init()`.
+    #[track_caller]
+    fn checked_fold_axis<I, F>(&self, axis: Axis, init: I, f: F) -> Result<Array<A, D::Smaller>, OverflowError>
+    where
+        A: Clone,
+        D: RemoveAxis,
+        I: Fn() -> A,
+        F: Fn(&A, &A) -> Option<A>,
+    {
+        let min_stride_axis = self.dim.min_stride_axis(&self.strides);
+        let res_dim = self.raw_dim().remove_axis(axis);
+        let mut res = Array::from_shape_simple_fn(res_dim.clone(), &init);
+        // `res` is in standard layout, so its index follows from the position
+        // in the iteration
+        if axis == min_stride_axis {
+            for (i, (acc, lane)) in res.iter_mut().zip(self.lanes(axis)).enumerate() {
+                match lane.checked_fold(init(), &f) {
+                    Ok(x) => *acc = x,
+                    Err(e) => {
+                        let index = insert_index(unravel_index(&res_dim, i), axis, e.index()[0]);
+                        return Err(OverflowError::new(index));
+                    }
+                }
+            }
+        } else {
+            for (k, subview) in self.axis_iter(axis).enumerate() {
+                for (i, (acc, x)) in res.iter_mut().zip(&subview).enumerate() {
+                    match f(acc, x) {
+                        Some(x) => *acc = x,
+                        None => {
+                            let index = insert_index(unravel_index(&res_dim, i), axis, k);
+                            return Err(OverflowError::new(index));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(res)
+    }
+}
+
+/// # Integer Powers
+impl<A, D> ArrayRef<A, D>
+where
+    A: PrimInt,
+    D: Dimension,
+{
+    /// Raise each element to the power `n`.
+    ///
+    /// Like the `pow` method of the integer types, this panics on overflow in
+    /// debug builds and wraps around in release builds; use
+    /// [`try_pow`](Self::try_pow) to detect overflow. Zero to the power of
+    /// zero is one.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[-3, 0],
+    ///                [2, 10]];
+    /// assert_eq!(a.pow(3), array![[-27, 0],
+    ///                             [8, 1000]]);
+    /// assert_eq!(a.pow(0), array![[1, 1],
+    ///                             [1, 1]]);
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn pow(&self, n: u32) -> Array<A, D>
+    {
+        self.mapv(|x| x.pow(n))
+    }
+
+    /// Raise each element to the power `n`, in place.
+    ///
+    /// This overflows like [`pow`](Self::pow).
+    pub fn pow_inplace(&mut self, n: u32)
+    {
+        self.mapv_inplace(|x| x.pow(n))
+    }
+
+    /// Raise each element to the power `n`, or return an error if any of the
+    /// powers overflows.
+    ///
+    /// The index of the error is that of the first element, in logical
+    /// order, whose power overflows.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[2u8, 3],
+    ///                [7, 1]];
+    /// assert_eq!(a.row(0).try_pow(5), Ok(array![32, 243]));
+    /// assert_eq!(a.try_pow(5).unwrap_err().index(), &[1, 0]);
+    /// ```
+    pub fn try_pow(&self, n: u32) -> Result<Array<A, D>, OverflowError>
+    {
+        self.checked_map(|x| num_traits::checked_pow(*x, n as usize))
+            .map_err(OverflowError::new)
+    }
+}
+
+/// # Integer Absolute Values
+impl<A, D> ArrayRef<A, D>
+where
+    A: PrimInt + Signed,
+    D: Dimension,
+{
+    /// Return the absolute value of each element, or an error if it is out
+    /// of range.
+    ///
+    /// Only the minimum of the element type, such as `i32::MIN`, has an
+    /// absolute value that is out of range. The index of the error is that
+    /// of the first such element, in logical order. For floats, use
+    /// [`abs`](Self::abs).
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![-3, 0, 2].try_abs(), Ok(array![3, 0, 2]));
+    /// assert_eq!(array![[1, i8::MIN]].try_abs().unwrap_err().index(), &[0, 1]);
+    /// ```
+    pub fn try_abs(&self) -> Result<Array<A, D>, OverflowError>
+    {
+        self.checked_map(|&x| if x == A::min_value() { None } else { Some(x.abs()) })
+            .map_err(OverflowError::new)
+    }
+}
+
+/// # Checked Arithmetic
+///
+/// These methods are for integer arrays, where the arithmetic operators wrap
+/// around in release builds and panic in debug builds on overflow. Instead,
+/// they return an [`ArithmeticError`] with the index of the first element of
+/// the result, in logical order, that overflows, or for a division, whose
+/// divisor is zero.
+///
+/// The right hand side is a scalar, or an array that is broadcast with the
+/// array like with the arithmetic operators.
+///
+/// ```
+/// use ndarray::{array, ArithmeticOp};
+///
+/// let a = array![[100u8, 220],
+///                [50, 0]];
+/// let b = array![100, 50];
+/// let err = a.checked_add(&b).unwrap_err();
+/// assert_eq!((err.index(), err.op()), (&[0, 1][..], ArithmeticOp::Add));
+/// assert_eq!(a.checked_sub_scalar(50).unwrap_err().index(), &[1, 1]);
+/// assert_eq!(a.column(0).checked_mul_scalar(2), Ok(array![200, 100]));
+/// ```
+impl<A, D> ArrayRef<A, D>
+where
+    A: PrimInt,
+    D: Dimension,
+{
+    checked_ops! {
+        /// Return the sum of each element and the corresponding element of
+        /// `rhs`, after broadcasting both to their common shape, or an error
+        /// if any of the sums overflows.
+        ///
+        /// The index of the error is that of the first element of the
+        /// result, in logical order, whose sum overflows.
+        ///
+        /// **Panics** if the shapes of the arrays can not be broadcast
+        /// together, like the arithmetic operators.
+        fn checked_add / checked_add_scalar(checked_add, Add, "plus")
+        /// Return the difference of each element and the corresponding
+        /// element of `rhs`, after broadcasting both to their common shape,
+        /// or an error if any of the differences overflows.
+        ///
+        /// The index of the error is that of the first element of the
+        /// result, in logical order, whose difference overflows.
+        ///
+        /// **Panics** if the shapes of the arrays can not be broadcast
+        /// together, like the arithmetic operators.
+        fn checked_sub / checked_sub_scalar(checked_sub, Sub, "minus")
+        /// Return the product of each element and the corresponding element
+        /// of `rhs`, after broadcasting both to their common shape, or an
+        /// error if any of the products overflows.
+        ///
+        /// The index of the error is that of the first element of the
+        /// result, in logical order, whose product overflows.
+        ///
+        /// **Panics** if the shapes of the arrays can not be broadcast
+        /// together, like the arithmetic operators.
+        fn checked_mul / checked_mul_scalar(checked_mul, Mul, "times")
+        /// Return the quotient of each element and the corresponding element
+        /// of `rhs`, after broadcasting both to their common shape, or an
+        /// error if any of the divisors is zero or any of the quotients
+        /// overflows.
+        ///
+        /// The index of the error is that of the first element of the
+        /// result, in logical order, whose quotient can not be computed.
+        ///
+        /// **Panics** if the shapes of the arrays can not be broadcast
+        /// together, like the arithmetic operators.
+        fn checked_div / checked_div_scalar(checked_div, Div, "divided by")
+    }
+}
+
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Map all elements in logical order with `f`, or return the index of
+    /// the first element for which it returns `None`.
+    pub(crate) fn checked_map<B, F>(&self, f: F) -> Result<Array<B, D>, IxDyn>
+    where F: Fn(&A) -> Option<B>
+    {
+        let mut res = Vec::with_capacity(self.len());
+        if let Some(slc) = self.as_slice() {
+            for (i, x) in slc.iter().enumerate() {
+                res.push(f(x).ok_or_else(|| unravel_index(&self.dim, i))?);
+            }
+        } else {
+            for (index, x) in self.indexed_iter() {
+                res.push(f(x).ok_or_else(|| IxDyn(index.into_dimension().slice()))?);
+            }
+        }
+        Ok(Array::from_shape_vec(self.raw_dim(), res).unwrap())
+    }
+}
+
+/// Apply `f` to the pairs of elements of `a` and `b`.
+///
+/// The elements are visited in memory order, and only if `f` fails is the
+/// first failure in logical order looked up in a second pass.
+fn checked_zip<A, D>(
+    a: &ArrayView<'_, A, D>, b: &ArrayView<'_, A, D>, op: ArithmeticOp, f: impl Fn(&A, &A) -> Option<A>,
+) -> Result<Array<A, D>, ArithmeticError>
+where
+    A: Zero,
+    D: Dimension,
+{
+    let mut failed = false;
+    let res = Zip::from(a).and(b).map_collect(|x, y| {
+        f(x, y).unwrap_or_else(|| {
+            failed = true;
+            A::zero()
+        })
+    });
+    if failed {
+        let (index, _) = a
+            .indexed_iter()
+            .zip(b)
+            .find(|((_, x), y)| f(x, y).is_none())
+            .unwrap()
+            .0;
+        return Err(ArithmeticError::new(IxDyn(index.into_dimension().slice()), op));
+    }
+    Ok(res)
+}
+
+/// Return the index of element `i` in logical order of an array with shape
+/// `dim`.
+fn unravel_index<D: Dimension>(dim: &D, mut i: usize) -> IxDyn
+{
+    let mut index = IxDyn::zeros(dim.ndim());
+    for (ix, &len) in index.slice_mut().iter_mut().zip(dim.slice()).rev() {
+        *ix = i % len;
+        i /= len;
+    }
+    index
+}
+
+/// Return `index` of a lane along `axis`, extended with the position `k` in
+/// the lane.
+fn insert_index(index: IxDyn, axis: Axis, k: usize) -> IxDyn
+{
+    let mut index = index.insert_axis(axis);
+    index[axis.index()] = k;
+    index
+}
@@ -0,0 +1,157 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Float;
+
+use crate::dimension::DimMax;
+use crate::error::ShapeError;
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// # Comparing Float Arrays
+///
+/// These methods compare the array with `other` elementwise, after
+/// broadcasting both to their common shape like the arithmetic operators do,
+/// and return a [`ShapeError`] if the shapes can not be broadcast together.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    /// Return the largest absolute difference `|a - b|` between the elements
+    /// of the array and `other`, or `None` if they are empty.
+    ///
+    /// The differences are computed in a single pass, without allocating. If
+    /// any difference is NaN, which includes the difference between two
+    /// infinities of the same sign, the result is NaN.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2.], [3., 4.]];
+    /// let b = array![[1.5, 2.], [3., 1.]];
+    /// assert_eq!(a.abs_diff_max(&b), Ok(Some(3.)));
+    /// let row = array![2., 4.];
+    /// assert_eq!(a.abs_diff_max(&row), Ok(Some(2.)));
+    /// let col = array![1., 2., 3.];
+    /// assert!(a.abs_diff_max(&col).is_err());
+    /// ```
+    pub fn abs_diff_max<E>(&self, other: &ArrayRef<A, E>) -> Result<Option<A>, ShapeError>
+    where
+        E: Dimension,
+        D: DimMax<E>,
+    {
+        let (a, b) = self.broadcast_with(other)?;
+        if a.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Zip::from(&a).and(&b).fold(A::zero(), |max, &a, &b| {
+            let diff = (a - b).abs();
+            if max.is_nan() {
+                max
+            } else if diff.is_nan() || diff > max {
+                diff
+            } else {
+                max
+            }
+        })))
+    }
+
+    /// Return `true` if all elements of the array are close to the
+    /// corresponding elements of `other`.
+    ///
+    /// Like NumPy's `allclose`, elements `a` and `b` are close if
+    ///
+    /// ```text
+    /// |a - b| <= atol + rtol * |b|
+    /// ```
+    ///
+    /// Infinities are only close to an infinity of the same sign. NaN is
+    /// never close to anything, unless `equal_nan` is true, in which case
+    /// two NaNs are close to each other. Note that the comparison is not
+    /// symmetric, since `rtol` is relative to the elements of `other`.
+    ///
+    /// This stops at the first pair of elements that are not close.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1e10, 1e-7, f64::NAN];
+    /// let b = array![1.00001e10, 1e-8, f64::NAN];
+    /// assert_eq!(a.allclose(&b, 1e-5, 1e-8, false), Ok(false));
+    /// assert_eq!(a.allclose(&b, 1e-5, 1e-7, true), Ok(true));
+    /// let c = array![1., 2.];
+    /// assert!(a.allclose(&c, 1e-5, 1e-8, true).is_err());
+    /// ```
+    pub fn allclose<E>(&self, other: &ArrayRef<A, E>, rtol: A, atol: A, equal_nan: bool) -> Result<bool, ShapeError>
+    where
+        E: Dimension,
+        D: DimMax<E>,
+    {
+        let (a, b) = self.broadcast_with(other)?;
+        Ok(Zip::from(&a)
+            .and(&b)
+            .all(|&a, &b| is_close(a, b, rtol, atol, equal_nan)))
+    }
+
+    /// Return a boolean array with the result of comparing each element of
+    /// the array with the corresponding element of `other`, in their common
+    /// broadcast shape.
+    ///
+    /// The elements are compared like for [`allclose`](Self::allclose),
+    /// which is the same as checking that all elements of the result are
+    /// `true`.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2.],
+    ///                [3., f64::INFINITY]];
+    /// let b = array![1.0000001, f64::INFINITY];
+    /// assert_eq!(a.isclose(&b, 1e-5, 1e-8, false),
+    ///            Ok(array![[true, false],
+    ///                      [false, true]]));
+    /// ```
+    pub fn isclose<E>(
+        &self, other: &ArrayRef<A, E>, rtol: A, atol: A, equal_nan: bool,
+    ) -> Result<Array<bool, <D as DimMax<E>>::Output>, ShapeError>
+    where
+        E: Dimension,
+        D: DimMax<E>,
+    {
+        let (a, b) = self.broadcast_with(other)?;
+        Ok(Zip::from(&a)
+            .and(&b)
+            .map_collect(|&a, &b| is_close(a, b, rtol, atol, equal_nan)))
+    }
+}
+
+/// Return whether `a` is close to `b`, see
+/// [`allclose`](ArrayRef::allclose).
+fn is_close<A: Float>(a: A, b: A, rtol: A, atol: A, equal_nan: bool) -> bool
+{
+    if a == b {
+        true
+    } else if a.is_nan() || b.is_nan() {
+        equal_nan && a.is_nan() && b.is_nan()
+    } else if a.is_infinite() || b.is_infinite() {
+        false
+    } else {
+        (a - b).abs() <= atol + rtol * b.abs()
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::dimension::DimMax;
+use crate::error::ShapeError;
+use crate::imp_prelude::*;
+use crate::Zip;
+
+macro_rules! comparison_ops {
+    ($($(#[$meta:meta])* fn $id:ident / $scalar:ident($trt:ident, $op:tt, $desc:expr))+) => {
+        $($(#[$meta])*
+        pub fn $id<E>(&self, rhs: &ArrayRef<A, E>) -> Result<Array<bool, <D as DimMax<E>>::Output>, ShapeError>
+        where
+            A: $trt,
+            E: Dimension,
+            D: DimMax<E>,
+        {
+            let (a, b) = self.broadcast_with(rhs)?;
+            Ok(Zip::from(&a).and(&b).map_collect(|a, b| a $op b))
+        }
+
+        #[doc = concat!("Return the mask of the elements that are ", $desc, " `x`.")]
+        #[must_use = "method returns a new array and does not mutate the original value"]
+        pub fn $scalar(&self, x: A) -> Array<bool, D>
+        where A: $trt
+        {
+            self.map(|a| *a $op x)
+        })+
+    };
+}
+
+/// # Elementwise Comparisons
+///
+/// These methods compare the array elementwise with another array, after
+/// broadcasting both to their common shape like the arithmetic operators do,
+/// or with a scalar, and return the boolean mask of the results. The masks
+/// can be combined with the [logical operations](ArrayRef::and) and used with
+/// the masked methods, like NumPy's `a[(a > 0) & (b < 1)]`.
+///
+/// The comparisons are those of [`PartialOrd`] and [`PartialEq`], so for
+/// floats every comparison with `NaN` is `false`, except for
+/// [`ne_elem`](Self::ne_elem), which is `true`, and `a.le(b)` is not the
+/// same as `!a.gt(b)`.
+///
+/// ```
+/// use ndarray::array;
+///
+/// let a = array![[1., -2., 3.],
+///                [f64::NAN, 5., -6.]];
+/// let b = array![0., 0., 4.];
+/// let mask = a.gt_scalar(0.).and(&b.lt_scalar(1.)).unwrap();
+/// assert_eq!(mask, array![[true, false, false],
+///                         [false, true, false]]);
+/// assert_eq!(a.le(&b), Ok(array![[false, true, true],
+///                                [false, false, true]]));
+/// ```
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    comparison_ops! {
+        /// Return the mask of the elements that are greater than the
+        /// corresponding elements of `rhs`.
+        ///
+        /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can
+        /// not be broadcast together.
+        fn gt / gt_scalar(PartialOrd, >, "greater than")
+        /// Return the mask of the elements that are greater than or equal to
+        /// the corresponding elements of `rhs`.
+        ///
+        /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can
+        /// not be broadcast together.
+        fn ge / ge_scalar(PartialOrd, >=, "greater than or equal to")
+        /// Return the mask of the elements that are less than the
+        /// corresponding elements of `rhs`.
+        ///
+        /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can
+        /// not be broadcast together.
+        fn lt / lt_scalar(PartialOrd, <, "less than")
+        /// Return the mask of the elements that are less than or equal to
+        /// the corresponding elements of `rhs`.
+        ///
+        /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can
+        /// not be broadcast together.
+        fn le / le_scalar(PartialOrd, <=, "less than or equal to")
+        /// Return the mask of the elements that are equal to the
+        /// corresponding elements of `rhs`.
+        ///
+        /// This is named so as not to be confused with [`PartialEq::eq`],
+        /// which compares whole arrays.
+        ///
+        /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can
+        /// not be broadcast together.
+        fn eq_elem / eq_scalar(PartialEq, ==, "equal to")
+        /// Return the mask of the elements that are not equal to the
+        /// corresponding elements of `rhs`.
+        ///
+        /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can
+        /// not be broadcast together.
+        fn ne_elem / ne_scalar(PartialEq, !=, "not equal to")
+    }
+}
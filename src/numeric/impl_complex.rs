@@ -0,0 +1,158 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_complex::Complex;
+use num_traits::{Float, FromPrimitive};
+
+use super::impl_numeric::check_ddof;
+use crate::error::StatsError;
+use crate::imp_prelude::*;
+
+/// # Variance of Complex Arrays
+///
+/// The variance of complex elements is the mean squared magnitude of their
+/// deviations from the mean,
+///
+/// ```text
+///               1       n
+/// variance = ――――――――   ∑ |xᵢ - x̅|²
+///            n - ddof  i=1
+/// ```
+///
+/// which is real, and equal to the sum of the variances of the real and
+/// imaginary parts. It is computed with the same [Welford one-pass
+/// algorithm](https://www.jstor.org/stable/1266577) as
+/// [`var`](ArrayRef::var).
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<T, D> ArrayRef<Complex<T>, D>
+where
+    T: Float + FromPrimitive,
+    D: Dimension,
+{
+    /// Return the variance of the complex elements in the array.
+    ///
+    /// The variance of an empty array is NaN.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than `n`, or if
+    /// `T::from_usize()` fails for any of the numbers in the range `0..=n`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![Complex64::new(1., 0.), Complex64::new(-1., 0.),
+    ///                Complex64::new(0., 1.), Complex64::new(0., -1.)];
+    /// assert_eq!(a.var_complex(0.), 1.);
+    /// ```
+    #[track_caller]
+    pub fn var_complex(&self, ddof: T) -> T
+    {
+        let dof = match check_ddof(ddof, self.len()) {
+            Ok(dof) => dof,
+            Err(StatsError::EmptyInput) => return T::nan(),
+            Err(error) => panic!("{}", error),
+        };
+        let mut mean = Complex::new(T::zero(), T::zero());
+        let mut sum_sq = T::zero();
+        let mut i = 0;
+        self.for_each(|&x| {
+            i += 1;
+            let count = T::from_usize(i).expect("Converting index to `T` must not fail.");
+            welford_update(&mut mean, &mut sum_sq, x, count);
+        });
+        sum_sq / dof
+    }
+
+    /// Return the standard deviation of the complex elements in the array,
+    /// the square root of [`var_complex`](Self::var_complex).
+    ///
+    /// **Panics** under the same conditions as
+    /// [`var_complex`](Self::var_complex).
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![Complex64::new(3., 0.), Complex64::new(0., 4.)];
+    /// assert_eq!(a.std_complex(0.), 2.5);
+    /// ```
+    #[track_caller]
+    pub fn std_complex(&self, ddof: T) -> T
+    {
+        self.var_complex(ddof).sqrt()
+    }
+
+    /// Return the variance of the complex elements along `axis`.
+    ///
+    /// Lanes of length zero have a variance of NaN.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than `n`, if `axis`
+    /// is out of bounds, or if `T::from_usize()` fails for any of the numbers
+    /// in the range `0..=n`, where `n` is the length of the axis.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![[Complex64::new(1., 1.), Complex64::new(2., 0.)],
+    ///                [Complex64::new(-1., -1.), Complex64::new(2., 0.)]];
+    /// assert_eq!(a.var_axis_complex(Axis(0), 0.), array![2., 0.]);
+    /// ```
+    #[track_caller]
+    pub fn var_axis_complex(&self, axis: Axis, ddof: T) -> Array<T, D::Smaller>
+    where D: RemoveAxis
+    {
+        let dof = match check_ddof(ddof, self.len_of(axis)) {
+            Ok(dof) => dof,
+            Err(StatsError::EmptyInput) => return Array::from_elem(self.dim.remove_axis(axis), T::nan()),
+            Err(error) => panic!("{}", error),
+        };
+        let mut mean = Array::from_elem(self.dim.remove_axis(axis), Complex::new(T::zero(), T::zero()));
+        let mut sum_sq = Array::<T, _>::zeros(self.dim.remove_axis(axis));
+        for (i, subview) in self.axis_iter(axis).enumerate() {
+            let count = T::from_usize(i + 1).expect("Converting index to `T` must not fail.");
+            azip!((mean in &mut mean, sum_sq in &mut sum_sq, &x in &subview) {
+                welford_update(mean, sum_sq, x, count);
+            });
+        }
+        sum_sq.mapv_into(|s| s / dof)
+    }
+
+    /// Return the standard deviation of the complex elements along `axis`,
+    /// the square root of [`var_axis_complex`](Self::var_axis_complex).
+    ///
+    /// **Panics** under the same conditions as
+    /// [`var_axis_complex`](Self::var_axis_complex).
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![[Complex64::new(0., 1.), Complex64::new(0., -1.)]];
+    /// assert_eq!(a.std_axis_complex(Axis(1), 0.), array![1.]);
+    /// ```
+    #[track_caller]
+    pub fn std_axis_complex(&self, axis: Axis, ddof: T) -> Array<T, D::Smaller>
+    where D: RemoveAxis
+    {
+        self.var_axis_complex(axis, ddof).mapv_into(|x| x.sqrt())
+    }
+}
+
+/// Add `x`, the `count`th element, to the running `mean` and sum of squared
+/// deviations `sum_sq`.
+fn welford_update<T: Float>(mean: &mut Complex<T>, sum_sq: &mut T, x: Complex<T>, count: T)
+{
+    let delta = x - *mean;
+    *mean = *mean + delta / count;
+    let delta_new = x - *mean;
+    // the real part of delta_new * conj(delta)
+    *sum_sq = delta_new
+        .re
+        .mul_add(delta.re, delta_new.im.mul_add(delta.im, *sum_sq));
+}
@@ -0,0 +1,241 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::Float;
+use std::cmp::min;
+use std::ops::Range;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::error::StatsError;
+use crate::imp_prelude::*;
+use crate::numeric_util;
+use crate::{LinalgScalar, Slice, Zip};
+
+/// The part of the full discrete convolution (or correlation) that is
+/// computed by [`convolve`](ArrayRef::convolve) and
+/// [`correlate`](ArrayRef::correlate).
+///
+/// The lengths are for an axis of length `n` and a kernel of length `m`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConvMode
+{
+    /// Only the positions where the kernel lies entirely within the array,
+    /// `n - m + 1` elements (or none if `m > n`).
+    Valid,
+    /// `n` elements from the middle of the full output, starting at its
+    /// element `(m - 1) / 2`. With [`convolve`](ArrayRef::convolve), element
+    /// `i` is where element `(m - 1) / 2` of the kernel meets element `i` of
+    /// the array.
+    Same,
+    /// Every position where the kernel overlaps with the array, `n + m - 1`
+    /// elements.
+    Full,
+}
+
+impl ConvMode
+{
+    /// Return the length of the output and the index of its first element
+    /// in the full output.
+    fn len_and_offset(self, n: usize, m: usize) -> (usize, usize)
+    {
+        match self {
+            ConvMode::Valid => ((n + 1).saturating_sub(m), m - 1),
+            ConvMode::Same => (n, (m - 1) / 2),
+            ConvMode::Full => (n + m - 1, 0),
+        }
+    }
+}
+
+/// # Convolution
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Return the discrete convolution of each lane along `axis` with the
+    /// one-dimensional `kernel`.
+    ///
+    /// Element `k` of the full convolution of a lane `x` is the sum of
+    /// `kernel[j] * x[k - j]` over the `j` for which `x[k - j]` exists; `mode`
+    /// selects which part of it is returned, see [`ConvMode`]. The result has
+    /// the same shape as the array, except along `axis`. This is
+    /// `numpy.convolve` applied along an axis, except that with
+    /// [`ConvMode::Valid`] and a kernel longer than the axis the result is
+    /// empty rather than the arguments being swapped.
+    ///
+    /// Each element of the result is computed directly, in time proportional
+    /// to the length of the kernel.
+    ///
+    /// **Panics** if `kernel` is empty or if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, ConvMode};
+    ///
+    /// let a = array![[1., 2., 3., 4.],
+    ///                [0., 1., 0., 0.]];
+    /// let kernel = array![1., 0., -1.];
+    /// assert_eq!(a.convolve(&kernel, Axis(1), ConvMode::Valid), array![[2., 2.],
+    ///                                                                  [0., -1.]]);
+    /// assert_eq!(a.convolve(&kernel, Axis(1), ConvMode::Same), array![[2., 2., 2., -3.],
+    ///                                                                 [1., 0., -1., 0.]]);
+    /// assert_eq!(a.convolve(&kernel, Axis(1), ConvMode::Full).shape(), &[2, 6]);
+    /// ```
+    #[track_caller]
+    pub fn convolve(&self, kernel: &ArrayRef<A, Ix1>, axis: Axis, mode: ConvMode) -> Array<A, D>
+    where A: LinalgScalar
+    {
+        let weights: Vec<A> = kernel.iter().rev().cloned().collect();
+        correlate_lanes(self, &weights, axis, mode)
+    }
+
+    /// Return the discrete cross-correlation of each lane along `axis` with
+    /// the one-dimensional `kernel`.
+    ///
+    /// This is [`convolve`](Self::convolve) without flipping the kernel:
+    /// element `k` of the full correlation of a lane `x` is the sum of
+    /// `kernel[j] * x[k + j - (m - 1)]`, where `m` is the length of the
+    /// kernel, like `numpy.correlate`.
+    ///
+    /// **Panics** if `kernel` is empty or if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, ConvMode};
+    ///
+    /// let a = array![1., 2., 3., 4.];
+    /// let kernel = array![1., 0., -1.];
+    /// assert_eq!(a.correlate(&kernel, Axis(0), ConvMode::Valid), array![-2., -2.]);
+    /// assert_eq!(a.correlate(&kernel, Axis(0), ConvMode::Full), array![-1., -2., -2., -2., 3., 4.]);
+    /// ```
+    #[track_caller]
+    pub fn correlate(&self, kernel: &ArrayRef<A, Ix1>, axis: Axis, mode: ConvMode) -> Array<A, D>
+    where A: LinalgScalar
+    {
+        let weights: Vec<A> = kernel.iter().cloned().collect();
+        correlate_lanes(self, &weights, axis, mode)
+    }
+}
+
+/// # Cross-Correlation of 1-D Arrays
+impl<A> ArrayRef<A, Ix1>
+{
+    /// Return the cross-correlation of the array with `other`, optionally
+    /// normalized.
+    ///
+    /// This is [`correlate`](Self::correlate) with `other` as the kernel:
+    /// for an array `x` of length `n` and `other` `y` of length `m`, the
+    /// correlation at lag `l` is the sum of `x[j + l] * y[j]` over the `j`
+    /// for which both exist, so at a positive lag `y` is compared with `x`
+    /// shifted `l` elements to the left, and `x` is a copy of `y` delayed by
+    /// `l` if the correlation peaks at lag `l`. [`ConvMode::Full`] returns
+    /// the lags `1 - m..n` in order, [`ConvMode::Valid`] the lags `0..=n - m`
+    /// and [`ConvMode::Same`] the `n` lags starting at `-(m / 2)`.
+    ///
+    /// If `normalized` is true, the correlation at each lag is divided by
+    /// the norms of the two overlapping windows, so that it is between `-1`
+    /// and `1` (and `NaN` where a window is all zeros).
+    ///
+    /// The correlation is computed directly, in time proportional to `n * m`.
+    ///
+    /// ***Errors*** with [`StatsError::EmptyInput`] if either array is
+    /// empty.
+    ///
+    /// ```
+    /// use ndarray::{array, ConvMode};
+    ///
+    /// let signal = array![0., 0., 1., 2., 1., 0.];
+    /// let pulse = array![1., 2., 1.];
+    /// // the pulse starts at element 2 of the signal, lag 2 is element 4
+    /// let c = signal.cross_correlate(&pulse, ConvMode::Full, true).unwrap();
+    /// assert_eq!(c.len(), 8);
+    /// assert_eq!(c[4], 1.);
+    /// let c = signal.cross_correlate(&pulse, ConvMode::Valid, false).unwrap();
+    /// assert_eq!(c, array![1., 4., 6., 4.]);
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+    pub fn cross_correlate(
+        &self, other: &ArrayRef<A, Ix1>, mode: ConvMode, normalized: bool,
+    ) -> Result<Array1<A>, StatsError>
+    where A: Float + LinalgScalar
+    {
+        if self.is_empty() || other.is_empty() {
+            return Err(StatsError::EmptyInput);
+        }
+        let xs = self.to_vec();
+        let ys = other.to_vec();
+        let (n, m) = (xs.len(), ys.len());
+        let (len, offset) = mode.len_and_offset(n, m);
+        Ok(Array::from_shape_fn(len, |i| {
+            let (x, y) = overlap(i + offset, n, m);
+            let (x, y) = (&xs[x], &ys[y]);
+            let dot = numeric_util::unrolled_dot(x, y);
+            if !normalized {
+                return dot;
+            }
+            let norms = numeric_util::unrolled_dot(x, x).sqrt() * numeric_util::unrolled_dot(y, y).sqrt();
+            // rounding can take the quotient just outside of [-1, 1]
+            let r = dot / norms;
+            if r > A::one() {
+                A::one()
+            } else if r < -A::one() {
+                -A::one()
+            } else {
+                r
+            }
+        }))
+    }
+}
+
+/// Return the ranges of the elements of an array of length `n` and of the
+/// weights of length `m` that are multiplied for element `k` of their full
+/// correlation: the elements `k + 1 - m..=k` of the array, clipped to the
+/// array.
+fn overlap(k: usize, n: usize, m: usize) -> (Range<usize>, Range<usize>)
+{
+    let start = (k + 1).saturating_sub(m);
+    let end = min(k + 1, n);
+    (start..end, start + m - 1 - k..end + m - 1 - k)
+}
+
+/// Correlate each lane of `a` along `axis` with `weights`.
+#[track_caller]
+fn correlate_lanes<A, D>(a: &ArrayRef<A, D>, weights: &[A], axis: Axis, mode: ConvMode) -> Array<A, D>
+where
+    A: LinalgScalar,
+    D: Dimension,
+{
+    if axis.index() >= a.ndim() {
+        panic!("axis is out of bounds for array of dimension");
+    }
+    assert!(!weights.is_empty(), "kernel must not be empty");
+
+    let n = a.len_of(axis);
+    let m = weights.len();
+    let (len, offset) = mode.len_and_offset(n, m);
+    let mut dim = a.raw_dim();
+    dim[axis.index()] = len;
+    let mut res = Array::zeros(dim);
+    Zip::from(a.lanes(axis))
+        .and(res.lanes_mut(axis))
+        .for_each(|lane, out| {
+            let contiguous = lane.as_slice();
+            for (i, y) in out.into_iter().enumerate() {
+                let (x, w) = overlap(i + offset, n, m);
+                let w = &weights[w];
+                *y = match contiguous {
+                    Some(xs) => numeric_util::unrolled_dot(&xs[x], w),
+                    None => lane
+                        .slice_axis(Axis(0), Slice::from(x))
+                        .iter()
+                        .zip(w)
+                        .fold(A::zero(), |acc, (&x, &w)| acc + x * w),
+                };
+            }
+        });
+    res
+}
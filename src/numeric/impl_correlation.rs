@@ -0,0 +1,163 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use num_traits::{Float, FromPrimitive};
+
+use crate::imp_prelude::*;
+use crate::{LinalgScalar, Zip};
+
+use super::impl_rank::{rank_lane, RankMethod};
+
+/// # Covariance and Correlation
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A> ArrayRef<A, Ix2>
+where A: Float + FromPrimitive + LinalgScalar
+{
+    /// Return the covariance matrix of the array, where each row is a
+    /// variable and each column an observation.
+    ///
+    /// The parameter `ddof` specifies the "delta degrees of freedom" like for
+    /// [`var`](Self::var): use `ddof = 1` for the sample covariance (like
+    /// NumPy's `cov`), or `ddof = 0` for the population covariance. For `m`
+    /// rows of `n` observations, the result is the `m × m` matrix
+    ///
+    /// ```text
+    ///               1       n
+    /// cov(a, b) = ――――――――   ∑ (aᵢ - a̅)(bᵢ - b̅)
+    ///            n - ddof  i=1
+    /// ```
+    ///
+    /// The data is centered once and the sums are computed with a single
+    /// matrix product.
+    ///
+    /// See [`cov_of_columns`](Self::cov_of_columns) for data where the
+    /// columns are the variables.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than `n`, or if
+    /// `A::from_usize()` fails for `n`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2., 3.],
+    ///                [6., 4., 2.]];
+    /// assert_eq!(a.cov(1.), array![[1., -2.], [-2., 4.]]);
+    /// ```
+    #[track_caller]
+    pub fn cov(&self, ddof: A) -> Array2<A>
+    {
+        let n = A::from_usize(self.ncols()).expect("Converting number of observations to `A` must not fail.");
+        assert!(
+            !(ddof < A::zero() || ddof > n),
+            "`ddof` must not be less than zero or greater than the number of observations",
+        );
+        let dof = n - ddof;
+        let mean = self
+            .mean_axis(Axis(1))
+            .unwrap_or_else(|| Array::zeros(self.nrows()));
+        let mut centered = self.to_owned();
+        Zip::from(centered.rows_mut())
+            .and(&mean)
+            .for_each(|mut row, &m| row.mapv_inplace(|x| x - m));
+        let mut cov = centered.dot(&centered.t());
+        cov.mapv_inplace(|x| x / dof);
+        cov
+    }
+
+    /// Return the covariance matrix of the array, where each column is a
+    /// variable and each row an observation.
+    ///
+    /// This is the same as `self.t().cov(ddof)`, see [`cov`](Self::cov).
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 6.],
+    ///                [2., 4.],
+    ///                [3., 2.]];
+    /// assert_eq!(a.cov_of_columns(1.), array![[1., -2.], [-2., 4.]]);
+    /// ```
+    #[track_caller]
+    pub fn cov_of_columns(&self, ddof: A) -> Array2<A>
+    {
+        self.t().cov(ddof)
+    }
+
+    /// Return the Pearson correlation coefficient matrix of the array, where
+    /// each row is a variable and each column an observation.
+    ///
+    /// This is the [covariance matrix](Self::cov) normalized by the standard
+    /// deviations of the variables, so the diagonal is one (up to rounding)
+    /// and all other elements are in the range `[-1, 1]` (like NumPy's
+    /// `corrcoef`, the elements are clipped to this range to hide rounding
+    /// errors).
+    ///
+    /// A variable with zero variance, or an array with no observations, has
+    /// no defined correlation: its row and column are all NaN.
+    ///
+    /// **Panics** if `A::from_usize()` fails for the number of observations.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2., 3.],
+    ///                [6., 4., 2.],
+    ///                [1., 1., 1f64]];
+    /// let corr = a.pearson_correlation();
+    /// assert!((corr[[0, 0]] - 1.).abs() < 1e-15);
+    /// assert!((corr[[0, 1]] + 1.).abs() < 1e-15);
+    /// assert!(corr.row(2).iter().all(|x| x.is_nan()));
+    /// ```
+    #[track_caller]
+    pub fn pearson_correlation(&self) -> Array2<A>
+    {
+        let mut corr = self.cov(A::zero());
+        let std = corr.diag().mapv(A::sqrt);
+        Zip::indexed(&mut corr).for_each(|(i, j), c| {
+            let r = *c / (std[i] * std[j]);
+            *c = if r.is_nan() { r } else { r.max(-A::one()).min(A::one()) };
+        });
+        corr
+    }
+
+    /// Return the Spearman rank correlation coefficient matrix of the array,
+    /// where each row is a variable and each column an observation.
+    ///
+    /// Each variable is replaced by the ranks of its observations, tied
+    /// values receiving the average of the ranks they span, and the result is
+    /// the [Pearson correlation](Self::pearson_correlation) of the ranks. It
+    /// measures how well the relationship between two variables is described
+    /// by a monotonic function, so it is one for any strictly increasing
+    /// relationship, linear or not.
+    ///
+    /// NaNs are ranked after all other values. A variable whose observations
+    /// are all equal has NaN in its row and column.
+    ///
+    /// **Panics** if `A::from_usize()` fails for the number of observations.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2., 3., 4.],
+    ///                [1., 8., 27., 64f64]];
+    /// let corr = a.spearman_correlation();
+    /// assert!((corr[[0, 1]] - 1.).abs() < 1e-15);
+    /// assert!(a.pearson_correlation()[[0, 1]] < 0.99);
+    /// ```
+    #[track_caller]
+    pub fn spearman_correlation(&self) -> Array2<A>
+    {
+        let mut ranks = Array2::zeros(self.raw_dim());
+        let mut idx = Vec::with_capacity(self.ncols());
+        Zip::from(self.rows())
+            .and(ranks.rows_mut())
+            .for_each(|row, rank| rank_lane(row, rank, RankMethod::Average, &mut idx));
+        ranks.pearson_correlation()
+    }
+}
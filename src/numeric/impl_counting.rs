@@ -0,0 +1,345 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Zero;
+
+use crate::dimension::DimMax;
+use crate::error::ShapeError;
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// # Counting Elements
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Return the number of elements for which `f` returns `true`.
+    ///
+    /// Contiguous data is traversed as a slice in memory order.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, -2, 3],
+    ///                [-4, 5, 6]];
+    /// assert_eq!(a.count_where(|&x| x > 2), 3);
+    /// ```
+    pub fn count_where<F>(&self, f: F) -> usize
+    where F: Fn(&A) -> bool
+    {
+        if let Some(slc) = self.as_slice_memory_order() {
+            return slc.iter().filter(|x| f(x)).count();
+        }
+        let mut count = 0;
+        for row in self.rows() {
+            if let Some(slc) = row.as_slice() {
+                count += slc.iter().filter(|x| f(x)).count();
+            } else {
+                count += row.iter().filter(|x| f(x)).count();
+            }
+        }
+        count
+    }
+
+    /// Return `true` if `f` returns `true` for at least one element, and
+    /// `false` if the array is empty.
+    ///
+    /// This stops at the first element for which `f` returns `true`. The
+    /// elements are visited in an unspecified order, the memory order for
+    /// contiguous arrays.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, -2, 3],
+    ///                [-4, 5, 6]];
+    /// assert!(a.any_where(|&x| x < -3));
+    /// assert!(!a.any_where(|&x| x > 6));
+    /// ```
+    pub fn any_where<F>(&self, f: F) -> bool
+    where F: FnMut(&A) -> bool
+    {
+        Zip::from(self).any(f)
+    }
+
+    /// Return `true` if `f` returns `true` for all elements, including if
+    /// the array is empty.
+    ///
+    /// This stops at the first element for which `f` returns `false`. The
+    /// elements are visited in an unspecified order, the memory order for
+    /// contiguous arrays.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, -2, 3],
+    ///                [-4, 5, 6]];
+    /// assert!(a.all_where(|&x| x != 0));
+    /// assert!(!a.all_where(|&x| x > 0));
+    /// ```
+    pub fn all_where<F>(&self, f: F) -> bool
+    where F: FnMut(&A) -> bool
+    {
+        Zip::from(self).all(f)
+    }
+
+    /// Return the number of elements that are not zero.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[0, 1, 2],
+    ///                [3, 0, 0]];
+    /// assert_eq!(a.count_nonzero(), 3);
+    /// ```
+    pub fn count_nonzero(&self) -> usize
+    where A: Zero + PartialEq
+    {
+        let zero = A::zero();
+        self.count_where(|x| *x != zero)
+    }
+
+    /// Return the number of elements that are not zero along `axis`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[0, 1, 2],
+    ///                [3, 0, 0]];
+    /// assert_eq!(a.count_nonzero_axis(Axis(0)), array![1, 1, 1]);
+    /// assert_eq!(a.count_nonzero_axis(Axis(1)), array![2, 1]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn count_nonzero_axis(&self, axis: Axis) -> Array<usize, D::Smaller>
+    where
+        A: Zero + PartialEq,
+        D: RemoveAxis,
+    {
+        let min_stride_axis = self.dim.min_stride_axis(&self.strides);
+        if axis == min_stride_axis {
+            Zip::from(self.lanes(axis)).map_collect(|lane| lane.count_nonzero())
+        } else {
+            let zero = A::zero();
+            let mut res = Array::zeros(self.raw_dim().remove_axis(axis));
+            for subview in self.axis_iter(axis) {
+                Zip::from(&mut res)
+                    .and(&subview)
+                    .for_each(|count, x| *count += (*x != zero) as usize);
+            }
+            res
+        }
+    }
+}
+
+/// # Boolean Reductions
+impl<D> ArrayRef<bool, D>
+where D: Dimension
+{
+    /// Return `true` if any element is `true`, and `false` if the array is
+    /// empty.
+    ///
+    /// This stops at the first `true` element.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert!(array![false, true, false].any());
+    /// assert!(!array![false, false].any());
+    /// ```
+    pub fn any(&self) -> bool
+    {
+        self.any_where(|&x| x)
+    }
+
+    /// Return `true` if all elements are `true`, including if the array is
+    /// empty.
+    ///
+    /// This stops at the first `false` element.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert!(array![true, true].all());
+    /// assert!(!array![true, false, true].all());
+    /// ```
+    pub fn all(&self) -> bool
+    {
+        self.all_where(|&x| x)
+    }
+
+    /// Return whether any element is `true` along `axis`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[true, false, false],
+    ///                [true, true, false]];
+    /// assert_eq!(a.any_axis(Axis(0)), array![true, true, false]);
+    /// assert_eq!(a.any_axis(Axis(1)), array![true, true]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn any_axis(&self, axis: Axis) -> Array<bool, D::Smaller>
+    where D: RemoveAxis
+    {
+        let min_stride_axis = self.dim.min_stride_axis(&self.strides);
+        if axis == min_stride_axis {
+            Zip::from(self.lanes(axis)).map_collect(|lane| lane.any())
+        } else {
+            let mut res = Array::from_elem(self.raw_dim().remove_axis(axis), false);
+            for subview in self.axis_iter(axis) {
+                Zip::from(&mut res).and(&subview).for_each(|r, &x| *r |= x);
+            }
+            res
+        }
+    }
+
+    /// Return whether all elements are `true` along `axis`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[true, false, false],
+    ///                [true, true, false]];
+    /// assert_eq!(a.all_axis(Axis(0)), array![true, false, false]);
+    /// assert_eq!(a.all_axis(Axis(1)), array![false, false]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn all_axis(&self, axis: Axis) -> Array<bool, D::Smaller>
+    where D: RemoveAxis
+    {
+        let min_stride_axis = self.dim.min_stride_axis(&self.strides);
+        if axis == min_stride_axis {
+            Zip::from(self.lanes(axis)).map_collect(|lane| lane.all())
+        } else {
+            let mut res = Array::from_elem(self.raw_dim().remove_axis(axis), true);
+            for subview in self.axis_iter(axis) {
+                Zip::from(&mut res).and(&subview).for_each(|r, &x| *r &= x);
+            }
+            res
+        }
+    }
+}
+
+/// # Logical Operations
+///
+/// The operators `&`, `|`, `^` and `!` also combine boolean arrays
+/// elementwise, panicking if the shapes can not be broadcast together; the
+/// methods here return an error instead. Each method makes a single pass
+/// over the arrays, which is over contiguous slices if they have the same
+/// layout.
+///
+/// ```
+/// use ndarray::array;
+///
+/// let a = array![[1., -2., 3.],
+///                [-4., 5., 6.]];
+/// let positive = a.mapv(|x| x > 0.);
+/// let small = a.mapv(|x| x < 5.5);
+/// let row = array![true, false, true];
+/// let mask = positive.and(&small).unwrap().and(&row).unwrap();
+/// assert_eq!(mask, array![[true, false, true],
+///                         [false, false, false]]);
+/// assert_eq!(mask, &positive & &small & &row);
+/// ```
+impl<D> ArrayRef<bool, D>
+where D: Dimension
+{
+    /// Return the logical and of each element and the corresponding element
+    /// of `rhs`, after broadcasting both to their common shape.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    pub fn and<E>(&self, rhs: &ArrayRef<bool, E>) -> Result<Array<bool, <D as DimMax<E>>::Output>, ShapeError>
+    where
+        E: Dimension,
+        D: DimMax<E>,
+    {
+        let (a, b) = self.broadcast_with(rhs)?;
+        Ok(Zip::from(&a).and(&b).map_collect(|&x, &y| x & y))
+    }
+
+    /// Return the logical or of each element and the corresponding element
+    /// of `rhs`, after broadcasting both to their common shape.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    pub fn or<E>(&self, rhs: &ArrayRef<bool, E>) -> Result<Array<bool, <D as DimMax<E>>::Output>, ShapeError>
+    where
+        E: Dimension,
+        D: DimMax<E>,
+    {
+        let (a, b) = self.broadcast_with(rhs)?;
+        Ok(Zip::from(&a).and(&b).map_collect(|&x, &y| x | y))
+    }
+
+    /// Return the logical exclusive or of each element and the
+    /// corresponding element of `rhs`, after broadcasting both to their
+    /// common shape.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[true], [false]];
+    /// let b = array![true, false];
+    /// assert_eq!(a.xor(&b), Ok(array![[false, true],
+    ///                                  [true, false]]));
+    /// ```
+    pub fn xor<E>(&self, rhs: &ArrayRef<bool, E>) -> Result<Array<bool, <D as DimMax<E>>::Output>, ShapeError>
+    where
+        E: Dimension,
+        D: DimMax<E>,
+    {
+        let (a, b) = self.broadcast_with(rhs)?;
+        Ok(Zip::from(&a).and(&b).map_collect(|&x, &y| x ^ y))
+    }
+
+    /// Return the logical negation of each element.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![true, false].not(), array![false, true]);
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn not(&self) -> Array<bool, D>
+    {
+        self.map(|&x| !x)
+    }
+
+    /// Return the logical and of the elements along `axis`.
+    ///
+    /// This is the same as [`all_axis`](Self::all_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn logical_and_axis(&self, axis: Axis) -> Array<bool, D::Smaller>
+    where D: RemoveAxis
+    {
+        self.all_axis(axis)
+    }
+
+    /// Return the logical or of the elements along `axis`.
+    ///
+    /// This is the same as [`any_axis`](Self::any_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn logical_or_axis(&self, axis: Axis) -> Array<bool, D::Smaller>
+    where D: RemoveAxis
+    {
+        self.any_axis(axis)
+    }
+}
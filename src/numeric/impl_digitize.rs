@@ -0,0 +1,100 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+
+use crate::error::StatsError;
+use crate::imp_prelude::*;
+
+/// # Binning
+impl<A, D> ArrayRef<A, D>
+where
+    A: PartialOrd,
+    D: Dimension,
+{
+    /// Return the index of the bin that each element of the array falls
+    /// into, for bins between the increasing `edges`.
+    ///
+    /// Like NumPy's `digitize`, index `i` is the bin between `edges[i - 1]`
+    /// and `edges[i]`: 0 for elements below the first edge, and
+    /// `edges.len()` for elements above the last edge. If `right` is false an
+    /// element equal to an edge falls into the bin to its right,
+    /// `edges[i - 1] <= x < edges[i]`, and if `right` is true into the bin to
+    /// its left, `edges[i - 1] < x <= edges[i]`. Elements that can not be
+    /// compared, such as NaN, get index `edges.len()`.
+    ///
+    /// Each element is found with a binary search over the edges, or, if the
+    /// elements are already sorted, with a single scan over the elements and
+    /// the edges together.
+    ///
+    /// ***Errors*** with [`StatsError::UnsortedEdges`] if `edges` is not in
+    /// increasing order.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let edges = array![0., 1., 2.];
+    /// let a = array![-1., 0., 0.5, 1., 2., 3.];
+    /// assert_eq!(a.digitize(&edges, false), Ok(array![0, 1, 1, 2, 3, 3]));
+    /// assert_eq!(a.digitize(&edges, true), Ok(array![0, 0, 1, 1, 2, 3]));
+    /// ```
+    pub fn digitize(&self, edges: &ArrayRef<A, Ix1>, right: bool) -> Result<Array<usize, D>, StatsError>
+    where A: Clone
+    {
+        let edges = edges.as_standard_layout();
+        let edges = edges.as_slice().unwrap();
+        if !is_sorted(edges.iter()) {
+            return Err(StatsError::UnsortedEdges);
+        }
+        // whether `x` falls after `edge`; incomparable `x` fall after every edge
+        let after = |x: &A, edge: &A| match x.partial_cmp(edge) {
+            Some(Ordering::Less) => false,
+            Some(Ordering::Equal) => !right,
+            Some(Ordering::Greater) | None => true,
+        };
+        if is_sorted(self.iter()) {
+            let mut bins = Vec::with_capacity(self.len());
+            let mut bin = 0;
+            for x in self.iter() {
+                while bin < edges.len() && after(x, &edges[bin]) {
+                    bin += 1;
+                }
+                bins.push(bin);
+            }
+            Ok(Array::from_shape_vec(self.raw_dim(), bins).unwrap())
+        } else {
+            Ok(self.map(|x| edges.partition_point(|edge| after(x, edge))))
+        }
+    }
+}
+
+/// Return whether the elements of `iter` are in increasing order and
+/// comparable.
+pub(crate) fn is_sorted<'a, A, I>(iter: I) -> bool
+where
+    A: PartialOrd + 'a,
+    I: IntoIterator<Item = &'a A>,
+{
+    let mut iter = iter.into_iter();
+    let mut prev = match iter.next() {
+        Some(x) => x,
+        None => return true,
+    };
+    if prev.partial_cmp(prev).is_none() {
+        return false;
+    }
+    for x in iter {
+        match prev.partial_cmp(x) {
+            Some(Ordering::Less) | Some(Ordering::Equal) => {}
+            Some(Ordering::Greater) | None => return false,
+        }
+        prev = x;
+    }
+    true
+}
@@ -0,0 +1,270 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::{Euclid, One, Zero};
+
+use crate::dimension::DimMax;
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+use crate::{IntoDimension, Zip};
+
+/// # Euclidean Division
+///
+/// These methods divide the array elementwise by a divisor, a scalar or an
+/// array that is broadcast like with the arithmetic operators, with
+/// [`Euclid`]. Unlike with `/` and `%`, which round the quotient towards
+/// zero, the remainder `r` of `x` divided by `d` is never negative,
+/// `0 <= r < |d|`, and the quotient `q` is such that `x == q * d + r`
+/// (for floats up to rounding, which can take `r` to `|d|`). This is what is
+/// needed to wrap angles into `[0, 2π)` or indices into `[0, n)`.
+///
+/// Dividing an integer by zero panics, with the index of the element in the
+/// message, and dividing a float by zero gives `NaN` for
+/// [`rem_euclid`](Self::rem_euclid) and an infinity or `NaN` for
+/// [`div_euclid`](Self::div_euclid).
+impl<A, D> ArrayRef<A, D>
+where
+    A: Clone + Euclid + Zero + One,
+    D: Dimension,
+{
+    /// Return the Euclidean quotient of each element divided by the
+    /// corresponding element of `rhs`, after broadcasting both to their
+    /// common shape.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    ///
+    /// **Panics** if a divisor is an integer zero, or if the quotient
+    /// overflows.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[7, -7],
+    ///                [7, -7]];
+    /// let rhs = array![[2], [-2]];
+    /// assert_eq!(a.div_euclid(&rhs), Ok(array![[3, -4],
+    ///                                          [-3, 4]]));
+    /// ```
+    #[track_caller]
+    pub fn div_euclid<E>(&self, rhs: &ArrayRef<A, E>) -> Result<Array<A, <D as DimMax<E>>::Output>, ShapeError>
+    where
+        E: Dimension,
+        D: DimMax<E>,
+    {
+        euclid_arrays(self, rhs, "divide", Euclid::div_euclid)
+    }
+
+    /// Return the Euclidean remainder of each element divided by the
+    /// corresponding element of `rhs`, after broadcasting both to their
+    /// common shape.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    ///
+    /// **Panics** if a divisor is an integer zero, or if the quotient
+    /// overflows.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[7, -7],
+    ///                [7, -7]];
+    /// let rhs = array![[2], [-2]];
+    /// assert_eq!(a.rem_euclid(&rhs), Ok(array![[1, 1],
+    ///                                          [1, 1]]));
+    /// ```
+    #[track_caller]
+    pub fn rem_euclid<E>(&self, rhs: &ArrayRef<A, E>) -> Result<Array<A, <D as DimMax<E>>::Output>, ShapeError>
+    where
+        E: Dimension,
+        D: DimMax<E>,
+    {
+        euclid_arrays(self, rhs, "calculate the remainder", Euclid::rem_euclid)
+    }
+
+    /// Return the Euclidean quotient of each element divided by `rhs`.
+    ///
+    /// **Panics** if `rhs` is an integer zero and the array is not empty, or
+    /// if a quotient overflows.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![-3, -2, -1, 0, 1, 2, 3];
+    /// assert_eq!(a.div_euclid_scalar(2), array![-2, -1, -1, 0, 0, 1, 1]);
+    /// ```
+    #[track_caller]
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn div_euclid_scalar(&self, rhs: A) -> Array<A, D>
+    {
+        check_scalar_divisor(self, &rhs, "divide");
+        self.map(|x| x.div_euclid(&rhs))
+    }
+
+    /// Return the Euclidean remainder of each element divided by `rhs`.
+    ///
+    /// **Panics** if `rhs` is an integer zero and the array is not empty, or
+    /// if a quotient overflows.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use std::f64::consts::PI;
+    ///
+    /// let a = array![-3, -2, -1, 0, 1, 2, 3];
+    /// assert_eq!(a.rem_euclid_scalar(3), array![0, 1, 2, 0, 1, 2, 0]);
+    /// // wrap angles into [0, 2π)
+    /// let angles = array![-PI / 2., 3. * PI];
+    /// assert_eq!(angles.rem_euclid_scalar(2. * PI), array![1.5 * PI, PI]);
+    /// ```
+    #[track_caller]
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn rem_euclid_scalar(&self, rhs: A) -> Array<A, D>
+    {
+        check_scalar_divisor(self, &rhs, "calculate the remainder");
+        self.map(|x| x.rem_euclid(&rhs))
+    }
+
+    /// Replace each element with its Euclidean quotient divided by the
+    /// corresponding element of `rhs`, in place.
+    ///
+    /// `rhs` is broadcast to the shape of the array.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if `rhs` can not be broadcast to
+    /// the shape of the array, in which case the array is not modified.
+    ///
+    /// **Panics** if a divisor is an integer zero, or if the quotient
+    /// overflows.
+    #[track_caller]
+    pub fn div_euclid_inplace<E>(&mut self, rhs: &ArrayRef<A, E>) -> Result<(), ShapeError>
+    where E: Dimension
+    {
+        euclid_arrays_inplace(self, rhs, "divide", Euclid::div_euclid)
+    }
+
+    /// Replace each element with its Euclidean remainder divided by the
+    /// corresponding element of `rhs`, in place.
+    ///
+    /// `rhs` is broadcast to the shape of the array.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if `rhs` can not be broadcast to
+    /// the shape of the array, in which case the array is not modified.
+    ///
+    /// **Panics** if a divisor is an integer zero, or if the quotient
+    /// overflows.
+    #[track_caller]
+    pub fn rem_euclid_inplace<E>(&mut self, rhs: &ArrayRef<A, E>) -> Result<(), ShapeError>
+    where E: Dimension
+    {
+        euclid_arrays_inplace(self, rhs, "calculate the remainder", Euclid::rem_euclid)
+    }
+
+    /// Replace each element with its Euclidean quotient divided by `rhs`, in
+    /// place.
+    ///
+    /// **Panics** if `rhs` is an integer zero and the array is not empty, or
+    /// if a quotient overflows.
+    #[track_caller]
+    pub fn div_euclid_scalar_inplace(&mut self, rhs: A)
+    {
+        check_scalar_divisor(self, &rhs, "divide");
+        self.map_inplace(|x| *x = x.div_euclid(&rhs));
+    }
+
+    /// Replace each element with its Euclidean remainder divided by `rhs`,
+    /// in place.
+    ///
+    /// **Panics** if `rhs` is an integer zero and the array is not empty, or
+    /// if a quotient overflows.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// // wrap indices into [0, 5)
+    /// let mut indices = array![-6, -1, 4, 5, 12];
+    /// indices.rem_euclid_scalar_inplace(5);
+    /// assert_eq!(indices, array![4, 4, 4, 0, 2]);
+    /// ```
+    #[track_caller]
+    pub fn rem_euclid_scalar_inplace(&mut self, rhs: A)
+    {
+        check_scalar_divisor(self, &rhs, "calculate the remainder");
+        self.map_inplace(|x| *x = x.rem_euclid(&rhs));
+    }
+}
+
+/// Return `true` if `A` is an integer type, where one divided by two
+/// truncates to zero.
+fn is_integer<A>() -> bool
+where A: Euclid + Zero + One
+{
+    (A::one() / (A::one() + A::one())).is_zero()
+}
+
+/// Panic if `rhs` is an integer zero and `a` is not empty.
+#[track_caller]
+fn check_scalar_divisor<A, D>(a: &ArrayRef<A, D>, rhs: &A, op: &str)
+where
+    A: Euclid + Zero + One,
+    D: Dimension,
+{
+    if !a.is_empty() && rhs.is_zero() && is_integer::<A>() {
+        divide_by_zero(op, a.raw_dim().first_index().unwrap().slice());
+    }
+}
+
+/// Panic if any element of `rhs` is an integer zero.
+#[track_caller]
+fn check_divisors<A, D>(rhs: &ArrayRef<A, D>, op: &str)
+where
+    A: Euclid + Zero + One,
+    D: Dimension,
+{
+    if is_integer::<A>() {
+        if let Some((index, _)) = rhs.indexed_iter().find(|(_, d)| d.is_zero()) {
+            divide_by_zero(op, index.into_dimension().slice());
+        }
+    }
+}
+
+#[track_caller]
+fn divide_by_zero(op: &str, index: &[Ix]) -> !
+{
+    panic!("attempt to {} with a divisor of zero at index {:?}", op, index)
+}
+
+#[track_caller]
+fn euclid_arrays<A, D, E>(
+    a: &ArrayRef<A, D>, rhs: &ArrayRef<A, E>, op: &str, f: fn(&A, &A) -> A,
+) -> Result<Array<A, <D as DimMax<E>>::Output>, ShapeError>
+where
+    A: Clone + Euclid + Zero + One,
+    D: Dimension + DimMax<E>,
+    E: Dimension,
+{
+    let (a, rhs) = a.broadcast_with(rhs)?;
+    check_divisors(&rhs, op);
+    Ok(Zip::from(&a).and(&rhs).map_collect(f))
+}
+
+#[track_caller]
+fn euclid_arrays_inplace<A, D, E>(
+    a: &mut ArrayRef<A, D>, rhs: &ArrayRef<A, E>, op: &str, f: fn(&A, &A) -> A,
+) -> Result<(), ShapeError>
+where
+    A: Clone + Euclid + Zero + One,
+    D: Dimension,
+    E: Dimension,
+{
+    let rhs = rhs
+        .broadcast(a.raw_dim())
+        .ok_or_else(|| from_kind(ErrorKind::IncompatibleShape))?;
+    check_divisors(&rhs, op);
+    Zip::from(a).and(&rhs).for_each(|x, d| *x = f(x, d));
+    Ok(())
+}
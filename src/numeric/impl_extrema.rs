@@ -0,0 +1,250 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::Float;
+use std::cmp::Ordering;
+
+use crate::dimension::DimMax;
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// # Elementwise Maximum and Minimum
+///
+/// These methods compare the array with `other` elementwise, after
+/// broadcasting both to their common shape like the arithmetic operators do.
+///
+/// Like NumPy's `maximum` and `minimum`, if either of two elements is
+/// unordered with respect to the other, as a float `NaN` is, the result is
+/// that element, so that `NaN` propagates. Use [`fmax`](Self::fmax) and
+/// [`fmin`](Self::fmin) to ignore `NaN` instead.
+impl<A, D> ArrayRef<A, D>
+where
+    A: Clone + PartialOrd,
+    D: Dimension,
+{
+    /// Return the elementwise maximum of the array and `other`.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 5],
+    ///                [7, 2]];
+    /// let column = array![[3], [4]];
+    /// assert_eq!(a.maximum(&column), Ok(array![[3, 5],
+    ///                                          [7, 4]]));
+    /// let b = array![2., 0.];
+    /// let b = array![1., f64::NAN].maximum(&b).unwrap();
+    /// assert_eq!(b[0], 2.);
+    /// assert!(b[1].is_nan());
+    /// ```
+    pub fn maximum<E>(&self, other: &ArrayRef<A, E>) -> Result<Array<A, <D as DimMax<E>>::Output>, ShapeError>
+    where
+        E: Dimension,
+        D: DimMax<E>,
+    {
+        let (a, b) = self.broadcast_with(other)?;
+        Ok(Zip::from(&a).and(&b).map_collect(|a, b| max(a, b).clone()))
+    }
+
+    /// Return the elementwise minimum of the array and `other`.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 5],
+    ///                [7, 2]];
+    /// let row = array![3, 4];
+    /// assert_eq!(a.minimum(&row), Ok(array![[1, 4],
+    ///                                       [3, 2]]));
+    /// ```
+    pub fn minimum<E>(&self, other: &ArrayRef<A, E>) -> Result<Array<A, <D as DimMax<E>>::Output>, ShapeError>
+    where
+        E: Dimension,
+        D: DimMax<E>,
+    {
+        let (a, b) = self.broadcast_with(other)?;
+        Ok(Zip::from(&a).and(&b).map_collect(|a, b| min(a, b).clone()))
+    }
+
+    /// Return the elementwise maximum of the array and `x`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![-1, 0, 3];
+    /// assert_eq!(a.maximum_scalar(0), array![0, 0, 3]);
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn maximum_scalar(&self, x: A) -> Array<A, D>
+    {
+        self.map(|a| max(a, &x).clone())
+    }
+
+    /// Return the elementwise minimum of the array and `x`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![-1, 0, 3];
+    /// assert_eq!(a.minimum_scalar(0), array![-1, 0, 0]);
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn minimum_scalar(&self, x: A) -> Array<A, D>
+    {
+        self.map(|a| min(a, &x).clone())
+    }
+
+    /// Replace each element with its maximum with the corresponding element
+    /// of `other`, in place.
+    ///
+    /// `other` is broadcast to the shape of the array.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if `other` can not be broadcast to
+    /// the shape of the array, in which case the array is not modified.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut running_max = array![[0, 0, 0]];
+    /// for row in array![[1, 4, 2], [3, 0, 5]].rows() {
+    ///     running_max.maximum_assign(&row).unwrap();
+    /// }
+    /// assert_eq!(running_max, array![[3, 4, 5]]);
+    /// ```
+    pub fn maximum_assign<E>(&mut self, other: &ArrayRef<A, E>) -> Result<(), ShapeError>
+    where E: Dimension
+    {
+        self.zip_assign_with(other, max)
+    }
+
+    /// Replace each element with its minimum with the corresponding element
+    /// of `other`, in place.
+    ///
+    /// `other` is broadcast to the shape of the array.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if `other` can not be broadcast to
+    /// the shape of the array, in which case the array is not modified.
+    pub fn minimum_assign<E>(&mut self, other: &ArrayRef<A, E>) -> Result<(), ShapeError>
+    where E: Dimension
+    {
+        self.zip_assign_with(other, min)
+    }
+
+    /// Replace each element `a` with `f(a, b)` for the corresponding element
+    /// `b` of `other` broadcast to the shape of the array.
+    fn zip_assign_with<E, F>(&mut self, other: &ArrayRef<A, E>, f: F) -> Result<(), ShapeError>
+    where
+        E: Dimension,
+        F: for<'a> Fn(&'a A, &'a A) -> &'a A,
+    {
+        let other = other
+            .broadcast(self.raw_dim())
+            .ok_or_else(|| from_kind(ErrorKind::IncompatibleShape))?;
+        Zip::from(self).and(&other).for_each(|a, b| {
+            if !core::ptr::eq(f(a, b), a) {
+                *a = b.clone();
+            }
+        });
+        Ok(())
+    }
+}
+
+/// # Elementwise Maximum and Minimum Ignoring NaN
+#[cfg(any(feature = "std", feature = "libm"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    /// Return the elementwise maximum of the array and `other`, ignoring
+    /// `NaN`.
+    ///
+    /// Like NumPy's `fmax`, if one of two elements is `NaN` the result is the
+    /// other one, and it is `NaN` only if both are.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let nan = f64::NAN;
+    /// let a = array![1., nan, nan];
+    /// let b = array![nan, 2., nan];
+    /// let c = a.fmax(&b).unwrap();
+    /// assert_eq!(c.slice(ndarray::s![..2]), array![1., 2.]);
+    /// assert!(c[2].is_nan());
+    /// ```
+    pub fn fmax<E>(&self, other: &ArrayRef<A, E>) -> Result<Array<A, <D as DimMax<E>>::Output>, ShapeError>
+    where
+        E: Dimension,
+        D: DimMax<E>,
+    {
+        let (a, b) = self.broadcast_with(other)?;
+        Ok(Zip::from(&a).and(&b).map_collect(|&a, &b| a.max(b)))
+    }
+
+    /// Return the elementwise minimum of the array and `other`, ignoring
+    /// `NaN`.
+    ///
+    /// Like NumPy's `fmin`, if one of two elements is `NaN` the result is the
+    /// other one, and it is `NaN` only if both are.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    pub fn fmin<E>(&self, other: &ArrayRef<A, E>) -> Result<Array<A, <D as DimMax<E>>::Output>, ShapeError>
+    where
+        E: Dimension,
+        D: DimMax<E>,
+    {
+        let (a, b) = self.broadcast_with(other)?;
+        Ok(Zip::from(&a).and(&b).map_collect(|&a, &b| a.min(b)))
+    }
+}
+
+/// Return the greater of `a` and `b`, or the one that is unordered with
+/// respect to itself if they are unordered.
+fn max<'a, A: PartialOrd>(a: &'a A, b: &'a A) -> &'a A
+{
+    match a.partial_cmp(b) {
+        Some(Ordering::Less) => b,
+        Some(_) => a,
+        None => unordered(a, b),
+    }
+}
+
+/// Return the lesser of `a` and `b`, or the one that is unordered with
+/// respect to itself if they are unordered.
+fn min<'a, A: PartialOrd>(a: &'a A, b: &'a A) -> &'a A
+{
+    match a.partial_cmp(b) {
+        Some(Ordering::Greater) => b,
+        Some(_) => a,
+        None => unordered(a, b),
+    }
+}
+
+#[allow(clippy::eq_op)]
+fn unordered<'a, A: PartialOrd>(a: &'a A, b: &'a A) -> &'a A
+{
+    // a `NaN` is the element that is not equal to itself
+    if a != a {
+        a
+    } else {
+        b
+    }
+}
@@ -1,9 +1,16 @@
 // Element-wise methods for ndarray
 
 #[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(any(feature = "std", feature = "libm"))]
 use num_traits::Float;
 
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::dimension::DimMax;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::error::{from_kind, ErrorKind, ShapeError};
 use crate::imp_prelude::*;
+use crate::Zip;
 
 #[cfg(feature = "std")]
 macro_rules! boolean_ops {
@@ -13,7 +20,7 @@ macro_rules! boolean_ops {
         $(#[$meta1])*
         #[must_use = "method returns a new array and does not mutate the original value"]
         pub fn $func(&self) -> Array<bool, D> {
-            self.mapv(A::$func)
+            standard_mask(self, |x| x.$func())
         }
         $(#[$meta2])*
         #[must_use = "method returns a new boolean value and does not mutate the original value"]
@@ -50,6 +57,21 @@ macro_rules! binary_ops {
     };
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
+macro_rules! inplace_ops {
+    ($($(#[$meta:meta])* fn $id:ident / $inplace:ident($f:expr))+) => {
+        $($(#[$meta])*
+        #[must_use = "method returns a new array and does not mutate the original value"]
+        pub fn $id(&self) -> Array<A, D> {
+            self.mapv($f)
+        }
+        #[doc = concat!("Replace each element with the result of [`", stringify!($id), "`](Self::", stringify!($id), "), in place.")]
+        pub fn $inplace(&mut self) {
+            self.mapv_inplace($f)
+        })+
+    };
+}
+
 /// # Element-wise methods for float arrays
 ///
 /// Element-wise math functions for any array type that contains float number.
@@ -62,6 +84,8 @@ where
 {
     boolean_ops! {
         /// If the number is `NaN` (not a number), then `true` is returned for each element.
+        ///
+        /// The mask has the same shape as the array, in standard order.
         fn is_nan
         /// Return `true` if all elements are `NaN` (not a number).
         fn is_all_nan
@@ -70,65 +94,62 @@ where
     }
     boolean_ops! {
         /// If the number is infinity, then `true` is returned for each element.
+        ///
+        /// The mask has the same shape as the array, in standard order.
         fn is_infinite
         /// Return `true` if all elements are infinity.
         fn is_all_infinite
         /// Return `true` if any element is infinity.
         fn is_any_infinite
     }
+    /// If the number is neither infinite nor `NaN`, then `true` is returned
+    /// for each element.
+    ///
+    /// The mask has the same shape as the array, in standard order.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., f64::NAN],
+    ///                [f64::INFINITY, -0.]];
+    /// assert_eq!(a.is_finite(), array![[true, false],
+    ///                                  [false, true]]);
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn is_finite(&self) -> Array<bool, D>
+    {
+        standard_mask(self, |x| x.is_finite())
+    }
+
+    /// Return the number of `NaN` (not a number) elements, without
+    /// materializing a mask.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., f64::NAN],
+    ///                [f64::NAN, f64::INFINITY]];
+    /// assert_eq!(a.count_nan(), 2);
+    /// ```
+    #[must_use = "method returns a new number and does not mutate the original value"]
+    pub fn count_nan(&self) -> usize
+    {
+        self.count_where(|x| x.is_nan())
+    }
+
     unary_ops! {
-        /// The largest integer less than or equal to each element.
-        fn floor
-        /// The smallest integer less than or equal to each element.
-        fn ceil
-        /// The nearest integer of each element.
-        fn round
-        /// The integer part of each element.
-        fn trunc
         /// The fractional part of each element.
         fn fract
-        /// Absolute of each element.
-        fn abs
-        /// Sign number of each element.
-        ///
-        /// + `1.0` for all positive numbers.
-        /// + `-1.0` for all negative numbers.
-        /// + `NaN` for all `NaN` (not a number).
-        fn signum
         /// The reciprocal (inverse) of each element, `1/x`.
         fn recip
-        /// Square root of each element.
-        fn sqrt
-        /// `e^x` of each element (exponential function).
-        fn exp
         /// `2^x` of each element.
         fn exp2
-        /// Natural logarithm of each element.
-        fn ln
-        /// Base 2 logarithm of each element.
-        fn log2
-        /// Base 10 logarithm of each element.
-        fn log10
-        /// Cubic root of each element.
-        fn cbrt
-        /// Sine of each element (in radians).
-        fn sin
-        /// Cosine of each element (in radians).
-        fn cos
-        /// Tangent of each element (in radians).
-        fn tan
         /// Converts radians to degrees for each element.
         fn to_degrees
         /// Converts degrees to radians for each element.
         fn to_radians
     }
     binary_ops! {
-        /// Integer power of each element.
-        ///
-        /// This function is generally faster than using float power.
-        fn powi(i32)
-        /// Float power of each element.
-        fn powf(A)
         /// Logarithm of each element with respect to an arbitrary base.
         fn log(A)
         /// The positive difference between given number and each element.
@@ -143,6 +164,480 @@ where
     }
 }
 
+/// # Powers
+///
+/// A negative base raised to a fractional exponent is `NaN`, and any base
+/// raised to the power of zero is one, including zero and `NaN`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    /// Integer power of each element.
+    ///
+    /// This function is generally faster than using float power.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![-2., 0., 0.5];
+    /// assert_eq!(a.powi(3), array![-8., 0., 0.125]);
+    /// assert_eq!(a.powi(-1), array![-0.5, f64::INFINITY, 2.]);
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn powi(&self, n: i32) -> Array<A, D>
+    {
+        self.mapv(|x| x.powi(n))
+    }
+
+    /// Raise each element to the integer power `n`, in place.
+    pub fn powi_inplace(&mut self, n: i32)
+    {
+        self.mapv_inplace(|x| x.powi(n))
+    }
+
+    /// Float power of each element.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![4., 0., -8.];
+    /// let b = a.powf(0.5_f64);
+    /// assert_eq!(b.slice(ndarray::s![..2]), array![2., 0.]);
+    /// assert!(b[2].is_nan());
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn powf(&self, p: A) -> Array<A, D>
+    {
+        self.mapv(|x| x.powf(p))
+    }
+
+    /// Raise each element to the float power `p`, in place.
+    pub fn powf_inplace(&mut self, p: A)
+    {
+        self.mapv_inplace(|x| x.powf(p))
+    }
+
+    /// Return each element raised to the power of the corresponding element
+    /// of `exponents`, after broadcasting both to their common shape like the
+    /// arithmetic operators do.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2., 3.],
+    ///                [4., 5., 6.]];
+    /// let exponents = array![0., 1., 2.];
+    /// assert_eq!(a.pow_elem(&exponents), Ok(array![[1., 2., 9.],
+    ///                                              [1., 5., 36.]]));
+    /// let short = array![1., 2.];
+    /// assert!(a.pow_elem(&short).is_err());
+    /// ```
+    pub fn pow_elem<E>(&self, exponents: &ArrayRef<A, E>) -> Result<Array<A, <D as DimMax<E>>::Output>, ShapeError>
+    where
+        E: Dimension,
+        D: DimMax<E>,
+    {
+        let (base, exponents) = self.broadcast_with(exponents)?;
+        Ok(Zip::from(&base)
+            .and(&exponents)
+            .map_collect(|&x, &p| x.powf(p)))
+    }
+
+    /// Raise each element to the power of the corresponding element of
+    /// `exponents`, in place.
+    ///
+    /// `exponents` is broadcast to the shape of the array.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if `exponents` can not be broadcast
+    /// to the shape of the array, in which case the array is not modified.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[2., 2.],
+    ///                    [3., 3.]];
+    /// let exponents = array![[3.], [2.]];
+    /// a.pow_elem_inplace(&exponents).unwrap();
+    /// assert_eq!(a, array![[8., 8.],
+    ///                      [9., 9.]]);
+    /// ```
+    pub fn pow_elem_inplace<E>(&mut self, exponents: &ArrayRef<A, E>) -> Result<(), ShapeError>
+    where E: Dimension
+    {
+        let exponents = exponents
+            .broadcast(self.raw_dim())
+            .ok_or_else(|| from_kind(ErrorKind::IncompatibleShape))?;
+        Zip::from(self)
+            .and(&exponents)
+            .for_each(|x, &p| *x = x.powf(p));
+        Ok(())
+    }
+}
+
+/// Return the mask of the elements of `a` for which `f` returns `true`, in
+/// standard order whatever the memory layout of `a`.
+#[cfg(feature = "std")]
+fn standard_mask<A, D, F>(a: &ArrayRef<A, D>, f: F) -> Array<bool, D>
+where
+    D: Dimension,
+    F: FnMut(&A) -> bool,
+{
+    let mask: Vec<bool> = a.iter().map(f).collect();
+    Array::from_shape_vec(a.raw_dim(), mask).unwrap()
+}
+
+/// # Elementwise Math Functions
+///
+/// Each method applies the function of the same name of [`Float`] to every
+/// element and returns a new array, and each has an `_inplace` variant that
+/// replaces the elements instead:
+///
+/// | Method | In place | Function |
+/// |--------|----------|----------|
+/// | [`exp`](Self::exp) | [`exp_inplace`](Self::exp_inplace) | `e^x` |
+/// | [`exp_m1`](Self::exp_m1) | [`exp_m1_inplace`](Self::exp_m1_inplace) | `e^x - 1`, accurate near zero |
+/// | [`ln`](Self::ln) | [`ln_inplace`](Self::ln_inplace) | natural logarithm |
+/// | [`ln_1p`](Self::ln_1p) | [`ln_1p_inplace`](Self::ln_1p_inplace) | `ln(1 + x)`, accurate near zero |
+/// | [`log2`](Self::log2) | [`log2_inplace`](Self::log2_inplace) | base 2 logarithm |
+/// | [`log10`](Self::log10) | [`log10_inplace`](Self::log10_inplace) | base 10 logarithm |
+/// | [`sqrt`](Self::sqrt) | [`sqrt_inplace`](Self::sqrt_inplace) | square root |
+/// | [`cbrt`](Self::cbrt) | [`cbrt_inplace`](Self::cbrt_inplace) | cube root |
+/// | [`sin`](Self::sin) | [`sin_inplace`](Self::sin_inplace) | sine, in radians |
+/// | [`cos`](Self::cos) | [`cos_inplace`](Self::cos_inplace) | cosine, in radians |
+/// | [`tan`](Self::tan) | [`tan_inplace`](Self::tan_inplace) | tangent, in radians |
+/// | [`tanh`](Self::tanh) | [`tanh_inplace`](Self::tanh_inplace) | hyperbolic tangent |
+///
+/// Elements outside of the domain of a function, such as negative elements
+/// for the logarithms and `sqrt`, become `NaN`.
+///
+/// ```
+/// use ndarray::array;
+///
+/// let mut a = array![[0., 1.],
+///                    [4., f64::INFINITY]];
+/// assert_eq!(a.sqrt(), array![[0., 1.],
+///                             [2., f64::INFINITY]]);
+/// a.ln_1p_inplace();
+/// assert_eq!(a[[0, 0]], 0.);
+/// assert_eq!(a[[1, 1]], f64::INFINITY);
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    inplace_ops! {
+        /// `e^x` of each element (exponential function).
+        fn exp / exp_inplace(A::exp)
+        /// `e^x - 1` of each element, more accurately than with
+        /// [`exp`](Self::exp) for elements near zero.
+        fn exp_m1 / exp_m1_inplace(A::exp_m1)
+        /// Natural logarithm of each element.
+        fn ln / ln_inplace(A::ln)
+        /// `ln(1 + x)` of each element, more accurately than with
+        /// [`ln`](Self::ln) for elements near zero.
+        fn ln_1p / ln_1p_inplace(A::ln_1p)
+        /// Base 2 logarithm of each element.
+        fn log2 / log2_inplace(A::log2)
+        /// Base 10 logarithm of each element.
+        fn log10 / log10_inplace(A::log10)
+        /// Square root of each element.
+        fn sqrt / sqrt_inplace(A::sqrt)
+        /// Cubic root of each element.
+        fn cbrt / cbrt_inplace(A::cbrt)
+        /// Sine of each element (in radians).
+        fn sin / sin_inplace(A::sin)
+        /// Cosine of each element (in radians).
+        fn cos / cos_inplace(A::cos)
+        /// Tangent of each element (in radians).
+        fn tan / tan_inplace(A::tan)
+        /// Hyperbolic tangent of each element.
+        fn tanh / tanh_inplace(A::tanh)
+    }
+
+    /// Return the length of the hypotenuse `sqrt(a² + b²)` of each element
+    /// `a` of the array and the corresponding element `b` of `other`, after
+    /// broadcasting both to their common shape like the arithmetic operators
+    /// do.
+    ///
+    /// This uses [`Float::hypot`], which does not overflow or underflow in
+    /// the intermediate squares, and computes the result in a single pass.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let dx = array![[3f64, 0.],
+    ///                 [-5., 1e200]];
+    /// let dy = array![[4., 2.],
+    ///                 [12., 1e200]];
+    /// let magnitude = dx.hypot(&dy).unwrap();
+    /// assert_eq!(magnitude.row(0), array![5., 2.]);
+    /// assert_eq!(magnitude[[1, 0]], 13.);
+    /// assert!(magnitude[[1, 1]].is_finite());
+    /// ```
+    pub fn hypot<E>(&self, other: &ArrayRef<A, E>) -> Result<Array<A, <D as DimMax<E>>::Output>, ShapeError>
+    where
+        E: Dimension,
+        D: DimMax<E>,
+    {
+        let (a, b) = self.broadcast_with(other)?;
+        Ok(Zip::from(&a).and(&b).map_collect(|&a, &b| a.hypot(b)))
+    }
+
+    /// Replace each element `a` with the length of the hypotenuse
+    /// `sqrt(a² + b²)`, for the corresponding element `b` of `other`, in
+    /// place.
+    ///
+    /// `other` is broadcast to the shape of the array.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if `other` can not be broadcast to
+    /// the shape of the array, in which case the array is not modified.
+    pub fn hypot_inplace<E>(&mut self, other: &ArrayRef<A, E>) -> Result<(), ShapeError>
+    where E: Dimension
+    {
+        let other = other
+            .broadcast(self.raw_dim())
+            .ok_or_else(|| from_kind(ErrorKind::IncompatibleShape))?;
+        Zip::from(self)
+            .and(&other)
+            .for_each(|a, &b| *a = a.hypot(b));
+        Ok(())
+    }
+}
+
+/// # Rounding
+///
+/// Each element is rounded to an integer, which is still of the float type.
+/// `NaN`, infinities and the sign of zero are kept.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    inplace_ops! {
+        /// The nearest integer to each element, rounding half-way cases away
+        /// from zero, like [`f64::round`].
+        ///
+        /// Unlike NumPy's `round` and `rint`, which round half-way cases to
+        /// the nearest even integer; see
+        /// [`round_ties_even`](Self::round_ties_even) for that.
+        ///
+        /// ```
+        /// use ndarray::array;
+        ///
+        /// let a = array![-1.5, -0.5, 0.5, 1.5, 2.5, 2.7];
+        /// assert_eq!(a.round(), array![-2., -1., 1., 2., 3., 3.]);
+        /// ```
+        fn round / round_inplace(A::round)
+        /// The nearest integer to each element, rounding half-way cases to
+        /// the nearest even integer, like NumPy's `round` (with zero decimals)
+        /// and `rint`.
+        ///
+        /// Rounding half-way cases to even is not biased upwards in magnitude,
+        /// so that, for example, sums of rounded values are closer to the
+        /// sums of the original values than with [`round`](Self::round).
+        ///
+        /// ```
+        /// use ndarray::array;
+        ///
+        /// let a = array![-1.5, -0.5, 0.5, 1.5, 2.5, 2.7];
+        /// assert_eq!(a.round_ties_even(), array![-2., -0., 0., 2., 2., 3.]);
+        /// ```
+        fn round_ties_even / round_ties_even_inplace(round_ties_even)
+        /// The largest integer less than or equal to each element, like
+        /// NumPy's `floor`.
+        ///
+        /// ```
+        /// use ndarray::array;
+        ///
+        /// let a = array![-1.5, -0.5, 0.5, 1.5];
+        /// assert_eq!(a.floor(), array![-2., -1., 0., 1.]);
+        /// ```
+        fn floor / floor_inplace(A::floor)
+        /// The smallest integer greater than or equal to each element, like
+        /// NumPy's `ceil`.
+        ///
+        /// ```
+        /// use ndarray::array;
+        ///
+        /// let a = array![-1.5, -0.5, 0.5, 1.5];
+        /// assert_eq!(a.ceil(), array![-1., -0., 1., 2.]);
+        /// ```
+        fn ceil / ceil_inplace(A::ceil)
+        /// The integer part of each element, rounding towards zero, like
+        /// NumPy's `trunc` and `fix`.
+        ///
+        /// ```
+        /// use ndarray::array;
+        ///
+        /// let a = array![-1.5, -0.5, 0.5, 1.5];
+        /// assert_eq!(a.trunc(), array![-1., -0., 0., 1.]);
+        /// ```
+        fn trunc / trunc_inplace(A::trunc)
+    }
+}
+
+/// Round `x` to the nearest integer, with half-way cases to the nearest even
+/// integer.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(crate) fn round_ties_even<A: Float>(x: A) -> A
+{
+    let rounded = x.round();
+    // `x - rounded` is exact, since they are within one of each other
+    let two = A::one() + A::one();
+    if (x - rounded).abs() * two == A::one() {
+        (x / two).round() * two
+    } else {
+        rounded
+    }
+}
+
+/// # Replacing Non-Finite Values
+#[cfg(any(feature = "std", feature = "libm"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    /// Return a copy of the array with `NaN` replaced by zero, positive
+    /// infinity by the largest finite value of `A`, and negative infinity by
+    /// the smallest finite value, like NumPy's `nan_to_num`.
+    ///
+    /// See [`nan_to_num_with`](Self::nan_to_num_with) for other
+    /// replacements.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1., f64::NAN, f64::INFINITY, f64::NEG_INFINITY];
+    /// assert_eq!(a.nan_to_num(), array![1., 0., f64::MAX, f64::MIN]);
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn nan_to_num(&self) -> Array<A, D>
+    {
+        self.nan_to_num_with(A::zero(), A::max_value(), A::min_value())
+    }
+
+    /// Replace `NaN` with zero, positive infinity with the largest finite
+    /// value of `A`, and negative infinity with the smallest finite value, in
+    /// place.
+    ///
+    /// This is the in place counterpart of [`nan_to_num`](Self::nan_to_num),
+    /// which does not allocate.
+    pub fn nan_to_num_inplace(&mut self)
+    {
+        self.nan_to_num_with_inplace(A::zero(), A::max_value(), A::min_value())
+    }
+
+    /// Return a copy of the array with `NaN` replaced by `nan`, positive
+    /// infinity by `posinf`, and negative infinity by `neginf`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1., f64::NAN, f64::INFINITY, f64::NEG_INFINITY];
+    /// assert_eq!(a.nan_to_num_with(-1., 1e3, -1e3), array![1., -1., 1e3, -1e3]);
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn nan_to_num_with(&self, nan: A, posinf: A, neginf: A) -> Array<A, D>
+    {
+        self.mapv(|x| replace_non_finite(x, nan, posinf, neginf))
+    }
+
+    /// Replace `NaN` with `nan`, positive infinity with `posinf`, and negative
+    /// infinity with `neginf`, in place.
+    pub fn nan_to_num_with_inplace(&mut self, nan: A, posinf: A, neginf: A)
+    {
+        self.map_inplace(|x| *x = replace_non_finite(*x, nan, posinf, neginf));
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn replace_non_finite<A: Float>(x: A, nan: A, posinf: A, neginf: A) -> A
+{
+    if x.is_finite() {
+        x
+    } else if x.is_nan() {
+        nan
+    } else if x > A::zero() {
+        posinf
+    } else {
+        neginf
+    }
+}
+
+/// # Absolute Values and Signs
+///
+/// Element-wise methods for float arrays. For signed integers, see
+/// [`try_abs`](ArrayRef::try_abs).
+#[cfg(any(feature = "std", feature = "libm"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    /// The absolute value of each element.
+    ///
+    /// The absolute value of `-0.0` is `0.0`, and that of `NaN` is `NaN`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![-1.5, -0., f64::NEG_INFINITY].abs(), array![1.5, 0., f64::INFINITY]);
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn abs(&self) -> Array<A, D>
+    {
+        self.mapv(A::abs)
+    }
+
+    /// Replace each element with its absolute value, in place.
+    pub fn abs_inplace(&mut self)
+    {
+        self.mapv_inplace(A::abs)
+    }
+
+    /// The sign of each element, like [`f64::signum`].
+    ///
+    /// + `1.0` for positive numbers, including `0.0` and infinity.
+    /// + `-1.0` for negative numbers, including `-0.0` and negative infinity.
+    /// + `NaN` for `NaN`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![-1.5, -0., 0.].signum(), array![-1., -1., 1.]);
+    /// assert!(array![f64::NAN].signum()[0].is_nan());
+    /// ```
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn signum(&self) -> Array<A, D>
+    {
+        self.mapv(A::signum)
+    }
+
+    /// Replace each element with its sign, in place, like
+    /// [`signum`](Self::signum).
+    pub fn signum_inplace(&mut self)
+    {
+        self.mapv_inplace(A::signum)
+    }
+}
+
 impl<A, D> ArrayRef<A, D>
 where
     A: 'static + PartialOrd + Clone,
@@ -150,20 +645,140 @@ where
 {
     /// Limit the values for each element, similar to NumPy's `clip` function.
     ///
+    /// Elements less than `min` become `min`, and elements greater than `max`
+    /// become `max`. Elements that can not be compared with the bounds, such
+    /// as `NaN`, are left unchanged.
+    ///
     /// ```
     /// use ndarray::array;
     ///
     /// let a = array![0., 1., 2., 3., 4., 5., 6., 7., 8., 9.];
     /// assert_eq!(a.clamp(1., 8.), array![1., 1., 2., 3., 4., 5., 6., 7., 8., 8.]);
     /// assert_eq!(a.clamp(3., 6.), array![3., 3., 3., 3., 4., 5., 6., 6., 6., 6.]);
+    /// assert!(array![f64::NAN].clamp(0., 1.)[0].is_nan());
     /// ```
     ///
     /// # Panics
     ///
-    /// Panics if `!(min <= max)`.
+    /// Panics if `!(min <= max)`, which includes `NaN` bounds.
+    #[track_caller]
     pub fn clamp(&self, min: A, max: A) -> Array<A, D>
     {
         assert!(min <= max, "min must be less than or equal to max");
         self.mapv(|a| num_traits::clamp(a, min.clone(), max.clone()))
     }
+
+    /// Limit the values of the elements in place, like
+    /// [`clamp`](Self::clamp).
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![-2, 0, 3, 7];
+    /// a.clamp_inplace(0, 5);
+    /// assert_eq!(a, array![0, 0, 3, 5]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `!(min <= max)`, which includes `NaN` bounds.
+    #[track_caller]
+    pub fn clamp_inplace(&mut self, min: A, max: A)
+    {
+        assert!(min <= max, "min must be less than or equal to max");
+        self.map_inplace(|a| *a = num_traits::clamp(a.clone(), min.clone(), max.clone()));
+    }
+
+    /// Limit the values for each element to the corresponding elements of
+    /// `min` and `max`, which are broadcast to the shape of the array.
+    ///
+    /// This is [`clamp`](Self::clamp) with a pair of bounds for each element;
+    /// elements that can not be compared with their bounds, such as `NaN`,
+    /// are left unchanged.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[0., 5., 10.],
+    ///                [-5., 2., f64::NAN]];
+    /// let min = array![1., 2., 3.];
+    /// let max = array![[4.], [6.]];
+    /// let clamped = a.clamp_arrays(&min, &max);
+    /// assert_eq!(clamped.row(0), array![1., 4., 4.]);
+    /// assert_eq!(clamped[[1, 0]], 1.);
+    /// assert!(clamped[[1, 2]].is_nan());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` or `max` can not be broadcast to the shape of the
+    /// array, or if `!(min <= max)` for any pair of bounds.
+    #[track_caller]
+    pub fn clamp_arrays<E, F>(&self, min: &ArrayRef<A, E>, max: &ArrayRef<A, F>) -> Array<A, D>
+    where
+        E: Dimension,
+        F: Dimension,
+    {
+        let (min, max) = broadcast_bounds(self.raw_dim(), min, max);
+        Zip::from(self)
+            .and(&min)
+            .and(&max)
+            .map_collect(|a, min, max| clamp_checked(a.clone(), min, max))
+    }
+
+    /// Limit the values of the elements in place to the corresponding
+    /// elements of `min` and `max`, like [`clamp_arrays`](Self::clamp_arrays).
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[0, 5, 10],
+    ///                    [-5, 2, 8]];
+    /// let min = array![1, 2, 3];
+    /// let max = array![4, 9, 9];
+    /// a.clamp_arrays_inplace(&min, &max);
+    /// assert_eq!(a, array![[1, 5, 9],
+    ///                      [1, 2, 8]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` or `max` can not be broadcast to the shape of the
+    /// array, or if `!(min <= max)` for any pair of bounds.
+    #[track_caller]
+    pub fn clamp_arrays_inplace<E, F>(&mut self, min: &ArrayRef<A, E>, max: &ArrayRef<A, F>)
+    where
+        E: Dimension,
+        F: Dimension,
+    {
+        let (min, max) = broadcast_bounds(self.raw_dim(), min, max);
+        Zip::from(self)
+            .and(&min)
+            .and(&max)
+            .for_each(|a, min, max| *a = clamp_checked(a.clone(), min, max));
+    }
+}
+
+/// Return `min` and `max` broadcast to the shape `dim` of the array they
+/// bound.
+#[track_caller]
+fn broadcast_bounds<'a, A, D, E, F>(
+    dim: D, min: &'a ArrayRef<A, E>, max: &'a ArrayRef<A, F>,
+) -> (ArrayView<'a, A, D>, ArrayView<'a, A, D>)
+where
+    D: Dimension,
+    E: Dimension,
+    F: Dimension,
+{
+    match (min.broadcast(dim.clone()), max.broadcast(dim)) {
+        (Some(min), Some(max)) => (min, max),
+        _ => panic!("min and max must broadcast to the shape of the array"),
+    }
+}
+
+fn clamp_checked<A>(a: A, min: &A, max: &A) -> A
+where A: PartialOrd + Clone
+{
+    assert!(min <= max, "min must be less than or equal to max");
+    num_traits::clamp(a, min.clone(), max.clone())
 }
@@ -0,0 +1,275 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::{Float, FromPrimitive};
+
+use crate::error::{from_kind, ErrorKind, StatsError};
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// The bins of a histogram, for [`histogram`](ArrayRef::histogram).
+///
+/// Like in NumPy, every bin is half-open, `[left, right)`, except the last
+/// one which also includes its right edge.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub enum Bins<A>
+{
+    /// The given number of equal-width bins spanning the smallest to the
+    /// largest (non-`NaN`) value of the data.
+    ///
+    /// If the data is empty the bins span `[0, 1]`, and if all values are
+    /// equal to `x` they span `[x - 0.5, x + 0.5]`.
+    Count(usize),
+    /// The bins between consecutive edges, which must be sorted in
+    /// increasing order.
+    Edges(Array1<A>),
+}
+
+/// # Histograms
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float + FromPrimitive,
+    D: Dimension,
+{
+    /// Return the histogram of the elements of the array, as the number of
+    /// elements in each bin and the `bins + 1` edges of the bins.
+    ///
+    /// Elements outside the range of the bins, and `NaN`s, are not counted;
+    /// see [`histogram_clamped`](Self::histogram_clamped) to count them in
+    /// the first and last bin instead.
+    ///
+    /// ***Errors*** with [`StatsError::UnsortedEdges`] if `bins` is `Edges`
+    /// and the edges are not in increasing order, like
+    /// [`digitize`](Self::digitize).
+    ///
+    /// **Panics** if `bins` is `Count(0)`, if `bins` is `Edges` with fewer
+    /// than two edges, or if `bins` is `Count` and the range of the data is
+    /// not finite.
+    ///
+    /// ```
+    /// use ndarray::{array, Bins};
+    ///
+    /// let a = array![1., 2., 2., 3., 4.];
+    /// let (counts, edges) = a.histogram(Bins::Count(3)).unwrap();
+    /// assert_eq!(counts, array![1, 2, 2]);
+    /// assert_eq!(edges, array![1., 2., 3., 4.]);
+    ///
+    /// let (counts, _) = a.histogram(Bins::Edges(array![0., 2., 3.])).unwrap();
+    /// assert_eq!(counts, array![1, 3]);
+    /// ```
+    #[track_caller]
+    pub fn histogram(&self, bins: Bins<A>) -> Result<(Array1<usize>, Array1<A>), StatsError>
+    {
+        self.histogram_impl(bins, false)
+    }
+
+    /// Return the histogram of the elements of the array, counting elements
+    /// below the first edge in the first bin and elements above the last edge
+    /// in the last bin.
+    ///
+    /// `NaN`s are not counted. Otherwise this is the same as
+    /// [`histogram`](Self::histogram).
+    ///
+    /// ***Errors*** and **Panics** under the same conditions as
+    /// [`histogram`](Self::histogram).
+    ///
+    /// ```
+    /// use ndarray::{array, Bins};
+    ///
+    /// let a = array![-5., 1., 2., 9., f64::NAN];
+    /// let (counts, _) = a.histogram_clamped(Bins::Edges(array![0., 2., 3.])).unwrap();
+    /// assert_eq!(counts, array![2, 2]);
+    /// ```
+    #[track_caller]
+    pub fn histogram_clamped(&self, bins: Bins<A>) -> Result<(Array1<usize>, Array1<A>), StatsError>
+    {
+        self.histogram_impl(bins, true)
+    }
+
+    #[track_caller]
+    fn histogram_impl(&self, bins: Bins<A>, clamp: bool) -> Result<(Array1<usize>, Array1<A>), StatsError>
+    {
+        let edges = BinEdges::new(bins, self.iter())?;
+        let mut counts = Array1::zeros(edges.len());
+        for &x in self.iter() {
+            if let Some(i) = edges.index(x, clamp) {
+                counts[i] += 1;
+            }
+        }
+        Ok((counts, edges.edges))
+    }
+}
+
+/// Return the two-dimensional histogram of the points `(x[i], y[i])`, as the
+/// number of points in each pair of bins and the edges of the bins along `x`
+/// and along `y`.
+///
+/// Element `[i, j]` of the counts is the number of points in bin `i` of `x`
+/// and bin `j` of `y`. The bins are resolved from each coordinate like for
+/// [`histogram`](ArrayRef::histogram), with the same edge conventions, and
+/// points that are outside the range of the bins, or that have a `NaN`
+/// coordinate, are not counted.
+///
+/// ***Errors*** with [`StatsError::Shape`] if `x` and `y` do not have the
+/// same length, and with [`StatsError::UnsortedEdges`] if the edges of either
+/// of the bins are not in increasing order.
+///
+/// **Panics** under the same conditions as [`histogram`](ArrayRef::histogram),
+/// for either of the bins.
+///
+/// ```
+/// use ndarray::{array, histogram2d, Bins};
+///
+/// let x = array![0., 1., 1., 2.];
+/// let y = array![0., 0., 5., 10.];
+/// let (counts, xedges, yedges) = histogram2d(&x, &y, (Bins::Count(2), Bins::Count(2))).unwrap();
+/// assert_eq!(counts, array![[1, 0],
+///                           [1, 2]]);
+/// assert_eq!(xedges, array![0., 1., 2.]);
+/// assert_eq!(yedges, array![0., 5., 10.]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[track_caller]
+#[allow(clippy::type_complexity)]
+pub fn histogram2d<A>(
+    x: &ArrayRef<A, Ix1>, y: &ArrayRef<A, Ix1>, bins: (Bins<A>, Bins<A>),
+) -> Result<(Array2<usize>, Array1<A>, Array1<A>), StatsError>
+where A: Float + FromPrimitive
+{
+    if x.len() != y.len() {
+        return Err(from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let xedges = BinEdges::new(bins.0, x.iter())?;
+    let yedges = BinEdges::new(bins.1, y.iter())?;
+    let mut counts = Array2::zeros((xedges.len(), yedges.len()));
+    Zip::from(x).and(y).for_each(|&x, &y| {
+        if let (Some(i), Some(j)) = (xedges.index(x, false), yedges.index(y, false)) {
+            counts[[i, j]] += 1;
+        }
+    });
+    Ok((counts, xedges.edges, yedges.edges))
+}
+
+/// Resolved histogram bins: contiguous edges, and whether they are uniform so
+/// that the bin of a value can be computed instead of searched for.
+struct BinEdges<A>
+{
+    edges: Array1<A>,
+    uniform: bool,
+}
+
+impl<A> BinEdges<A>
+where A: Float + FromPrimitive
+{
+    /// Resolve `bins`, using `data` for the range of `Bins::Count`.
+    #[track_caller]
+    fn new<'a, I>(bins: Bins<A>, data: I) -> Result<Self, StatsError>
+    where
+        I: IntoIterator<Item = &'a A>,
+        A: 'a,
+    {
+        match bins {
+            Bins::Count(n) => {
+                assert!(n > 0, "the number of bins must be greater than zero");
+                let (lo, hi) = data_range(data);
+                assert!(
+                    lo.is_finite() && hi.is_finite(),
+                    "the range of the data must be finite to use uniform bins"
+                );
+                let n_a = A::from_usize(n).expect("Converting number of bins to `A` must not fail.");
+                let edges = Array1::from_shape_fn(n + 1, |i| {
+                    if i == n {
+                        hi
+                    } else {
+                        let i = A::from_usize(i).expect("Converting bin index to `A` must not fail.");
+                        lo + (hi - lo) * i / n_a
+                    }
+                });
+                Ok(BinEdges { edges, uniform: true })
+            }
+            Bins::Edges(edges) => {
+                assert!(edges.len() >= 2, "there must be at least two bin edges");
+                let edges = if edges.as_slice().is_some() {
+                    edges
+                } else {
+                    edges.iter().cloned().collect()
+                };
+                if !edges.as_slice().unwrap().windows(2).all(|w| w[0] <= w[1]) {
+                    return Err(StatsError::UnsortedEdges);
+                }
+                Ok(BinEdges { edges, uniform: false })
+            }
+        }
+    }
+
+    /// Return the number of bins.
+    fn len(&self) -> usize
+    {
+        self.edges.len() - 1
+    }
+
+    /// Return the bin that `x` falls into, or `None` if `x` is `NaN` or out
+    /// of range and `clamp` is false.
+    fn index(&self, x: A, clamp: bool) -> Option<usize>
+    {
+        let e = self.edges.as_slice().unwrap();
+        let n = e.len() - 1;
+        if x.is_nan() {
+            None
+        } else if x < e[0] {
+            if clamp {
+                Some(0)
+            } else {
+                None
+            }
+        } else if x >= e[n] {
+            if clamp || x == e[n] {
+                Some(n - 1)
+            } else {
+                None
+            }
+        } else if self.uniform {
+            // e[0] <= x < e[n]; correct the computed bin for rounding errors
+            let t = (x - e[0]) / (e[n] - e[0]) * A::from_usize(n).unwrap();
+            let mut i = t.to_usize().unwrap_or(0).min(n - 1);
+            while x < e[i] {
+                i -= 1;
+            }
+            while x >= e[i + 1] {
+                i += 1;
+            }
+            Some(i)
+        } else {
+            // the first edge is <= x and the last is > x, so 1 <= p <= n
+            Some(e.partition_point(|&edge| edge <= x) - 1)
+        }
+    }
+}
+
+/// Return the range of the non-`NaN` values of `data` for uniform bins.
+fn data_range<'a, A, I>(data: I) -> (A, A)
+where
+    A: Float + FromPrimitive + 'a,
+    I: IntoIterator<Item = &'a A>,
+{
+    let range = data
+        .into_iter()
+        .filter(|x| !x.is_nan())
+        .fold(None, |range, &x| match range {
+            None => Some((x, x)),
+            Some((lo, hi)) => Some((x.min(lo), x.max(hi))),
+        });
+    let half = A::from_f64(0.5).expect("Converting 0.5 to `A` must not fail.");
+    match range {
+        None => (A::zero(), A::one()),
+        Some((lo, hi)) if lo == hi => (lo - half, hi + half),
+        Some(range) => range,
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use num_traits::Float;
+
+use super::impl_digitize::is_sorted;
+use crate::error::{from_kind, ErrorKind, StatsError};
+use crate::imp_prelude::*;
+
+/// # Interpolation
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    /// Return the piecewise linear interpolation at each element of the
+    /// array of the function with values `fp` at the points `xp`.
+    ///
+    /// Like NumPy's `interp`, each element `x` is interpolated between the
+    /// two points of `xp` around it, and an element equal to a point gets
+    /// exactly the value of the function there. Elements below `xp[0]` get
+    /// `left`, and elements above the last point get `right`, which default
+    /// to the values of the function at the first and the last point. `NaN`
+    /// elements are interpolated to `NaN`.
+    ///
+    /// The points around each element are found with a binary search, or,
+    /// if the elements are already sorted, with a single scan over the
+    /// elements and the points together.
+    ///
+    /// ***Errors*** with [`StatsError::Shape`] if `xp` and `fp` do not have
+    /// the same length, with [`StatsError::EmptyInput`] if they are empty,
+    /// and with [`StatsError::UnsortedEdges`] if `xp` is not in increasing
+    /// order.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let xp = array![0., 1., 3.];
+    /// let fp = array![10., 20., 0.];
+    /// let x = array![[-1., 0.5, 1.],
+    ///                [2., 3., 4.]];
+    /// assert_eq!(x.interp(&xp, &fp, None, None), Ok(array![[10., 15., 20.],
+    ///                                                      [10., 0., 0.]]));
+    /// let y = x.interp(&xp, &fp, Some(f64::NAN), Some(-1.)).unwrap();
+    /// assert!(y[[0, 0]].is_nan());
+    /// assert_eq!(y[[1, 2]], -1.);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+    pub fn interp(
+        &self, xp: &ArrayRef<A, Ix1>, fp: &ArrayRef<A, Ix1>, left: Option<A>, right: Option<A>,
+    ) -> Result<Array<A, D>, StatsError>
+    {
+        if xp.len() != fp.len() {
+            return Err(StatsError::Shape(from_kind(ErrorKind::IncompatibleShape)));
+        }
+        if xp.is_empty() {
+            return Err(StatsError::EmptyInput);
+        }
+        let xp = xp.as_standard_layout();
+        let xp = xp.as_slice().unwrap();
+        let fp = fp.as_standard_layout();
+        let fp = fp.as_slice().unwrap();
+        if !is_sorted(xp.iter()) {
+            return Err(StatsError::UnsortedEdges);
+        }
+        let left = left.unwrap_or(fp[0]);
+        let right = right.unwrap_or(fp[fp.len() - 1]);
+        // Interpolate `x`, given the number `i` of points that are less than
+        // or equal to it.
+        let at = |x: A, i: usize| {
+            if x.is_nan() {
+                x
+            } else if i == 0 {
+                left
+            } else if x == xp[i - 1] {
+                fp[i - 1]
+            } else if i == xp.len() {
+                right
+            } else {
+                let slope = (fp[i] - fp[i - 1]) / (xp[i] - xp[i - 1]);
+                fp[i - 1] + slope * (x - xp[i - 1])
+            }
+        };
+        if is_sorted(self.iter()) {
+            let mut res = Vec::with_capacity(self.len());
+            let mut i = 0;
+            for &x in self.iter() {
+                while i < xp.len() && xp[i] <= x {
+                    i += 1;
+                }
+                res.push(at(x, i));
+            }
+            Ok(Array::from_shape_vec(self.raw_dim(), res).unwrap())
+        } else {
+            Ok(self.mapv(|x| at(x, xp.partition_point(|&p| p <= x))))
+        }
+    }
+}
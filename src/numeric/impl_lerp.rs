@@ -0,0 +1,186 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Float;
+
+use crate::dimension::DimMax;
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// # Linear Blending
+///
+/// These methods interpolate linearly between the elements of the array and
+/// those of another array, `a + t * (b - a)` for a weight `t` that is a
+/// scalar or an array of weights, after broadcasting like with the arithmetic
+/// operators. Unlike with the arithmetic operators, no intermediate arrays
+/// are allocated.
+///
+/// The result is exact at the ends, `a` for `t == 0` and `b` for `t == 1`,
+/// also for infinite ends, and `a` for any `t` if `a == b`. Weights outside
+/// of `[0, 1]` extrapolate.
+///
+/// The methods are for floats only, since `b - a` is out of range for
+/// unsigned integers if `b < a`.
+///
+/// ```compile_fail
+/// use ndarray::array;
+///
+/// let _ = array![10u8, 20].lerp(&array![0u8, 30], 0);
+/// ```
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    /// Return the linear interpolation at `t` between each element and the
+    /// corresponding element of `other`, after broadcasting both to their
+    /// common shape.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[0., 10.],
+    ///                [2., 4.]];
+    /// let b = array![1., 20.];
+    /// assert_eq!(a.lerp(&b, 0.25), Ok(array![[0.25, 12.5],
+    ///                                         [1.75, 8.]]));
+    /// assert_eq!(a.lerp(&b, 1.), Ok(array![[1., 20.],
+    ///                                      [1., 20.]]));
+    /// ```
+    pub fn lerp<E>(&self, other: &ArrayRef<A, E>, t: A) -> Result<Array<A, <D as DimMax<E>>::Output>, ShapeError>
+    where
+        E: Dimension,
+        D: DimMax<E>,
+    {
+        let (a, b) = self.broadcast_with(other)?;
+        Ok(Zip::from(&a).and(&b).map_collect(|a, b| lerp(*a, *b, t)))
+    }
+
+    /// Return the linear interpolation between each element and the
+    /// corresponding element of `other` at the corresponding weight of `t`,
+    /// after broadcasting all three to their common shape.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// // a cross-fade from `a` to `b` in five steps
+    /// let a = array![0., 8.];
+    /// let b = array![4., 0.];
+    /// let t = array![[0.], [0.25], [0.5], [0.75], [1.]];
+    /// assert_eq!(a.lerp_elem(&b, &t), Ok(array![[0., 8.],
+    ///                                           [1., 6.],
+    ///                                           [2., 4.],
+    ///                                           [3., 2.],
+    ///                                           [4., 0.]]));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn lerp_elem<E1, E2>(
+        &self, other: &ArrayRef<A, E1>, t: &ArrayRef<A, E2>,
+    ) -> Result<Array<A, <<D as DimMax<E1>>::Output as DimMax<E2>>::Output>, ShapeError>
+    where
+        E1: Dimension,
+        E2: Dimension,
+        D: DimMax<E1>,
+        <D as DimMax<E1>>::Output: DimMax<E2>,
+    {
+        let (a, b) = self.broadcast_with(other)?;
+        let (a, t) = a.broadcast_with(t)?;
+        let b = b
+            .broadcast(a.raw_dim())
+            .ok_or_else(|| from_kind(ErrorKind::IncompatibleShape))?;
+        Ok(Zip::from(&a)
+            .and(&b)
+            .and(&t)
+            .map_collect(|a, b, t| lerp(*a, *b, *t)))
+    }
+
+    /// Replace each element with the linear interpolation at `t` between it
+    /// and the corresponding element of `other`, in place.
+    ///
+    /// `other` is broadcast to the shape of the array.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if `other` can not be broadcast to
+    /// the shape of the array, in which case the array is not modified.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// // move a set of points halfway towards the origin
+    /// let mut points = array![[2., 4.],
+    ///                         [-6., 0.]];
+    /// let origin = array![0., 0.];
+    /// points.lerp_inplace(&origin, 0.5).unwrap();
+    /// assert_eq!(points, array![[1., 2.],
+    ///                           [-3., 0.]]);
+    /// ```
+    pub fn lerp_inplace<E>(&mut self, other: &ArrayRef<A, E>, t: A) -> Result<(), ShapeError>
+    where E: Dimension
+    {
+        let other = other
+            .broadcast(self.raw_dim())
+            .ok_or_else(|| from_kind(ErrorKind::IncompatibleShape))?;
+        Zip::from(self)
+            .and(&other)
+            .for_each(|a, b| *a = lerp(*a, *b, t));
+        Ok(())
+    }
+
+    /// Replace each element with the linear interpolation between it and the
+    /// corresponding element of `other` at the corresponding weight of `t`,
+    /// in place.
+    ///
+    /// `other` and `t` are broadcast to the shape of the array.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if `other` or `t` can not be
+    /// broadcast to the shape of the array, in which case the array is not
+    /// modified.
+    pub fn lerp_elem_inplace<E1, E2>(&mut self, other: &ArrayRef<A, E1>, t: &ArrayRef<A, E2>) -> Result<(), ShapeError>
+    where
+        E1: Dimension,
+        E2: Dimension,
+    {
+        let dim = self.raw_dim();
+        let (other, t) = match (other.broadcast(dim.clone()), t.broadcast(dim)) {
+            (Some(other), Some(t)) => (other, t),
+            _ => return Err(from_kind(ErrorKind::IncompatibleShape)),
+        };
+        Zip::from(self)
+            .and(&other)
+            .and(&t)
+            .for_each(|a, b, t| *a = lerp(*a, *b, *t));
+        Ok(())
+    }
+}
+
+/// Return `a + t * (b - a)`, which is `a` for `t == 0` and when `a == b`, and
+/// `b` for `t == 1`.
+fn lerp<A>(a: A, b: A, t: A) -> A
+where A: Float
+{
+    if t.is_zero() {
+        return a;
+    } else if t.is_one() {
+        return b;
+    }
+    let d = b - a;
+    if !d.is_finite() {
+        // with an infinite end, `t * d` is not finite either
+        a * (A::one() - t) + b * t
+    } else if t < A::from(0.5).unwrap() {
+        a + t * d
+    } else {
+        b - (A::one() - t) * d
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Float;
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// # Log-space Methods for Float Arrays
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    /// Return `ln(∑ exp(xᵢ))` over all elements in the array, computed
+    /// without overflow as `max + ln(∑ exp(xᵢ - max))`.
+    ///
+    /// The result is `-inf` if the array is empty or if all elements are
+    /// `-inf`, and `NaN` if any element is `NaN`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1000., 1000.];
+    /// assert_eq!(a.logsumexp(), 1000. + 2f64.ln());
+    /// assert_eq!(array![f64::NEG_INFINITY, f64::NEG_INFINITY].logsumexp(), f64::NEG_INFINITY);
+    /// ```
+    pub fn logsumexp(&self) -> A
+    {
+        if let Some(slc) = self.as_slice_memory_order() {
+            log_sum_exp(slc)
+        } else {
+            log_sum_exp(self.iter())
+        }
+    }
+
+    /// Return `ln(∑ exp(xᵢ))` along `axis`.
+    ///
+    /// Each lane is reduced like [`logsumexp`](Self::logsumexp), in two
+    /// passes over the lane (one for its maximum and one for the sum) and
+    /// without allocating any intermediate array.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[0., 1000.],
+    ///                [0., 1000.]]);
+    /// let lse = a.logsumexp_axis(Axis(0));
+    /// assert_eq!(lse[1], 1000. + 2f64.ln());
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn logsumexp_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where D: RemoveAxis
+    {
+        Zip::from(self.lanes(axis)).map_collect(|lane| {
+            if let Some(slc) = lane.as_slice() {
+                log_sum_exp(slc)
+            } else {
+                log_sum_exp(lane)
+            }
+        })
+    }
+
+    /// Return the softmax of the array along `axis`, that is
+    /// `exp(xᵢ - max) / ∑ exp(xⱼ - max)` for each lane, where `max` is the
+    /// maximum of the lane.
+    ///
+    /// Subtracting the maximum keeps the computation stable for elements of
+    /// large magnitude. Elements that are `-inf` become zero. Lanes that
+    /// contain `NaN` or `+inf`, or only `-inf`s, become `NaN`.
+    ///
+    /// See [`softmax_inplace`](Self::softmax_inplace) for a version that
+    /// does not allocate.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[1000., 1000., f64::NEG_INFINITY],
+    ///                [0., 0., 0.]]);
+    /// let p = a.softmax(Axis(1));
+    /// assert_eq!(p.row(0), arr2(&[[0.5, 0.5, 0.]]).row(0));
+    /// assert!((p[(1, 0)] - 1. / 3.).abs() < 1e-15);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn softmax(&self, axis: Axis) -> Array<A, D>
+    {
+        let mut res = self.to_owned();
+        res.softmax_inplace(axis);
+        res
+    }
+
+    /// Replace the array with its softmax along `axis`.
+    ///
+    /// This is the in-place version of [`softmax`](Self::softmax), see there
+    /// for details.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn softmax_inplace(&mut self, axis: Axis)
+    {
+        Zip::from(self.lanes_mut(axis)).for_each(|mut lane| {
+            let max = nan_max(lane.iter());
+            lane.mapv_inplace(|x| (x - max).exp());
+            let sum = lane.iter().fold(A::zero(), |sum, &x| sum + x);
+            lane.mapv_inplace(|x| x / sum);
+        });
+    }
+
+    /// Return the product of all elements in the array as its sign and the
+    /// natural logarithm of its magnitude, `(sign, ln|∏ xᵢ|)`.
+    ///
+    /// The logarithms of the magnitudes are summed, so this does not
+    /// overflow or underflow for products far outside the range of `A`, such
+    /// as the product of many small probabilities. The product is
+    /// `sign * exp(ln_abs)`.
+    ///
+    /// The sign is `1`, `-1`, or `0` if any element is zero, in which case
+    /// the logarithm is `-inf`. Both are `NaN` if any element is `NaN`. The
+    /// product of an empty array is `(1, 0)`.
+    ///
+    /// ```
+    /// use ndarray::{array, Array};
+    ///
+    /// let p = Array::from_elem(1000, 1e-3);
+    /// assert_eq!(p.product(), 0.);
+    /// let (sign, ln_abs) = p.product_log();
+    /// assert_eq!(sign, 1.);
+    /// assert!((ln_abs - 1000. * 1e-3f64.ln()).abs() < 1e-9);
+    /// assert_eq!(array![-2., 0.5, 3.].product_log(), (-1., 3f64.ln()));
+    /// ```
+    pub fn product_log(&self) -> (A, A)
+    {
+        self.fold((A::one(), A::zero()), |(sign, ln_abs), &x| {
+            let x_sign = if x == A::zero() { A::zero() } else { x.signum() };
+            (sign * x_sign, ln_abs + x.abs().ln())
+        })
+    }
+}
+
+/// Return the maximum of `iter`, or `NaN` if any element is `NaN`, or `-inf`
+/// if `iter` is empty.
+fn nan_max<'a, A, I>(iter: I) -> A
+where
+    A: 'a + Float,
+    I: IntoIterator<Item = &'a A>,
+{
+    iter.into_iter()
+        .fold(A::neg_infinity(), |max, &x| if x > max || x.is_nan() { x } else { max })
+}
+
+/// Return `ln(∑ exp(xᵢ))` over `iter` in two passes.
+fn log_sum_exp<'a, A, I>(iter: I) -> A
+where
+    A: 'a + Float,
+    I: IntoIterator<Item = &'a A> + Clone,
+{
+    let max = nan_max(iter.clone());
+    // shifting by an infinite max would give `NaN`, but then the max is the
+    // result; this is also where a lane of only `-inf` gives `-inf`
+    if max.is_infinite() || max.is_nan() {
+        return max;
+    }
+    let sum = iter
+        .into_iter()
+        .fold(A::zero(), |sum, &x| sum + (x - max).exp());
+    max + sum.ln()
+}
@@ -0,0 +1,319 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::Float;
+use num_traits::{FromPrimitive, Zero};
+use std::ops::{Add, Div};
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use super::impl_numeric::check_ddof;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::error::StatsError;
+use crate::error::{from_kind, ErrorKind};
+use crate::imp_prelude::*;
+use crate::{ShapeError, Zip};
+
+/// # Masked Reductions
+///
+/// These methods reduce only the elements of the array where `mask` is
+/// `true`, without copying them out first. `mask` is broadcast to the shape
+/// of the array, and an error is returned if that is not possible.
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Return the sum of the elements of the array where `mask` is `true`.
+    ///
+    /// The sum is zero if `mask` is `false` everywhere.
+    ///
+    /// ***Errors*** if `mask` can not be broadcast to the shape of the array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// let mask = array![[true, false, true],
+    ///                   [false, false, true]];
+    /// assert_eq!(a.sum_where(&mask), Ok(10));
+    /// // the mask of each column, broadcast over the rows
+    /// let columns = array![false, true, true];
+    /// assert_eq!(a.sum_where(&columns), Ok(16));
+    /// ```
+    pub fn sum_where<E>(&self, mask: &ArrayRef<bool, E>) -> Result<A, ShapeError>
+    where
+        A: Clone + Add<Output = A> + Zero,
+        E: Dimension,
+    {
+        let mask = broadcast_mask(mask, self.raw_dim())?;
+        Ok(Zip::from(self)
+            .and(&mask)
+            .fold(A::zero(), |sum, x, &m| if m { sum + x.clone() } else { sum }))
+    }
+
+    /// Return the mean of the elements of the array where `mask` is `true`,
+    /// or `None` if `mask` is `false` everywhere.
+    ///
+    /// ***Errors*** if `mask` can not be broadcast to the shape of the array.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of
+    /// elements where `mask` is `true`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2., 3.],
+    ///                [4., 5., 6.]];
+    /// let mask = a.mapv(|x| x > 2.5);
+    /// assert_eq!(a.mean_where(&mask), Ok(Some(4.5)));
+    /// assert_eq!(a.mean_where(&ndarray::arr0(false)), Ok(None));
+    /// ```
+    #[track_caller]
+    pub fn mean_where<E>(&self, mask: &ArrayRef<bool, E>) -> Result<Option<A>, ShapeError>
+    where
+        A: Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero,
+        E: Dimension,
+    {
+        let mask = broadcast_mask(mask, self.raw_dim())?;
+        let (sum, count) = Zip::from(self)
+            .and(&mask)
+            .fold((A::zero(), 0), |(sum, count), x, &m| if m { (sum + x.clone(), count + 1) } else { (sum, count) });
+        if count == 0 {
+            return Ok(None);
+        }
+        let count = A::from_usize(count).expect("Converting number of elements to `A` must not fail.");
+        Ok(Some(sum / count))
+    }
+
+    /// Return the sums along `axis` of the elements where `mask` is `true`.
+    ///
+    /// `mask` is broadcast to the shape of the array, not to the shape of the
+    /// result, so it selects elements within each lane.
+    ///
+    /// ***Errors*** if `mask` can not be broadcast to the shape of the array.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// let mask = array![true, false, true];
+    /// assert_eq!(a.sum_axis_where(Axis(0), &mask), Ok(array![5, 0, 9]));
+    /// assert_eq!(a.sum_axis_where(Axis(1), &mask), Ok(array![4, 10]));
+    /// ```
+    #[track_caller]
+    pub fn sum_axis_where<E>(&self, axis: Axis, mask: &ArrayRef<bool, E>) -> Result<Array<A, D::Smaller>, ShapeError>
+    where
+        A: Clone + Add<Output = A> + Zero,
+        D: RemoveAxis,
+        E: Dimension,
+    {
+        Ok(self.masked_sum_axis(axis, mask)?.0)
+    }
+
+    /// Return the means along `axis` of the elements where `mask` is `true`.
+    ///
+    /// Each lane is divided by its own number of selected elements, and the
+    /// mean of a lane is `None` if `mask` is `false` everywhere in the lane.
+    /// `mask` is broadcast to the shape of the array, not to the shape of the
+    /// result.
+    ///
+    /// ***Errors*** if `mask` can not be broadcast to the shape of the array.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `A::from_usize()` fails
+    /// to convert the number of selected elements of a lane.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 2., 3.],
+    ///                [4., 5., 6.]];
+    /// let mask = array![[true, false, true],
+    ///                   [false, false, true]];
+    /// assert_eq!(a.mean_axis_where(Axis(1), &mask), Ok(array![Some(2.), Some(6.)]));
+    /// assert_eq!(a.mean_axis_where(Axis(0), &mask), Ok(array![Some(1.), None, Some(4.5)]));
+    /// ```
+    #[track_caller]
+    pub fn mean_axis_where<E>(
+        &self, axis: Axis, mask: &ArrayRef<bool, E>,
+    ) -> Result<Array<Option<A>, D::Smaller>, ShapeError>
+    where
+        A: Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero,
+        D: RemoveAxis,
+        E: Dimension,
+    {
+        let (sum, count) = self.masked_sum_axis(axis, mask)?;
+        Ok(Zip::from(&sum).and(&count).map_collect(|sum, &count| {
+            if count == 0 {
+                None
+            } else {
+                let count = A::from_usize(count).expect("Converting number of elements to `A` must not fail.");
+                Some(sum.clone() / count)
+            }
+        }))
+    }
+
+    /// Return the sums along `axis` of the elements where `mask` is `true`,
+    /// and the number of these elements in each lane.
+    #[track_caller]
+    #[allow(clippy::type_complexity)]
+    fn masked_sum_axis<E>(
+        &self, axis: Axis, mask: &ArrayRef<bool, E>,
+    ) -> Result<(Array<A, D::Smaller>, Array<usize, D::Smaller>), ShapeError>
+    where
+        A: Clone + Add<Output = A> + Zero,
+        D: RemoveAxis,
+        E: Dimension,
+    {
+        let mask = broadcast_mask(mask, self.raw_dim())?;
+        let mut sum = Array::<A, _>::zeros(self.raw_dim().remove_axis(axis));
+        let mut count = Array::<usize, _>::zeros(sum.raw_dim());
+        for (subview, mask) in self.axis_iter(axis).zip(mask.axis_iter(axis)) {
+            Zip::from(&mut sum)
+                .and(&mut count)
+                .and(&subview)
+                .and(&mask)
+                .for_each(|sum, count, x, &m| {
+                    if m {
+                        *sum = sum.clone() + x.clone();
+                        *count += 1;
+                    }
+                });
+        }
+        Ok((sum, count))
+    }
+}
+
+/// # Masked Statistics of Float Arrays
+#[cfg(any(feature = "std", feature = "libm"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float + FromPrimitive,
+    D: Dimension,
+{
+    /// Return the variance of the elements of the array where `mask` is
+    /// `true`.
+    ///
+    /// This is the same as [`try_var`](Self::try_var) of the selected
+    /// elements, where `n` is the number of selected elements, and it is
+    /// computed in a single pass with the same algorithm.
+    ///
+    /// ***Errors*** with [`StatsError::Shape`] if `mask` can not be broadcast
+    /// to the shape of the array, with [`StatsError::DdofOutOfRange`] if
+    /// `ddof` is less than zero or greater than `n`, or else with
+    /// [`StatsError::EmptyInput`] if `mask` is `false` everywhere.
+    ///
+    /// ```
+    /// use ndarray::{array, StatsError};
+    ///
+    /// let a = array![[1., 2., 3.],
+    ///                [4., 5., 5.]];
+    /// let mask = array![[true, false, true],
+    ///                   [false, false, true]];
+    /// assert_eq!(a.var_where(&mask, 1.), Ok(4.));
+    /// assert_eq!(a.var_where(&mask, 4.), Err(StatsError::DdofOutOfRange { ddof: 4., n: 3 }));
+    /// ```
+    pub fn var_where<E>(&self, mask: &ArrayRef<bool, E>, ddof: A) -> Result<A, StatsError>
+    where E: Dimension
+    {
+        let mask = broadcast_mask(mask, self.raw_dim())?;
+        let (count, _, sum_sq) =
+            Zip::from(self)
+                .and(&mask)
+                .fold((0, A::zero(), A::zero()), |(count, mean, sum_sq), &x, &m| {
+                    if !m {
+                        return (count, mean, sum_sq);
+                    }
+                    let count = count + 1;
+                    let delta = x - mean;
+                    let mean = mean + delta / A::from_usize(count).expect("Converting index to `A` must not fail.");
+                    (count, mean, (x - mean).mul_add(delta, sum_sq))
+                });
+        let dof = check_ddof(ddof, count)?;
+        Ok(sum_sq / dof)
+    }
+
+    /// Return the variances along `axis` of the elements where `mask` is
+    /// `true`.
+    ///
+    /// Each lane uses its own number of selected elements `nᵢ`, see
+    /// [`var_where`](Self::var_where). The variance of a lane is NaN if
+    /// `ddof` is greater than `nᵢ`, or if `mask` is `false` everywhere in the
+    /// lane.
+    ///
+    /// ***Errors*** with [`StatsError::Shape`] if `mask` can not be broadcast
+    /// to the shape of the array, with [`StatsError::DdofOutOfRange`] if
+    /// `ddof` is less than zero or greater than the length of the axis, or
+    /// else with [`StatsError::EmptyInput`] if the axis has length zero.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 2., 3.],
+    ///                [4., 5., 7f64]];
+    /// let mask = array![[true, false, true],
+    ///                   [true, true, true]];
+    /// let var = a.var_axis_where(Axis(1), &mask, 0.).unwrap();
+    /// assert_eq!(var[0], 1.);
+    /// assert!((var[1] - 14. / 9.).abs() < 1e-15);
+    /// ```
+    #[track_caller]
+    pub fn var_axis_where<E>(
+        &self, axis: Axis, mask: &ArrayRef<bool, E>, ddof: A,
+    ) -> Result<Array<A, D::Smaller>, StatsError>
+    where
+        D: RemoveAxis,
+        E: Dimension,
+    {
+        check_ddof(ddof, self.len_of(axis))?;
+        let mask = broadcast_mask(mask, self.raw_dim())?;
+        let mut count = Array::<usize, _>::zeros(self.raw_dim().remove_axis(axis));
+        let mut mean = Array::<A, _>::zeros(count.raw_dim());
+        let mut sum_sq = Array::<A, _>::zeros(count.raw_dim());
+        for (subview, mask) in self.axis_iter(axis).zip(mask.axis_iter(axis)) {
+            Zip::from(&mut count)
+                .and(&mut mean)
+                .and(&mut sum_sq)
+                .and(&subview)
+                .and(&mask)
+                .for_each(|count, mean, sum_sq, &x, &m| {
+                    if m {
+                        *count += 1;
+                        let delta = x - *mean;
+                        *mean = *mean + delta / A::from_usize(*count).expect("Converting index to `A` must not fail.");
+                        *sum_sq = (x - *mean).mul_add(delta, *sum_sq);
+                    }
+                });
+        }
+        Ok(Zip::from(&sum_sq)
+            .and(&count)
+            .map_collect(|&sum_sq, &count| {
+                let n = A::from_usize(count).expect("Converting index to `A` must not fail.");
+                if ddof > n {
+                    A::nan()
+                } else {
+                    sum_sq / (n - ddof)
+                }
+            }))
+    }
+}
+
+/// Return `mask` broadcast to the shape `dim` of the array it masks.
+fn broadcast_mask<E, D>(mask: &ArrayRef<bool, E>, dim: D) -> Result<ArrayView<'_, bool, D>, ShapeError>
+where
+    E: Dimension,
+    D: Dimension,
+{
+    mask.broadcast(dim)
+        .ok_or_else(|| from_kind(ErrorKind::IncompatibleShape))
+}
@@ -0,0 +1,295 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::{Float, FromPrimitive};
+
+use crate::imp_prelude::*;
+use crate::numeric_util;
+
+/// # Geometric and Harmonic Means of Float Arrays
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    /// Return the geometric mean of all elements in the array.
+    ///
+    /// The geometric mean is computed in log space as `exp(mean(ln x))`, so
+    /// it does not overflow even when the product of the elements would.
+    ///
+    /// If the array is empty, `None` is returned. If any element is zero,
+    /// negative or `NaN`, the result is `NaN`.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of
+    /// elements in the array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let growth = array![1.25, 0.8, 2.];
+    /// assert!((growth.geomean().unwrap() - 2f64.cbrt()).abs() < 1e-15);
+    /// assert!(array![1., 0., 2f64].geomean().unwrap().is_nan());
+    /// ```
+    pub fn geomean(&self) -> Option<A>
+    where A: FromPrimitive
+    {
+        if self.is_empty() {
+            return None;
+        }
+        let n = A::from_usize(self.len()).expect("Converting number of elements to `A` must not fail.");
+        Some((sum_map(self, ln_or_nan) / n).exp())
+    }
+
+    /// Return the geometric mean along `axis`.
+    ///
+    /// Each lane is reduced like [`geomean`](Self::geomean).
+    ///
+    /// Return `None` if the length of the axis is zero.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails
+    /// for the length of the axis.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 4.],
+    ///                [4., 16f64]]);
+    /// let g = a.geomean_axis(Axis(0)).unwrap();
+    /// assert!((&g - &aview1(&[2., 8.])).iter().all(|d| d.abs() < 1e-12));
+    /// ```
+    #[track_caller]
+    pub fn geomean_axis(&self, axis: Axis) -> Option<Array<A, D::Smaller>>
+    where
+        A: FromPrimitive,
+        D: RemoveAxis,
+    {
+        let axis_length = self.len_of(axis);
+        if axis_length == 0 {
+            return None;
+        }
+        let n = A::from_usize(axis_length).expect("Converting axis length to `A` must not fail.");
+        Some(sum_map_axis(self, axis, ln_or_nan).mapv_into(|sum| (sum / n).exp()))
+    }
+
+    /// Return the harmonic mean of all elements in the array, that is
+    /// `n / ∑ 1/xᵢ`.
+    ///
+    /// If the array is empty, `None` is returned. If any element is zero, the
+    /// result is zero, which is the limit as that element goes to zero.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of
+    /// elements in the array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// // driving the same distance at 30 km/h and then at 60 km/h
+    /// let speeds = array![30., 60.];
+    /// assert_eq!(speeds.harmonic_mean(), Some(40.));
+    /// ```
+    pub fn harmonic_mean(&self) -> Option<A>
+    where A: FromPrimitive
+    {
+        if self.is_empty() {
+            return None;
+        }
+        let n = A::from_usize(self.len()).expect("Converting number of elements to `A` must not fail.");
+        // a zero element makes the sum infinite, and the mean zero
+        Some(n / sum_map(self, A::recip))
+    }
+
+    /// Return the harmonic mean along `axis`.
+    ///
+    /// Each lane is reduced like [`harmonic_mean`](Self::harmonic_mean), by
+    /// accumulating the reciprocals of all lanes in a single pass.
+    ///
+    /// Return `None` if the length of the axis is zero.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails
+    /// for the length of the axis.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 3.],
+    ///                [0., 6.]]);
+    /// assert_eq!(a.harmonic_mean_axis(Axis(0)).unwrap(), aview1(&[0., 4.]));
+    /// assert_eq!(a.harmonic_mean_axis(Axis(1)).unwrap(), aview1(&[1.5, 0.]));
+    /// ```
+    #[track_caller]
+    pub fn harmonic_mean_axis(&self, axis: Axis) -> Option<Array<A, D::Smaller>>
+    where
+        A: FromPrimitive,
+        D: RemoveAxis,
+    {
+        let axis_length = self.len_of(axis);
+        if axis_length == 0 {
+            return None;
+        }
+        let n = A::from_usize(axis_length).expect("Converting axis length to `A` must not fail.");
+        Some(sum_map_axis(self, axis, A::recip).mapv_into(|sum| n / sum))
+    }
+}
+
+/// # Sums of Squares of Float Arrays
+///
+/// These are fused reductions that square each element as it is added, so
+/// unlike `a.mapv(|x| x * x).sum()` they do not allocate.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    /// Return the sum of the squares of all elements in the array, `∑ xᵢ²`.
+    ///
+    /// The sum of an empty array is zero.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., -2.],
+    ///                [3., 4.]];
+    /// assert_eq!(a.sum_of_squares(), 30.);
+    /// ```
+    pub fn sum_of_squares(&self) -> A
+    {
+        sum_map(self, square)
+    }
+
+    /// Return the sum of the squares of the elements along `axis`.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., -2.],
+    ///                [3., 4.]]);
+    /// assert_eq!(a.sum_of_squares_axis(Axis(0)), aview1(&[10., 20.]));
+    /// assert_eq!(a.sum_of_squares_axis(Axis(1)), aview1(&[5., 25.]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn sum_of_squares_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where D: RemoveAxis
+    {
+        sum_map_axis(self, axis, square)
+    }
+
+    /// Return the root mean square (quadratic mean) of all elements in the
+    /// array, `sqrt(∑ xᵢ² / n)`.
+    ///
+    /// If the array is empty, `None` is returned.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of
+    /// elements in the array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1., -1., 7., -7.];
+    /// assert_eq!(a.rms(), Some(5.));
+    /// ```
+    pub fn rms(&self) -> Option<A>
+    where A: FromPrimitive
+    {
+        if self.is_empty() {
+            return None;
+        }
+        let n = A::from_usize(self.len()).expect("Converting number of elements to `A` must not fail.");
+        Some((self.sum_of_squares() / n).sqrt())
+    }
+
+    /// Return the root mean square along `axis`.
+    ///
+    /// Return `None` if the length of the axis is zero.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails
+    /// for the length of the axis.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[3., 0.],
+    ///                [-3., 4.]]);
+    /// assert_eq!(a.rms_axis(Axis(0)).unwrap(), aview1(&[3., 8f64.sqrt()]));
+    /// ```
+    #[track_caller]
+    pub fn rms_axis(&self, axis: Axis) -> Option<Array<A, D::Smaller>>
+    where
+        A: FromPrimitive,
+        D: RemoveAxis,
+    {
+        let axis_length = self.len_of(axis);
+        if axis_length == 0 {
+            return None;
+        }
+        let n = A::from_usize(axis_length).expect("Converting axis length to `A` must not fail.");
+        Some(
+            self.sum_of_squares_axis(axis)
+                .mapv_into(|sum| (sum / n).sqrt()),
+        )
+    }
+}
+
+fn square<A: Float>(x: A) -> A
+{
+    x * x
+}
+
+/// Return the natural logarithm of `x`, or `NaN` if `x` is not positive.
+fn ln_or_nan<A: Float>(x: A) -> A
+{
+    if x > A::zero() {
+        x.ln()
+    } else {
+        A::nan()
+    }
+}
+
+/// Return the sum of `map(x)` over all elements `x` of `a`.
+fn sum_map<A, D, F>(a: &ArrayRef<A, D>, map: F) -> A
+where
+    A: Float,
+    D: Dimension,
+    F: Fn(A) -> A,
+{
+    if let Some(slc) = a.as_slice_memory_order() {
+        return numeric_util::unrolled_fold_map(slc, A::zero, |&x| map(x), A::add);
+    }
+    let mut sum = A::zero();
+    for row in a.rows() {
+        if let Some(slc) = row.as_slice() {
+            sum = sum + numeric_util::unrolled_fold_map(slc, A::zero, |&x| map(x), A::add);
+        } else {
+            sum = sum + row.iter().fold(A::zero(), |acc, &x| acc + map(x));
+        }
+    }
+    sum
+}
+
+/// Return the sum of `map(x)` over the elements `x` of each lane along
+/// `axis`.
+fn sum_map_axis<A, D, F>(a: &ArrayRef<A, D>, axis: Axis, map: F) -> Array<A, D::Smaller>
+where
+    A: Float,
+    D: RemoveAxis,
+    F: Fn(A) -> A,
+{
+    let min_stride_axis = a.dim.min_stride_axis(&a.strides);
+    if axis == min_stride_axis {
+        crate::Zip::from(a.lanes(axis)).map_collect(|lane| sum_map(&lane, &map))
+    } else {
+        let mut res = Array::zeros(a.raw_dim().remove_axis(axis));
+        for subview in a.axis_iter(axis) {
+            res.zip_mut_with(&subview, |sum, &x| *sum = *sum + map(x));
+        }
+        res
+    }
+}
@@ -0,0 +1,214 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::{Float, FromPrimitive};
+
+use crate::imp_prelude::*;
+
+/// # Higher Moments of Float Arrays
+///
+/// The moments are computed in a single pass with the extension of the
+/// [Welford algorithm](https://www.jstor.org/stable/1266577) used by
+/// [`var`](ArrayRef::var) to the third and fourth central moments.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float + FromPrimitive,
+    D: Dimension,
+{
+    /// Return the skewness of the elements in the array.
+    ///
+    /// This is the (biased) sample skewness
+    ///
+    /// ```text
+    ///        m₃              1   n
+    /// g₁ = ――――――   where mₖ = ―   ∑ (xᵢ - x̅)ᵏ
+    ///      m₂^1.5            n  i=1
+    /// ```
+    ///
+    /// and `n` is the length of the array. It is NaN if there are fewer than
+    /// three elements, or if all elements are equal.
+    ///
+    /// **Panics** if `A::from_usize()` fails for any of the numbers in the
+    /// range `0..=n`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1., 2., 3., 4., 10f64];
+    /// assert!((a.skewness() - 1.1384199576606167).abs() < 1e-15);
+    /// assert_eq!(array![1., 2., 3.].skewness(), 0.);
+    /// ```
+    #[track_caller]
+    pub fn skewness(&self) -> A
+    {
+        self.fold(Moments::new(), |m, &x| m.update(x)).skewness()
+    }
+
+    /// Return the excess kurtosis of the elements in the array.
+    ///
+    /// This is the (biased) sample kurtosis with Fisher's definition,
+    /// which subtracts 3 so that a normal distribution has a kurtosis of zero:
+    ///
+    /// ```text
+    ///       m₄               1   n
+    /// g₂ = ――― - 3   where mₖ = ―   ∑ (xᵢ - x̅)ᵏ
+    ///      m₂²                n  i=1
+    /// ```
+    ///
+    /// and `n` is the length of the array. It is NaN if there are fewer than
+    /// four elements, or if all elements are equal.
+    ///
+    /// **Panics** if `A::from_usize()` fails for any of the numbers in the
+    /// range `0..=n`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1., 2., 3., 4., 10f64];
+    /// assert!((a.kurtosis() - -0.212).abs() < 1e-15);
+    /// assert!(array![1., 2., 3f64].kurtosis().is_nan());
+    /// ```
+    #[track_caller]
+    pub fn kurtosis(&self) -> A
+    {
+        self.fold(Moments::new(), |m, &x| m.update(x)).kurtosis()
+    }
+
+    /// Return the skewness along `axis`.
+    ///
+    /// See [`skewness`](Self::skewness) for the definition; lanes with fewer
+    /// than three elements have a skewness of NaN.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `A::from_usize()` fails
+    /// for any of the numbers in the range `0..=n`, where `n` is the length
+    /// of the axis.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 5., 3.],
+    ///                [2., 2., 2.],
+    ///                [3., -1., 1f64]];
+    /// assert_eq!(a.skewness_axis(Axis(0)), array![0., 0., 0.]);
+    /// assert!(a.skewness_axis(Axis(1))[1].is_nan());
+    /// ```
+    #[track_caller]
+    pub fn skewness_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where D: RemoveAxis
+    {
+        self.moments_axis(axis).map(Moments::skewness)
+    }
+
+    /// Return the excess kurtosis along `axis`.
+    ///
+    /// See [`kurtosis`](Self::kurtosis) for the definition; lanes with fewer
+    /// than four elements have a kurtosis of NaN.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `A::from_usize()` fails
+    /// for any of the numbers in the range `0..=n`, where `n` is the length
+    /// of the axis.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 0., 1., 0.],
+    ///                [4., 3., 2., 1f64]];
+    /// let kurtosis = a.kurtosis_axis(Axis(1));
+    /// assert_eq!(kurtosis[0], -2.);
+    /// assert!((kurtosis[1] - -1.36).abs() < 1e-15);
+    /// ```
+    #[track_caller]
+    pub fn kurtosis_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where D: RemoveAxis
+    {
+        self.moments_axis(axis).map(Moments::kurtosis)
+    }
+
+    #[track_caller]
+    fn moments_axis(&self, axis: Axis) -> Array<Moments<A>, D::Smaller>
+    where D: RemoveAxis
+    {
+        if axis.0 >= self.ndim() {
+            panic!("axis is out of bounds for array of dimension");
+        }
+        let mut moments = Array::from_elem(self.dim.remove_axis(axis), Moments::new());
+        for subview in self.axis_iter(axis) {
+            azip!((m in &mut moments, &x in &subview) *m = m.update(x));
+        }
+        moments
+    }
+}
+
+/// The running count, mean and sums of the powers of the deviations from the
+/// mean (`mₖ = ∑ (xᵢ - x̅)ᵏ`), updated one element at a time as described
+/// by Pébay (2008), "Formulas for robust, one-pass parallel computation of
+/// covariances and arbitrary-order statistical moments".
+#[derive(Copy, Clone)]
+struct Moments<A>
+{
+    n: usize,
+    mean: A,
+    m2: A,
+    m3: A,
+    m4: A,
+}
+
+impl<A> Moments<A>
+where A: Float + FromPrimitive
+{
+    fn new() -> Self
+    {
+        Moments {
+            n: 0,
+            mean: A::zero(),
+            m2: A::zero(),
+            m3: A::zero(),
+            m4: A::zero(),
+        }
+    }
+
+    #[track_caller]
+    fn update(self, x: A) -> Self
+    {
+        let from = |k: usize| A::from_usize(k).expect("Converting index to `A` must not fail.");
+        let n = self.n + 1;
+        let n_a = from(n);
+        let delta = x - self.mean;
+        let delta_n = delta / n_a;
+        let delta_n2 = delta_n * delta_n;
+        let term = delta * delta_n * from(self.n);
+        Moments {
+            n,
+            mean: self.mean + delta_n,
+            m4: self.m4 + term * delta_n2 * (n_a * n_a - from(3) * n_a + from(3)) + from(6) * delta_n2 * self.m2
+                - from(4) * delta_n * self.m3,
+            m3: self.m3 + term * delta_n * (n_a - from(2)) - from(3) * delta_n * self.m2,
+            m2: self.m2 + term,
+        }
+    }
+
+    fn skewness(&self) -> A
+    {
+        if self.n < 3 {
+            return A::nan();
+        }
+        let n = A::from_usize(self.n).unwrap();
+        // `m2^1.5` without the overflow of `m2^3`
+        n.sqrt() * self.m3 / (self.m2 * self.m2.sqrt())
+    }
+
+    fn kurtosis(&self) -> A
+    {
+        if self.n < 4 {
+            return A::nan();
+        }
+        let n = A::from_usize(self.n).unwrap();
+        n * self.m4 / (self.m2 * self.m2) - A::from_usize(3).unwrap()
+    }
+}
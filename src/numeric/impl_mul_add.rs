@@ -0,0 +1,104 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::MulAdd;
+
+use crate::dimension::DimMax;
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// # Fused Multiply-Add
+///
+/// These methods compute `x * a + b` elementwise with [`MulAdd`], which for
+/// floats is [`Float::mul_add`](num_traits::Float::mul_add), with a single
+/// rounding, and for integers is a plain multiplication and addition. The
+/// `MulAdd` implementations for floats need the `std` or the `libm`
+/// feature.
+///
+/// Unlike `&x * &a + &b`, no intermediate array is allocated for the
+/// products.
+impl<A, D> ArrayRef<A, D>
+where
+    A: Clone + MulAdd<Output = A>,
+    D: Dimension,
+{
+    /// Return `x * a + b` for each element `x` of the array and the
+    /// corresponding elements of `a` and `b`, after broadcasting all three
+    /// to their common shape like the arithmetic operators do.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let x = array![[1, 2],
+    ///                [3, 4]];
+    /// let scale = array![10, 100];
+    /// let offset = array![[1], [2]];
+    /// assert_eq!(x.mul_add(&scale, &offset), Ok(array![[11, 201],
+    ///                                                  [32, 402]]));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn mul_add<E1, E2>(
+        &self, a: &ArrayRef<A, E1>, b: &ArrayRef<A, E2>,
+    ) -> Result<Array<A, <<D as DimMax<E1>>::Output as DimMax<E2>>::Output>, ShapeError>
+    where
+        E1: Dimension,
+        E2: Dimension,
+        D: DimMax<E1>,
+        <D as DimMax<E1>>::Output: DimMax<E2>,
+    {
+        let (x, a) = self.broadcast_with(a)?;
+        let (x, b) = x.broadcast_with(b)?;
+        let a = a
+            .broadcast(x.raw_dim())
+            .ok_or_else(|| from_kind(ErrorKind::IncompatibleShape))?;
+        Ok(Zip::from(&x)
+            .and(&a)
+            .and(&b)
+            .map_collect(|x, a, b| x.clone().mul_add(a.clone(), b.clone())))
+    }
+
+    /// Replace each element `x` with `x * a + b` for the corresponding
+    /// elements of `a` and `b`, in place.
+    ///
+    /// `a` and `b` are broadcast to the shape of the array.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if `a` or `b` can not be broadcast
+    /// to the shape of the array, in which case the array is not modified.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut x = array![[1., 2.],
+    ///                    [3., 4.]];
+    /// let scale = array![0.5];
+    /// let offset = array![1., -1.];
+    /// x.mul_add_assign(&scale, &offset).unwrap();
+    /// assert_eq!(x, array![[1.5, 0.],
+    ///                      [2.5, 1.]]);
+    /// ```
+    pub fn mul_add_assign<E1, E2>(&mut self, a: &ArrayRef<A, E1>, b: &ArrayRef<A, E2>) -> Result<(), ShapeError>
+    where
+        E1: Dimension,
+        E2: Dimension,
+    {
+        let dim = self.raw_dim();
+        let (a, b) = match (a.broadcast(dim.clone()), b.broadcast(dim)) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return Err(from_kind(ErrorKind::IncompatibleShape)),
+        };
+        Zip::from(self)
+            .and(&a)
+            .and(&b)
+            .for_each(|x, a, b| *x = x.clone().mul_add(a.clone(), b.clone()));
+        Ok(())
+    }
+}
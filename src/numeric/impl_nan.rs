@@ -0,0 +1,457 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::{Float, FromPrimitive};
+
+use crate::imp_prelude::*;
+use crate::numeric_util;
+
+/// # NaN-ignoring Numerical Methods for Float Arrays
+///
+/// These methods behave like their counterparts without the `nan` prefix,
+/// except that `NaN` elements are skipped as if they were not present.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    /// Return the sum of all elements in the array, ignoring `NaN`s.
+    ///
+    /// The sum of an array that is empty or contains only `NaN`s is zero.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., f64::NAN],
+    ///                [3., 4.]]);
+    /// assert!(a.sum().is_nan());
+    /// assert_eq!(a.nansum(), 8.);
+    /// ```
+    pub fn nansum(&self) -> A
+    {
+        if let Some(slc) = self.as_slice_memory_order() {
+            return numeric_util::unrolled_fold_map(slc, A::zero, nan_to_zero, A::add);
+        }
+        let mut sum = A::zero();
+        for row in self.rows() {
+            if let Some(slc) = row.as_slice() {
+                sum = sum + numeric_util::unrolled_fold_map(slc, A::zero, nan_to_zero, A::add);
+            } else {
+                sum = sum
+                    + row
+                        .iter()
+                        .fold(A::zero(), |acc, elt| acc + nan_to_zero(elt));
+            }
+        }
+        sum
+    }
+
+    /// Return the product of all elements in the array, ignoring `NaN`s.
+    ///
+    /// The product of an array that is empty or contains only `NaN`s is one.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., f64::NAN],
+    ///                [3., 4.]]);
+    /// assert!(a.product().is_nan());
+    /// assert_eq!(a.nanprod(), 12.);
+    /// ```
+    pub fn nanprod(&self) -> A
+    {
+        if let Some(slc) = self.as_slice_memory_order() {
+            return numeric_util::unrolled_fold_map(slc, A::one, nan_to_one, A::mul);
+        }
+        let mut prod = A::one();
+        for row in self.rows() {
+            if let Some(slc) = row.as_slice() {
+                prod = prod * numeric_util::unrolled_fold_map(slc, A::one, nan_to_one, A::mul);
+            } else {
+                prod = prod * row.iter().fold(A::one(), |acc, elt| acc * nan_to_one(elt));
+            }
+        }
+        prod
+    }
+
+    /// Return sum along `axis`, ignoring `NaN`s.
+    ///
+    /// Lanes that are empty or contain only `NaN`s sum to zero.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., f64::NAN, 3.],
+    ///                [4., 5., f64::NAN]]);
+    /// assert!(
+    ///     a.nansum_axis(Axis(0)) == aview1(&[5., 5., 3.]) &&
+    ///     a.nansum_axis(Axis(1)) == aview1(&[4., 9.])
+    /// );
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn nansum_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where D: RemoveAxis
+    {
+        let min_stride_axis = self.dim.min_stride_axis(&self.strides);
+        if axis == min_stride_axis {
+            crate::Zip::from(self.lanes(axis)).map_collect(|lane| lane.nansum())
+        } else {
+            let mut res = Array::zeros(self.raw_dim().remove_axis(axis));
+            for subview in self.axis_iter(axis) {
+                res.zip_mut_with(&subview, |r, x| *r = *r + nan_to_zero(x));
+            }
+            res
+        }
+    }
+
+    /// Return product along `axis`, ignoring `NaN`s.
+    ///
+    /// Lanes that are empty or contain only `NaN`s have a product of one.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., f64::NAN, 3.],
+    ///                [4., 5., f64::NAN]]);
+    /// assert!(
+    ///     a.nanprod_axis(Axis(0)) == aview1(&[4., 5., 3.]) &&
+    ///     a.nanprod_axis(Axis(1)) == aview1(&[3., 20.])
+    /// );
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn nanprod_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where D: RemoveAxis
+    {
+        let min_stride_axis = self.dim.min_stride_axis(&self.strides);
+        if axis == min_stride_axis {
+            crate::Zip::from(self.lanes(axis)).map_collect(|lane| lane.nanprod())
+        } else {
+            let mut res = Array::ones(self.raw_dim().remove_axis(axis));
+            for subview in self.axis_iter(axis) {
+                res.zip_mut_with(&subview, |r, x| *r = *r * nan_to_one(x));
+            }
+            res
+        }
+    }
+
+    /// Returns the arithmetic mean of all elements in the array, ignoring
+    /// `NaN`s.
+    ///
+    /// If the array is empty or contains only `NaN`s, `None` is returned.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the number of
+    /// non-`NaN` elements in the array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1., f64::NAN, 3., 8.];
+    /// assert_eq!(a.nanmean(), Some(4.));
+    ///
+    /// let b = array![f64::NAN, f64::NAN];
+    /// assert_eq!(b.nanmean(), None);
+    /// ```
+    pub fn nanmean(&self) -> Option<A>
+    where A: FromPrimitive
+    {
+        let mut sum = A::zero();
+        let mut count = 0;
+        self.for_each(|&x| {
+            if !x.is_nan() {
+                sum = sum + x;
+                count += 1;
+            }
+        });
+        if count == 0 {
+            None
+        } else {
+            let count = A::from_usize(count).expect("Converting number of elements to `A` must not fail.");
+            Some(sum / count)
+        }
+    }
+
+    /// Return mean along `axis`, ignoring `NaN`s.
+    ///
+    /// Each lane is divided by its own number of non-`NaN` elements. Lanes
+    /// that are empty or contain only `NaN`s have a mean of `NaN`.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails
+    /// for the number of non-`NaN` elements in a lane.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., f64::NAN, 3.],
+    ///                [3., 5., f64::NAN]]);
+    /// assert_eq!(a.nanmean_axis(Axis(0)), aview1(&[2., 5., 3.]));
+    /// assert_eq!(a.nanmean_axis(Axis(1)), aview1(&[2., 4.]));
+    /// ```
+    #[track_caller]
+    pub fn nanmean_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: FromPrimitive,
+        D: RemoveAxis,
+    {
+        let mut sum = Array::<A, _>::zeros(self.dim.remove_axis(axis));
+        let mut count = Array::<usize, _>::zeros(self.dim.remove_axis(axis));
+        for subview in self.axis_iter(axis) {
+            azip!((sum in &mut sum, count in &mut count, &x in &subview) {
+                if !x.is_nan() {
+                    *sum = *sum + x;
+                    *count += 1;
+                }
+            });
+        }
+        crate::Zip::from(&sum)
+            .and(&count)
+            .map_collect(|&sum, &count| {
+                if count == 0 {
+                    A::nan()
+                } else {
+                    sum / A::from_usize(count).expect("Converting number of elements to `A` must not fail.")
+                }
+            })
+    }
+
+    /// Return variance of elements in the array, ignoring `NaN`s.
+    ///
+    /// This is [`var`](Self::var) computed over the non-`NaN` elements only:
+    /// the Welford update skips `NaN`s and `n` is the number of non-`NaN`
+    /// elements.
+    ///
+    /// If `n` is less than or equal to `ddof`, the result is `NaN`.
+    ///
+    /// **Panics** if `ddof` is less than zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let a = array![1., f64::NAN, -4.32, 1.14, 0.32];
+    /// let var = a.nanvar(1.);
+    /// assert_abs_diff_eq!(var, 6.7331, epsilon = 1e-4);
+    /// assert!(array![1., f64::NAN].nanvar(1.).is_nan());
+    /// ```
+    #[track_caller]
+    pub fn nanvar(&self, ddof: A) -> A
+    where A: FromPrimitive
+    {
+        assert!(ddof >= A::zero() || ddof.is_nan(), "`ddof` must not be less than zero");
+        let mut mean = A::zero();
+        let mut sum_sq = A::zero();
+        let mut count = 0;
+        self.for_each(|&x| {
+            if !x.is_nan() {
+                count += 1;
+                welford_update(x, count, &mut mean, &mut sum_sq);
+            }
+        });
+        nan_dof_divide(sum_sq, count, ddof)
+    }
+
+    /// Return standard deviation of elements in the array, ignoring `NaN`s.
+    ///
+    /// This is the square root of [`nanvar`](Self::nanvar).
+    ///
+    /// If the number of non-`NaN` elements is less than or equal to `ddof`,
+    /// the result is `NaN`.
+    ///
+    /// **Panics** if `ddof` is less than zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use approx::assert_abs_diff_eq;
+    ///
+    /// let a = array![1., -4.32, f64::NAN, 1.14, 0.32];
+    /// let stddev = a.nanstd(1.);
+    /// assert_abs_diff_eq!(stddev, 2.59483, epsilon = 1e-4);
+    /// ```
+    #[track_caller]
+    pub fn nanstd(&self, ddof: A) -> A
+    where A: FromPrimitive
+    {
+        self.nanvar(ddof).sqrt()
+    }
+
+    /// Return variance along `axis`, ignoring `NaN`s.
+    ///
+    /// Each lane is computed like [`nanvar`](Self::nanvar), with `n` being
+    /// the number of non-`NaN` elements in that lane. Lanes where `n` is less
+    /// than or equal to `ddof` have a variance of `NaN`.
+    ///
+    /// **Panics** if `ddof` is less than zero or if `axis` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., f64::NAN],
+    ///                [5., 6.]]);
+    /// let var = a.nanvar_axis(Axis(0), 1.);
+    /// assert_eq!(var, aview1(&[4., 8.]));
+    /// ```
+    #[track_caller]
+    pub fn nanvar_axis(&self, axis: Axis, ddof: A) -> Array<A, D::Smaller>
+    where
+        A: FromPrimitive,
+        D: RemoveAxis,
+    {
+        assert!(ddof >= A::zero() || ddof.is_nan(), "`ddof` must not be less than zero");
+        let mut mean = Array::<A, _>::zeros(self.dim.remove_axis(axis));
+        let mut sum_sq = Array::<A, _>::zeros(self.dim.remove_axis(axis));
+        let mut count = Array::<usize, _>::zeros(self.dim.remove_axis(axis));
+        for subview in self.axis_iter(axis) {
+            azip!((mean in &mut mean, sum_sq in &mut sum_sq, count in &mut count, &x in &subview) {
+                if !x.is_nan() {
+                    *count += 1;
+                    welford_update(x, *count, mean, sum_sq);
+                }
+            });
+        }
+        crate::Zip::from(&sum_sq)
+            .and(&count)
+            .map_collect(|&sum_sq, &count| nan_dof_divide(sum_sq, count, ddof))
+    }
+
+    /// Return standard deviation along `axis`, ignoring `NaN`s.
+    ///
+    /// This is the square root of [`nanvar_axis`](Self::nanvar_axis).
+    ///
+    /// **Panics** if `ddof` is less than zero or if `axis` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [f64::NAN, 4.],
+    ///                [5., 6.]]);
+    /// let stddev = a.nanstd_axis(Axis(0), 1.);
+    /// assert_eq!(stddev, aview1(&[8f64.sqrt(), 2.]));
+    /// ```
+    #[track_caller]
+    pub fn nanstd_axis(&self, axis: Axis, ddof: A) -> Array<A, D::Smaller>
+    where
+        A: FromPrimitive,
+        D: RemoveAxis,
+    {
+        self.nanvar_axis(axis, ddof).mapv_into(|x| x.sqrt())
+    }
+
+    /// Return the cumulative sum of elements along a given axis, treating
+    /// `NaN`s as zero.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[f64::NAN, 2., 3.],
+    ///                [4., f64::NAN, 6.]]);
+    /// assert_eq!(
+    ///     a.nancumsum(Axis(1)),
+    ///     arr2(&[[0., 2., 5.],
+    ///           [4., 4., 10.]])
+    /// );
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn nancumsum(&self, axis: Axis) -> Array<A, D>
+    {
+        if axis.0 >= self.ndim() {
+            panic!("axis is out of bounds for array of dimension");
+        }
+
+        let mut result = self.mapv(|x| nan_to_zero(&x));
+        result.accumulate_axis_inplace(axis, |&prev, curr| *curr = *curr + prev);
+        result
+    }
+
+    /// Return the cumulative product of elements along a given axis,
+    /// treating `NaN`s as one.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[f64::NAN, 2., 3.],
+    ///                [4., f64::NAN, 6.]]);
+    /// assert_eq!(
+    ///     a.nancumprod(Axis(1)),
+    ///     arr2(&[[1., 2., 6.],
+    ///           [4., 4., 24.]])
+    /// );
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn nancumprod(&self, axis: Axis) -> Array<A, D>
+    {
+        if axis.0 >= self.ndim() {
+            panic!("axis is out of bounds for array of dimension");
+        }
+
+        let mut result = self.mapv(|x| nan_to_one(&x));
+        result.accumulate_axis_inplace(axis, |&prev, curr| *curr = *curr * prev);
+        result
+    }
+}
+
+/// Update the running `mean` and sum of squared deviations `sum_sq` with
+/// the `count`-th value `x`, using Welford's algorithm.
+fn welford_update<A>(x: A, count: usize, mean: &mut A, sum_sq: &mut A)
+where A: Float + FromPrimitive
+{
+    let count = A::from_usize(count).expect("Converting index to `A` must not fail.");
+    let delta = x - *mean;
+    *mean = *mean + delta / count;
+    *sum_sq = (x - *mean).mul_add(delta, *sum_sq);
+}
+
+/// Divide `sum_sq` by the degrees of freedom `count - ddof`, or return `NaN`
+/// if there are none left.
+fn nan_dof_divide<A>(sum_sq: A, count: usize, ddof: A) -> A
+where A: Float + FromPrimitive
+{
+    let n = A::from_usize(count).expect("Converting length to `A` must not fail.");
+    if n > ddof {
+        sum_sq / (n - ddof)
+    } else {
+        A::nan()
+    }
+}
+
+/// Return `x`, or zero if `x` is `NaN`.
+fn nan_to_zero<A: Float>(x: &A) -> A
+{
+    if x.is_nan() {
+        A::zero()
+    } else {
+        *x
+    }
+}
+
+/// Return `x`, or one if `x` is `NaN`.
+fn nan_to_one<A: Float>(x: &A) -> A
+{
+    if x.is_nan() {
+        A::one()
+    } else {
+        *x
+    }
+}
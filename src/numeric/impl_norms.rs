@@ -0,0 +1,234 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Float;
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// The order of a vector norm, for [`norm_axis`](ArrayRef::norm_axis).
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub enum NormOrd<A>
+{
+    /// `∑ |xᵢ|`, see [`norm_l1`](ArrayRef::norm_l1)
+    L1,
+    /// `sqrt(∑ |xᵢ|²)`, see [`norm_l2`](ArrayRef::norm_l2)
+    L2,
+    /// `max |xᵢ|`, see [`norm_max`](ArrayRef::norm_max)
+    Inf,
+    /// `(∑ |xᵢ|ᵖ)^(1/p)` for `p > 0`; an infinite `p` is the same as `Inf`
+    P(A),
+}
+
+/// # Norms of Float Arrays
+///
+/// These methods treat the array as a flat vector of its elements.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    /// Return the L1 norm of the array, the sum of the absolute values of
+    /// the elements.
+    ///
+    /// The norm of an empty array is zero.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![[3., -4.], [0., 1.]].norm_l1(), 8.);
+    /// ```
+    pub fn norm_l1(&self) -> A
+    {
+        self.fold(A::zero(), |sum, &x| sum + x.abs())
+    }
+
+    /// Return the L2 (Euclidean) norm of the array, the square root of the
+    /// sum of the squares of the elements.
+    ///
+    /// Like LAPACK's `dnrm2`, the sum is scaled by the largest absolute
+    /// value seen so far, so the norm does not overflow or underflow unless
+    /// the result itself does. This is computed in a single pass.
+    ///
+    /// The norm of an empty array is zero.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![[3., -4.], [0., 0.]].norm_l2(), 5.);
+    /// // the squares overflow `f32`, but the norm does not
+    /// assert_eq!(array![3e30f32, 4e30].norm_l2(), 5e30);
+    /// ```
+    pub fn norm_l2(&self) -> A
+    {
+        scaled_norm_l2(self.iter())
+    }
+
+    /// Return the maximum norm of the array, the largest absolute value of
+    /// the elements.
+    ///
+    /// The norm of an empty array is zero. If any element is `NaN`, the
+    /// result is `NaN`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![[3., -4.], [0., 1.]].norm_max(), 4.);
+    /// ```
+    pub fn norm_max(&self) -> A
+    {
+        norm_max(self.iter())
+    }
+
+    /// Return the norm of order `ord` of each lane along `axis`.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis, NormOrd};
+    ///
+    /// let a = arr2(&[[3., 1.],
+    ///                [-4., -1.]]);
+    /// assert_eq!(a.norm_axis(Axis(0), NormOrd::L1), aview1(&[7., 2.]));
+    /// assert_eq!(a.norm_axis(Axis(0), NormOrd::L2), aview1(&[5., 2f64.sqrt()]));
+    /// assert_eq!(a.norm_axis(Axis(1), NormOrd::Inf), aview1(&[3., 4.]));
+    /// assert_eq!(a.norm_axis(Axis(1), NormOrd::P(1.)), aview1(&[4., 5.]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `ord` is `P(p)` where
+    /// `p` is not greater than zero.
+    #[track_caller]
+    pub fn norm_axis(&self, axis: Axis, ord: NormOrd<A>) -> Array<A, D::Smaller>
+    where D: RemoveAxis
+    {
+        check_ord(ord);
+        Zip::from(self.lanes(axis)).map_collect(|lane| norm_of_lane(lane, ord))
+    }
+
+    /// Return a copy of the array where each lane along `axis` is divided by
+    /// its norm of order `ord`.
+    ///
+    /// Lanes with a norm of zero (that is, lanes of only zeros) are left as
+    /// they are instead of becoming `NaN`.
+    ///
+    /// See [`normalize_axis_inplace`](Self::normalize_axis_inplace) for a
+    /// version that does not allocate.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis, NormOrd};
+    ///
+    /// let embeddings = arr2(&[[3., 4.],
+    ///                         [0., 0.]]);
+    /// let unit = embeddings.normalize_axis(Axis(1), NormOrd::L2);
+    /// assert_eq!(unit, arr2(&[[0.6, 0.8], [0., 0.]]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `ord` is `P(p)` where
+    /// `p` is not greater than zero.
+    #[track_caller]
+    pub fn normalize_axis(&self, axis: Axis, ord: NormOrd<A>) -> Array<A, D>
+    {
+        let mut res = self.to_owned();
+        res.normalize_axis_inplace(axis, ord);
+        res
+    }
+
+    /// Divide each lane along `axis` by its norm of order `ord`, in place.
+    ///
+    /// Each lane is normalized completely (its norm computed and then applied)
+    /// before moving on to the next one. Lanes with a norm of zero are left
+    /// as they are.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis, NormOrd};
+    ///
+    /// let mut a = arr2(&[[1., 0.],
+    ///                    [-3., 0.]]);
+    /// a.normalize_axis_inplace(Axis(0), NormOrd::L1);
+    /// assert_eq!(a, arr2(&[[0.25, 0.], [-0.75, 0.]]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `ord` is `P(p)` where
+    /// `p` is not greater than zero.
+    #[track_caller]
+    pub fn normalize_axis_inplace(&mut self, axis: Axis, ord: NormOrd<A>)
+    {
+        check_ord(ord);
+        Zip::from(self.lanes_mut(axis)).for_each(|mut lane| {
+            let norm = norm_of_lane(lane.view(), ord);
+            if !norm.is_zero() {
+                lane.mapv_inplace(|x| x / norm);
+            }
+        });
+    }
+}
+
+#[track_caller]
+fn check_ord<A: Float>(ord: NormOrd<A>)
+{
+    if let NormOrd::P(p) = ord {
+        assert!(p > A::zero(), "the order `p` of the norm must be greater than zero");
+    }
+}
+
+fn norm_of_lane<A: Float>(lane: ArrayView1<'_, A>, ord: NormOrd<A>) -> A
+{
+    match ord {
+        NormOrd::L1 => lane.norm_l1(),
+        NormOrd::L2 => lane.norm_l2(),
+        NormOrd::Inf => lane.norm_max(),
+        NormOrd::P(p) if p.is_infinite() => lane.norm_max(),
+        NormOrd::P(p) => lane
+            .fold(A::zero(), |sum, &x| sum + x.abs().powf(p))
+            .powf(p.recip()),
+    }
+}
+
+/// Return the largest absolute value of `iter`, or `NaN` if any element is
+/// `NaN`.
+fn norm_max<'a, A, I>(iter: I) -> A
+where
+    A: 'a + Float,
+    I: IntoIterator<Item = &'a A>,
+{
+    iter.into_iter().fold(A::zero(), |max, &x| {
+        let x = x.abs();
+        if x > max || x.is_nan() {
+            x
+        } else {
+            max
+        }
+    })
+}
+
+/// Return the L2 norm of `iter`, keeping the sum of squares scaled by the
+/// largest absolute value so far (`norm = scale * sqrt(ssq)`).
+fn scaled_norm_l2<'a, A, I>(iter: I) -> A
+where
+    A: 'a + Float,
+    I: IntoIterator<Item = &'a A>,
+{
+    let mut scale = A::zero();
+    let mut ssq = A::one();
+    for &x in iter {
+        let x = x.abs();
+        if x > scale {
+            let r = scale / x;
+            ssq = A::one() + ssq * r * r;
+            scale = x;
+        } else if x == scale {
+            // also covers two infinities, whose ratio would be `NaN`
+            ssq = ssq + A::one();
+        } else {
+            // `NaN` also ends up here, and is propagated through `ssq`
+            let r = x / scale;
+            ssq = ssq + r * r;
+        }
+    }
+    scale * ssq.sqrt()
+}
@@ -6,22 +6,57 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use alloc::vec::Vec;
 #[cfg(feature = "std")]
+use num_traits::AsPrimitive;
+#[cfg(any(feature = "std", feature = "libm"))]
 use num_traits::Float;
 use num_traits::One;
 use num_traits::{FromPrimitive, Zero};
-use std::ops::{Add, Div, Mul, MulAssign, Sub};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub};
 
+use crate::error::MeanError;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::error::StatsError;
+#[cfg(feature = "std")]
+use crate::error::{from_kind, ErrorKind};
 use crate::imp_prelude::*;
 use crate::numeric_util;
+use crate::ShapeError;
 use crate::Slice;
 
+/// The kind of finite differences computed by
+/// [`diff_mode`](ArrayRef::diff_mode).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DiffMode
+{
+    /// `x[i + 1] - x[i]`, the difference ahead of sample `i`, for every
+    /// sample but the last.
+    Forward,
+    /// `x[i] - x[i - 1]`, the difference behind sample `i`, for every sample;
+    /// the first sample has no difference behind it and gets zero.
+    Backward,
+    /// `(x[i + 1] - x[i - 1]) / 2`, the difference centered on sample `i`,
+    /// for every sample but the first and the last. For integers the division
+    /// truncates towards zero.
+    Central,
+}
+
 /// # Numerical Methods for Arrays
 impl<A, D> ArrayRef<A, D>
 where D: Dimension
 {
     /// Return the sum of all elements in the array.
     ///
+    /// Contiguous data (the whole array, or else each row) is summed with
+    /// pairwise summation, like in NumPy: it is split in halves recursively
+    /// and only short blocks are summed sequentially. For floating point
+    /// elements the rounding error then grows with the logarithm of the
+    /// number of elements rather than linearly, which means that the result
+    /// can differ in the last bits from a sequential sum. See
+    /// [`sum_compensated`](Self::sum_compensated) for an even more accurate
+    /// sum.
+    ///
     /// ```
     /// use ndarray::arr2;
     ///
@@ -33,12 +68,12 @@ where D: Dimension
     where A: Clone + Add<Output = A> + num_traits::Zero
     {
         if let Some(slc) = self.as_slice_memory_order() {
-            return numeric_util::unrolled_fold(slc, A::zero, A::add);
+            return numeric_util::pairwise_sum(slc);
         }
         let mut sum = A::zero();
         for row in self.rows() {
             if let Some(slc) = row.as_slice() {
-                sum = sum + numeric_util::unrolled_fold(slc, A::zero, A::add);
+                sum = sum + numeric_util::pairwise_sum(slc);
             } else {
                 sum = sum + row.iter().fold(A::zero(), |acc, elt| acc + elt.clone());
             }
@@ -46,6 +81,63 @@ where D: Dimension
         sum
     }
 
+    /// Return the sum of all elements in the array, using compensated
+    /// summation.
+    ///
+    /// The low-order bits that are lost when adding each element are
+    /// accumulated separately with the Kahan–Babuška–Neumaier algorithm, so
+    /// the error does not grow with the number of elements the way it does
+    /// for [`sum`](Self::sum). This is about as accurate as summing in twice
+    /// the precision, at the cost of a few more operations per element.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::from_elem(1_000_000, 0.1f32);
+    /// assert_eq!(a.sum_compensated(), 100_000.);
+    /// assert_ne!(a.sum(), 100_000.);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn sum_compensated(&self) -> A
+    where A: Float
+    {
+        if let Some(slc) = self.as_slice_memory_order() {
+            return numeric_util::unrolled_sum_compensated(slc).total();
+        }
+        let mut sum = numeric_util::Compensated::zero();
+        for row in self.rows() {
+            if let Some(slc) = row.as_slice() {
+                sum = sum.merge(numeric_util::unrolled_sum_compensated(slc));
+            } else {
+                sum = numeric_util::sum_compensated_iter(row, sum);
+            }
+        }
+        sum.total()
+    }
+
+    /// Return the sum of all elements in the array, accumulated in the type
+    /// `W`.
+    ///
+    /// Each element is converted to `W` as it is added, without making a
+    /// converted copy of the array. This sums small integers without
+    /// overflow, or `f32` elements in `f64` for accuracy. The elements are
+    /// added sequentially, in memory order if the array is contiguous.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::from_elem((1000, 1000), 255u8);
+    /// assert_eq!(a.sum_as::<u32>(), 255_000_000);
+    /// ```
+    pub fn sum_as<W>(&self) -> W
+    where
+        A: Clone,
+        W: From<A> + Zero + Add<Output = W>,
+    {
+        self.fold(W::zero(), |sum, x| sum + W::from(x.clone()))
+    }
+
     /// Returns the [arithmetic mean] x̅ of all elements in the array:
     ///
     /// ```text
@@ -61,14 +153,74 @@ where D: Dimension
     /// [arithmetic mean]: https://en.wikipedia.org/wiki/Arithmetic_mean
     pub fn mean(&self) -> Option<A>
     where A: Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero
+    {
+        self.try_mean()
+            .expect("Converting number of elements to `A` must not fail.")
+    }
+
+    /// Returns the arithmetic mean of all elements in the array, or an error
+    /// if the number of elements can not be converted to `A`.
+    ///
+    /// This is the same as [`mean`](Self::mean), except that it returns an
+    /// error instead of panicking when `A::from_usize()` fails.
+    ///
+    /// If the array is empty, `Ok(None)` is returned.
+    ///
+    /// ***Errors*** if `A::from_usize()` fails to convert the number of
+    /// elements in the array.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::from_elem(300, 1i8);
+    /// assert_eq!(a.slice(ndarray::s![..100]).try_mean(), Ok(Some(1)));
+    /// assert_eq!(a.try_mean().unwrap_err().count(), 300);
+    /// ```
+    pub fn try_mean(&self) -> Result<Option<A>, MeanError>
+    where A: Clone + FromPrimitive + Add<Output = A> + Div<Output = A> + Zero
     {
         let n_elements = self.len();
         if n_elements == 0 {
-            None
-        } else {
-            let n_elements = A::from_usize(n_elements).expect("Converting number of elements to `A` must not fail.");
-            Some(self.sum() / n_elements)
+            return Ok(None);
+        }
+        let n = A::from_usize(n_elements).ok_or_else(|| MeanError::new(n_elements))?;
+        Ok(Some(self.sum() / n))
+    }
+
+    /// Return the arithmetic mean of all elements in the array, computed in
+    /// the float type `F`.
+    ///
+    /// Each element is converted to `F` with `as` as it is added, without
+    /// making a converted copy of the array, so this gives the mean of
+    /// integer arrays whose sum does not fit in the element type.
+    ///
+    /// If the array is empty, `None` is returned.
+    ///
+    /// **Panics** if `F::from_usize()` fails to convert the number of elements in the array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[i64::MAX, i64::MAX],
+    ///                [1, 3]];
+    /// assert_eq!(a.mean_as::<f64>(), Some(i64::MAX as f64 / 2.));
+    /// let pixels = array![255u8, 255, 0];
+    /// assert_eq!(pixels.mean_as::<f32>(), Some(170.));
+    /// ```
+    #[track_caller]
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn mean_as<F>(&self) -> Option<F>
+    where
+        A: AsPrimitive<F>,
+        F: Float + FromPrimitive + 'static,
+    {
+        let n_elements = self.len();
+        if n_elements == 0 {
+            return None;
         }
+        let n_elements = F::from_usize(n_elements).expect("Converting number of elements to `F` must not fail.");
+        Some(self.fold(F::zero(), |sum, &x| sum + x.as_()) / n_elements)
     }
 
     /// Return the product of all elements in the array.
@@ -136,10 +288,73 @@ where D: Dimension
         result
     }
 
+    /// Replace the elements with their cumulative sum along a given axis.
+    ///
+    /// Unlike [`cumprod`](Self::cumprod), this does not allocate; the array
+    /// (or mutable view) is updated in place, whatever its memory layout.
+    ///
+    /// ```
+    /// use ndarray::{arr2, s, Axis};
+    ///
+    /// let mut a = arr2(&[[1., 2., 3.],
+    ///                    [4., 5., 6.]]);
+    ///
+    /// // Cumulative sum over a mutable view of the second and third columns
+    /// a.slice_mut(s![.., 1..]).cumsum_axis_inplace(Axis(1));
+    /// assert_eq!(
+    ///     a,
+    ///     arr2(&[[1., 2., 5.],
+    ///           [4., 5., 11.]])
+    /// );
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn cumsum_axis_inplace(&mut self, axis: Axis)
+    where A: Clone + AddAssign
+    {
+        if axis.0 >= self.ndim() {
+            panic!("axis is out of bounds for array of dimension");
+        }
+
+        self.accumulate_axis_inplace(axis, |prev, curr| *curr += prev.clone());
+    }
+
+    /// Replace the elements with their cumulative product along a given axis.
+    ///
+    /// This is the in place counterpart of [`cumprod`](Self::cumprod).
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let mut a = arr2(&[[1., 2., 3.],
+    ///                    [4., 5., 6.]]);
+    /// a.cumprod_axis_inplace(Axis(0));
+    /// assert_eq!(
+    ///     a,
+    ///     arr2(&[[1., 2., 3.],
+    ///           [4., 10., 18.]])
+    /// );
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn cumprod_axis_inplace(&mut self, axis: Axis)
+    where A: Clone + MulAssign
+    {
+        if axis.0 >= self.ndim() {
+            panic!("axis is out of bounds for array of dimension");
+        }
+
+        self.accumulate_axis_inplace(axis, |prev, curr| *curr *= prev.clone());
+    }
+
     /// Return variance of elements in the array.
     ///
     /// The variance is computed using the [Welford one-pass
     /// algorithm](https://www.jstor.org/stable/1266577).
+    /// See [`var_twopass`](Self::var_twopass) for a more accurate
+    /// alternative.
     ///
     /// The parameter `ddof` specifies the "delta degrees of freedom". For
     /// example, to calculate the population variance, use `ddof = 0`, or to
@@ -176,19 +391,43 @@ where D: Dimension
     /// assert_abs_diff_eq!(var, 6.7331, epsilon = 1e-4);
     /// ```
     #[track_caller]
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
     pub fn var(&self, ddof: A) -> A
     where A: Float + FromPrimitive
     {
-        let zero = A::from_usize(0).expect("Converting 0 to `A` must not fail.");
-        let n = A::from_usize(self.len()).expect("Converting length to `A` must not fail.");
-        assert!(
-            !(ddof < zero || ddof > n),
-            "`ddof` must not be less than zero or greater than the length of \
-             the axis",
-        );
-        let dof = n - ddof;
+        match self.try_var(ddof) {
+            Ok(var) => var,
+            Err(StatsError::EmptyInput) => A::nan(),
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Return variance of elements in the array, or an error if `ddof` is
+    /// out of range or the array is empty.
+    ///
+    /// This is the same as [`var`](Self::var), except that it returns an
+    /// error instead of panicking, and instead of returning NaN for an empty
+    /// array.
+    ///
+    /// ***Errors*** with [`StatsError::DdofOutOfRange`] if `ddof` is less
+    /// than zero or greater than `n`, or else [`StatsError::EmptyInput`] if
+    /// the array is empty.
+    ///
+    /// ```
+    /// use ndarray::{array, Array1, StatsError};
+    ///
+    /// let a = array![1., 2., 3., 4.];
+    /// assert_eq!(a.try_var(1.), Ok(5. / 3.));
+    /// assert_eq!(a.try_var(5.), Err(StatsError::DdofOutOfRange { ddof: 5., n: 4 }));
+    /// assert_eq!(Array1::<f64>::zeros(0).try_var(0.), Err(StatsError::EmptyInput));
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+    pub fn try_var(&self, ddof: A) -> Result<A, StatsError>
+    where A: Float + FromPrimitive
+    {
+        let dof = check_ddof(ddof, self.len())?;
         let mut mean = A::zero();
         let mut sum_sq = A::zero();
         let mut i = 0;
@@ -199,7 +438,7 @@ where D: Dimension
             sum_sq = (x - mean).mul_add(delta, sum_sq);
             i += 1;
         });
-        sum_sq / dof
+        Ok(sum_sq / dof)
     }
 
     /// Return standard deviation of elements in the array.
@@ -242,14 +481,87 @@ where D: Dimension
     /// assert_abs_diff_eq!(stddev, 2.59483, epsilon = 1e-4);
     /// ```
     #[track_caller]
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
     pub fn std(&self, ddof: A) -> A
     where A: Float + FromPrimitive
     {
         self.var(ddof).sqrt()
     }
 
+    /// Return standard deviation of elements in the array, or an error if
+    /// `ddof` is out of range or the array is empty.
+    ///
+    /// This is the square root of [`try_var`](Self::try_var).
+    ///
+    /// ***Errors*** under the same conditions as [`try_var`](Self::try_var).
+    ///
+    /// ```
+    /// use ndarray::{array, StatsError};
+    ///
+    /// let a = array![1., 3.];
+    /// assert_eq!(a.try_std(0.), Ok(1.));
+    /// assert_eq!(a.try_std(-1.), Err(StatsError::DdofOutOfRange { ddof: -1., n: 2 }));
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+    pub fn try_std(&self, ddof: A) -> Result<A, StatsError>
+    where A: Float + FromPrimitive
+    {
+        self.try_var(ddof).map(|var| var.sqrt())
+    }
+
+    /// Return variance of elements in the array, computed with the two-pass
+    /// algorithm.
+    ///
+    /// The variance has the same definition as for [`var`](Self::var), but
+    /// it is computed by first finding the mean `x̅`, and then summing the
+    /// squared deviations from it in a second pass, with the correction term
+    /// of Chan, Golub and LeVeque (1983):
+    ///
+    /// ```text
+    ///               1     ⎛  n             1 ⎛  n         ⎞²⎞
+    /// variance = ――――――――  ⎜  ∑ (xᵢ - x̅)² - ― ⎜  ∑ (xᵢ - x̅)⎟ ⎟
+    ///            n - ddof ⎝ i=1            n ⎝ i=1        ⎠ ⎠
+    /// ```
+    ///
+    /// This reads the elements twice, but it is more accurate than the
+    /// one-pass [`var`](Self::var) for ill-conditioned data, where the mean is
+    /// large compared to the spread of the elements. Prefer `var` when the
+    /// elements are produced on the fly or are expensive to read, and this
+    /// method when accuracy matters most.
+    ///
+    /// The variance of an empty array is NaN.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than `n`
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1e9 + 4., 1e9 + 7., 1e9 + 13., 1e9 + 16f64];
+    /// assert_eq!(a.var_twopass(0.), 22.5);
+    /// assert_eq!(a.var_twopass(1.), 30.);
+    /// ```
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+    pub fn var_twopass(&self, ddof: A) -> A
+    where A: Float + FromPrimitive
+    {
+        let dof = match check_ddof(ddof, self.len()) {
+            Ok(dof) => dof,
+            Err(StatsError::EmptyInput) => return A::nan(),
+            Err(error) => panic!("{}", error),
+        };
+        let n = A::from_usize(self.len()).expect("Converting length to `A` must not fail.");
+        let mean = self.sum() / n;
+        let (sum_dev, sum_sq) = self.fold((A::zero(), A::zero()), |(sum_dev, sum_sq), &x| {
+            let dev = x - mean;
+            (sum_dev + dev, dev.mul_add(dev, sum_sq))
+        });
+        (sum_sq - sum_dev * sum_dev / n) / dof
+    }
+
     /// Return sum along `axis`.
     ///
     /// ```
@@ -284,6 +596,75 @@ where D: Dimension
         }
     }
 
+    /// Return sum along `axis`, using compensated summation.
+    ///
+    /// See [`sum_compensated`](Self::sum_compensated) for details.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let a = Array::from_elem((2, 100_000), 0.1f32);
+    /// assert_eq!(a.sum_axis_compensated(Axis(1)), Array::from_elem(2, 10_000.));
+    /// assert_eq!(a.sum_axis_compensated(Axis(0)), Array::from_elem(100_000, 0.2));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn sum_axis_compensated(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        let min_stride_axis = self.dim.min_stride_axis(&self.strides);
+        if axis == min_stride_axis {
+            crate::Zip::from(self.lanes(axis)).map_collect(|lane| lane.sum_compensated())
+        } else {
+            let mut res = Array::from_elem(self.raw_dim().remove_axis(axis), numeric_util::Compensated::zero());
+            for (i, subview) in self.axis_iter(axis).enumerate() {
+                azip!((sum in &mut res, &x in &subview) *sum = sum.add(x));
+                if (i + 1) % numeric_util::RENORMALIZE_PERIOD == 0 {
+                    res.map_inplace(|sum| *sum = sum.renormalize());
+                }
+            }
+            res.map(|sum| sum.total())
+        }
+    }
+
+    /// Return sum along `axis`, accumulated in the type `W`.
+    ///
+    /// See [`sum_as`](Self::sum_as) for details.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[200u8, 100, 1],
+    ///                [100, 200, 2]];
+    /// assert_eq!(a.sum_axis_as::<u16>(Axis(0)), array![300, 300, 3]);
+    /// assert_eq!(a.sum_axis_as::<u16>(Axis(1)), array![301, 302]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn sum_axis_as<W>(&self, axis: Axis) -> Array<W, D::Smaller>
+    where
+        A: Clone,
+        W: Clone + From<A> + Zero + Add<Output = W>,
+        D: RemoveAxis,
+    {
+        let min_stride_axis = self.dim.min_stride_axis(&self.strides);
+        if axis == min_stride_axis {
+            crate::Zip::from(self.lanes(axis)).map_collect(|lane| lane.sum_as())
+        } else {
+            let mut res = Array::<W, _>::zeros(self.raw_dim().remove_axis(axis));
+            for subview in self.axis_iter(axis) {
+                azip!((sum in &mut res, x in &subview) *sum = sum.clone() + W::from(x.clone()));
+            }
+            res
+        }
+    }
+
     /// Return product along `axis`.
     ///
     /// The product of an empty array is 1.
@@ -345,14 +726,86 @@ where D: Dimension
     where
         A: Clone + Zero + FromPrimitive + Add<Output = A> + Div<Output = A>,
         D: RemoveAxis,
+    {
+        self.try_mean_axis(axis)
+            .expect("Converting axis length to `A` must not fail.")
+    }
+
+    /// Return mean along `axis`, or an error if the length of the axis can
+    /// not be converted to `A`.
+    ///
+    /// This is the same as [`mean_axis`](Self::mean_axis), except that it
+    /// returns an error instead of panicking when `A::from_usize()` fails.
+    ///
+    /// Return `Ok(None)` if the length of the axis is zero.
+    ///
+    /// ***Errors*** if `A::from_usize()` fails for the axis length.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let a = Array::from_elem((2, 200), 1i8);
+    /// assert_eq!(a.try_mean_axis(Axis(0)), Ok(Some(Array::ones(200))));
+    /// assert_eq!(a.try_mean_axis(Axis(1)).unwrap_err().count(), 200);
+    /// ```
+    #[track_caller]
+    pub fn try_mean_axis(&self, axis: Axis) -> Result<Option<Array<A, D::Smaller>>, MeanError>
+    where
+        A: Clone + Zero + FromPrimitive + Add<Output = A> + Div<Output = A>,
+        D: RemoveAxis,
+    {
+        let axis_length = self.len_of(axis);
+        if axis_length == 0 {
+            return Ok(None);
+        }
+        let n = A::from_usize(axis_length).ok_or_else(|| MeanError::new(axis_length))?;
+        let sum = self.sum_axis(axis);
+        Ok(Some(sum / aview0(&n)))
+    }
+
+    /// Return mean along `axis`, computed in the float type `F`.
+    ///
+    /// See [`mean_as`](Self::mean_as) for details.
+    ///
+    /// Return `None` if the length of the axis is zero.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `F::from_usize()`
+    /// fails for the axis length.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[200u8, 255, 1],
+    ///                [100, 254, 2]];
+    /// assert_eq!(a.mean_axis_as::<f32>(Axis(0)), Some(array![150., 254.5, 1.5]));
+    /// assert_eq!(a.mean_axis_as::<f64>(Axis(1)), Some(array![152., 118.66666666666667]));
+    /// ```
+    #[track_caller]
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn mean_axis_as<F>(&self, axis: Axis) -> Option<Array<F, D::Smaller>>
+    where
+        A: AsPrimitive<F>,
+        F: Float + FromPrimitive + 'static,
+        D: RemoveAxis,
     {
         let axis_length = self.len_of(axis);
         if axis_length == 0 {
-            None
+            return None;
+        }
+        let n = F::from_usize(axis_length).expect("Converting axis length to `F` must not fail.");
+        let min_stride_axis = self.dim.min_stride_axis(&self.strides);
+        if axis == min_stride_axis {
+            let sum = |lane: ArrayView1<'_, A>| lane.fold(F::zero(), |sum, &x| sum + x.as_());
+            Some(crate::Zip::from(self.lanes(axis)).map_collect(|lane| sum(lane) / n))
         } else {
-            let axis_length = A::from_usize(axis_length).expect("Converting axis length to `A` must not fail.");
-            let sum = self.sum_axis(axis);
-            Some(sum / aview0(&axis_length))
+            let mut res = Array::<F, _>::zeros(self.raw_dim().remove_axis(axis));
+            for subview in self.axis_iter(axis) {
+                azip!((sum in &mut res, &x in &subview) *sum = *sum + x.as_());
+            }
+            Some(res.mapv_into(|sum| sum / n))
         }
     }
 
@@ -360,6 +813,8 @@ where D: Dimension
     ///
     /// The variance is computed using the [Welford one-pass
     /// algorithm](https://www.jstor.org/stable/1266577).
+    /// See [`var_axis_twopass`](Self::var_axis_twopass) for a more accurate
+    /// alternative.
     ///
     /// The parameter `ddof` specifies the "delta degrees of freedom". For
     /// example, to calculate the population variance, use `ddof = 0`, or to
@@ -399,48 +854,69 @@ where D: Dimension
     /// assert_eq!(var, aview1(&[4., 4.]));
     /// ```
     #[track_caller]
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
     pub fn var_axis(&self, axis: Axis, ddof: A) -> Array<A, D::Smaller>
     where
         A: Float + FromPrimitive,
         D: RemoveAxis,
     {
-        let zero = A::from_usize(0).expect("Converting 0 to `A` must not fail.");
-        let n = A::from_usize(self.len_of(axis)).expect("Converting length to `A` must not fail.");
-        assert!(
-            !(ddof < zero || ddof > n),
-            "`ddof` must not be less than zero or greater than the length of \
-             the axis",
-        );
-        let dof = n - ddof;
-        let mut mean = Array::<A, _>::zeros(self.dim.remove_axis(axis));
-        let mut sum_sq = Array::<A, _>::zeros(self.dim.remove_axis(axis));
-        for (i, subview) in self.axis_iter(axis).enumerate() {
-            let count = A::from_usize(i + 1).expect("Converting index to `A` must not fail.");
-            azip!((mean in &mut mean, sum_sq in &mut sum_sq, &x in &subview) {
-                let delta = x - *mean;
-                *mean = *mean + delta / count;
-                *sum_sq = (x - *mean).mul_add(delta, *sum_sq);
-            });
+        match self.try_var_axis(axis, ddof) {
+            Ok(var) => var,
+            Err(StatsError::EmptyInput) => Array::from_elem(self.dim.remove_axis(axis), A::nan()),
+            Err(error) => panic!("{}", error),
         }
-        sum_sq.mapv_into(|s| s / dof)
     }
 
-    /// Return standard deviation along `axis`.
+    /// Return variance along `axis`, or an error if `ddof` is out of range or
+    /// the axis has length zero.
     ///
-    /// The standard deviation is computed from the variance using
-    /// the [Welford one-pass algorithm](https://www.jstor.org/stable/1266577).
+    /// This is the same as [`var_axis`](Self::var_axis), except that it
+    /// returns an error instead of panicking for a bad `ddof`, and instead of
+    /// returning NaNs for an axis of length zero.
     ///
-    /// The parameter `ddof` specifies the "delta degrees of freedom". For
-    /// example, to calculate the population standard deviation, use `ddof = 0`,
-    /// or to calculate the sample standard deviation, use `ddof = 1`.
+    /// ***Errors*** with [`StatsError::DdofOutOfRange`] if `ddof` is less
+    /// than zero or greater than `n`, or else [`StatsError::EmptyInput`] if
+    /// `n` is zero.
     ///
-    /// The standard deviation is defined as:
+    /// **Panics** if `axis` is out of bounds.
     ///
-    /// ```text
-    ///               ⎛    1       n          ⎞
-    /// stddev = sqrt ⎜ ――――――――   ∑ (xᵢ - x̅)²⎟
+    /// ```
+    /// use ndarray::{array, Axis, StatsError};
+    ///
+    /// let a = array![[1., 2.],
+    ///                [3., 4.],
+    ///                [5., 6.]];
+    /// assert_eq!(a.try_var_axis(Axis(0), 1.), Ok(array![4., 4.]));
+    /// assert_eq!(a.try_var_axis(Axis(1), 3.), Err(StatsError::DdofOutOfRange { ddof: 3., n: 2 }));
+    /// ```
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+    pub fn try_var_axis(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, StatsError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        let dof = check_ddof(ddof, self.len_of(axis))?;
+        let (_, sum_sq) = self.welford_axis(axis);
+        Ok(sum_sq.mapv_into(|s| s / dof))
+    }
+
+    /// Return standard deviation along `axis`.
+    ///
+    /// The standard deviation is computed from the variance using
+    /// the [Welford one-pass algorithm](https://www.jstor.org/stable/1266577).
+    ///
+    /// The parameter `ddof` specifies the "delta degrees of freedom". For
+    /// example, to calculate the population standard deviation, use `ddof = 0`,
+    /// or to calculate the sample standard deviation, use `ddof = 1`.
+    ///
+    /// The standard deviation is defined as:
+    ///
+    /// ```text
+    ///               ⎛    1       n          ⎞
+    /// stddev = sqrt ⎜ ――――――――   ∑ (xᵢ - x̅)²⎟
     ///               ⎝ n - ddof  i=1         ⎠
     /// ```
     ///
@@ -470,8 +946,8 @@ where D: Dimension
     /// assert_eq!(stddev, aview1(&[2., 2.]));
     /// ```
     #[track_caller]
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
     pub fn std_axis(&self, axis: Axis, ddof: A) -> Array<A, D::Smaller>
     where
         A: Float + FromPrimitive,
@@ -480,6 +956,287 @@ where D: Dimension
         self.var_axis(axis, ddof).mapv_into(|x| x.sqrt())
     }
 
+    /// Return standard deviation along `axis`, or an error if `ddof` is out
+    /// of range or the axis has length zero.
+    ///
+    /// This is the square root of [`try_var_axis`](Self::try_var_axis).
+    ///
+    /// ***Errors*** under the same conditions as
+    /// [`try_var_axis`](Self::try_var_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2, Axis, StatsError};
+    ///
+    /// let a = array![[1., 3.],
+    ///                [5., 5.]];
+    /// assert_eq!(a.try_std_axis(Axis(1), 0.), Ok(array![1., 0.]));
+    /// assert_eq!(Array2::<f64>::zeros((2, 0)).try_std_axis(Axis(1), 0.), Err(StatsError::EmptyInput));
+    /// ```
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+    pub fn try_std_axis(&self, axis: Axis, ddof: A) -> Result<Array<A, D::Smaller>, StatsError>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        self.try_var_axis(axis, ddof)
+            .map(|var| var.mapv_into(|x| x.sqrt()))
+    }
+
+    /// Return variance along `axis`, computed with the two-pass algorithm.
+    ///
+    /// See [`var_twopass`](Self::var_twopass) for the algorithm and when to
+    /// prefer it over [`var_axis`](Self::var_axis). Lanes of an axis of
+    /// length zero have a variance of NaN.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than `n`, or if
+    /// `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1e9 + 1., 1e9 + 2.],
+    ///                [1e9 + 3., 1e9 + 4.],
+    ///                [1e9 + 5., 1e9 + 6f64]];
+    /// assert_eq!(a.var_axis_twopass(Axis(0), 1.), array![4., 4.]);
+    /// assert_eq!(a.var_axis_twopass(Axis(1), 0.), array![0.25, 0.25, 0.25]);
+    /// ```
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+    pub fn var_axis_twopass(&self, axis: Axis, ddof: A) -> Array<A, D::Smaller>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        let dof = match check_ddof(ddof, self.len_of(axis)) {
+            Ok(dof) => dof,
+            Err(StatsError::EmptyInput) => return Array::from_elem(self.dim.remove_axis(axis), A::nan()),
+            Err(error) => panic!("{}", error),
+        };
+        let n = A::from_usize(self.len_of(axis)).expect("Converting length to `A` must not fail.");
+        let mean = self.sum_axis(axis).mapv_into(|sum| sum / n);
+        let mut sum_dev = Array::<A, _>::zeros(mean.raw_dim());
+        let mut sum_sq = Array::<A, _>::zeros(mean.raw_dim());
+        for subview in self.axis_iter(axis) {
+            azip!((sum_dev in &mut sum_dev, sum_sq in &mut sum_sq, &mean in &mean, &x in &subview) {
+                let dev = x - mean;
+                *sum_dev = *sum_dev + dev;
+                *sum_sq = dev.mul_add(dev, *sum_sq);
+            });
+        }
+        azip!((sum_sq in &mut sum_sq, &sum_dev in &sum_dev) *sum_sq = (*sum_sq - sum_dev * sum_dev / n) / dof);
+        sum_sq
+    }
+
+    /// Return the array standardized along `axis`, by subtracting the mean
+    /// and dividing by the standard deviation of each lane.
+    ///
+    /// Each element `xᵢ` of a lane is replaced by its z-score
+    ///
+    /// ```text
+    ///      xᵢ - x̅
+    /// zᵢ = ――――――
+    ///        σ
+    /// ```
+    ///
+    /// where `x̅` is the mean and `σ` the standard deviation of the lane with
+    /// `ddof` delta degrees of freedom, as computed by
+    /// [`std_axis`](Self::std_axis). The statistics and the result are
+    /// computed in two passes over the array, without temporary arrays of
+    /// the full size.
+    ///
+    /// The elements of a lane with a standard deviation of zero, that is, a
+    /// lane where all elements are equal, are zero if `zero_constant` is
+    /// true, and NaN otherwise.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than `n`, if `axis`
+    /// is out of bounds, or if `A::from_usize()` fails for any of the numbers
+    /// in the range `0..=n`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 2., 6.],
+    ///                [3., 2., 8f64]];
+    /// assert_eq!(a.standardize_axis(Axis(0), 0., true),
+    ///            array![[-1., 0., -1.],
+    ///                   [1., 0., 1.]]);
+    /// assert!(a.standardize_axis(Axis(0), 0., false)[[0, 1]].is_nan());
+    /// ```
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+    pub fn standardize_axis(&self, axis: Axis, ddof: A, zero_constant: bool) -> Array<A, D>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        let (mean, std) = self.standardize_params(axis, ddof, zero_constant);
+        let mut res = Array::zeros(self.raw_dim());
+        for (mut res, subview) in res.axis_iter_mut(axis).zip(self.axis_iter(axis)) {
+            azip!((r in &mut res, &x in &subview, &mean in &mean, &std in &std) *r = (x - mean) / std);
+        }
+        res
+    }
+
+    /// Standardize the array in place along `axis`, by subtracting the mean
+    /// and dividing by the standard deviation of each lane.
+    ///
+    /// See [`standardize_axis`](Self::standardize_axis) for the details.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than `n`, if `axis`
+    /// is out of bounds, or if `A::from_usize()` fails for any of the numbers
+    /// in the range `0..=n`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[1., 3., 5.],
+    ///                    [4., 4., 4.]];
+    /// a.standardize_axis_inplace(Axis(1), 1., true);
+    /// assert_eq!(a, array![[-1., 0., 1.],
+    ///                      [0., 0., 0.]]);
+    /// ```
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+    pub fn standardize_axis_inplace(&mut self, axis: Axis, ddof: A, zero_constant: bool)
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        let (mean, std) = self.standardize_params(axis, ddof, zero_constant);
+        for mut subview in self.axis_iter_mut(axis) {
+            azip!((x in &mut subview, &mean in &mean, &std in &std) *x = (*x - mean) / std);
+        }
+    }
+
+    /// Return the exponentially weighted moving average along `axis`, with
+    /// smoothing factor `alpha`.
+    ///
+    /// With `adjust == false`, this is the recursive form, where each
+    /// average is `y[t] = (1 - alpha) * y[t - 1] + alpha * x[t]` and
+    /// `y[0] = x[0]`. With `adjust == true`, each average is normalized by
+    /// the sum of the weights seen so far,
+    ///
+    /// ```text
+    /// y[t] = Σᵢ (1 - alpha)ⁱ x[t - i] / Σᵢ (1 - alpha)ⁱ,  i = 0..=t,
+    /// ```
+    ///
+    /// so that the first elements are not biased towards `x[0]`. These are
+    /// the two forms of `ewm(alpha=alpha, adjust=adjust).mean()` in pandas.
+    /// The result has the same shape as the array. A `NaN` propagates to the
+    /// rest of its lane; see [`ewma_skipnan`](Self::ewma_skipnan) to skip
+    /// `NaN`s instead.
+    ///
+    /// ***Errors*** with [`StatsError::AlphaOutOfRange`] if `alpha` is not in
+    /// the interval `(0, 1]`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![1., 2., 4.];
+    /// assert_eq!(a.ewma(Axis(0), 0.5, false).unwrap(), array![1., 1.5, 2.75]);
+    /// assert_eq!(a.ewma(Axis(0), 0.5, true).unwrap(), array![1., 5. / 3., 3.]);
+    /// assert!(a.ewma(Axis(0), 0., true).is_err());
+    /// ```
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+    pub fn ewma(&self, axis: Axis, alpha: A, adjust: bool) -> Result<Array<A, D>, StatsError>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        check_ewma_alpha(alpha)?;
+        if axis.0 >= self.ndim() {
+            panic!("axis is out of bounds for array of dimension");
+        }
+
+        let beta = A::one() - alpha;
+        let mut res = self.to_owned();
+        if !adjust {
+            res.accumulate_axis_inplace(axis, |&prev, curr| *curr = beta * prev + alpha * *curr);
+            return Ok(res);
+        }
+        // The weights of a lane don't depend on its elements, so the
+        // running sums of weights are shared by all lanes.
+        res.accumulate_axis_inplace(axis, |&prev, curr| *curr = *curr + beta * prev);
+        let mut weight = A::zero();
+        for mut subview in res.axis_iter_mut(axis) {
+            weight = A::one() + beta * weight;
+            subview.mapv_inplace(|x| x / weight);
+        }
+        Ok(res)
+    }
+
+    /// Return the exponentially weighted moving average along `axis`,
+    /// ignoring `NaN`s.
+    ///
+    /// This is [`ewma`](Self::ewma) of each lane with its `NaN`s removed: the
+    /// weights decay with the number of elements that are not `NaN`, and
+    /// the average at a `NaN` is the previous average. Leading `NaN`s of a
+    /// lane stay `NaN`. This matches `ewm(alpha=alpha, adjust=adjust,
+    /// ignore_na=True).mean()` in pandas.
+    ///
+    /// ***Errors*** with [`StatsError::AlphaOutOfRange`] if `alpha` is not in
+    /// the interval `(0, 1]`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![f64::NAN, 1., f64::NAN, 3.];
+    /// let ewma = a.ewma_skipnan(Axis(0), 0.5, false).unwrap();
+    /// assert!(ewma[0].is_nan());
+    /// assert_eq!(ewma.slice(ndarray::s![1..]), array![1., 1., 2.]);
+    /// ```
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+    pub fn ewma_skipnan(&self, axis: Axis, alpha: A, adjust: bool) -> Result<Array<A, D>, StatsError>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        check_ewma_alpha(alpha)?;
+        if axis.0 >= self.ndim() {
+            panic!("axis is out of bounds for array of dimension");
+        }
+
+        let beta = A::one() - alpha;
+        let mut res = self.to_owned();
+        crate::Zip::from(res.lanes_mut(axis)).for_each(|lane| {
+            // the weighted sum of the elements and the sum of their weights
+            let mut sum = A::zero();
+            let mut weight = A::zero();
+            let mut avg = A::nan();
+            for x in lane {
+                if !x.is_nan() {
+                    if adjust {
+                        sum = *x + beta * sum;
+                        weight = A::one() + beta * weight;
+                        avg = sum / weight;
+                    } else if weight.is_zero() {
+                        avg = *x;
+                        weight = A::one();
+                    } else {
+                        avg = beta * avg + alpha * *x;
+                    }
+                }
+                *x = avg;
+            }
+        });
+        Ok(res)
+    }
+
     /// Calculates the (forward) finite differences of order `n`, along the `axis`.
     /// For the 1D-case, `n==1`, this means: `diff[i] == arr[i+1] - arr[i]`
     ///
@@ -534,4 +1291,477 @@ where D: Dimension
         }
         inp
     }
+
+    /// Calculates the finite differences of order `n` along `axis`, of the
+    /// kind given by `mode`.
+    ///
+    /// Forward and backward differences are the same values, `x[i + 1] -
+    /// x[i]`, and only differ in the sample they belong to. Forward
+    /// differences are the same as for [`diff`](Self::diff): element `i` of
+    /// the output belongs to sample `i`, and each order shrinks the axis by
+    /// one. Backward differences keep the length of the axis so that element
+    /// `i` of the output belongs to sample `i`: the first `n` elements, which
+    /// have no backward difference of order `n`, are zero.
+    ///
+    /// Element `i` of the central differences of order one is the central
+    /// difference of sample `i + 1`, `(x[i + 2] - x[i]) / 2`, so each order
+    /// shrinks the axis by two. For integers the division by two truncates
+    /// towards zero, so central differences of integers are exact only where
+    /// `x[i + 2] - x[i]` is even.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, DiffMode};
+    ///
+    /// let x = array![1., 2., 4., 7., 11., 16.];
+    /// assert_eq!(x.diff_mode(1, Axis(0), DiffMode::Forward), array![1., 2., 3., 4., 5.]);
+    /// assert_eq!(x.diff_mode(1, Axis(0), DiffMode::Backward), array![0., 1., 2., 3., 4., 5.]);
+    /// assert_eq!(x.diff_mode(2, Axis(0), DiffMode::Backward), array![0., 0., 1., 1., 1., 1.]);
+    /// assert_eq!(x.diff_mode(1, Axis(0), DiffMode::Central), array![1.5, 2.5, 3.5, 4.5]);
+    /// assert_eq!(x.diff_mode(2, Axis(0), DiffMode::Central), array![1., 1.]);
+    ///
+    /// // integer central differences truncate
+    /// let k = array![0, 1, 3, 6];
+    /// assert_eq!(k.diff_mode(1, Axis(0), DiffMode::Central), array![1, 2]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds, or if the length of the array
+    /// along `axis` is not greater than `n` for forward and backward
+    /// differences, or `2 * n` for central differences.
+    #[track_caller]
+    pub fn diff_mode(&self, n: usize, axis: Axis, mode: DiffMode) -> Array<A, D>
+    where A: Sub<A, Output = A> + Add<A, Output = A> + Div<A, Output = A> + Zero + One + Clone
+    {
+        assert!(
+            axis.0 < self.ndim(),
+            "{:?} differences along axis {}: the array has only ndim {}",
+            mode,
+            axis.0,
+            self.ndim()
+        );
+        let step = if mode == DiffMode::Central { 2 } else { 1 };
+        let len = self.len_of(axis);
+        assert!(
+            n.saturating_mul(step) < len,
+            "{:?} differences of order {} along axis {}: the array must have length at least {}, but has length {}",
+            mode,
+            n,
+            axis.0,
+            n.saturating_mul(step).saturating_add(1),
+            len
+        );
+        match mode {
+            DiffMode::Forward => return self.diff(n, axis),
+            DiffMode::Backward => {
+                let mut out = Array::zeros(self.raw_dim());
+                out.slice_axis_mut(axis, Slice::from(n..))
+                    .assign(&self.diff(n, axis));
+                return out;
+            }
+            DiffMode::Central => {}
+        }
+
+        let two = A::one() + A::one();
+        let mut inp = self.to_owned();
+        for _ in 0..n {
+            let head = inp.slice_axis(axis, Slice::from(..-2));
+            let tail = inp.slice_axis(axis, Slice::from(2..));
+            let out = crate::Zip::from(&tail)
+                .and(&head)
+                .map_collect(|t, h| (t.clone() - h.clone()) / two.clone());
+            inp = out;
+        }
+        inp
+    }
+
+    /// Calculates the (forward) finite differences of order `n` along `axis`,
+    /// after joining `prepend` before and `append` after the array along
+    /// `axis`.
+    ///
+    /// Like with NumPy's `diff`, the padding is applied once, before
+    /// differencing, so with `n == 1` and one row of padding the output has
+    /// the same length along `axis` as the input:
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let x = array![[1., 2., 4.],
+    ///                [2., 2., 2.]];
+    /// let start = array![[0.], [1.]];
+    /// let d = x.diff_with(1, Axis(1), Some(&start), None).unwrap();
+    /// assert_eq!(d, array![[1., 1., 2.],
+    ///                      [1., 0., 0.]]);
+    /// ```
+    ///
+    /// ***Errors*** if the shape of `prepend` or `append` differs from the shape
+    /// of the array other than along `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `n` is not less than the
+    /// length of the padded array along `axis`.
+    #[track_caller]
+    pub fn diff_with(
+        &self, n: usize, axis: Axis, prepend: Option<&ArrayRef<A, D>>, append: Option<&ArrayRef<A, D>>,
+    ) -> Result<Array<A, D>, ShapeError>
+    where
+        A: Sub<A, Output = A> + Zero + Clone,
+        D: RemoveAxis,
+    {
+        assert!(axis.0 < self.ndim(), "The array has only ndim {}, but `axis` {:?} is given.", self.ndim(), axis);
+        if prepend.is_none() && append.is_none() {
+            return Ok(self.diff(n, axis));
+        }
+        let mut parts = Vec::with_capacity(3);
+        parts.extend(prepend.map(|a| a.view()));
+        parts.push(self.view());
+        parts.extend(append.map(|a| a.view()));
+        Ok(crate::concatenate(axis, &parts)?.diff(n, axis))
+    }
+
+    /// Return the gradient along `axis`, for samples with a spacing of one.
+    ///
+    /// This is `gradient_with_spacing(axis, 1.)`, see
+    /// [`gradient_with_spacing`](Self::gradient_with_spacing).
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![1., 2., 4., 7., 11.];
+    /// assert_eq!(a.gradient(Axis(0)), array![0.5, 1.5, 2.5, 3.5, 4.5]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or if the length of the axis is
+    /// less than two.
+    #[track_caller]
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn gradient(&self, axis: Axis) -> Array<A, D>
+    where A: Float
+    {
+        self.gradient_with_spacing(axis, A::one())
+    }
+
+    /// Return the gradient along `axis`, for samples with a uniform spacing
+    /// of `dx`.
+    ///
+    /// The result has the same shape as the array. Like NumPy's `gradient`
+    /// with `edge_order=2`, central differences are used in the interior and
+    /// one-sided differences at the boundaries, all of which are second-order
+    /// accurate, so the gradient of a quadratic is exact up to rounding. If
+    /// the axis has only two elements, both get the first-order difference
+    /// `(f₁ - f₀) / dx`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// // f(x) = x² sampled at x = 0, 0.5, 1, 1.5
+    /// let f = array![0., 0.25, 1., 2.25];
+    /// assert_eq!(f.gradient_with_spacing(Axis(0), 0.5), array![0., 1., 2., 3.]);
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or if the length of the axis is
+    /// less than two.
+    #[track_caller]
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn gradient_with_spacing(&self, axis: Axis, dx: A) -> Array<A, D>
+    where A: Float
+    {
+        let n = self.len_of(axis);
+        let two = A::one() + A::one();
+        let h = dx.recip();
+        let h2 = (two * dx).recip();
+        let three = two + A::one();
+        self.gradient_by(axis, |i| {
+            if n == 2 {
+                [(0, -h), (1, h), (1, A::zero())]
+            } else if i == 0 {
+                [(0, -three * h2), (1, two * two * h2), (2, -h2)]
+            } else if i == n - 1 {
+                [(n - 3, h2), (n - 2, -two * two * h2), (n - 1, three * h2)]
+            } else {
+                [(i - 1, -h2), (i, A::zero()), (i + 1, h2)]
+            }
+        })
+    }
+
+    /// Return the gradient along `axis`, for samples at the coordinates `x`.
+    ///
+    /// This is [`gradient_with_spacing`](Self::gradient_with_spacing) for
+    /// non-uniform spacing: the differences in the interior and at the
+    /// boundaries are second-order accurate, computed with the formulas of
+    /// NumPy's `gradient` with `edge_order=2`. The coordinates must be
+    /// strictly increasing or strictly decreasing.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// // f(x) = x² sampled at x = 0, 1, 3, 4
+    /// let x = array![0., 1., 3., 4.];
+    /// let f = x.mapv(|x| x * x);
+    /// let g = f.gradient_with_coords(Axis(0), &x);
+    /// assert!((g - array![0., 2., 6., 8.]).iter().all(|d: &f64| d.abs() < 1e-12));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds, if the length of the axis is
+    /// less than two, or if the length of `x` differs from the length of the
+    /// axis.
+    #[track_caller]
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn gradient_with_coords(&self, axis: Axis, x: &ArrayRef<A, Ix1>) -> Array<A, D>
+    where A: Float
+    {
+        let n = self.len_of(axis);
+        assert_eq!(
+            x.len(),
+            n,
+            "The coordinates must have the same length as the array along `axis`"
+        );
+        self.gradient_by(axis, |i| {
+            if n == 2 {
+                let h = (x[1] - x[0]).recip();
+                return [(0, -h), (1, h), (1, A::zero())];
+            }
+            // the three samples used, and the two spacings between them
+            let j = i.clamp(1, n - 2) - 1;
+            let d1 = x[j + 1] - x[j];
+            let d2 = x[j + 2] - x[j + 1];
+            let coefs = if i == 0 {
+                [-(d1 + d1 + d2) / (d1 * (d1 + d2)), (d1 + d2) / (d1 * d2), -d1 / (d2 * (d1 + d2))]
+            } else if i == n - 1 {
+                [d2 / (d1 * (d1 + d2)), -(d1 + d2) / (d1 * d2), (d2 + d2 + d1) / (d2 * (d1 + d2))]
+            } else {
+                [-d2 / (d1 * (d1 + d2)), (d2 - d1) / (d1 * d2), d1 / (d2 * (d1 + d2))]
+            };
+            [(j, coefs[0]), (j + 1, coefs[1]), (j + 2, coefs[2])]
+        })
+    }
+
+    /// Compute each element `i` along `axis` of the gradient as the linear
+    /// combination `∑ c * self[j]` of the three `(j, c)` in `coefs(i)`.
+    #[track_caller]
+    #[cfg(feature = "std")]
+    fn gradient_by<F>(&self, axis: Axis, coefs: F) -> Array<A, D>
+    where
+        A: Float,
+        F: Fn(usize) -> [(usize, A); 3],
+    {
+        let n = self.len_of(axis);
+        assert!(n >= 2, "The array must have length at least 2 in the direction of `axis`. It has length {}", n);
+        let mut out = Array::zeros(self.raw_dim());
+        let at = |j: usize| self.slice_axis(axis, Slice::from(j..j + 1));
+        for i in 0..n {
+            let [(j0, c0), (j1, c1), (j2, c2)] = coefs(i);
+            azip!((o in out.slice_axis_mut(axis, Slice::from(i..i + 1)), &f0 in at(j0), &f1 in at(j1), &f2 in at(j2)) {
+                *o = c0 * f0 + c1 * f1 + c2 * f2
+            });
+        }
+        out
+    }
+
+    /// Integrate along `axis` with the trapezoid rule, for samples with a
+    /// uniform spacing of `dx`.
+    ///
+    /// The integral over an axis of length zero or one is zero.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 2., 3.],
+    ///                [4., 6., 8.]]);
+    /// assert_eq!(a.trapz_axis(Axis(1), 0.5), aview1(&[2., 6.]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn trapz_axis(&self, axis: Axis, dx: A) -> Array<A, D::Smaller>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        self.trapz_by(axis, |_| dx)
+    }
+
+    /// Integrate along `axis` with the trapezoid rule, for samples at the
+    /// coordinates `x`.
+    ///
+    /// The integral over an axis of length zero or one is zero.
+    ///
+    /// Return an error if the length of `x` differs from the length of the
+    /// axis.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 2., 3.],
+    ///                [4., 6., 8.]]);
+    /// let x = aview1(&[0., 1., 3.]);
+    /// assert_eq!(a.trapz_axis_with_coords(Axis(1), &x).unwrap(), aview1(&[6.5, 19.]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn trapz_axis_with_coords(&self, axis: Axis, x: &ArrayRef<A, Ix1>) -> Result<Array<A, D::Smaller>, StatsError>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        if x.len() != self.len_of(axis) {
+            return Err(from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        Ok(self.trapz_by(axis, |i| x[i + 1] - x[i]))
+    }
+
+    /// Integrate along `axis` with the trapezoid rule, where `width(i)` is
+    /// the spacing between the samples `i` and `i + 1`.
+    #[track_caller]
+    #[cfg(feature = "std")]
+    fn trapz_by<F>(&self, axis: Axis, width: F) -> Array<A, D::Smaller>
+    where
+        A: Float,
+        D: RemoveAxis,
+        F: Fn(usize) -> A,
+    {
+        let two = A::one() + A::one();
+        let mut res = Array::zeros(self.raw_dim().remove_axis(axis));
+        let mut subviews = self.axis_iter(axis);
+        if let Some(mut prev) = subviews.next() {
+            for (i, curr) in subviews.enumerate() {
+                let half_width = width(i) / two;
+                azip!((r in &mut res, &f0 in &prev, &f1 in &curr) *r = (f0 + f1).mul_add(half_width, *r));
+                prev = curr;
+            }
+        }
+        res
+    }
+
+    /// Return the means and the sums of the squared deviations from the mean
+    /// of the lanes along `axis`, computed with the Welford algorithm.
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn welford_axis(&self, axis: Axis) -> (Array<A, D::Smaller>, Array<A, D::Smaller>)
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        let mut mean = Array::<A, _>::zeros(self.dim.remove_axis(axis));
+        let mut sum_sq = Array::<A, _>::zeros(self.dim.remove_axis(axis));
+        for (i, subview) in self.axis_iter(axis).enumerate() {
+            let count = A::from_usize(i + 1).expect("Converting index to `A` must not fail.");
+            azip!((mean in &mut mean, sum_sq in &mut sum_sq, &x in &subview) {
+                let delta = x - *mean;
+                *mean = *mean + delta / count;
+                *sum_sq = (x - *mean).mul_add(delta, *sum_sq);
+            });
+        }
+        (mean, sum_sq)
+    }
+
+    /// Return the means and standard deviations of the lanes along `axis`
+    /// for standardizing them, where a standard deviation of zero is
+    /// replaced by one if `zero_constant` is true.
+    #[track_caller]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn standardize_params(
+        &self, axis: Axis, ddof: A, zero_constant: bool,
+    ) -> (Array<A, D::Smaller>, Array<A, D::Smaller>)
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        let dof = match check_ddof(ddof, self.len_of(axis)) {
+            Ok(dof) => dof,
+            // there are no elements to standardize
+            Err(StatsError::EmptyInput) => A::one(),
+            Err(error) => panic!("{}", error),
+        };
+        let (mean, sum_sq) = self.welford_axis(axis);
+        // the deviations in a constant lane are exactly zero, so dividing them
+        // by one gives zeros
+        let std = sum_sq.mapv_into(|s| {
+            let std = (s / dof).sqrt();
+            if zero_constant && std == A::zero() {
+                A::one()
+            } else {
+                std
+            }
+        });
+        (mean, std)
+    }
+}
+
+/// # Numerical Methods for 1-D Arrays
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A> ArrayRef<A, Ix1>
+where A: Float
+{
+    /// Integrate with the trapezoid rule, for samples with a uniform spacing
+    /// of `dx`.
+    ///
+    /// The integral of an array with less than two elements is zero.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![1., 2., 3., 4.].trapz(2.), 15.);
+    /// ```
+    pub fn trapz(&self, dx: A) -> A
+    {
+        self.trapz_axis(Axis(0), dx).into_scalar()
+    }
+
+    /// Integrate with the trapezoid rule, for samples at the coordinates `x`.
+    ///
+    /// The integral of an array with less than two elements is zero.
+    ///
+    /// Return an error if the length of `x` differs from the length of the
+    /// array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let x = array![0., 1., 3.];
+    /// assert_eq!(array![1., 2., 3.].trapz_with_coords(&x), Ok(6.5));
+    /// ```
+    pub fn trapz_with_coords(&self, x: &ArrayRef<A, Ix1>) -> Result<A, StatsError>
+    {
+        self.trapz_axis_with_coords(Axis(0), x)
+            .map(Array::into_scalar)
+    }
+}
+
+/// Return the degrees of freedom `n - ddof`, or an error if `ddof` is out of
+/// range or `n` is zero.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[track_caller]
+pub(crate) fn check_ddof<A>(ddof: A, n: usize) -> Result<A, StatsError>
+where A: Float + FromPrimitive
+{
+    let n_a = A::from_usize(n).expect("Converting length to `A` must not fail.");
+    if ddof < A::zero() || ddof > n_a {
+        return Err(StatsError::DdofOutOfRange {
+            ddof: ddof.to_f64().unwrap_or(f64::NAN),
+            n,
+        });
+    }
+    if n == 0 {
+        return Err(StatsError::EmptyInput);
+    }
+    Ok(n_a - ddof)
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn check_ewma_alpha<A: Float>(alpha: A) -> Result<(), StatsError>
+{
+    if alpha > A::zero() && alpha <= A::one() {
+        Ok(())
+    } else {
+        Err(StatsError::AlphaOutOfRange {
+            alpha: alpha.to_f64().unwrap_or(f64::NAN),
+        })
+    }
 }
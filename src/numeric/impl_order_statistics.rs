@@ -0,0 +1,1632 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use num_traits::Float;
+use num_traits::{FromPrimitive, Signed};
+use std::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::Hash;
+use std::ops::{Add, Div, Sub};
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// Interpolation strategy for quantiles that fall between two data points.
+///
+/// For a quantile `q` of `n` sorted elements `x`, the (fractional) index of
+/// the quantile is `h = q * (n - 1)`. Let `i` and `j` be `h` rounded down and
+/// up, respectively. This mirrors the `interpolation` (now `method`)
+/// argument of NumPy's `quantile`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Interpolate
+{
+    /// `x[i] + (h - i) * (x[j] - x[i])`
+    Linear,
+    /// `x[i]`
+    Lower,
+    /// `x[j]`
+    Higher,
+    /// `x[i]` or `x[j]`, whichever is closer to `h`; ties go to the even index.
+    Nearest,
+    /// `(x[i] + x[j]) / 2`
+    Midpoint,
+}
+
+/// # Order Statistics
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Return a reference to the minimum of all elements in the array.
+    ///
+    /// If several elements are equal to the minimum, the first one in memory
+    /// order is returned.
+    ///
+    /// If the array is empty, or if any element is not comparable (such as
+    /// `NaN`), `None` is returned. Use [`min_skipnan`](Self::min_skipnan) to
+    /// ignore `NaN`s instead.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1.0, f64::NAN, 3.0];
+    /// assert_eq!(a.min(), None);
+    /// assert_eq!(array![[2, 5], [-1, 3]].min(), Some(&-1));
+    /// ```
+    pub fn min(&self) -> Option<&A>
+    where A: PartialOrd
+    {
+        if let Some(slc) = self.as_slice_memory_order() {
+            extremum_by(slc, Ordering::Less)
+        } else {
+            extremum_by(self.iter(), Ordering::Less)
+        }
+    }
+
+    /// Return a reference to the maximum of all elements in the array.
+    ///
+    /// If several elements are equal to the maximum, the first one in memory
+    /// order is returned.
+    ///
+    /// If the array is empty, or if any element is not comparable (such as
+    /// `NaN`), `None` is returned. Use [`max_skipnan`](Self::max_skipnan) to
+    /// ignore `NaN`s instead.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1.0, f64::NAN, 3.0];
+    /// assert_eq!(a.max(), None);
+    /// assert_eq!(array![[2, 5], [-1, 3]].max(), Some(&5));
+    /// ```
+    pub fn max(&self) -> Option<&A>
+    where A: PartialOrd
+    {
+        if let Some(slc) = self.as_slice_memory_order() {
+            extremum_by(slc, Ordering::Greater)
+        } else {
+            extremum_by(self.iter(), Ordering::Greater)
+        }
+    }
+
+    /// Return references to the minimum and the maximum of all elements in
+    /// the array, found in a single pass over the elements.
+    ///
+    /// The extrema are the same ones as [`min`](Self::min) and
+    /// [`max`](Self::max) return, but this reads the elements only once.
+    ///
+    /// If the array is empty, or if any element is not comparable (such as
+    /// `NaN`), `None` is returned. Use
+    /// [`min_max_skipnan`](Self::min_max_skipnan) to ignore `NaN`s instead.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[2, 5], [-1, 3]];
+    /// assert_eq!(a.min_max(), Some((&-1, &5)));
+    /// assert_eq!(array![1.0, f64::NAN, 3.0].min_max(), None);
+    /// ```
+    pub fn min_max(&self) -> Option<(&A, &A)>
+    where A: PartialOrd
+    {
+        if let Some(slc) = self.as_slice_memory_order() {
+            min_max_by(slc)
+        } else {
+            min_max_by(self.iter())
+        }
+    }
+
+    /// Return the maximum absolute value of all elements in the array.
+    ///
+    /// This is the `L∞` norm of the array, computed in a single pass without
+    /// allocating an array of absolute values. It applies to signed integers
+    /// as well as floats.
+    ///
+    /// If the array is empty, or if any element is not comparable (such as
+    /// `NaN`), `None` is returned.
+    ///
+    /// The elements are compared by their negated absolute values, which
+    /// can always be represented, so only the result itself is negated. For
+    /// signed integers, this overflows if the result is not representable,
+    /// which is the case if the array contains the minimum value of the type,
+    /// such as `i32::MIN`. Like `i32::MIN.abs()`, this then panics in debug
+    /// builds and returns the minimum value in release builds.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[2, -7], [5, 3]];
+    /// assert_eq!(a.abs_max(), Some(7));
+    /// assert_eq!(array![-1.5, 0.5].abs_max(), Some(1.5));
+    /// assert_eq!(array![1.0, f64::NAN].abs_max(), None);
+    /// ```
+    pub fn abs_max(&self) -> Option<A>
+    where A: Clone + Signed + PartialOrd
+    {
+        if self.is_empty() {
+            return None;
+        }
+        let mut min = A::zero();
+        for x in self.iter() {
+            let x = neg_abs(x);
+            if x.partial_cmp(&min)? == Ordering::Less {
+                min = x;
+            }
+        }
+        Some(-min)
+    }
+
+    /// Return the cumulative maximum of elements along `axis`.
+    ///
+    /// Once an element that is not comparable to itself (such as `NaN`) is
+    /// seen, the rest of the lane is that element. Use
+    /// [`cummax_skipnan`](Self::cummax_skipnan) to ignore `NaN`s instead.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 5, 3],
+    ///                [4, 2, 6]]);
+    /// assert_eq!(a.cummax(Axis(0)), arr2(&[[1, 5, 3], [4, 5, 6]]));
+    /// assert_eq!(a.cummax(Axis(1)), arr2(&[[1, 5, 5], [4, 4, 6]]));
+    ///
+    /// let b = arr1(&[1., f64::NAN, 3.]).cummax(Axis(0));
+    /// assert!(b[0] == 1. && b[1].is_nan() && b[2].is_nan());
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn cummax(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + PartialOrd,
+        D: RemoveAxis,
+    {
+        cumulative_extremum_by(self, axis, |x, best| replaces(x, best, Ordering::Greater))
+    }
+
+    /// Return the cumulative minimum of elements along `axis`.
+    ///
+    /// Once an element that is not comparable to itself (such as `NaN`) is
+    /// seen, the rest of the lane is that element. Use
+    /// [`cummin_skipnan`](Self::cummin_skipnan) to ignore `NaN`s instead.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 5, 3],
+    ///                [4, 2, 6]]);
+    /// assert_eq!(a.cummin(Axis(0)), arr2(&[[1, 5, 3], [1, 2, 3]]));
+    /// assert_eq!(a.cummin(Axis(1)), arr2(&[[1, 1, 1], [4, 2, 2]]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn cummin(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + PartialOrd,
+        D: RemoveAxis,
+    {
+        cumulative_extremum_by(self, axis, |x, best| replaces(x, best, Ordering::Less))
+    }
+
+    /// Return the peak-to-peak range of all elements in the array, that is
+    /// the maximum minus the minimum.
+    ///
+    /// Both extrema are found in a single pass over the elements.
+    ///
+    /// If the array is empty, or if any element is not comparable (such as
+    /// `NaN`), `None` is returned.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[3, 1, 4], [1, 5, 9]];
+    /// assert_eq!(a.ptp(), Some(8));
+    /// assert_eq!(array![1.0, f64::NAN, 3.0].ptp(), None);
+    /// ```
+    pub fn ptp(&self) -> Option<A>
+    where A: Clone + PartialOrd + Sub<Output = A>
+    {
+        let (min, max) = self.min_max()?;
+        Some(max.clone() - min.clone())
+    }
+
+    /// Return the peak-to-peak range along `axis`.
+    ///
+    /// Both extrema of each lane are found in a single pass over the
+    /// elements. If a lane contains an element that is not comparable to
+    /// itself (such as `NaN`), the extrema are chosen like
+    /// [`min_axis`](Self::min_axis) and [`max_axis`](Self::max_axis) do.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 5, 3],
+    ///                [4, 2, 6]]);
+    /// assert_eq!(a.ptp_axis(Axis(0)), aview1(&[3, 3, 3]));
+    /// assert_eq!(a.ptp_axis(Axis(1)), aview1(&[4, 4]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or if the length of the axis is
+    /// zero.
+    #[track_caller]
+    pub fn ptp_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Clone + PartialOrd + Sub<Output = A>,
+        D: RemoveAxis,
+    {
+        let (min, mut max) = min_max_axis_by(self, axis, replaces);
+        Zip::from(&mut max)
+            .and(&min)
+            .for_each(|max, min| *max = max.clone() - min.clone());
+        max
+    }
+
+    /// Return the index of the minimum of all elements in the array.
+    ///
+    /// If several elements are equal to the minimum, the index of the first
+    /// one in logical order is returned.
+    ///
+    /// If the array is empty, or if any element is not comparable (such as
+    /// `NaN`), `None` is returned.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[3, 1, 4], [1, 5, 9]];
+    /// assert_eq!(a.argmin(), Some((0, 1)));
+    /// assert_eq!(array![1.0, f64::NAN, 3.0].argmin(), None);
+    /// ```
+    pub fn argmin(&self) -> Option<D::Pattern>
+    where A: PartialOrd
+    {
+        self.arg_extremum_by(Ordering::Less)
+    }
+
+    /// Return the index of the maximum of all elements in the array.
+    ///
+    /// If several elements are equal to the maximum, the index of the first
+    /// one in logical order is returned.
+    ///
+    /// If the array is empty, or if any element is not comparable (such as
+    /// `NaN`), `None` is returned.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[3, 1, 4], [1, 5, 9]];
+    /// assert_eq!(a.argmax(), Some((1, 2)));
+    /// assert_eq!(array![1.0, f64::NAN, 3.0].argmax(), None);
+    /// ```
+    pub fn argmax(&self) -> Option<D::Pattern>
+    where A: PartialOrd
+    {
+        self.arg_extremum_by(Ordering::Greater)
+    }
+
+    /// Return the indices of the minima along `axis`.
+    ///
+    /// If several elements of a lane are equal to the minimum, the lowest
+    /// index is returned. If a lane contains an element that is not comparable
+    /// to itself (such as `NaN`), the index of the first such element is
+    /// returned.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 5, 3],
+    ///                [4, 2, 1]]);
+    /// assert_eq!(a.argmin_axis(Axis(0)), aview1(&[0, 1, 1]));
+    /// assert_eq!(a.argmin_axis(Axis(1)), aview1(&[0, 2]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or if the length of the axis is
+    /// zero.
+    #[track_caller]
+    pub fn argmin_axis(&self, axis: Axis) -> Array<usize, D::Smaller>
+    where
+        A: PartialOrd,
+        D: RemoveAxis,
+    {
+        arg_extremum_axis_by(self, axis, Ordering::Less)
+    }
+
+    /// Return the indices of the maxima along `axis`.
+    ///
+    /// If several elements of a lane are equal to the maximum, the lowest
+    /// index is returned. If a lane contains an element that is not comparable
+    /// to itself (such as `NaN`), the index of the first such element is
+    /// returned.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let scores = arr2(&[[0.1, 0.7, 0.2],
+    ///                     [0.5, 0.1, 0.4]]);
+    /// assert_eq!(scores.argmax_axis(Axis(1)), aview1(&[1, 0]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or if the length of the axis is
+    /// zero.
+    #[track_caller]
+    pub fn argmax_axis(&self, axis: Axis) -> Array<usize, D::Smaller>
+    where
+        A: PartialOrd,
+        D: RemoveAxis,
+    {
+        arg_extremum_axis_by(self, axis, Ordering::Greater)
+    }
+
+    fn arg_extremum_by(&self, better: Ordering) -> Option<D::Pattern>
+    where A: PartialOrd
+    {
+        if self.ndim() == 1 {
+            if let Some(slc) = self.as_slice() {
+                let i = arg_extremum_by(slc.iter().enumerate(), better)?;
+                let mut index = self.raw_dim();
+                index[0] = i;
+                return Some(index.into_pattern());
+            }
+        }
+        arg_extremum_by(self.indexed_iter(), better)
+    }
+
+    /// Return the minimum along `axis`.
+    ///
+    /// If a lane contains an element that is not comparable to itself (such
+    /// as `NaN`), the first such element is the result for that lane. Use
+    /// [`min_axis_skipnan`](Self::min_axis_skipnan) to ignore `NaN`s instead.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 5, 3],
+    ///                [4, 2, 6]]);
+    /// assert_eq!(a.min_axis(Axis(0)), aview1(&[1, 2, 3]));
+    /// assert_eq!(a.min_axis(Axis(1)), aview1(&[1, 2]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or if the length of the axis is
+    /// zero.
+    #[track_caller]
+    pub fn min_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Clone + PartialOrd,
+        D: RemoveAxis,
+    {
+        extremum_axis_by(self, axis, |x, best| replaces(x, best, Ordering::Less))
+    }
+
+    /// Return the maximum along `axis`.
+    ///
+    /// If a lane contains an element that is not comparable to itself (such
+    /// as `NaN`), the first such element is the result for that lane. Use
+    /// [`max_axis_skipnan`](Self::max_axis_skipnan) to ignore `NaN`s instead.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 5, 3],
+    ///                [4, 2, 6]]);
+    /// assert_eq!(a.max_axis(Axis(0)), aview1(&[4, 5, 6]));
+    /// assert_eq!(a.max_axis(Axis(1)), aview1(&[5, 6]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or if the length of the axis is
+    /// zero.
+    #[track_caller]
+    pub fn max_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Clone + PartialOrd,
+        D: RemoveAxis,
+    {
+        extremum_axis_by(self, axis, |x, best| replaces(x, best, Ordering::Greater))
+    }
+
+    /// Return the minima and the maxima along `axis`, found in a single pass
+    /// over the elements.
+    ///
+    /// The extrema are the same ones as [`min_axis`](Self::min_axis) and
+    /// [`max_axis`](Self::max_axis) return, including for lanes with
+    /// elements that are not comparable to themselves (such as `NaN`). Use
+    /// [`min_max_axis_skipnan`](Self::min_max_axis_skipnan) to ignore `NaN`s
+    /// instead.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 5, 3],
+    ///                [4, 2, 6]]);
+    /// let (min, max) = a.min_max_axis(Axis(1));
+    /// assert_eq!(min, aview1(&[1, 2]));
+    /// assert_eq!(max, aview1(&[5, 6]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or if the length of the axis is
+    /// zero.
+    #[track_caller]
+    pub fn min_max_axis(&self, axis: Axis) -> (Array<A, D::Smaller>, Array<A, D::Smaller>)
+    where
+        A: Clone + PartialOrd,
+        D: RemoveAxis,
+    {
+        min_max_axis_by(self, axis, replaces)
+    }
+
+    /// Return the maximum absolute values along `axis`.
+    ///
+    /// See [`abs_max`](Self::abs_max) for the caveat about signed integers.
+    /// If a lane contains an element that is not comparable to itself (such
+    /// as `NaN`), the result for that lane is that element, like for
+    /// [`max_axis`](Self::max_axis), up to its sign.
+    ///
+    /// Return `None` if the length of the axis is zero.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, -5, 3],
+    ///                [-4, 2, 6]]);
+    /// assert_eq!(a.abs_max_axis(Axis(0)).unwrap(), aview1(&[4, 5, 6]));
+    /// assert_eq!(a.abs_max_axis(Axis(1)).unwrap(), aview1(&[5, 6]));
+    /// ```
+    #[track_caller]
+    pub fn abs_max_axis(&self, axis: Axis) -> Option<Array<A, D::Smaller>>
+    where
+        A: Clone + Signed + PartialOrd,
+        D: RemoveAxis,
+    {
+        if self.len_of(axis) == 0 {
+            return None;
+        }
+        let min = self.fold_axis(axis, A::zero(), |min, x| {
+            let x = neg_abs(x);
+            if replaces(&x, min, Ordering::Less) {
+                x
+            } else {
+                min.clone()
+            }
+        });
+        Some(min.mapv_into(|x| -x))
+    }
+
+    /// Return the median of all elements in the array.
+    ///
+    /// For an even number of elements, the mean of the two middle elements
+    /// is returned (for integer element types, this is rounded towards zero,
+    /// like integer division, and it does not overflow).
+    ///
+    /// The elements are copied into a scratch buffer and the median is found
+    /// by selection (`select_nth_unstable`) rather than by sorting, so this
+    /// takes O(n) time on average. The array does not need to be contiguous.
+    ///
+    /// If the array is empty, or if it contains an element that is not
+    /// comparable to itself (such as `NaN`), `None` is returned.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[3., 1., 2.],
+    ///                [6., 5., 4.]];
+    /// assert_eq!(a.median(), Some(3.5));
+    /// assert_eq!(a.row(1).median(), Some(5.));
+    /// assert_eq!(array![1., f64::NAN, 3.].median(), None);
+    /// ```
+    pub fn median(&self) -> Option<A>
+    where A: Clone + PartialOrd + FromPrimitive + Add<Output = A> + Sub<Output = A> + Div<Output = A>
+    {
+        let mut buf = Vec::with_capacity(self.len());
+        if !extend_comparable(&mut buf, self.iter()) {
+            return None;
+        }
+        median_of_slice(&mut buf)
+    }
+
+    /// Return the median along `axis`.
+    ///
+    /// Each lane is reduced like [`median`](Self::median), reusing a single
+    /// scratch buffer for all lanes.
+    ///
+    /// Return `None` if the length of the axis is zero, or if any lane
+    /// contains an element that is not comparable to itself (such as `NaN`).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 5., 3.],
+    ///                [4., 2., 6.]]);
+    /// assert_eq!(a.median_axis(Axis(0)).unwrap(), aview1(&[2.5, 3.5, 4.5]));
+    /// assert_eq!(a.median_axis(Axis(1)).unwrap(), aview1(&[3., 4.]));
+    /// ```
+    #[track_caller]
+    pub fn median_axis(&self, axis: Axis) -> Option<Array<A, D::Smaller>>
+    where
+        A: Clone + PartialOrd + FromPrimitive + Add<Output = A> + Sub<Output = A> + Div<Output = A>,
+        D: RemoveAxis,
+    {
+        let axis_len = self.len_of(axis);
+        if axis_len == 0 {
+            return None;
+        }
+        let mut buf = Vec::with_capacity(axis_len);
+        let mut comparable = true;
+        let res = Zip::from(self.lanes(axis)).map_collect(|lane| {
+            buf.clear();
+            if comparable && extend_comparable(&mut buf, lane) {
+                median_of_slice(&mut buf).expect("the lane is not empty")
+            } else {
+                // the result is discarded, any element will do
+                comparable = false;
+                lane[0].clone()
+            }
+        });
+        if comparable {
+            Some(res)
+        } else {
+            None
+        }
+    }
+}
+
+/// # Extrema of Float Arrays Ignoring NaN
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    /// Return a reference to the minimum of all elements in the array,
+    /// ignoring `NaN`s.
+    ///
+    /// If the array is empty or contains only `NaN`s, `None` is returned.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![1.0, f64::NAN, 3.0].min_skipnan(), Some(&1.0));
+    /// assert_eq!(array![f64::NAN].min_skipnan(), None);
+    /// ```
+    pub fn min_skipnan(&self) -> Option<&A>
+    {
+        let not_nan = |x: &&A| !x.is_nan();
+        if let Some(slc) = self.as_slice_memory_order() {
+            extremum_by(slc.iter().filter(not_nan), Ordering::Less)
+        } else {
+            extremum_by(self.iter().filter(not_nan), Ordering::Less)
+        }
+    }
+
+    /// Return a reference to the maximum of all elements in the array,
+    /// ignoring `NaN`s.
+    ///
+    /// If the array is empty or contains only `NaN`s, `None` is returned.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![1.0, f64::NAN, 3.0].max_skipnan(), Some(&3.0));
+    /// assert_eq!(array![f64::NAN].max_skipnan(), None);
+    /// ```
+    pub fn max_skipnan(&self) -> Option<&A>
+    {
+        let not_nan = |x: &&A| !x.is_nan();
+        if let Some(slc) = self.as_slice_memory_order() {
+            extremum_by(slc.iter().filter(not_nan), Ordering::Greater)
+        } else {
+            extremum_by(self.iter().filter(not_nan), Ordering::Greater)
+        }
+    }
+
+    /// Return references to the minimum and the maximum of all elements in
+    /// the array, ignoring `NaN`s, found in a single pass over the elements.
+    ///
+    /// If the array is empty or contains only `NaN`s, `None` is returned.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![f64::NAN, 3.0, 1.0];
+    /// assert_eq!(a.min_max_skipnan(), Some((&1.0, &3.0)));
+    /// assert_eq!(array![f64::NAN].min_max_skipnan(), None);
+    /// ```
+    pub fn min_max_skipnan(&self) -> Option<(&A, &A)>
+    {
+        let not_nan = |x: &&A| !x.is_nan();
+        if let Some(slc) = self.as_slice_memory_order() {
+            min_max_by(slc.iter().filter(not_nan))
+        } else {
+            min_max_by(self.iter().filter(not_nan))
+        }
+    }
+
+    /// Return the minimum along `axis`, ignoring `NaN`s.
+    ///
+    /// Lanes that contain only `NaN`s have a minimum of `NaN`.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., f64::NAN, 3.],
+    ///                [4., 2., f64::NAN]]);
+    /// assert_eq!(a.min_axis_skipnan(Axis(0)), aview1(&[1., 2., 3.]));
+    /// assert_eq!(a.min_axis_skipnan(Axis(1)), aview1(&[1., 2.]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or if the length of the axis is
+    /// zero.
+    #[track_caller]
+    pub fn min_axis_skipnan(&self, axis: Axis) -> Array<A, D::Smaller>
+    where D: RemoveAxis
+    {
+        extremum_axis_by(self, axis, |x, best| replaces_skipnan(x, best, Ordering::Less))
+    }
+
+    /// Return the maximum along `axis`, ignoring `NaN`s.
+    ///
+    /// Lanes that contain only `NaN`s have a maximum of `NaN`.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., f64::NAN, 3.],
+    ///                [4., 2., f64::NAN]]);
+    /// assert_eq!(a.max_axis_skipnan(Axis(0)), aview1(&[4., 2., 3.]));
+    /// assert_eq!(a.max_axis_skipnan(Axis(1)), aview1(&[3., 4.]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or if the length of the axis is
+    /// zero.
+    #[track_caller]
+    pub fn max_axis_skipnan(&self, axis: Axis) -> Array<A, D::Smaller>
+    where D: RemoveAxis
+    {
+        extremum_axis_by(self, axis, |x, best| replaces_skipnan(x, best, Ordering::Greater))
+    }
+
+    /// Return the minima and the maxima along `axis`, ignoring `NaN`s, found
+    /// in a single pass over the elements.
+    ///
+    /// Lanes that contain only `NaN`s have a minimum and a maximum of `NaN`.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., f64::NAN, 3.],
+    ///                [4., 2., f64::NAN]]);
+    /// let (min, max) = a.min_max_axis_skipnan(Axis(0));
+    /// assert_eq!(min, aview1(&[1., 2., 3.]));
+    /// assert_eq!(max, aview1(&[4., 2., 3.]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or if the length of the axis is
+    /// zero.
+    #[track_caller]
+    pub fn min_max_axis_skipnan(&self, axis: Axis) -> (Array<A, D::Smaller>, Array<A, D::Smaller>)
+    where D: RemoveAxis
+    {
+        min_max_axis_by(self, axis, replaces_skipnan)
+    }
+
+    /// Return the cumulative maximum of elements along `axis`, ignoring
+    /// `NaN`s.
+    ///
+    /// `NaN`s carry the last maximum forward; leading `NaN`s of a lane stay
+    /// `NaN`.
+    ///
+    /// ```
+    /// use ndarray::{arr1, Axis};
+    ///
+    /// let prices = arr1(&[f64::NAN, 3., 5., f64::NAN, 4., 6.]);
+    /// let peak = prices.cummax_skipnan(Axis(0));
+    /// assert!(peak[0].is_nan());
+    /// assert_eq!(peak.slice(ndarray::s![1..]), arr1(&[3., 5., 5., 5., 6.]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn cummax_skipnan(&self, axis: Axis) -> Array<A, D>
+    where D: RemoveAxis
+    {
+        cumulative_extremum_by(self, axis, |x, best| replaces_skipnan(x, best, Ordering::Greater))
+    }
+
+    /// Return the cumulative minimum of elements along `axis`, ignoring
+    /// `NaN`s.
+    ///
+    /// `NaN`s carry the last minimum forward; leading `NaN`s of a lane stay
+    /// `NaN`.
+    ///
+    /// ```
+    /// use ndarray::{arr1, Axis};
+    ///
+    /// let a = arr1(&[4., f64::NAN, 2., 3.]);
+    /// assert_eq!(a.cummin_skipnan(Axis(0)), arr1(&[4., 4., 2., 2.]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn cummin_skipnan(&self, axis: Axis) -> Array<A, D>
+    where D: RemoveAxis
+    {
+        cumulative_extremum_by(self, axis, |x, best| replaces_skipnan(x, best, Ordering::Less))
+    }
+
+    /// Return the index of the minimum of all elements in the array, ignoring
+    /// `NaN`s.
+    ///
+    /// If several elements are equal to the minimum, the index of the first
+    /// one in logical order is returned. If the array is empty or contains
+    /// only `NaN`s, `None` is returned.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[f64::NAN, 4.], [-2., f64::NAN]];
+    /// assert_eq!(a.nanargmin(), Some((1, 0)));
+    /// assert_eq!(array![f64::NAN].nanargmin(), None);
+    /// ```
+    pub fn nanargmin(&self) -> Option<D::Pattern>
+    {
+        arg_extremum_by(self.indexed_iter().filter(|(_, x)| !x.is_nan()), Ordering::Less)
+    }
+
+    /// Return the index of the maximum of all elements in the array, ignoring
+    /// `NaN`s.
+    ///
+    /// If several elements are equal to the maximum, the index of the first
+    /// one in logical order is returned. If the array is empty or contains
+    /// only `NaN`s, `None` is returned.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[f64::NAN, 4.], [-2., f64::NAN]];
+    /// assert_eq!(a.nanargmax(), Some((0, 1)));
+    /// assert_eq!(array![f64::NAN].nanargmax(), None);
+    /// ```
+    pub fn nanargmax(&self) -> Option<D::Pattern>
+    {
+        arg_extremum_by(self.indexed_iter().filter(|(_, x)| !x.is_nan()), Ordering::Greater)
+    }
+
+    /// Return the indices of the minima along `axis`, ignoring `NaN`s.
+    ///
+    /// If several elements of a lane are equal to the minimum, the lowest
+    /// index is returned.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[f64::NAN, 5., 3.],
+    ///                [4., 2., f64::NAN]]);
+    /// assert_eq!(a.nanargmin_axis(Axis(0)), aview1(&[1, 1, 0]));
+    /// assert_eq!(a.nanargmin_axis(Axis(1)), aview1(&[2, 1]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds, if the length of the axis is
+    /// zero, or if any lane contains only `NaN`s.
+    #[track_caller]
+    pub fn nanargmin_axis(&self, axis: Axis) -> Array<usize, D::Smaller>
+    where D: RemoveAxis
+    {
+        nanarg_extremum_axis_by(self, axis, Ordering::Less)
+    }
+
+    /// Return the indices of the maxima along `axis`, ignoring `NaN`s.
+    ///
+    /// If several elements of a lane are equal to the maximum, the lowest
+    /// index is returned.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[f64::NAN, 5., 3.],
+    ///                [4., 2., f64::NAN]]);
+    /// assert_eq!(a.nanargmax_axis(Axis(0)), aview1(&[1, 0, 0]));
+    /// assert_eq!(a.nanargmax_axis(Axis(1)), aview1(&[1, 0]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds, if the length of the axis is
+    /// zero, or if any lane contains only `NaN`s.
+    #[track_caller]
+    pub fn nanargmax_axis(&self, axis: Axis) -> Array<usize, D::Smaller>
+    where D: RemoveAxis
+    {
+        nanarg_extremum_axis_by(self, axis, Ordering::Greater)
+    }
+}
+
+/// Return, for each lane along `axis`, the index of its extremum ignoring
+/// `NaN`s.
+///
+/// **Panics** if a lane contains only `NaN`s.
+#[cfg(feature = "std")]
+#[track_caller]
+fn nanarg_extremum_axis_by<A, D>(a: &ArrayRef<A, D>, axis: Axis, better: Ordering) -> Array<usize, D::Smaller>
+where
+    A: Float,
+    D: RemoveAxis,
+{
+    assert!(a.len_of(axis) != 0, "cannot reduce along an axis of length zero: there is no identity element");
+    let args = Zip::from(a.lanes(axis)).map_collect(|lane| {
+        let not_nan = |(_, x): &(usize, &A)| !x.is_nan();
+        if let Some(slc) = lane.as_slice() {
+            arg_extremum_by(slc.iter().enumerate().filter(not_nan), better)
+        } else {
+            arg_extremum_by(lane.iter().enumerate().filter(not_nan), better)
+        }
+    });
+    if let Some((index, _)) = args.indexed_iter().find(|(_, arg)| arg.is_none()) {
+        panic!("lane {:?} along {:?} contains only NaN", index, axis);
+    }
+    args.mapv(Option::unwrap)
+}
+
+/// Return the first element of `iter` that compares as `better` to all
+/// elements before it (`Less` for the minimum, `Greater` for the maximum).
+///
+/// Return `None` if `iter` is empty or if any element is not comparable.
+fn extremum_by<'a, A, I>(iter: I, better: Ordering) -> Option<&'a A>
+where
+    A: 'a + PartialOrd,
+    I: IntoIterator<Item = &'a A>,
+{
+    let mut iter = iter.into_iter();
+    let first = iter.next()?;
+    // the first element is not compared to anything else if it is alone
+    first.partial_cmp(first)?;
+    let mut best = first;
+    for elt in iter {
+        if elt.partial_cmp(best)? == better {
+            best = elt;
+        }
+    }
+    Some(best)
+}
+
+/// Return the minimum and the maximum of `iter` in a single pass, where ties
+/// resolve to the first element.
+///
+/// Return `None` if `iter` is empty or if any element is not comparable.
+fn min_max_by<'a, A, I>(iter: I) -> Option<(&'a A, &'a A)>
+where
+    A: 'a + PartialOrd,
+    I: IntoIterator<Item = &'a A>,
+{
+    let mut iter = iter.into_iter();
+    let first = iter.next()?;
+    first.partial_cmp(first)?;
+    let (mut min, mut max) = (first, first);
+    for elt in iter {
+        if elt.partial_cmp(min)? == Ordering::Less {
+            min = elt;
+        } else if elt.partial_cmp(max)? == Ordering::Greater {
+            max = elt;
+        }
+    }
+    Some((min, max))
+}
+
+/// Return the index of the first element of `iter` that compares as `better`
+/// to all elements before it.
+///
+/// Return `None` if `iter` is empty or if any element is not comparable.
+fn arg_extremum_by<'a, A, P, I>(iter: I, better: Ordering) -> Option<P>
+where
+    A: 'a + PartialOrd,
+    I: IntoIterator<Item = (P, &'a A)>,
+{
+    let mut iter = iter.into_iter();
+    let (mut best_index, mut best) = iter.next()?;
+    best.partial_cmp(best)?;
+    for (index, elt) in iter {
+        if elt.partial_cmp(best)? == better {
+            best_index = index;
+            best = elt;
+        }
+    }
+    Some(best_index)
+}
+
+/// Return, for each lane along `axis`, the index of the first element that
+/// no later element replaces according to [`replaces`].
+#[track_caller]
+fn arg_extremum_axis_by<A, D>(a: &ArrayRef<A, D>, axis: Axis, better: Ordering) -> Array<usize, D::Smaller>
+where
+    A: PartialOrd,
+    D: RemoveAxis,
+{
+    assert!(a.len_of(axis) != 0, "cannot reduce along an axis of length zero: there is no identity element");
+    Zip::from(a.lanes(axis)).map_collect(|lane| {
+        if let Some(slc) = lane.as_slice() {
+            arg_extremum_of_lane(slc, better)
+        } else {
+            arg_extremum_of_lane(lane, better)
+        }
+    })
+}
+
+/// Return the index of the extremum of a nonempty lane, see
+/// [`arg_extremum_axis_by`].
+fn arg_extremum_of_lane<'a, A, I>(lane: I, better: Ordering) -> usize
+where
+    A: 'a + PartialOrd,
+    I: IntoIterator<Item = &'a A>,
+{
+    let mut iter = lane.into_iter();
+    let mut best = iter.next().unwrap();
+    let mut best_index = 0;
+    for (i, x) in iter.enumerate() {
+        if replaces(x, best, better) {
+            best_index = i + 1;
+            best = x;
+        }
+    }
+    best_index
+}
+
+/// Reduce each lane along `axis` to its first element `best` for which no
+/// later element `x` satisfies `replaces(x, best)`.
+#[track_caller]
+fn extremum_axis_by<A, D, F>(a: &ArrayRef<A, D>, axis: Axis, replaces: F) -> Array<A, D::Smaller>
+where
+    A: Clone,
+    D: RemoveAxis,
+    F: Fn(&A, &A) -> bool,
+{
+    assert!(a.len_of(axis) != 0, "cannot reduce along an axis of length zero: there is no identity element");
+    let min_stride_axis = a.dim.min_stride_axis(&a.strides);
+    if axis == min_stride_axis {
+        Zip::from(a.lanes(axis)).map_collect(|lane| {
+            let mut iter = lane.into_iter();
+            let first = iter.next().unwrap();
+            iter.fold(first, |best, x| if replaces(x, best) { x } else { best })
+                .clone()
+        })
+    } else {
+        let mut res = a.index_axis(axis, 0).to_owned();
+        for subview in a.axis_iter(axis).skip(1) {
+            res.zip_mut_with(&subview, |best, x| {
+                if replaces(x, best) {
+                    *best = x.clone();
+                }
+            });
+        }
+        res
+    }
+}
+
+/// Reduce each lane along `axis` to its minimum and maximum in a single
+/// pass, where `replaces(x, best, better)` decides whether `x` is the new
+/// extremum.
+#[track_caller]
+fn min_max_axis_by<A, D, F>(a: &ArrayRef<A, D>, axis: Axis, replaces: F) -> (Array<A, D::Smaller>, Array<A, D::Smaller>)
+where
+    A: Clone,
+    D: RemoveAxis,
+    F: Fn(&A, &A, Ordering) -> bool,
+{
+    assert!(a.len_of(axis) != 0, "cannot reduce along an axis of length zero: there is no identity element");
+    let mut min = a.index_axis(axis, 0).to_owned();
+    let mut max = min.clone();
+    let min_stride_axis = a.dim.min_stride_axis(&a.strides);
+    if axis == min_stride_axis {
+        Zip::from(&mut min)
+            .and(&mut max)
+            .and(a.lanes(axis))
+            .for_each(|min, max, lane| {
+                let mut iter = lane.into_iter();
+                let first = iter.next().unwrap();
+                let (lane_min, lane_max) = iter.fold((first, first), |(min, max), x| {
+                    let min = if replaces(x, min, Ordering::Less) { x } else { min };
+                    let max = if replaces(x, max, Ordering::Greater) { x } else { max };
+                    (min, max)
+                });
+                *min = lane_min.clone();
+                *max = lane_max.clone();
+            });
+    } else {
+        for subview in a.axis_iter(axis).skip(1) {
+            Zip::from(&mut min)
+                .and(&mut max)
+                .and(&subview)
+                .for_each(|min, max, x| {
+                    if replaces(x, min, Ordering::Less) {
+                        *min = x.clone();
+                    }
+                    if replaces(x, max, Ordering::Greater) {
+                        *max = x.clone();
+                    }
+                });
+        }
+    }
+    (min, max)
+}
+
+/// Replace each element along `axis` by the extremum of the lane so far,
+/// where `replaces(x, best)` decides whether `x` is the new extremum.
+#[track_caller]
+fn cumulative_extremum_by<A, D, F>(a: &ArrayRef<A, D>, axis: Axis, replaces: F) -> Array<A, D>
+where
+    A: Clone,
+    D: RemoveAxis,
+    F: Fn(&A, &A) -> bool,
+{
+    if axis.0 >= a.ndim() {
+        panic!("axis is out of bounds for array of dimension");
+    }
+
+    let mut result = a.to_owned();
+    result.accumulate_axis_inplace(axis, |prev, curr| {
+        if !replaces(curr, prev) {
+            *curr = prev.clone();
+        }
+    });
+    result
+}
+
+/// Return `true` if `x` compares as `better` to `best`, or if `x` is the
+/// first element that is not comparable to itself.
+fn replaces<A: PartialOrd>(x: &A, best: &A, better: Ordering) -> bool
+{
+    match x.partial_cmp(best) {
+        Some(ord) => ord == better,
+        None => best.partial_cmp(best).is_some(),
+    }
+}
+
+/// Return `-|x|`, which unlike `|x|` never overflows for signed integers.
+fn neg_abs<A: Clone + Signed + PartialOrd>(x: &A) -> A
+{
+    if *x > A::zero() {
+        -x.clone()
+    } else {
+        x.clone()
+    }
+}
+
+/// Return `true` if `x` is not `NaN` and compares as `better` to `best`, or
+/// if `best` is `NaN`.
+#[cfg(feature = "std")]
+fn replaces_skipnan<A: Float>(x: &A, best: &A, better: Ordering) -> bool
+{
+    !x.is_nan() && (best.is_nan() || x.partial_cmp(best) == Some(better))
+}
+
+/// # Mode
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Clone + Eq + Hash,
+    D: Dimension,
+{
+    /// Return the most frequent element of the array.
+    ///
+    /// If several elements are equally frequent, the smallest one wins, so
+    /// the result does not depend on hashing or on the order of the
+    /// elements. For element types that are not `Ord`, use
+    /// [`mode_first`](Self::mode_first).
+    ///
+    /// If the array is empty, `None` is returned.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let labels = array![[3u8, 1, 2],
+    ///                     [1, 3, 0]];
+    /// // 3 and 1 both occur twice, 1 is smaller
+    /// assert_eq!(labels.mode(), Some(1));
+    /// ```
+    pub fn mode(&self) -> Option<A>
+    where A: Ord
+    {
+        let mut counts = HashMap::new();
+        mode_of(&mut counts, self.iter(), |(a, _), (b, _)| b.cmp(a))
+    }
+
+    /// Return the most frequent element along `axis`.
+    ///
+    /// Each lane is reduced like [`mode`](Self::mode), with ties going to
+    /// the smallest element.
+    ///
+    /// Return `None` if the length of the axis is zero.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let labels = arr2(&[[3u8, 1, 2],
+    ///                     [1, 1, 2],
+    ///                     [3, 0, 0]]);
+    /// assert_eq!(labels.mode_axis(Axis(0)).unwrap(), aview1(&[3, 1, 2]));
+    /// assert_eq!(labels.mode_axis(Axis(1)).unwrap(), aview1(&[1, 1, 0]));
+    /// ```
+    #[track_caller]
+    pub fn mode_axis(&self, axis: Axis) -> Option<Array<A, D::Smaller>>
+    where
+        A: Ord,
+        D: RemoveAxis,
+    {
+        mode_axis_of(self, axis, |(a, _), (b, _)| b.cmp(a))
+    }
+
+    /// Return the most frequent element of the array, with ties going to the
+    /// element that occurs first in logical order.
+    ///
+    /// This is [`mode`](Self::mode) for element types that are not `Ord`.
+    ///
+    /// If the array is empty, `None` is returned.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let labels = array![["b", "a", "c"],
+    ///                     ["a", "b", "d"]];
+    /// // "b" and "a" both occur twice, "b" occurs first
+    /// assert_eq!(labels.mode_first(), Some("b"));
+    /// ```
+    pub fn mode_first(&self) -> Option<A>
+    {
+        let mut counts = HashMap::new();
+        mode_of(&mut counts, self.iter(), |(_, i), (_, j)| j.cmp(&i))
+    }
+
+    /// Return the most frequent element along `axis`, with ties going to the
+    /// element that occurs first in the lane.
+    ///
+    /// Each lane is reduced like [`mode_first`](Self::mode_first).
+    ///
+    /// Return `None` if the length of the axis is zero.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn mode_first_axis(&self, axis: Axis) -> Option<Array<A, D::Smaller>>
+    where D: RemoveAxis
+    {
+        mode_axis_of(self, axis, |(_, i), (_, j)| j.cmp(&i))
+    }
+}
+
+/// Reduce each lane along `axis` like [`mode_of`].
+#[cfg(feature = "std")]
+#[track_caller]
+fn mode_axis_of<A, D, F>(a: &ArrayRef<A, D>, axis: Axis, tie: F) -> Option<Array<A, D::Smaller>>
+where
+    A: Clone + Eq + Hash,
+    D: RemoveAxis,
+    F: Fn((&A, usize), (&A, usize)) -> Ordering,
+{
+    if a.len_of(axis) == 0 {
+        return None;
+    }
+    let mut counts = HashMap::new();
+    Some(Zip::from(a.lanes(axis)).map_collect(|lane| {
+        counts.clear();
+        mode_of(&mut counts, lane, &tie).expect("the lane is not empty")
+    }))
+}
+
+/// Return the most frequent element of `iter`, preferring the one that
+/// `tie` orders greater in case of ties.
+///
+/// `tie` is given each element with the position of its first occurrence.
+/// `counts` is scratch space mapping each element to its count and the
+/// position of its first occurrence; it must be empty.
+#[cfg(feature = "std")]
+fn mode_of<'a, A, I, F>(counts: &mut HashMap<&'a A, (usize, usize)>, iter: I, tie: F) -> Option<A>
+where
+    A: 'a + Clone + Eq + Hash,
+    I: IntoIterator<Item = &'a A>,
+    F: Fn((&A, usize), (&A, usize)) -> Ordering,
+{
+    for (i, elt) in iter.into_iter().enumerate() {
+        counts.entry(elt).or_insert((0, i)).0 += 1;
+    }
+    counts
+        .iter()
+        .max_by(|(&a, &(count_a, first_a)), (&b, &(count_b, first_b))| {
+            count_a
+                .cmp(&count_b)
+                .then_with(|| tie((a, first_a), (b, first_b)))
+        })
+        .map(|(&elt, _)| elt.clone())
+}
+
+/// # Quantiles of Float Arrays
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    /// Return the `q`-th quantile of all elements in the array.
+    ///
+    /// `q` is a fraction between zero and one, and `interp` selects how to
+    /// compute quantiles that fall between two elements; see
+    /// [`Interpolate`]. The quantile is found by partial selection on a copy
+    /// of the elements, not by sorting.
+    ///
+    /// If the array is empty, `None` is returned. If it contains `NaN`, the
+    /// result is `NaN`.
+    ///
+    /// **Panics** if `q` is not between `0.` and `1.` (inclusive).
+    ///
+    /// ```
+    /// use ndarray::{array, Interpolate};
+    ///
+    /// let a = array![[4., 1., 3.],
+    ///                [2., 6., 5.]];
+    /// assert_eq!(a.quantile(0.5, Interpolate::Linear), a.median());
+    /// assert_eq!(a.quantile(0.25, Interpolate::Linear), Some(2.25));
+    /// assert_eq!(a.quantile(0.25, Interpolate::Lower), Some(2.));
+    /// assert_eq!(a.quantile(0.25, Interpolate::Higher), Some(3.));
+    /// assert_eq!(a.quantile(0.25, Interpolate::Nearest), Some(2.));
+    /// assert_eq!(a.quantile(0.25, Interpolate::Midpoint), Some(2.5));
+    /// ```
+    #[track_caller]
+    pub fn quantile(&self, q: A, interp: Interpolate) -> Option<A>
+    {
+        check_quantile(q);
+        if self.is_empty() {
+            return None;
+        }
+        let mut buf = Vec::with_capacity(self.len());
+        if !extend_comparable(&mut buf, self.iter()) {
+            return Some(A::nan());
+        }
+        Some(quantile_of_slice(&mut buf, q, interp))
+    }
+
+    /// Return the `q`-th quantile along `axis`.
+    ///
+    /// Each lane is reduced like [`quantile`](Self::quantile), reusing a
+    /// single scratch buffer for all lanes. Lanes that contain `NaN` have a
+    /// quantile of `NaN`.
+    ///
+    /// Return `None` if the length of the axis is zero.
+    ///
+    /// **Panics** if `q` is not between `0.` and `1.` (inclusive), or if
+    /// `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis, Interpolate};
+    ///
+    /// let a = arr2(&[[1., 5., 3.],
+    ///                [4., 2., 6.]]);
+    /// let q = a.quantile_axis(Axis(1), 0.75, Interpolate::Linear).unwrap();
+    /// assert_eq!(q, aview1(&[4., 5.]));
+    /// ```
+    #[track_caller]
+    pub fn quantile_axis(&self, axis: Axis, q: A, interp: Interpolate) -> Option<Array<A, D::Smaller>>
+    where D: RemoveAxis
+    {
+        check_quantile(q);
+        let axis_len = self.len_of(axis);
+        if axis_len == 0 {
+            return None;
+        }
+        let mut buf = Vec::with_capacity(axis_len);
+        Some(Zip::from(self.lanes(axis)).map_collect(|lane| {
+            buf.clear();
+            if extend_comparable(&mut buf, lane) {
+                quantile_of_slice(&mut buf, q, interp)
+            } else {
+                A::nan()
+            }
+        }))
+    }
+
+    /// Return several quantiles along `axis` at once.
+    ///
+    /// Each lane is copied and sorted once, and all quantiles in `qs` are
+    /// then read off the sorted lane. The result is identical to calling
+    /// [`quantile_axis`](Self::quantile_axis) for each `q` and stacking the
+    /// results along a new leading axis, so its shape is `(qs.len(),)`
+    /// followed by the shape of `self` with `axis` removed.
+    ///
+    /// Lanes that contain `NaN` have all quantiles equal to `NaN`.
+    ///
+    /// Return `None` if the length of the axis is zero.
+    ///
+    /// **Panics** if any `q` is not between `0.` and `1.` (inclusive), or if
+    /// `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis, Interpolate};
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.],
+    ///                [5., 6.],
+    ///                [7., 8.],
+    ///                [9., 10.]]);
+    /// let q = a.quantiles_axis(Axis(0), &[0.05, 0.5, 0.95], Interpolate::Linear).unwrap();
+    /// assert_eq!(q.shape(), &[3, 2]);
+    /// assert_eq!(q.row(1), a.quantile_axis(Axis(0), 0.5, Interpolate::Linear).unwrap());
+    /// ```
+    #[track_caller]
+    pub fn quantiles_axis(&self, axis: Axis, qs: &[A], interp: Interpolate) -> Option<Array<A, D>>
+    where D: RemoveAxis
+    {
+        for &q in qs {
+            check_quantile(q);
+        }
+        let axis_len = self.len_of(axis);
+        if axis_len == 0 {
+            return None;
+        }
+        let mut dim = self.raw_dim();
+        {
+            let reduced = self.raw_dim().remove_axis(axis);
+            let dim = dim.slice_mut();
+            dim[0] = qs.len();
+            dim[1..].copy_from_slice(reduced.slice());
+        }
+        let mut res = Array::zeros(dim);
+        let mut buf = Vec::with_capacity(axis_len);
+        Zip::from(self.lanes(axis))
+            .and(res.lanes_mut(Axis(0)))
+            .for_each(|lane, mut out| {
+                buf.clear();
+                if extend_comparable(&mut buf, lane) {
+                    buf.sort_unstable_by(cmp_float);
+                    Zip::from(&mut out)
+                        .and(qs)
+                        .for_each(|out, &q| *out = quantile_of_sorted(&buf, q, interp));
+                } else {
+                    out.fill(A::nan());
+                }
+            });
+        Some(res)
+    }
+
+    /// Return the `q`-th quantile of all elements in the array, ignoring
+    /// `NaN`s.
+    ///
+    /// This is [`quantile`](Self::quantile) computed over the non-`NaN`
+    /// elements only. If the array is empty or contains only `NaN`s, `None`
+    /// is returned.
+    ///
+    /// **Panics** if `q` is not between `0.` and `1.` (inclusive).
+    ///
+    /// ```
+    /// use ndarray::{array, Interpolate};
+    ///
+    /// let a = array![4., f64::NAN, 1., 3., f64::NAN, 2.];
+    /// assert!(a.quantile(0.5, Interpolate::Linear).unwrap().is_nan());
+    /// assert_eq!(a.nanquantile(0.5, Interpolate::Linear), Some(2.5));
+    /// assert_eq!(a.nanquantile(1., Interpolate::Linear), Some(4.));
+    /// ```
+    #[track_caller]
+    pub fn nanquantile(&self, q: A, interp: Interpolate) -> Option<A>
+    {
+        check_quantile(q);
+        let mut buf = Vec::with_capacity(self.len());
+        extend_not_nan(&mut buf, self.iter());
+        if buf.is_empty() {
+            None
+        } else {
+            Some(quantile_of_slice(&mut buf, q, interp))
+        }
+    }
+
+    /// Return the `q`-th quantile along `axis`, ignoring `NaN`s.
+    ///
+    /// The non-`NaN` elements of each lane are compacted into a single
+    /// scratch buffer that is reused for all lanes. Lanes that contain only
+    /// `NaN`s have a quantile of `NaN`.
+    ///
+    /// Return `None` if the length of the axis is zero.
+    ///
+    /// **Panics** if `q` is not between `0.` and `1.` (inclusive), or if
+    /// `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis, Interpolate};
+    ///
+    /// let a = arr2(&[[1., f64::NAN, 3.],
+    ///                [4., 2., f64::NAN]]);
+    /// let q = a.nanquantile_axis(Axis(1), 0.5, Interpolate::Midpoint).unwrap();
+    /// assert_eq!(q, aview1(&[2., 3.]));
+    /// ```
+    #[track_caller]
+    pub fn nanquantile_axis(&self, axis: Axis, q: A, interp: Interpolate) -> Option<Array<A, D::Smaller>>
+    where D: RemoveAxis
+    {
+        check_quantile(q);
+        let axis_len = self.len_of(axis);
+        if axis_len == 0 {
+            return None;
+        }
+        let mut buf = Vec::with_capacity(axis_len);
+        Some(Zip::from(self.lanes(axis)).map_collect(|lane| {
+            buf.clear();
+            extend_not_nan(&mut buf, lane);
+            if buf.is_empty() {
+                A::nan()
+            } else {
+                quantile_of_slice(&mut buf, q, interp)
+            }
+        }))
+    }
+}
+
+/// Append the elements of `iter` that are not `NaN` to `buf`.
+#[cfg(feature = "std")]
+fn extend_not_nan<'a, A, I>(buf: &mut Vec<A>, iter: I)
+where
+    A: 'a + Float,
+    I: IntoIterator<Item = &'a A>,
+{
+    buf.extend(iter.into_iter().filter(|x| !x.is_nan()));
+}
+
+#[cfg(feature = "std")]
+#[track_caller]
+fn check_quantile<A: Float>(q: A)
+{
+    assert!(
+        q >= A::zero() && q <= A::one(),
+        "quantile `q` must be between 0 and 1 (inclusive), but it is {:?}",
+        q.to_f64()
+    );
+}
+
+/// Return the `q`-th quantile of `xs` (which is reordered).
+///
+/// `xs` must be non-empty and must not contain `NaN`.
+#[cfg(feature = "std")]
+fn quantile_of_slice<A: Float>(xs: &mut [A], q: A, interp: Interpolate) -> A
+{
+    let (i, frac) = quantile_index(xs.len(), q);
+    let (_, &mut lower, upper) = xs.select_nth_unstable_by(i, cmp_float);
+    // the element at `j = i + 1` is the smallest element of the right partition
+    interpolate(i, frac, lower, || upper.iter().cloned().min_by(cmp_float).unwrap_or(lower), interp)
+}
+
+/// Return the `q`-th quantile of the sorted slice `xs`.
+///
+/// `xs` must be non-empty and must not contain `NaN`.
+#[cfg(feature = "std")]
+fn quantile_of_sorted<A: Float>(xs: &[A], q: A, interp: Interpolate) -> A
+{
+    let (i, frac) = quantile_index(xs.len(), q);
+    interpolate(i, frac, xs[i], || xs.get(i + 1).cloned().unwrap_or(xs[i]), interp)
+}
+
+/// Return the index `i` of the element just below the `q`-th quantile of
+/// `len` elements, and the fractional distance of the quantile from it.
+#[cfg(feature = "std")]
+fn quantile_index<A: Float>(len: usize, q: A) -> (usize, A)
+{
+    let h = q * A::from(len - 1).expect("Converting length to `A` must not fail.");
+    let i = h.floor().to_usize().expect("quantile index is in bounds");
+    (i, h - h.floor())
+}
+
+/// Interpolate between the `i`-th element `lower` and the next element,
+/// which is computed by `higher` only if needed.
+#[cfg(feature = "std")]
+fn interpolate<A, F>(i: usize, frac: A, lower: A, higher: F, interp: Interpolate) -> A
+where
+    A: Float,
+    F: FnOnce() -> A,
+{
+    let half = A::from(0.5).expect("Converting 0.5 to `A` must not fail.");
+    if frac == A::zero() {
+        return lower;
+    }
+    match interp {
+        Interpolate::Linear => {
+            let higher = higher();
+            lower + frac * (higher - lower)
+        }
+        Interpolate::Lower => lower,
+        Interpolate::Higher => higher(),
+        Interpolate::Nearest =>
+            if frac < half || (frac == half && i % 2 == 0) {
+                lower
+            } else {
+                higher()
+            },
+        Interpolate::Midpoint => (lower + higher()) * half,
+    }
+}
+
+/// Total order for floats that are not `NaN`, with `-0.0` before `0.0`.
+///
+/// Elements that compare equal are bitwise identical, so selection and
+/// sorting find exactly the same elements.
+#[cfg(feature = "std")]
+fn cmp_float<A: Float>(a: &A, b: &A) -> Ordering
+{
+    cmp_comparable(a, b).then_with(|| b.is_sign_negative().cmp(&a.is_sign_negative()))
+}
+
+/// Total order for elements that were checked to be comparable.
+fn cmp_comparable<A: PartialOrd>(a: &A, b: &A) -> Ordering
+{
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
+
+/// Append clones of the elements of `iter` to `buf`.
+///
+/// Return `false` (leaving `buf` partially filled) if an element is not
+/// comparable to itself, such as `NaN`.
+fn extend_comparable<'a, A, I>(buf: &mut Vec<A>, iter: I) -> bool
+where
+    A: 'a + Clone + PartialOrd,
+    I: IntoIterator<Item = &'a A>,
+{
+    for elt in iter {
+        if elt.partial_cmp(elt).is_none() {
+            return false;
+        }
+        buf.push(elt.clone());
+    }
+    true
+}
+
+/// Return the median of `xs` (which is reordered), or `None` if it is empty.
+///
+/// All elements of `xs` must be comparable.
+/// Return the mean of `lower` and `upper`, where `lower <= upper`, rounded
+/// towards zero for integers.
+///
+/// `lower + upper` only overflows if both have the same sign, and
+/// `upper - lower` only if they have different signs, so each case uses the
+/// form that can not overflow.
+fn midpoint<A>(lower: A, upper: A) -> A
+where A: Clone + PartialOrd + FromPrimitive + Add<Output = A> + Sub<Output = A> + Div<Output = A>
+{
+    if lower == upper {
+        // also keeps two equal infinities, whose difference is NaN
+        return lower;
+    }
+    let zero = A::from_u8(0).expect("Converting 0 to `A` must not fail.");
+    let two = A::from_u8(2).expect("Converting 2 to `A` must not fail.");
+    if lower >= zero {
+        lower.clone() + (upper - lower) / two
+    } else if upper <= zero {
+        upper.clone() - (upper - lower) / two
+    } else {
+        (lower + upper) / two
+    }
+}
+
+fn median_of_slice<A>(xs: &mut [A]) -> Option<A>
+where A: Clone + PartialOrd + FromPrimitive + Add<Output = A> + Sub<Output = A> + Div<Output = A>
+{
+    if xs.is_empty() {
+        return None;
+    }
+    let len = xs.len();
+    let (lower, upper, _) = xs.select_nth_unstable_by(len / 2, cmp_comparable);
+    let upper = upper.clone();
+    if len % 2 == 1 {
+        Some(upper)
+    } else {
+        // the lower middle element is the largest element of the left partition
+        let lower = lower
+            .iter()
+            .max_by(|a, b| cmp_comparable(*a, *b))
+            .expect("the left partition is not empty for an even length")
+            .clone();
+        Some(midpoint(lower, upper))
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Zero;
+use std::ops::{Add, Mul};
+
+use crate::imp_prelude::*;
+
+/// Return the polynomial with coefficients `coeffs` evaluated at each
+/// element of `x`.
+///
+/// Like NumPy's `polyval`, the coefficients are in order of decreasing
+/// degree: element `i` of `coeffs` multiplies `x` to the power of
+/// `coeffs.len() - 1 - i`, and the last element is the constant term. The
+/// polynomial is evaluated with Horner's method, one element at a time, so
+/// that no powers of `x` are computed separately. If `coeffs` is empty, the
+/// result is zero.
+///
+/// ```
+/// use ndarray::{array, polyval};
+///
+/// // 2 x² - 3 x + 1
+/// let coeffs = array![2., -3., 1.];
+/// let x = array![[0., 1.],
+///                [2., -1.]];
+/// assert_eq!(polyval(&coeffs, &x), array![[1., 0.],
+///                                         [3., 6.]]);
+/// ```
+pub fn polyval<A, D>(coeffs: &ArrayRef<A, Ix1>, x: &ArrayRef<A, D>) -> Array<A, D>
+where
+    A: Clone + Zero + Add<Output = A> + Mul<Output = A>,
+    D: Dimension,
+{
+    let coeffs = coeffs.as_standard_layout();
+    let coeffs = coeffs.as_slice().unwrap();
+    x.map(|x| horner(coeffs, x))
+}
+
+/// Replace each element of `x` with the polynomial with coefficients
+/// `coeffs` evaluated at it.
+///
+/// This is the in place counterpart of [`polyval`], with the same order of
+/// the coefficients.
+///
+/// ```
+/// use ndarray::{array, polyval_inplace};
+///
+/// // a linear calibration curve, 0.5 x + 4
+/// let calibration = array![0.5, 4.];
+/// let mut readings = array![0., 10., 20.];
+/// polyval_inplace(&calibration, &mut readings);
+/// assert_eq!(readings, array![4., 9., 14.]);
+/// ```
+pub fn polyval_inplace<A, D>(coeffs: &ArrayRef<A, Ix1>, x: &mut ArrayRef<A, D>)
+where
+    A: Clone + Zero + Add<Output = A> + Mul<Output = A>,
+    D: Dimension,
+{
+    let coeffs = coeffs.as_standard_layout();
+    let coeffs = coeffs.as_slice().unwrap();
+    x.map_inplace(|x| *x = horner(coeffs, x));
+}
+
+/// Evaluate the polynomial with coefficients `coeffs`, highest degree first,
+/// at `x`.
+fn horner<A>(coeffs: &[A], x: &A) -> A
+where A: Clone + Zero + Add<Output = A> + Mul<Output = A>
+{
+    match coeffs.split_first() {
+        Some((first, rest)) => rest
+            .iter()
+            .fold(first.clone(), |acc, c| acc * x.clone() + c.clone()),
+        None => A::zero(),
+    }
+}
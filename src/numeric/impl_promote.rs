@@ -0,0 +1,263 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::dimension::DimMax;
+use crate::error::ShapeError;
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// The common type of a binary operation between elements of type `Self`
+/// and elements of type `B`, for [`zip_promote`](ArrayRef::zip_promote).
+///
+/// This is implemented for all pairs of the primitive integer and float
+/// types, with the promotion rules of NumPy: the result is the smallest type
+/// that can represent all values of both types, except that a 64-bit integer
+/// and a float, and `u64` and a signed integer, are promoted to `f64`, which
+/// rounds integers above 2<sup>53</sup> in magnitude.
+///
+/// | `Self` and `B`                            | `Output`                          |
+/// |-------------------------------------------|-----------------------------------|
+/// | the same type                             | that type                         |
+/// | two signed or two unsigned integers       | the larger type                   |
+/// | `u8`, `u16` or `u32` and a signed integer | the smallest signed type for both |
+/// | `u64` and a signed integer                | `f64`                             |
+/// | an integer of at most 16 bits and `f32`   | `f32`                             |
+/// | any other integer and a float             | `f64`                             |
+/// | `f32` and `f64`                           | `f64`                             |
+pub trait Promote<B>
+{
+    /// The common type.
+    type Output;
+
+    /// Convert `self` to the common type.
+    fn promote(self) -> Self::Output;
+
+    /// Convert `rhs` to the common type.
+    fn promote_rhs(rhs: B) -> Self::Output;
+}
+
+macro_rules! promote_same {
+    ($($t:ty)*) => {
+        $(impl Promote<$t> for $t
+        {
+            type Output = $t;
+
+            #[inline]
+            fn promote(self) -> $t
+            {
+                self
+            }
+
+            #[inline]
+            fn promote_rhs(rhs: $t) -> $t
+            {
+                rhs
+            }
+        })*
+    };
+}
+
+macro_rules! promote_pairs {
+    ($($a:ty, $b:ty => $c:ty;)*) => {
+        $(impl Promote<$b> for $a
+        {
+            type Output = $c;
+
+            #[inline]
+            fn promote(self) -> $c
+            {
+                self as $c
+            }
+
+            #[inline]
+            fn promote_rhs(rhs: $b) -> $c
+            {
+                rhs as $c
+            }
+        }
+
+        impl Promote<$a> for $b
+        {
+            type Output = $c;
+
+            #[inline]
+            fn promote(self) -> $c
+            {
+                self as $c
+            }
+
+            #[inline]
+            fn promote_rhs(rhs: $a) -> $c
+            {
+                rhs as $c
+            }
+        })*
+    };
+}
+
+promote_same!(u8 u16 u32 u64 i8 i16 i32 i64 f32 f64);
+
+promote_pairs! {
+    u8, u16 => u16;
+    u8, u32 => u32;
+    u8, u64 => u64;
+    u16, u32 => u32;
+    u16, u64 => u64;
+    u32, u64 => u64;
+
+    i8, i16 => i16;
+    i8, i32 => i32;
+    i8, i64 => i64;
+    i16, i32 => i32;
+    i16, i64 => i64;
+    i32, i64 => i64;
+
+    u8, i8 => i16;
+    u8, i16 => i16;
+    u8, i32 => i32;
+    u8, i64 => i64;
+    u16, i8 => i32;
+    u16, i16 => i32;
+    u16, i32 => i32;
+    u16, i64 => i64;
+    u32, i8 => i64;
+    u32, i16 => i64;
+    u32, i32 => i64;
+    u32, i64 => i64;
+    u64, i8 => f64;
+    u64, i16 => f64;
+    u64, i32 => f64;
+    u64, i64 => f64;
+
+    u8, f32 => f32;
+    u16, f32 => f32;
+    i8, f32 => f32;
+    i16, f32 => f32;
+    u32, f32 => f64;
+    u64, f32 => f64;
+    i32, f32 => f64;
+    i64, f32 => f64;
+    u8, f64 => f64;
+    u16, f64 => f64;
+    u32, f64 => f64;
+    u64, f64 => f64;
+    i8, f64 => f64;
+    i16, f64 => f64;
+    i32, f64 => f64;
+    i64, f64 => f64;
+    f32, f64 => f64;
+}
+
+macro_rules! promote_ops {
+    ($($(#[$meta:meta])* fn $id:ident($trt:ident::$mth:ident))+) => {
+        $($(#[$meta])*
+        pub fn $id<B, E>(
+            &self, rhs: &ArrayRef<B, E>,
+        ) -> Result<Array<<A as Promote<B>>::Output, <D as DimMax<E>>::Output>, ShapeError>
+        where
+            A: Promote<B>,
+            <A as Promote<B>>::Output: $trt<Output = <A as Promote<B>>::Output>,
+            B: Clone,
+            E: Dimension,
+            D: DimMax<E>,
+        {
+            self.zip_promote(rhs, $trt::$mth)
+        })+
+    };
+}
+
+/// # Mixed Element Types
+///
+/// These methods combine arrays of different element types, such as `f32`
+/// and `f64`, elementwise after broadcasting both to their common shape like
+/// the arithmetic operators do. The elements are converted to their common
+/// type, given by [`Promote`], one pair at a time in the loop, so unlike
+/// with a [`cast`](Self::cast) of one of the arrays first, no converted copy
+/// of either array is allocated.
+///
+/// ```
+/// use ndarray::array;
+///
+/// let a = array![[1u8, 2],
+///                [3, 250]];
+/// let b = array![0.5f32, 0.25];
+/// assert_eq!(a.add_promote(&b), Ok(array![[1.5, 2.25],
+///                                         [3.5, 250.25]]));
+/// ```
+impl<A, D> ArrayRef<A, D>
+where
+    A: Clone,
+    D: Dimension,
+{
+    /// Return the result of `f` for each element and the corresponding
+    /// element of `rhs`, both converted to their common type, after
+    /// broadcasting both arrays to their common shape.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not
+    /// be broadcast together.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1.5f32, -2.];
+    /// let b = array![[2i32], [-3]];
+    /// let c = a.zip_promote(&b, |x, y| x.max(y)).unwrap();
+    /// assert_eq!(c, array![[2f64, 2.],
+    ///                      [1.5, -2.]]);
+    /// ```
+    pub fn zip_promote<B, E, F, C>(
+        &self, rhs: &ArrayRef<B, E>, mut f: F,
+    ) -> Result<Array<C, <D as DimMax<E>>::Output>, ShapeError>
+    where
+        A: Promote<B>,
+        B: Clone,
+        E: Dimension,
+        D: DimMax<E>,
+        F: FnMut(<A as Promote<B>>::Output, <A as Promote<B>>::Output) -> C,
+    {
+        let (lhs, rhs) = self.broadcast_with(rhs)?;
+        Ok(Zip::from(&lhs)
+            .and(&rhs)
+            .map_collect(|a, b| f(a.clone().promote(), A::promote_rhs(b.clone()))))
+    }
+
+    promote_ops! {
+        /// Return the sum of each element and the corresponding element of
+        /// `rhs`, in their common type, after broadcasting both arrays to
+        /// their common shape.
+        ///
+        /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can
+        /// not be broadcast together.
+        fn add_promote(Add::add)
+        /// Return the difference of each element and the corresponding
+        /// element of `rhs`, in their common type, after broadcasting both
+        /// arrays to their common shape.
+        ///
+        /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can
+        /// not be broadcast together.
+        fn sub_promote(Sub::sub)
+        /// Return the product of each element and the corresponding element
+        /// of `rhs`, in their common type, after broadcasting both arrays to
+        /// their common shape.
+        ///
+        /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can
+        /// not be broadcast together.
+        fn mul_promote(Mul::mul)
+        /// Return the quotient of each element and the corresponding element
+        /// of `rhs`, in their common type, after broadcasting both arrays to
+        /// their common shape.
+        ///
+        /// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can
+        /// not be broadcast together.
+        ///
+        /// **Panics** if an integer divisor is zero.
+        fn div_promote(Div::div)
+    }
+}
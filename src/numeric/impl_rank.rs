@@ -0,0 +1,132 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use num_traits::{Float, FromPrimitive};
+use std::cmp::Ordering;
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// How tied elements are ranked, for [`rank_axis`](ArrayRef::rank_axis).
+///
+/// These mirror the methods of SciPy's `rankdata`. For example, the elements
+/// `[10, 20, 20, 30]` are ranked as follows:
+///
+/// | Method    | Ranks              |
+/// |-----------|--------------------|
+/// | `Average` | `[1, 2.5, 2.5, 4]` |
+/// | `Min`     | `[1, 2, 2, 4]`     |
+/// | `Max`     | `[1, 3, 3, 4]`     |
+/// | `Dense`   | `[1, 2, 2, 3]`     |
+/// | `Ordinal` | `[1, 2, 3, 4]`     |
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub enum RankMethod
+{
+    /// Tied elements get the average of the ranks they span
+    Average,
+    /// Tied elements get the lowest of the ranks they span
+    Min,
+    /// Tied elements get the highest of the ranks they span
+    Max,
+    /// Like `Min`, but the next distinct element gets the next rank, so
+    /// there are no gaps between ranks
+    Dense,
+    /// Tied elements get distinct ranks in the order they appear in the lane
+    Ordinal,
+}
+
+/// # Ranking
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float + FromPrimitive,
+    D: Dimension,
+{
+    /// Return the 1-based ranks of the elements of each lane along `axis`,
+    /// resolving ties with `method`.
+    ///
+    /// The result has the same shape as the array, and each lane is ranked
+    /// independently. NaNs are ranked after all other values and are tied
+    /// with each other.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `A::from_usize()` fails
+    /// for the length of the axis.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, RankMethod};
+    ///
+    /// let a = array![[3., 1., 3.],
+    ///                [2., 2., f64::NAN]];
+    /// assert_eq!(a.rank_axis(Axis(1), RankMethod::Average), array![[2.5, 1., 2.5], [1.5, 1.5, 3.]]);
+    /// assert_eq!(a.rank_axis(Axis(1), RankMethod::Ordinal), array![[2., 1., 3.], [1., 2., 3.]]);
+    /// assert_eq!(a.rank_axis(Axis(0), RankMethod::Min), array![[2., 1., 1.], [1., 2., 2.]]);
+    /// ```
+    #[track_caller]
+    pub fn rank_axis(&self, axis: Axis, method: RankMethod) -> Array<A, D>
+    {
+        let mut ranks = Array::zeros(self.raw_dim());
+        let mut idx = Vec::with_capacity(self.len_of(axis));
+        Zip::from(self.lanes(axis))
+            .and(ranks.lanes_mut(axis))
+            .for_each(|lane, out| rank_lane(lane, out, method, &mut idx));
+        ranks
+    }
+}
+
+/// Compare two floats, ordering NaN after every other value and equal to
+/// itself.
+fn cmp_nan_last<A: Float>(a: &A, b: &A) -> Ordering
+{
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(b).unwrap(),
+    }
+}
+
+/// Write the 1-based ranks of the elements of `lane` into `out`, resolving
+/// ties with `method`. NaNs are ranked last and tie with each other.
+///
+/// `idx` is scratch space for the sort permutation, so that it can be
+/// reused across lanes.
+pub(crate) fn rank_lane<A>(
+    lane: ArrayView1<'_, A>, mut out: ArrayViewMut1<'_, A>, method: RankMethod, idx: &mut Vec<usize>,
+) where A: Float + FromPrimitive
+{
+    debug_assert_eq!(lane.len(), out.len());
+    let from_usize = |x| A::from_usize(x).expect("Converting rank to `A` must not fail.");
+    idx.clear();
+    idx.extend(0..lane.len());
+    // stable, so that ties stay in order of appearance for `Ordinal`
+    idx.sort_by(|&i, &j| cmp_nan_last(&lane[i], &lane[j]));
+    let mut start = 0;
+    let mut dense = 0;
+    while start < idx.len() {
+        let first = &lane[idx[start]];
+        let end = start
+            + idx[start..]
+                .iter()
+                .take_while(|&&i| cmp_nan_last(&lane[i], first) == Ordering::Equal)
+                .count();
+        dense += 1;
+        // the tied elements span the ranks start + 1 ..= end
+        for (k, &i) in idx[start..end].iter().enumerate() {
+            out[i] = match method {
+                RankMethod::Average => from_usize(start + 1 + end) / from_usize(2),
+                RankMethod::Min => from_usize(start + 1),
+                RankMethod::Max => from_usize(end),
+                RankMethod::Dense => from_usize(dense),
+                RankMethod::Ordinal => from_usize(start + 1 + k),
+            };
+        }
+        start = end;
+    }
+}
@@ -0,0 +1,378 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::{Float, FromPrimitive};
+use std::cmp::Ordering;
+use std::iter::{once, repeat};
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use super::impl_numeric::check_ddof;
+use crate::imp_prelude::*;
+use crate::ShapeBuilder;
+
+/// Reductions over the sliding windows along an axis, created by
+/// [`rolling`](ArrayRef::rolling).
+///
+/// Each reduction returns an array with the same shape as the original one,
+/// except along the axis, where the length is `len - window + 1` (or zero if
+/// `len < window`), and element `i` is the reduction of the window of the
+/// elements `i..i + window`.
+///
+/// With [`padded`](Self::padded), the length along the axis is `len` instead,
+/// and element `i` is the reduction of the window that ends with element
+/// `i`. The first `window - 1` elements, which have no complete window, are
+/// the fill value.
+#[derive(Clone)]
+pub struct Rolling<'a, A, D>
+{
+    view: ArrayView<'a, A, D>,
+    axis: Axis,
+    window: usize,
+    fill: Option<A>,
+}
+
+/// # Rolling Windows
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Return an adapter for reductions over the sliding windows of `window`
+    /// consecutive elements along `axis`.
+    ///
+    /// See [`Rolling`] for the shape of the results. Unlike reducing each of
+    /// the [`windows`](Self::windows) separately, the sums are updated as the
+    /// window slides, and the minima and maxima are found with a monotonic
+    /// queue, so that each reduction takes time proportional to the number of
+    /// elements, independently of `window`.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `window` is zero.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 3., 2., 6.],
+    ///                [0., 4., 2., 2.]];
+    /// let rolling = a.rolling(Axis(1), 2);
+    /// assert_eq!(rolling.max(), array![[3., 3., 6.],
+    ///                                  [4., 4., 2.]]);
+    /// assert_eq!(rolling.mean(), array![[2., 2.5, 4.],
+    ///                                   [2., 3., 2.]]);
+    /// let sums = a.rolling(Axis(0), 2).padded(f64::NAN).sum();
+    /// assert!(sums.row(0).iter().all(|x| x.is_nan()));
+    /// assert_eq!(sums.row(1), array![1., 7., 4., 8.]);
+    /// ```
+    #[track_caller]
+    pub fn rolling(&self, axis: Axis, window: usize) -> Rolling<'_, A, D>
+    {
+        if axis.index() >= self.ndim() {
+            panic!("axis is out of bounds for array of dimension");
+        }
+        assert!(window != 0, "window must be greater than zero");
+        Rolling {
+            view: self.view(),
+            axis,
+            window,
+            fill: None,
+        }
+    }
+}
+
+impl<A, D> Rolling<'_, A, D>
+where D: Dimension
+{
+    /// Pad the results to the length of the axis, where the elements that
+    /// have no complete window are `fill`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![4, 1, 3, 5];
+    /// assert_eq!(a.rolling(Axis(0), 3).padded(0).min(), array![0, 0, 1, 1]);
+    /// ```
+    pub fn padded(self, fill: A) -> Self
+    {
+        Rolling {
+            fill: Some(fill),
+            ..self
+        }
+    }
+
+    /// Return the minimum of each window.
+    ///
+    /// If a window contains an element that is not comparable to itself
+    /// (such as `NaN`), the first such element is its minimum, like for
+    /// [`min_axis`](ArrayRef::min_axis).
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![4, 1, 3, 5, 2];
+    /// assert_eq!(a.rolling(Axis(0), 3).min(), array![1, 1, 2]);
+    /// ```
+    pub fn min(&self) -> Array<A, D>
+    where A: Clone + PartialOrd
+    {
+        self.reduce(|lane, out| rolling_extremum(lane, self.window, Ordering::Less, out))
+    }
+
+    /// Return the maximum of each window.
+    ///
+    /// If a window contains an element that is not comparable to itself
+    /// (such as `NaN`), the first such element is its maximum, like for
+    /// [`max_axis`](ArrayRef::max_axis).
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![4, 1, 3, 5, 2];
+    /// assert_eq!(a.rolling(Axis(0), 3).max(), array![4, 5, 5]);
+    /// ```
+    pub fn max(&self) -> Array<A, D>
+    where A: Clone + PartialOrd
+    {
+        self.reduce(|lane, out| rolling_extremum(lane, self.window, Ordering::Greater, out))
+    }
+
+    /// Reduce each lane along the axis with `f`, which pushes the reductions
+    /// of the complete windows of the lane to the output.
+    fn reduce<F>(&self, mut f: F) -> Array<A, D>
+    where
+        A: Clone,
+        F: FnMut(ArrayView1<'_, A>, &mut Vec<A>),
+    {
+        let axis = self.axis.index();
+        let len = self.view.len_of(self.axis);
+        let mut dim = self.view.raw_dim();
+        dim[axis] = match self.fill {
+            Some(_) => len,
+            None => (len + 1).saturating_sub(self.window),
+        };
+        let mut res = Vec::with_capacity(dim.size());
+        for lane in self.view.lanes(self.axis) {
+            if let Some(fill) = &self.fill {
+                res.extend(repeat(fill.clone()).take(Ord::min(self.window - 1, len)));
+            }
+            f(lane, &mut res);
+        }
+        // the results of each lane are contiguous, and the lanes are in
+        // logical order
+        let mut strides = D::zeros(dim.ndim());
+        let mut stride = 1;
+        for i in once(axis).chain((0..dim.ndim()).rev().filter(|&i| i != axis)) {
+            strides[i] = stride;
+            stride *= dim[i];
+        }
+        Array::from_shape_vec(dim.strides(strides), res).expect("the results of all windows fill the array")
+    }
+}
+
+/// # Rolling Windows of Float Arrays
+#[cfg(any(feature = "std", feature = "libm"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+impl<A, D> Rolling<'_, A, D>
+where
+    A: Float + FromPrimitive,
+    D: Dimension,
+{
+    /// Return the sum of each window.
+    ///
+    /// The sum is updated as the window slides, so it can differ from the
+    /// sum of the window computed from scratch by rounding errors. When a
+    /// `NaN` or an infinity leaves the window, the sum is computed from
+    /// scratch, so that they only affect the windows that contain them.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![1., 2., f64::NAN, 4., 5., 6.];
+    /// let sums = a.rolling(Axis(0), 2).sum();
+    /// assert_eq!(sums[0], 3.);
+    /// assert!(sums[1].is_nan() && sums[2].is_nan());
+    /// assert_eq!(sums.slice(ndarray::s![3..]), array![9., 11.]);
+    /// ```
+    pub fn sum(&self) -> Array<A, D>
+    {
+        self.reduce(|lane, out| rolling_sum(lane, self.window, out))
+    }
+
+    /// Return the mean of each window.
+    ///
+    /// This is the [`sum`](Self::sum) divided by the length of the window.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the length of the
+    /// window.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![1., 3., 2., 6.];
+    /// assert_eq!(a.rolling(Axis(0), 2).mean(), array![2., 2.5, 4.]);
+    /// ```
+    #[track_caller]
+    pub fn mean(&self) -> Array<A, D>
+    {
+        let n = A::from_usize(self.window).expect("Converting window length to `A` must not fail.");
+        self.reduce(|lane, out| {
+            let start = out.len();
+            rolling_sum(lane, self.window, out);
+            out[start..].iter_mut().for_each(|sum| *sum = *sum / n);
+        })
+    }
+
+    /// Return the variance of each window.
+    ///
+    /// The variance of a window is defined like for [`var`](ArrayRef::var)
+    /// with `n` equal to the length of the window. The mean and the sum of
+    /// squared deviations are updated as the window slides, with the same
+    /// handling of `NaN` and infinities as [`sum`](Self::sum).
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than the length of
+    /// the window, or if `A::from_usize()` fails to convert the length of the
+    /// window.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![1., 3., 2., 6.];
+    /// assert_eq!(a.rolling(Axis(0), 2).var(0.), array![1., 0.25, 4.]);
+    /// ```
+    #[track_caller]
+    pub fn var(&self, ddof: A) -> Array<A, D>
+    {
+        let dof = match check_ddof(ddof, self.window) {
+            Ok(dof) => dof,
+            Err(error) => panic!("{}", error),
+        };
+        self.reduce(|lane, out| {
+            let start = out.len();
+            rolling_sum_sq(lane, self.window, out);
+            out[start..]
+                .iter_mut()
+                .for_each(|sum_sq| *sum_sq = *sum_sq / dof);
+        })
+    }
+
+    /// Return the standard deviation of each window.
+    ///
+    /// This is the square root of [`var`](Self::var).
+    ///
+    /// **Panics** under the same conditions as [`var`](Self::var).
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![1., 3., 2., 6.];
+    /// assert_eq!(a.rolling(Axis(0), 2).std(0.), array![1., 0.5, 2.]);
+    /// ```
+    #[track_caller]
+    pub fn std(&self, ddof: A) -> Array<A, D>
+    {
+        self.var(ddof).mapv_into(|var| var.sqrt())
+    }
+}
+
+/// Push the extrema of the complete windows of `lane` to `out`, where
+/// `better` is `Less` for the minima and `Greater` for the maxima.
+fn rolling_extremum<A>(lane: ArrayView1<'_, A>, window: usize, better: Ordering, out: &mut Vec<A>)
+where A: Clone + PartialOrd
+{
+    // the indices of the candidates for the extremum of the current and
+    // later windows, with the best one at the front
+    let mut candidates = VecDeque::new();
+    // the indices of the elements that are not comparable to themselves
+    let mut incomparable = VecDeque::new();
+    for (i, x) in lane.iter().enumerate() {
+        if x.partial_cmp(x).is_none() {
+            incomparable.push_back(i);
+        } else {
+            // an element that is not worse than `x` stays, so that the first
+            // of equal elements is the extremum
+            while let Some(&j) = candidates.back() {
+                if x.partial_cmp(&lane[j]) != Some(better) {
+                    break;
+                }
+                candidates.pop_back();
+            }
+            candidates.push_back(i);
+        }
+        if i + 1 < window {
+            continue;
+        }
+        let start = i + 1 - window;
+        for queue in [&mut candidates, &mut incomparable] {
+            while let Some(&j) = queue.front() {
+                if j >= start {
+                    break;
+                }
+                queue.pop_front();
+            }
+        }
+        let best = incomparable.front().or(candidates.front()).unwrap();
+        out.push(lane[*best].clone());
+    }
+}
+
+/// Push the sums of the complete windows of `lane` to `out`.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn rolling_sum<A: Float>(lane: ArrayView1<'_, A>, window: usize, out: &mut Vec<A>)
+{
+    if lane.len() < window {
+        return;
+    }
+    let window_sum = |start: usize| lane.slice(s![start..start + window]).sum();
+    let mut sum = window_sum(0);
+    out.push(sum);
+    for i in window..lane.len() {
+        let old = lane[i - window];
+        // subtracting `NaN` or an infinity can not restore the sum
+        sum = if old.is_finite() {
+            sum - old + lane[i]
+        } else {
+            window_sum(i + 1 - window)
+        };
+        out.push(sum);
+    }
+}
+
+/// Push the sums of the squared deviations from the mean of the complete
+/// windows of `lane` to `out`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[track_caller]
+fn rolling_sum_sq<A>(lane: ArrayView1<'_, A>, window: usize, out: &mut Vec<A>)
+where A: Float + FromPrimitive
+{
+    if lane.len() < window {
+        return;
+    }
+    let n = A::from_usize(window).expect("Converting window length to `A` must not fail.");
+    let window_stats = |start: usize| {
+        let view = lane.slice(s![start..start + window]);
+        let mean = view.sum() / n;
+        let sum_sq = view.fold(A::zero(), |sum_sq, &x| (x - mean).mul_add(x - mean, sum_sq));
+        (mean, sum_sq)
+    };
+    let (mut mean, mut sum_sq) = window_stats(0);
+    out.push(sum_sq);
+    for i in window..lane.len() {
+        let (old, new) = (lane[i - window], lane[i]);
+        if old.is_finite() {
+            let old_mean = mean;
+            mean = mean + (new - old) / n;
+            sum_sq = sum_sq + (new - old) * (new - mean + old - old_mean);
+            // rounding errors can make the sum slightly negative
+            if sum_sq < A::zero() {
+                sum_sq = A::zero();
+            }
+        } else {
+            (mean, sum_sq) = window_stats(i + 1 - window);
+        }
+        out.push(sum_sq);
+    }
+}
@@ -0,0 +1,200 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::dimension::DimMax;
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// Return the elements of `if_true` where `cond` is `true` and those of
+/// `if_false` elsewhere, after broadcasting all three arrays to their common
+/// shape like the arithmetic operators do.
+///
+/// This is NumPy's `where(cond, if_true, if_false)`. The elements are only
+/// selected, so the elements of the branch that is not taken are not used in
+/// any computation and can be anything, such as `NaN`. A scalar can be passed
+/// as a zero-dimensional array, which broadcasts to any shape; see
+/// [`select_where_scalar`] if both branches are scalars.
+///
+/// ***Errors*** with a [`ShapeError`] if the shapes of the arrays can not be
+/// broadcast together.
+///
+/// ```
+/// use ndarray::{arr0, array, select_where};
+///
+/// let x = array![[1., -2.],
+///                [-3., 4.]];
+/// let positive = x.gt_scalar(0.);
+/// // zero where `x` is not positive
+/// assert_eq!(select_where(&positive, &x, &arr0(0.)), Ok(array![[1., 0.],
+///                                                            [0., 4.]]));
+/// // a column and a row
+/// let cond = array![[true], [false]];
+/// let row = array![1, 2];
+/// let column = array![[-1], [-2]];
+/// assert_eq!(select_where(&cond, &row, &column), Ok(array![[1, 2],
+///                                                         [-2, -2]]));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn select_where<A, D1, D2, D3>(
+    cond: &ArrayRef<bool, D1>, if_true: &ArrayRef<A, D2>, if_false: &ArrayRef<A, D3>,
+) -> Result<Array<A, <<D1 as DimMax<D2>>::Output as DimMax<D3>>::Output>, ShapeError>
+where
+    A: Clone,
+    D1: Dimension + DimMax<D2>,
+    D2: Dimension,
+    D3: Dimension,
+    <D1 as DimMax<D2>>::Output: DimMax<D3>,
+{
+    let (cond, if_true) = cond.broadcast_with(if_true)?;
+    let (cond, if_false) = cond.broadcast_with(if_false)?;
+    let if_true = if_true
+        .broadcast(cond.raw_dim())
+        .ok_or_else(|| from_kind(ErrorKind::IncompatibleShape))?;
+    Ok(Zip::from(&cond)
+        .and(&if_true)
+        .and(&if_false)
+        .map_collect(|&c, t, f| if c { t.clone() } else { f.clone() }))
+}
+
+/// Return `if_true` where `cond` is `true` and `if_false` elsewhere.
+///
+/// This is [`select_where`] with scalars for both branches, and the result
+/// has the shape of `cond`.
+///
+/// ```
+/// use ndarray::{array, select_where_scalar};
+///
+/// let cond = array![true, false, true];
+/// assert_eq!(select_where_scalar(&cond, 1, -1), array![1, -1, 1]);
+/// ```
+pub fn select_where_scalar<A, D>(cond: &ArrayRef<bool, D>, if_true: A, if_false: A) -> Array<A, D>
+where
+    A: Clone,
+    D: Dimension,
+{
+    cond.map(|&c| if c { if_true.clone() } else { if_false.clone() })
+}
+
+/// # Masked Assignment
+///
+/// These methods modify the elements of the array where a boolean mask is
+/// `true`, like NumPy's `a[mask] = v`, and leave the other elements
+/// untouched. The mask, and the source array if any, are broadcast to the
+/// shape of the array, and if that fails the array is not modified.
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Set the elements where `mask` is `true` to `value`.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if `mask` can not be broadcast to
+    /// the shape of the array, in which case the array is not modified.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[1., f64::NAN],
+    ///                    [f64::NAN, 4.]];
+    /// let mask = a.mapv(f64::is_nan);
+    /// a.set_where(&mask, 0.).unwrap();
+    /// assert_eq!(a, array![[1., 0.],
+    ///                      [0., 4.]]);
+    /// ```
+    pub fn set_where<E>(&mut self, mask: &ArrayRef<bool, E>, value: A) -> Result<(), ShapeError>
+    where
+        A: Clone,
+        E: Dimension,
+    {
+        let mask = mask
+            .broadcast(self.raw_dim())
+            .ok_or_else(|| from_kind(ErrorKind::IncompatibleShape))?;
+        Zip::from(self).and(&mask).for_each(|x, &m| {
+            if m {
+                *x = value.clone();
+            }
+        });
+        Ok(())
+    }
+
+    /// Copy the elements of `src` to the elements where `mask` is `true`.
+    ///
+    /// `mask` and `src` are broadcast to the shape of the array.
+    ///
+    /// ***Errors*** with a [`ShapeError`] if `mask` or `src` can not be
+    /// broadcast to the shape of the array, in which case the array is not
+    /// modified.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[1, 2, 3],
+    ///                    [4, 5, 6]];
+    /// let mask = array![true, false, true];
+    /// let src = array![[-1], [-2]];
+    /// a.assign_where(&mask, &src).unwrap();
+    /// assert_eq!(a, array![[-1, 2, -1],
+    ///                      [-2, 5, -2]]);
+    /// ```
+    pub fn assign_where<E, F>(&mut self, mask: &ArrayRef<bool, E>, src: &ArrayRef<A, F>) -> Result<(), ShapeError>
+    where
+        A: Clone,
+        E: Dimension,
+        F: Dimension,
+    {
+        let dim = self.raw_dim();
+        let (mask, src) = match (mask.broadcast(dim.clone()), src.broadcast(dim)) {
+            (Some(mask), Some(src)) => (mask, src),
+            _ => return Err(from_kind(ErrorKind::IncompatibleShape)),
+        };
+        Zip::from(self).and(&mask).and(&src).for_each(|x, &m, s| {
+            if m {
+                x.clone_from(s);
+            }
+        });
+        Ok(())
+    }
+
+    /// Call `f` by mutable reference on each element where `mask` is `true`.
+    ///
+    /// The elements are visited in the most efficient order for the memory
+    /// layout of the array and the mask, like with
+    /// [`map_inplace`](Self::map_inplace).
+    ///
+    /// ***Errors*** with a [`ShapeError`] if `mask` can not be broadcast to
+    /// the shape of the array, in which case `f` is not called.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// // replace the values above the threshold of their column with ±5
+    /// let mut a = array![[1f64, 50.],
+    ///                    [-3., 2.],
+    ///                    [7., -80.]];
+    /// let threshold = array![5., 10.];
+    /// let outliers = a.abs().gt(&threshold).unwrap();
+    /// a.map_where_inplace(&outliers, |x| *x = x.signum() * 5.).unwrap();
+    /// assert_eq!(a, array![[1., 5.],
+    ///                      [-3., 2.],
+    ///                      [5., -5.]]);
+    /// ```
+    pub fn map_where_inplace<E, F>(&mut self, mask: &ArrayRef<bool, E>, mut f: F) -> Result<(), ShapeError>
+    where
+        E: Dimension,
+        F: FnMut(&mut A),
+    {
+        let mask = mask
+            .broadcast(self.raw_dim())
+            .ok_or_else(|| from_kind(ErrorKind::IncompatibleShape))?;
+        Zip::from(self).and(&mask).for_each(|x, &m| {
+            if m {
+                f(x);
+            }
+        });
+        Ok(())
+    }
+}
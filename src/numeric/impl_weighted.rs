@@ -0,0 +1,335 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(feature = "std")]
+use num_traits::Float;
+
+use crate::error::{from_kind, ErrorKind, StatsError};
+use crate::imp_prelude::*;
+use crate::{LinalgScalar, Zip};
+
+/// # Weighted Statistics
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Return the weighted mean of all elements in the array, that is
+    /// `Σ wᵢ xᵢ / Σ wᵢ`.
+    ///
+    /// `weights` is broadcast to the shape of the array.
+    ///
+    /// Return an error if `weights` can not be broadcast to the shape of the
+    /// array, or if the weights sum to zero (which includes the case of an
+    /// empty array).
+    ///
+    /// ```
+    /// use ndarray::{array, aview1};
+    ///
+    /// let a = array![[1., 2.],
+    ///                [3., 4.]];
+    /// let w = array![[1., 3.],
+    ///                [0., 0.]];
+    /// assert_eq!(a.weighted_mean(&w), Ok(1.75));
+    /// // the weights of each column, broadcast over the rows
+    /// assert_eq!(a.weighted_mean(&aview1(&[3., 1.])), Ok(2.25));
+    /// ```
+    pub fn weighted_mean<D2>(&self, weights: &ArrayRef<A, D2>) -> Result<A, StatsError>
+    where
+        A: LinalgScalar,
+        D2: Dimension,
+    {
+        let weights = weights
+            .broadcast(self.raw_dim())
+            .ok_or_else(|| from_kind(ErrorKind::IncompatibleShape))?;
+        let (sum_wx, sum_w) = Zip::from(self)
+            .and(&weights)
+            .fold((A::zero(), A::zero()), |(sum_wx, sum_w), &x, &w| (sum_wx + w * x, sum_w + w));
+        if sum_w.is_zero() {
+            return Err(StatsError::ZeroWeight);
+        }
+        Ok(sum_wx / sum_w)
+    }
+
+    /// Return the weighted mean along `axis`, where `weights` holds one
+    /// weight for each index along `axis`.
+    ///
+    /// Return an error if the length of `weights` differs from the length of
+    /// `axis`, or if the weights sum to zero (which includes the case of a
+    /// zero-length axis).
+    ///
+    /// ```
+    /// use ndarray::{arr2, aview1, Axis};
+    ///
+    /// let a = arr2(&[[1., 2., 3.],
+    ///                [4., 5., 6.]]);
+    /// let w = aview1(&[1., 2., 1.]);
+    /// assert_eq!(a.weighted_mean_axis(Axis(1), &w), Ok(aview1(&[2., 5.]).to_owned()));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn weighted_mean_axis(&self, axis: Axis, weights: &ArrayRef<A, Ix1>) -> Result<Array<A, D::Smaller>, StatsError>
+    where
+        A: LinalgScalar,
+        D: RemoveAxis,
+    {
+        if weights.len() != self.len_of(axis) {
+            return Err(from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let sum_w = weights.sum();
+        if sum_w.is_zero() {
+            return Err(StatsError::ZeroWeight);
+        }
+        let min_stride_axis = self.dim.min_stride_axis(&self.strides);
+        let mut res = if axis == min_stride_axis {
+            Zip::from(self.lanes(axis)).map_collect(|lane| lane.dot(weights))
+        } else {
+            let mut res = Array::zeros(self.raw_dim().remove_axis(axis));
+            for (subview, &w) in self.axis_iter(axis).zip(weights) {
+                Zip::from(&mut res)
+                    .and(&subview)
+                    .for_each(|sum_wx, &x| *sum_wx = *sum_wx + w * x);
+            }
+            res
+        };
+        res.mapv_inplace(|sum_wx| sum_wx / sum_w);
+        Ok(res)
+    }
+}
+
+/// # Weighted Statistics of Float Arrays
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<A, D> ArrayRef<A, D>
+where
+    A: Float,
+    D: Dimension,
+{
+    /// Return the weighted variance of all elements in the array.
+    ///
+    /// The variance is computed in a single pass with the weighted version of
+    /// the [Welford algorithm](https://doi.org/10.1145/359146.359153), and
+    /// `weights` is broadcast to the shape of the array.
+    ///
+    /// The weights are treated as *reliability weights*, and the parameter
+    /// `ddof` specifies the "delta degrees of freedom": use `ddof = 0` for
+    /// the weighted population variance, or `ddof = 1` for the unbiased
+    /// weighted sample variance. The variance is defined as:
+    ///
+    /// ```text
+    ///                    1            n
+    /// variance = ―――――――――――――――――   ∑ wᵢ (xᵢ - x̅)²
+    ///            V₁ - ddof · V₂ / V₁  i=1
+    /// ```
+    ///
+    /// where `x̅` is the [weighted mean](Self::weighted_mean), `V₁ = ∑ wᵢ` and
+    /// `V₂ = ∑ wᵢ²`. With equal weights this is the same as
+    /// [`var`](Self::var).
+    ///
+    /// If the denominator is not positive, the result is `NaN`.
+    ///
+    /// Return an error if `weights` can not be broadcast to the shape of the
+    /// array, or if the weights sum to zero.
+    ///
+    /// **Panics** if `ddof` is less than zero.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1., 3., 5.];
+    /// let w = array![1., 0., 1.];
+    /// assert_eq!(a.weighted_var(&w, 0.), Ok(4.));
+    /// assert_eq!(a.weighted_var(&w, 1.), Ok(8.));
+    /// ```
+    #[track_caller]
+    pub fn weighted_var<D2>(&self, weights: &ArrayRef<A, D2>, ddof: A) -> Result<A, StatsError>
+    where D2: Dimension
+    {
+        check_ddof(ddof);
+        let weights = weights
+            .broadcast(self.raw_dim())
+            .ok_or_else(|| from_kind(ErrorKind::IncompatibleShape))?;
+        let mut acc = WeightedWelford::new();
+        Zip::from(self)
+            .and(&weights)
+            .for_each(|&x, &w| acc.update_scalar(x, w));
+        acc.var(ddof)
+    }
+
+    /// Return the weighted standard deviation of all elements in the array.
+    ///
+    /// This is the square root of [`weighted_var`](Self::weighted_var), see
+    /// there for the definition and the meaning of `ddof`.
+    ///
+    /// Return an error if `weights` can not be broadcast to the shape of the
+    /// array, or if the weights sum to zero.
+    ///
+    /// **Panics** if `ddof` is less than zero.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1., 3., 5.];
+    /// let w = array![1., 0., 1.];
+    /// assert_eq!(a.weighted_std(&w, 0.), Ok(2.));
+    /// ```
+    #[track_caller]
+    pub fn weighted_std<D2>(&self, weights: &ArrayRef<A, D2>, ddof: A) -> Result<A, StatsError>
+    where D2: Dimension
+    {
+        self.weighted_var(weights, ddof).map(A::sqrt)
+    }
+
+    /// Return the weighted variance along `axis`, where `weights` holds one
+    /// weight for each index along `axis`.
+    ///
+    /// The variance of each lane is defined like in
+    /// [`weighted_var`](Self::weighted_var). Since all lanes share the same
+    /// weights, lanes with a non-positive denominator only occur when this is
+    /// the case for `weights` as a whole, and then all lanes are `NaN`.
+    ///
+    /// Return an error if the length of `weights` differs from the length of
+    /// `axis`, or if the weights sum to zero.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `ddof` is less than zero.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.],
+    ///                [5., 9.]]);
+    /// let w = aview1(&[1., 0., 1.]);
+    /// assert_eq!(a.weighted_var_axis(Axis(0), &w, 0.), Ok(aview1(&[4., 12.25]).to_owned()));
+    /// ```
+    #[track_caller]
+    pub fn weighted_var_axis(
+        &self, axis: Axis, weights: &ArrayRef<A, Ix1>, ddof: A,
+    ) -> Result<Array<A, D::Smaller>, StatsError>
+    where D: RemoveAxis
+    {
+        check_ddof(ddof);
+        if weights.len() != self.len_of(axis) {
+            return Err(from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let mut acc = WeightedWelford::new();
+        let mut mean = Array::<A, _>::zeros(self.dim.remove_axis(axis));
+        let mut sum_sq = Array::<A, _>::zeros(self.dim.remove_axis(axis));
+        for (subview, &w) in self.axis_iter(axis).zip(weights) {
+            if !acc.add_weight(w) {
+                continue;
+            }
+            let ratio = w / acc.sum_w;
+            azip!((mean in &mut mean, sum_sq in &mut sum_sq, &x in &subview) {
+                let delta = x - *mean;
+                *mean = delta.mul_add(ratio, *mean);
+                *sum_sq = (w * delta).mul_add(x - *mean, *sum_sq);
+            });
+        }
+        let dof = acc.dof(ddof)?;
+        Ok(sum_sq.mapv_into(|s| s / dof))
+    }
+
+    /// Return the weighted standard deviation along `axis`, where `weights`
+    /// holds one weight for each index along `axis`.
+    ///
+    /// This is the square root of
+    /// [`weighted_var_axis`](Self::weighted_var_axis), see there for details.
+    ///
+    /// Return an error if the length of `weights` differs from the length of
+    /// `axis`, or if the weights sum to zero.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `ddof` is less than zero.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.],
+    ///                [5., 9.]]);
+    /// let w = aview1(&[1., 0., 1.]);
+    /// assert_eq!(a.weighted_std_axis(Axis(0), &w, 0.), Ok(aview1(&[2., 3.5]).to_owned()));
+    /// ```
+    #[track_caller]
+    pub fn weighted_std_axis(
+        &self, axis: Axis, weights: &ArrayRef<A, Ix1>, ddof: A,
+    ) -> Result<Array<A, D::Smaller>, StatsError>
+    where D: RemoveAxis
+    {
+        self.weighted_var_axis(axis, weights, ddof)
+            .map(|var| var.mapv_into(A::sqrt))
+    }
+}
+
+#[cfg(feature = "std")]
+#[track_caller]
+fn check_ddof<A: Float>(ddof: A)
+{
+    assert!(ddof >= A::zero() || ddof.is_nan(), "`ddof` must not be less than zero");
+}
+
+/// Running sums for the weighted Welford algorithm.
+///
+/// The `mean` and `sum_sq` fields are only used by `update_scalar`; the axis
+/// methods keep arrays of them instead.
+#[cfg(feature = "std")]
+struct WeightedWelford<A>
+{
+    sum_w: A,
+    sum_w2: A,
+    mean: A,
+    sum_sq: A,
+}
+
+#[cfg(feature = "std")]
+impl<A: Float> WeightedWelford<A>
+{
+    fn new() -> Self
+    {
+        WeightedWelford {
+            sum_w: A::zero(),
+            sum_w2: A::zero(),
+            mean: A::zero(),
+            sum_sq: A::zero(),
+        }
+    }
+
+    /// Add `w` to the sums of weights, and return `false` if the total weight
+    /// is still zero, in which case the element must be skipped.
+    fn add_weight(&mut self, w: A) -> bool
+    {
+        self.sum_w = self.sum_w + w;
+        self.sum_w2 = w.mul_add(w, self.sum_w2);
+        !self.sum_w.is_zero()
+    }
+
+    fn update_scalar(&mut self, x: A, w: A)
+    {
+        if !self.add_weight(w) {
+            return;
+        }
+        let delta = x - self.mean;
+        self.mean = delta.mul_add(w / self.sum_w, self.mean);
+        self.sum_sq = (w * delta).mul_add(x - self.mean, self.sum_sq);
+    }
+
+    /// Return the denominator of the reliability-weighted variance, which is
+    /// `NaN` if it is not positive.
+    fn dof(&self, ddof: A) -> Result<A, StatsError>
+    {
+        if self.sum_w.is_zero() {
+            return Err(StatsError::ZeroWeight);
+        }
+        let dof = (-ddof).mul_add(self.sum_w2 / self.sum_w, self.sum_w);
+        Ok(if dof > A::zero() { dof } else { A::nan() })
+    }
+
+    fn var(&self, ddof: A) -> Result<A, StatsError>
+    {
+        Ok(self.sum_sq / self.dof(ddof)?)
+    }
+}
@@ -1,3 +1,89 @@
 mod impl_numeric;
+pub use self::impl_numeric::DiffMode;
 
 mod impl_float_maths;
+
+#[cfg(feature = "std")]
+mod impl_nan;
+
+#[cfg(feature = "std")]
+mod impl_means;
+
+#[cfg(feature = "std")]
+mod impl_moments;
+
+#[cfg(feature = "std")]
+mod impl_complex;
+
+#[cfg(feature = "std")]
+mod impl_close;
+
+#[cfg(feature = "std")]
+mod impl_log_space;
+
+#[cfg(feature = "std")]
+mod impl_norms;
+#[cfg(feature = "std")]
+pub use self::impl_norms::NormOrd;
+
+mod impl_order_statistics;
+pub use self::impl_order_statistics::Interpolate;
+
+mod impl_weighted;
+
+mod impl_bincount;
+
+mod impl_counting;
+
+mod impl_checked;
+
+mod impl_cast;
+pub use self::impl_cast::RoundMode;
+
+mod impl_promote;
+pub use self::impl_promote::Promote;
+
+mod impl_extrema;
+
+mod impl_mul_add;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+mod impl_lerp;
+
+mod impl_euclid;
+
+mod impl_bitwise;
+
+mod impl_comparison;
+
+mod impl_select;
+pub use self::impl_select::{select_where, select_where_scalar};
+
+mod impl_masked;
+
+mod impl_rolling;
+pub use self::impl_rolling::Rolling;
+
+mod impl_convolve;
+pub use self::impl_convolve::ConvMode;
+
+mod impl_digitize;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+mod impl_interp;
+
+mod impl_polynomial;
+pub use self::impl_polynomial::{polyval, polyval_inplace};
+
+#[cfg(feature = "std")]
+mod impl_correlation;
+
+#[cfg(feature = "std")]
+mod impl_rank;
+#[cfg(feature = "std")]
+pub use self::impl_rank::RankMethod;
+
+#[cfg(feature = "std")]
+mod impl_histogram;
+#[cfg(feature = "std")]
+pub use self::impl_histogram::{histogram2d, Bins};
@@ -7,6 +7,11 @@
 // except according to those terms.
 
 use std::cmp;
+use std::ops::Add;
+
+#[cfg(feature = "std")]
+use num_traits::Float;
+use num_traits::Zero;
 
 use crate::LinalgScalar;
 
@@ -50,6 +55,196 @@ where
     acc
 }
 
+/// The length below which `pairwise_sum` stops splitting its input.
+const PAIRWISE_BLOCK: usize = 128;
+
+/// Compute the sum of `xs` with pairwise (cascade) summation.
+///
+/// `xs` is split in halves recursively until the parts have at most
+/// `PAIRWISE_BLOCK` elements, which are summed with `unrolled_fold`. For
+/// floating point, this makes the rounding error grow with the logarithm of
+/// the length instead of linearly, at no noticeable cost in speed.
+pub fn pairwise_sum<A>(xs: &[A]) -> A
+where A: Clone + Add<Output = A> + Zero
+{
+    if xs.len() <= PAIRWISE_BLOCK {
+        unrolled_fold(xs, A::zero, A::add)
+    } else {
+        // split at a multiple of 8 so that the unrolled blocks line up
+        let mid = xs.len() / 2 / 8 * 8;
+        let (left, right) = xs.split_at(mid);
+        pairwise_sum(left) + pairwise_sum(right)
+    }
+}
+
+/// Fold over the manually unrolled `xs` with `f`, passing each element
+/// through `map` first.
+///
+/// Unlike `unrolled_fold`, `map` is only applied to the elements, not when
+/// combining the partial accumulators.
+#[cfg(feature = "std")]
+pub fn unrolled_fold_map<A, B, I, M, F>(mut xs: &[A], init: I, map: M, f: F) -> B
+where
+    B: Clone,
+    I: Fn() -> B,
+    M: Fn(&A) -> B,
+    F: Fn(B, B) -> B,
+{
+    // eightfold unrolled so that floating point can be vectorized
+    // (even with strict floating point accuracy semantics)
+    let mut acc = init();
+    let (mut p0, mut p1, mut p2, mut p3, mut p4, mut p5, mut p6, mut p7) =
+        (init(), init(), init(), init(), init(), init(), init(), init());
+    while xs.len() >= 8 {
+        p0 = f(p0, map(&xs[0]));
+        p1 = f(p1, map(&xs[1]));
+        p2 = f(p2, map(&xs[2]));
+        p3 = f(p3, map(&xs[3]));
+        p4 = f(p4, map(&xs[4]));
+        p5 = f(p5, map(&xs[5]));
+        p6 = f(p6, map(&xs[6]));
+        p7 = f(p7, map(&xs[7]));
+
+        xs = &xs[8..];
+    }
+    acc = f(acc.clone(), f(p0, p4));
+    acc = f(acc.clone(), f(p1, p5));
+    acc = f(acc.clone(), f(p2, p6));
+    acc = f(acc.clone(), f(p3, p7));
+
+    // make it clear to the optimizer that this loop is short
+    // and can not be autovectorized.
+    for (i, x) in xs.iter().enumerate() {
+        if i >= 7 {
+            break;
+        }
+        acc = f(acc.clone(), map(x))
+    }
+    acc
+}
+
+/// A running sum with a compensation term for the lost low-order bits, added
+/// to with the Kahan–Babuška–Neumaier algorithm.
+///
+/// The lost bits of each addition are found with Knuth's branch-free two-sum,
+/// which gives the same result as the magnitude comparison in Neumaier's
+/// formulation but vectorizes better.
+#[cfg(feature = "std")]
+#[derive(Copy, Clone)]
+pub struct Compensated<A>
+{
+    sum: A,
+    comp: A,
+}
+
+#[cfg(feature = "std")]
+impl<A: Float> Compensated<A>
+{
+    pub fn zero() -> Self
+    {
+        Compensated {
+            sum: A::zero(),
+            comp: A::zero(),
+        }
+    }
+
+    #[inline]
+    pub fn add(self, x: A) -> Self
+    {
+        let sum = self.sum + x;
+        let x_part = sum - self.sum;
+        let lost = (self.sum - (sum - x_part)) + (x - x_part);
+        Compensated {
+            sum,
+            comp: self.comp + lost,
+        }
+    }
+
+    /// Fold the compensation into the sum as far as it fits.
+    ///
+    /// This keeps the compensation small enough that adding the lost bits to
+    /// it is (nearly) exact; without it, the rounding errors of the
+    /// compensation itself add up over millions of elements.
+    #[inline]
+    pub fn renormalize(self) -> Self
+    {
+        let sum = self.sum + self.comp;
+        Compensated {
+            sum,
+            comp: self.comp - (sum - self.sum),
+        }
+    }
+
+    /// Add the sum and compensation of `other`.
+    pub fn merge(self, other: Self) -> Self
+    {
+        let mut acc = self.add(other.sum);
+        acc.comp = acc.comp + other.comp;
+        acc
+    }
+
+    pub fn total(self) -> A
+    {
+        self.sum + self.comp
+    }
+}
+
+/// The number of additions to a `Compensated` sum after which it should be
+/// renormalized.
+#[cfg(feature = "std")]
+pub const RENORMALIZE_PERIOD: usize = 8;
+
+/// Compute the compensated sum of the manually unrolled `xs`, with a
+/// separate compensation term for each partial sum.
+#[cfg(feature = "std")]
+pub fn unrolled_sum_compensated<A: Float>(mut xs: &[A]) -> Compensated<A>
+{
+    let mut p = [Compensated::zero(); 8];
+    let mut blocks = 0;
+    while xs.len() >= 8 {
+        p[0] = p[0].add(xs[0]);
+        p[1] = p[1].add(xs[1]);
+        p[2] = p[2].add(xs[2]);
+        p[3] = p[3].add(xs[3]);
+        p[4] = p[4].add(xs[4]);
+        p[5] = p[5].add(xs[5]);
+        p[6] = p[6].add(xs[6]);
+        p[7] = p[7].add(xs[7]);
+
+        xs = &xs[8..];
+        blocks += 1;
+        if blocks % RENORMALIZE_PERIOD == 0 {
+            for part in &mut p {
+                *part = part.renormalize();
+            }
+        }
+    }
+    let mut acc = Compensated::zero();
+    for &part in &p {
+        acc = acc.merge(part);
+    }
+    for &x in xs {
+        acc = acc.add(x);
+    }
+    acc
+}
+
+/// Compute the compensated sum of the elements of `iter`, starting from `acc`.
+#[cfg(feature = "std")]
+pub fn sum_compensated_iter<'a, A, I>(iter: I, mut acc: Compensated<A>) -> Compensated<A>
+where
+    A: Float + 'a,
+    I: IntoIterator<Item = &'a A>,
+{
+    for (i, &x) in iter.into_iter().enumerate() {
+        acc = acc.add(x);
+        if (i + 1) % RENORMALIZE_PERIOD == 0 {
+            acc = acc.renormalize();
+        }
+    }
+    acc
+}
+
 /// Compute the dot product.
 ///
 /// `xs` and `ys` must be the same length
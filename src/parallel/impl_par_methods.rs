@@ -1,5 +1,9 @@
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+
+use crate::sort::sort_lane_by;
 use crate::AssignElem;
-use crate::{Array, ArrayRef, Dimension, IntoNdProducer, NdProducer, Zip};
+use crate::{Array, ArrayRef, Axis, Dimension, IntoNdProducer, NdProducer, Zip};
 
 use super::send_producer::SendProducer;
 use crate::parallel::par::ParallelSplits;
@@ -39,6 +43,46 @@ where
             .into_par_iter()
             .for_each(move |x| *x = f(x.clone()))
     }
+
+    /// Parallel version of `sort_axis_inplace`.
+    ///
+    /// Sort each lane along `axis` in increasing order, in place, with the
+    /// lanes distributed across threads. The result is the same as with
+    /// `sort_axis_inplace`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    #[track_caller]
+    pub fn par_sort_axis_inplace(&mut self, axis: Axis)
+    where A: Ord
+    {
+        self.par_sort_axis_by(axis, Ord::cmp)
+    }
+
+    /// Parallel version of `sort_axis_by`.
+    ///
+    /// Sort each lane along `axis` in place, in increasing order by `compare`,
+    /// which must be a total order, with the lanes distributed across
+    /// threads.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis};
+    ///
+    /// let mut a = Array::from_shape_fn((100, 50), |(i, j)| ((i * 37 + j * 11) % 23) as f64);
+    /// let mut b = a.clone();
+    /// a.par_sort_axis_by(Axis(1), |x, y| x.total_cmp(y));
+    /// b.sort_axis_by(Axis(1), |x, y| x.total_cmp(y));
+    /// assert_eq!(a, b);
+    /// ```
+    #[track_caller]
+    pub fn par_sort_axis_by<F>(&mut self, axis: Axis, compare: F)
+    where F: Fn(&A, &A) -> Ordering + Sync + Send
+    {
+        Zip::from(self.lanes_mut(axis))
+            .into_par_iter()
+            .for_each_init(Vec::new, |scratch, (lane,)| sort_lane_by(lane, scratch, &mut &compare));
+    }
 }
 
 // Zip
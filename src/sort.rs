@@ -0,0 +1,143 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+
+use crate::imp_prelude::*;
+
+/// # Sorting Along an Axis
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Sort each lane along `axis` in increasing order, in place.
+    ///
+    /// The sort is unstable. Lanes that are contiguous in memory are sorted
+    /// as slices, and the others by sorting their indices in a buffer that is
+    /// reused for all lanes and then moving their elements into place.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[3, 1, 2],
+    ///                    [9, 7, 8]];
+    /// a.sort_axis_inplace(Axis(1));
+    /// assert_eq!(a, array![[1, 2, 3],
+    ///                      [7, 8, 9]]);
+    /// ```
+    #[track_caller]
+    pub fn sort_axis_inplace(&mut self, axis: Axis)
+    where A: Ord
+    {
+        self.sort_axis_by(axis, Ord::cmp)
+    }
+
+    /// Sort each lane along `axis` in place, in increasing order by
+    /// `compare`, which must be a total order.
+    ///
+    /// See [`sort_axis_inplace`](Self::sort_axis_inplace). Floating point
+    /// elements can be sorted with [`f64::total_cmp`], which puts NaNs last.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[0.5, f64::NAN],
+    ///                    [-1., 2.]];
+    /// a.sort_axis_by(Axis(0), |a, b| a.total_cmp(b));
+    /// assert_eq!(a.row(0), array![-1., 2.]);
+    /// assert_eq!(a[[1, 0]], 0.5);
+    /// assert!(a[[1, 1]].is_nan());
+    /// ```
+    #[track_caller]
+    pub fn sort_axis_by<F>(&mut self, axis: Axis, mut compare: F)
+    where F: FnMut(&A, &A) -> Ordering
+    {
+        let mut scratch = Vec::new();
+        for lane in self.lanes_mut(axis) {
+            sort_lane_by(lane, &mut scratch, &mut compare);
+        }
+    }
+
+    /// Sort each lane along `axis` in place, in increasing order of the keys
+    /// given by `f`.
+    ///
+    /// See [`sort_axis_inplace`](Self::sort_axis_inplace).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![3, -1, -4, 2];
+    /// a.sort_axis_by_key(Axis(0), |x: &i32| x.abs());
+    /// assert_eq!(a, array![-1, 2, 3, -4]);
+    /// ```
+    #[track_caller]
+    pub fn sort_axis_by_key<K, F>(&mut self, axis: Axis, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&A) -> K,
+    {
+        self.sort_axis_by(axis, |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Return a copy of the array with each lane along `axis` sorted in
+    /// increasing order.
+    ///
+    /// See [`sort_axis_inplace`](Self::sort_axis_inplace).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[3, 1],
+    ///                [2, 4]];
+    /// assert_eq!(a.sorted_axis(Axis(0)), array![[2, 1],
+    ///                                           [3, 4]]);
+    /// ```
+    #[track_caller]
+    pub fn sorted_axis(&self, axis: Axis) -> Array<A, D>
+    where A: Clone + Ord
+    {
+        let mut sorted = self.to_owned();
+        sorted.sort_axis_inplace(axis);
+        sorted
+    }
+}
+
+/// Sort `lane` by `compare`, using `scratch` for the indices of the elements
+/// if it is not contiguous.
+pub(crate) fn sort_lane_by<A, F>(mut lane: ArrayViewMut1<'_, A>, scratch: &mut Vec<usize>, compare: &mut F)
+where F: FnMut(&A, &A) -> Ordering
+{
+    if let Some(slc) = lane.as_slice_mut() {
+        slc.sort_unstable_by(compare);
+        return;
+    }
+    scratch.clear();
+    scratch.extend(0..lane.len());
+    scratch.sort_unstable_by(|&i, &j| compare(&lane[i], &lane[j]));
+    // Element `scratch[k]` goes to index `k`: follow each cycle of the
+    // permutation, marking the indices that are in place.
+    for start in 0..scratch.len() {
+        let mut k = start;
+        while scratch[k] != k {
+            let next = scratch[k];
+            scratch[k] = k;
+            if next != start {
+                lane.swap(k, next);
+            }
+            k = next;
+        }
+    }
+}
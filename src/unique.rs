@@ -0,0 +1,305 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+
+use crate::imp_prelude::*;
+
+/// The distinct elements of an array and where they occur, created by
+/// [`unique_full`](ArrayRef::unique_full), or the distinct subviews along an
+/// axis, created by [`unique_axis_full`](ArrayRef::unique_axis_full).
+///
+/// For elements, the indices refer to the elements of the array in logical
+/// order, as given by [`iter`](ArrayRef::iter), so `values[inverse[i]]` is the
+/// element at index `i` of the flattened array. For subviews, they are
+/// indices along the axis.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Unique<A, D = Ix1>
+where D: Dimension
+{
+    /// The distinct elements or subviews, in increasing order.
+    pub values: Array<A, D>,
+    /// The number of elements or subviews equal to each value.
+    pub counts: Array1<usize>,
+    /// The index of the first element or subview equal to each value.
+    pub first_indices: Array1<usize>,
+    /// The index in `values` of each element or subview.
+    pub inverse: Array1<usize>,
+}
+
+/// # Unique Elements
+impl<A, D> ArrayRef<A, D>
+where D: Dimension
+{
+    /// Return the distinct elements of the array, in increasing order.
+    ///
+    /// For floating point elements, which are not `Ord`, use
+    /// [`unique_by`](Self::unique_by).
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let labels = array![[3, 1, 3],
+    ///                     [2, 1, 1]];
+    /// assert_eq!(labels.unique(), array![1, 2, 3]);
+    /// ```
+    pub fn unique(&self) -> Array1<A>
+    where A: Clone + Ord
+    {
+        self.unique_by(Ord::cmp)
+    }
+
+    /// Return the distinct elements of the array, in increasing order by
+    /// `compare`, which must be a total order.
+    ///
+    /// Elements that compare equal are the same value, and the first of them
+    /// is kept. With [`f64::total_cmp`], NaNs with the same bits, such as the
+    /// result of `0. / 0.`, are one value that sorts after all numbers, while
+    /// `-0.` and `0.` are two values.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![0.5, f64::NAN, -1., 0.5, f64::NAN];
+    /// let values = a.unique_by(f64::total_cmp);
+    /// assert_eq!(values.len(), 3);
+    /// assert_eq!(values.slice(ndarray::s![..2]), array![-1., 0.5]);
+    /// assert!(values[2].is_nan());
+    /// ```
+    pub fn unique_by<F>(&self, mut compare: F) -> Array1<A>
+    where
+        A: Clone,
+        F: FnMut(&A, &A) -> Ordering,
+    {
+        let mut values = self.iter().cloned().collect::<Vec<_>>();
+        values.sort_by(&mut compare);
+        values.dedup_by(|a, b| compare(a, b) == Ordering::Equal);
+        Array1::from(values)
+    }
+
+    /// Return the distinct elements of the array, in increasing order, with
+    /// their counts, their first indices and the inverse indices of the
+    /// elements.
+    ///
+    /// See [`Unique`] for the parts of the result, and
+    /// [`unique_full_by`](Self::unique_full_by) for floating point elements.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let labels = array![[3, 1, 3],
+    ///                     [2, 1, 1]];
+    /// let unique = labels.unique_full();
+    /// assert_eq!(unique.values, array![1, 2, 3]);
+    /// assert_eq!(unique.counts, array![3, 1, 2]);
+    /// assert_eq!(unique.first_indices, array![1, 3, 0]);
+    /// assert_eq!(unique.inverse, array![2, 0, 2, 1, 0, 0]);
+    /// assert_eq!(unique.values.select(ndarray::Axis(0), unique.inverse.as_slice().unwrap()),
+    ///            labels.flatten());
+    /// ```
+    pub fn unique_full(&self) -> Unique<A>
+    where A: Clone + Ord
+    {
+        self.unique_full_by(Ord::cmp)
+    }
+
+    /// Return the distinct elements of the array, in increasing order by
+    /// `compare`, with their counts, their first indices and the inverse
+    /// indices of the elements.
+    ///
+    /// Elements that compare equal are the same value, which is the first of
+    /// them; see [`unique_by`](Self::unique_by).
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![2.5, 1., 2.5, 2.5];
+    /// let unique = a.unique_full_by(f64::total_cmp);
+    /// assert_eq!(unique.values, array![1., 2.5]);
+    /// assert_eq!(unique.counts, array![1, 3]);
+    /// assert_eq!(unique.inverse, array![1, 0, 1, 1]);
+    /// ```
+    pub fn unique_full_by<F>(&self, mut compare: F) -> Unique<A>
+    where
+        A: Clone,
+        F: FnMut(&A, &A) -> Ordering,
+    {
+        let elems = self.iter().collect::<Vec<_>>();
+        let (first_indices, counts, inverse) = unique_groups(elems.len(), |i, j| compare(elems[i], elems[j]));
+        Unique {
+            values: first_indices.iter().map(|&i| elems[i].clone()).collect(),
+            counts: Array1::from(counts),
+            first_indices: Array1::from(first_indices),
+            inverse: Array1::from(inverse),
+        }
+    }
+}
+
+/// # Unique Subviews
+impl<A, D> ArrayRef<A, D>
+where D: RemoveAxis
+{
+    /// Return the array with only the distinct subviews along `axis`, in
+    /// increasing lexicographic order.
+    ///
+    /// This removes duplicate rows with `Axis(0)`, like NumPy's `unique` with
+    /// an axis. Subviews are compared by their elements in logical order, and
+    /// all subviews are equal if they are empty.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let points = array![[1, 2],
+    ///                     [0, 5],
+    ///                     [1, 2],
+    ///                     [0, 4]];
+    /// assert_eq!(points.unique_axis(Axis(0)), array![[0, 4],
+    ///                                                [0, 5],
+    ///                                                [1, 2]]);
+    /// ```
+    #[track_caller]
+    pub fn unique_axis(&self, axis: Axis) -> Array<A, D>
+    where A: Clone + Ord
+    {
+        self.unique_axis_full(axis).values
+    }
+
+    /// Return the distinct subviews along `axis`, in increasing lexicographic
+    /// order, with their counts, their first indices and the inverse indices
+    /// of the subviews.
+    ///
+    /// See [`Unique`] for the parts of the result, and
+    /// [`unique_axis`](Self::unique_axis) for the order of the subviews.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[3, 1, 3],
+    ///                [2, 1, 2]];
+    /// let unique = a.unique_axis_full(Axis(1));
+    /// assert_eq!(unique.values, array![[1, 3],
+    ///                                  [1, 2]]);
+    /// assert_eq!(unique.counts, array![1, 2]);
+    /// assert_eq!(unique.first_indices, array![1, 0]);
+    /// assert_eq!(unique.inverse, array![1, 0, 1]);
+    /// ```
+    #[track_caller]
+    pub fn unique_axis_full(&self, axis: Axis) -> Unique<A, D>
+    where A: Clone + Ord
+    {
+        let subviews = self.axis_iter(axis).collect::<Vec<_>>();
+        let (first_indices, counts, inverse) =
+            unique_groups(subviews.len(), |i, j| subviews[i].iter().cmp(subviews[j].iter()));
+        Unique {
+            values: self.select(axis, &first_indices),
+            counts: Array1::from(counts),
+            first_indices: Array1::from(first_indices),
+            inverse: Array1::from(inverse),
+        }
+    }
+
+    /// Return the array with each run of equal consecutive subviews along
+    /// `axis` replaced by its first subview.
+    ///
+    /// Unlike [`unique_axis`](Self::unique_axis), this keeps the order of the
+    /// subviews and only removes repeats that follow each other, like
+    /// [`Vec::dedup`].
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let samples = array![[0, 1],
+    ///                      [0, 1],
+    ///                      [2, 2],
+    ///                      [0, 1]];
+    /// assert_eq!(samples.dedup_axis(Axis(0)), array![[0, 1],
+    ///                                                [2, 2],
+    ///                                                [0, 1]]);
+    /// ```
+    #[track_caller]
+    pub fn dedup_axis(&self, axis: Axis) -> Array<A, D>
+    where A: Clone + PartialEq
+    {
+        self.dedup_axis_by(axis, |a, b| a == b)
+    }
+
+    /// Return the array with each run of consecutive subviews along `axis`
+    /// that `same` considers equal replaced by its first subview.
+    ///
+    /// `same` is called with the last subview that is kept and the next
+    /// subview, which is removed if it returns `true`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, Zip};
+    ///
+    /// let readings = array![[1.0f64, 0.0],
+    ///                       [1.001, 0.0],
+    ///                       [1.5, 0.5],
+    ///                       [1.5, 0.5005]];
+    /// let deduped = readings.dedup_axis_by(Axis(0), |a, b| {
+    ///     Zip::from(&a).and(&b).all(|x, y| (x - y).abs() < 1e-2)
+    /// });
+    /// assert_eq!(deduped, array![[1.0, 0.0],
+    ///                            [1.5, 0.5]]);
+    /// ```
+    #[track_caller]
+    pub fn dedup_axis_by<F>(&self, axis: Axis, mut same: F) -> Array<A, D>
+    where
+        A: Clone,
+        F: FnMut(ArrayView<'_, A, D::Smaller>, ArrayView<'_, A, D::Smaller>) -> bool,
+    {
+        let mut kept = Vec::new();
+        for i in 0..self.len_of(axis) {
+            match kept.last() {
+                Some(&last) if same(self.index_axis(axis, last), self.index_axis(axis, i)) => {}
+                _ => kept.push(i),
+            }
+        }
+        self.select(axis, &kept)
+    }
+}
+
+/// Group the indices `0..len` into the distinct values by `compare`, and
+/// return the first index and the count of each value, in increasing order,
+/// and the value of each index.
+fn unique_groups<F>(len: usize, mut compare: F) -> (Vec<usize>, Vec<usize>, Vec<usize>)
+where F: FnMut(usize, usize) -> Ordering
+{
+    // A stable sort keeps the indices of each value in increasing order.
+    let mut order = (0..len).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| compare(i, j));
+
+    let mut first_indices = Vec::new();
+    let mut counts = Vec::new();
+    let mut inverse = vec![0; len];
+    let mut start = 0;
+    while start < len {
+        let first = order[start];
+        let mut end = start + 1;
+        while end < len && compare(first, order[end]) == Ordering::Equal {
+            end += 1;
+        }
+        for &i in &order[start..end] {
+            inverse[i] = first_indices.len();
+        }
+        first_indices.push(first);
+        counts.push(end - start);
+        start = end;
+    }
+    (first_indices, counts, inverse)
+}
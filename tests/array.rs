@@ -776,6 +776,38 @@ fn diag()
     assert_eq!(d.dim(), 1);
 }
 
+#[test]
+fn trace()
+{
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(a.trace(), 6);
+    assert_eq!(a.t().trace(), 6);
+    assert_eq!(a.slice(s![.., ..;-1]).trace(), 8);
+    assert_eq!(a.slice(s![.., 1..]).trace(), 8);
+    assert_eq!(Array2::<i32>::zeros((0, 3)).trace(), 0);
+    let b = Array::from_shape_fn((4, 4).f(), |(i, j)| i * 10 + j);
+    assert_eq!(b.trace(), b.diag().sum());
+    assert_eq!(b.slice(s![..;2, ..;2]).trace(), 22);
+}
+
+#[test]
+fn trace_offset()
+{
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(a.trace_offset(0), a.trace());
+    assert_eq!(a.trace_offset(1), 2 + 6);
+    assert_eq!(a.trace_offset(2), 3);
+    assert_eq!(a.trace_offset(3), 0);
+    assert_eq!(a.trace_offset(-1), 4);
+    assert_eq!(a.trace_offset(-2), 0);
+    assert_eq!(a.trace_offset(isize::MIN), 0);
+    // transposing swaps super- and subdiagonals
+    for k in -3..=3 {
+        assert_eq!(a.t().trace_offset(k), a.trace_offset(-k));
+    }
+    assert_eq!(a.slice(s![..;-1, ..]).trace_offset(1), 5 + 3);
+}
+
 /// Check that the merged shape is correct.
 ///
 /// Note that this does not check the strides in the "merged" case!
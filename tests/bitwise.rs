@@ -0,0 +1,71 @@
+use ndarray::prelude::*;
+
+#[test]
+fn bit_planes_u8()
+{
+    let image = Array::from_iter((0..=255u8).step_by(17))
+        .into_shape_with_order((4, 4))
+        .unwrap();
+    let shifts = Array::from_iter(0..8u8)
+        .into_shape_with_order((8, 1, 1))
+        .unwrap();
+    let planes = image.shr_elem(&shifts).unwrap() & 1;
+    assert_eq!(planes.shape(), &[8, 4, 4]);
+    for (k, plane) in planes.outer_iter().enumerate() {
+        assert_eq!(plane, image.mapv(|x| (x >> k) & 1));
+    }
+    // reassemble the image from its planes
+    let weights = shifts.mapv(|k| 1u8 << k);
+    let mut restored = Array2::zeros((4, 4));
+    for (plane, weight) in planes.outer_iter().zip(&weights) {
+        restored |= &(&plane * *weight);
+    }
+    assert_eq!(restored, image);
+    assert_eq!(planes.index_axis(Axis(0), 0).shl_elem(&shifts).unwrap(), &planes.index_axis(Axis(0), 0) << &shifts);
+}
+
+#[test]
+fn scalar_mask()
+{
+    let flags = array![[0b0101u32, 0b1111], [0b0000, 0b1000]];
+    assert_eq!(&flags & 0b0011, array![[0b0001, 0b0011], [0b0000, 0b0000]]);
+    assert_eq!(&flags | 0b0001, array![[0b0101, 0b1111], [0b0001, 0b1001]]);
+    assert_eq!(&flags ^ 0b1111, array![[0b1010, 0b0000], [0b1111, 0b0111]]);
+    let mut f = flags.t().to_owned();
+    f &= 0b1100;
+    f >>= 2;
+    assert_eq!(f, array![[0b01, 0b00], [0b11, 0b10]]);
+    assert_eq!(!array![0u8, 0x0f], array![0xff, 0xf0]);
+    let mask = arr0(0b0110u32);
+    assert_eq!(flags.bitand_elem(&mask), Ok(&flags & 0b0110));
+}
+
+#[test]
+fn named_methods_broadcast()
+{
+    let a = array![[0b1100u16, 0b1010], [0b0110, 0b0001]];
+    let column = array![[0b1000u16], [0b0011]];
+    assert_eq!(a.bitand_elem(&column), Ok(array![[0b1000, 0b1000], [0b0010, 0b0001]]));
+    assert_eq!(a.bitor_elem(&column), Ok(array![[0b1100, 0b1010], [0b0111, 0b0011]]));
+    assert_eq!(a.bitxor_elem(&column), Ok(&a ^ &column));
+    assert_eq!(column.bitxor_elem(&a), a.bitxor_elem(&column));
+    let shifts = array![1u32, 2];
+    assert_eq!(a.shl_elem(&shifts), Ok(array![[0b11000, 0b101000], [0b1100, 0b0100]]));
+    assert_eq!(a.shr_elem(&shifts), Ok(array![[0b110, 0b10], [0b11, 0b0]]));
+    // signed integers are shifted arithmetically
+    let b = array![-8i32, 8];
+    assert_eq!(b.shr_elem(&shifts), Ok(array![-4, 2]));
+    let bad = array![1u16, 2, 3];
+    assert!(a.bitand_elem(&bad).is_err());
+    assert!(a.shl_elem(&bad).is_err());
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "overflow")]
+fn shift_out_of_range()
+{
+    let a = array![1u8, 2];
+    let shifts = array![1u8, 8];
+    let _ = a.shl_elem(&shifts);
+}
@@ -0,0 +1,114 @@
+use ndarray::prelude::*;
+#[cfg(any(feature = "std", feature = "libm"))]
+use ndarray::RoundMode;
+
+#[test]
+fn cast_float_to_int_is_lossy()
+{
+    let a = array![[0.9, -0.9, 1e10], [-1e10, f64::INFINITY, f64::NAN]];
+    assert_eq!(a.cast::<i32>(), array![[0, 0, i32::MAX], [i32::MIN, i32::MAX, 0]]);
+    assert_eq!(a.cast::<u8>(), array![[0, 0, 255], [0, 255, 0]]);
+    assert_eq!(array![300i32, -1].cast::<u8>(), array![44, 255]);
+}
+
+#[test]
+fn cast_widening()
+{
+    let a = array![[i16::MIN, -1], [0, i16::MAX]];
+    assert_eq!(a.cast::<i64>(), array![[-32768i64, -1], [0, 32767]]);
+    assert_eq!(a.try_cast::<i64>(), Ok(a.cast::<i64>()));
+    assert_eq!(a.try_cast::<f32>(), Ok(array![[-32768f32, -1.], [0., 32767.]]));
+    let b = array![1u8, 200];
+    assert_eq!(b.try_cast::<u64>(), Ok(array![1u64, 200]));
+    assert_eq!(b.try_cast::<i16>(), Ok(array![1i16, 200]));
+}
+
+#[test]
+fn try_cast_out_of_range()
+{
+    let a = array![[1., 2.5], [1e20, -1e20]];
+    assert_eq!(a.try_cast::<i32>().unwrap_err().index(), &[1, 0]);
+    assert_eq!(a.t().try_cast::<i32>().unwrap_err().index(), &[0, 1]);
+    assert_eq!(a.row(0).try_cast::<i32>(), Ok(array![1, 2]));
+    assert_eq!(array![127i32, 128, -129].try_cast::<i8>().unwrap_err().index(), &[1]);
+    assert_eq!(array![3i32, -3].try_cast::<u32>().unwrap_err().index(), &[1]);
+}
+
+#[test]
+fn try_cast_nan()
+{
+    let a = array![[0., 1.], [2., f64::NAN]];
+    assert_eq!(a.try_cast::<i64>().unwrap_err().index(), &[1, 1]);
+    assert_eq!(array![f64::NEG_INFINITY].try_cast::<u16>().unwrap_err().index(), &[0]);
+    // conversions between floats keep non-finite values
+    let b = array![f64::NAN, f64::INFINITY, 1e300]
+        .try_cast::<f32>()
+        .unwrap();
+    assert!(b[0].is_nan());
+    assert_eq!(b.slice(s![1..]), array![f32::INFINITY, f32::INFINITY]);
+}
+
+#[test]
+fn try_cast_strided_and_empty()
+{
+    let a = Array::from_iter(0..24i64)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    let v = a.slice(s![.., ..;-1, 1..;2]);
+    assert_eq!(v.try_cast::<u8>(), Ok(v.cast::<u8>()));
+    let mut b = a.clone();
+    b[[1, 0, 3]] = 1000;
+    assert_eq!(b.slice(s![.., ..;-1, 1..;2]).try_cast::<u8>().unwrap_err().index(), &[1, 2, 1]);
+    assert_eq!(Array2::<f64>::zeros((0, 3)).try_cast::<u8>(), Ok(Array2::zeros((0, 3))));
+    let e = array![u64::MAX].try_cast::<i64>().unwrap_err();
+    assert_eq!(e.to_string(), "CastError: the element at index [0] can not be represented in the target type");
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn cast_rounded_half_way()
+{
+    let a = array![-2.5f32, -1.5, -0.5, 0.5, 1.5, 2.5];
+    let expected = [
+        (RoundMode::Nearest, [-3, -2, -1, 1, 2, 3]),
+        (RoundMode::TiesEven, [-2, -2, 0, 0, 2, 2]),
+        (RoundMode::Floor, [-3, -2, -1, 0, 1, 2]),
+        (RoundMode::Ceil, [-2, -1, 0, 1, 2, 3]),
+        (RoundMode::Trunc, [-2, -1, 0, 0, 1, 2]),
+    ];
+    for (mode, rounded) in expected {
+        assert_eq!(a.cast_rounded::<i8>(mode), Ok(Array::from(rounded.to_vec())), "{:?}", mode);
+        assert_eq!(a.slice(s![..;-1]).cast_rounded::<i32>(mode).unwrap(), a.cast_rounded::<i32>(mode).unwrap().slice(s![..;-1]));
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn cast_rounded_boundaries()
+{
+    let a = array![127.4, -128.4];
+    assert_eq!(a.cast_rounded::<i8>(RoundMode::Nearest), Ok(array![127, -128]));
+    assert_eq!(a.cast_rounded::<i8>(RoundMode::Trunc), Ok(array![127, -128]));
+    assert_eq!(a.cast_rounded::<i8>(RoundMode::Ceil).unwrap_err().index(), &[0]);
+    assert_eq!(a.cast_rounded::<i8>(RoundMode::Floor).unwrap_err().index(), &[1]);
+    let b = array![[255.4, 0.2], [-0.4, -0.6]];
+    assert_eq!(b.row(0).cast_rounded::<u8>(RoundMode::Nearest), Ok(array![255, 0]));
+    assert_eq!(b.row(0).cast_rounded::<u8>(RoundMode::Ceil).unwrap_err().index(), &[0]);
+    assert_eq!(b.cast_rounded::<u8>(RoundMode::Ceil).unwrap_err().index(), &[0, 0]);
+    assert_eq!(b.cast_rounded::<u8>(RoundMode::Trunc), Ok(array![[255, 0], [0, 0]]));
+    assert_eq!(b.cast_rounded::<u8>(RoundMode::Nearest).unwrap_err().index(), &[1, 1]);
+    assert_eq!(b.t().cast_rounded::<u8>(RoundMode::Floor).unwrap_err().index(), &[0, 1]);
+    assert_eq!(array![2147483647.4f64].cast_rounded::<i32>(RoundMode::Nearest), Ok(array![i32::MAX]));
+    assert!(array![2147483647.6f64].cast_rounded::<i32>(RoundMode::Nearest).is_err());
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn cast_rounded_non_finite()
+{
+    let a = array![1., f64::NAN, f64::INFINITY];
+    assert_eq!(a.cast_rounded::<i64>(RoundMode::Nearest).unwrap_err().index(), &[1]);
+    assert_eq!(a.slice(s![..;-1]).cast_rounded::<i64>(RoundMode::Trunc).unwrap_err().index(), &[0]);
+    assert_eq!(a.cast_rounded::<f32>(RoundMode::Floor).unwrap_err().index(), &[1]);
+    assert_eq!(a.slice(s![..1]).cast_rounded::<f32>(RoundMode::Floor), Ok(array![1.]));
+}
@@ -0,0 +1,142 @@
+#![allow(clippy::float_cmp)]
+#![cfg(feature = "std")]
+
+use ndarray::prelude::*;
+use ndarray::ErrorKind;
+
+#[test]
+fn abs_diff_max()
+{
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f64);
+    let mut b = a.clone();
+    b[[1, 2]] += 0.5;
+    b[[2, 0]] -= 2.;
+    assert_eq!(a.abs_diff_max(&b).unwrap(), Some(2.));
+    assert_eq!(b.abs_diff_max(&a).unwrap(), Some(2.));
+    assert_eq!(a.t().abs_diff_max(&b.t()).unwrap(), Some(2.));
+    assert_eq!(a.abs_diff_max(&a).unwrap(), Some(0.));
+    // broadcasting
+    let row = array![0., 1., 2., 3.];
+    assert_eq!(a.abs_diff_max(&row).unwrap(), Some(8.));
+    let scalar = Array::from_elem((), 5.);
+    assert_eq!(a.abs_diff_max(&scalar).unwrap(), Some(6.));
+    assert_eq!(Array2::<f64>::zeros((0, 3)).abs_diff_max(&Array2::zeros((0, 3))), Ok(None));
+}
+
+#[test]
+fn abs_diff_max_nan()
+{
+    let a = array![1., f64::NAN, 3.];
+    let b = array![1., 2., 30.];
+    assert!(a.abs_diff_max(&b).unwrap().unwrap().is_nan());
+    assert!(b.abs_diff_max(&a).unwrap().unwrap().is_nan());
+    let inf = array![f64::INFINITY, 0.];
+    assert!(inf.abs_diff_max(&inf).unwrap().unwrap().is_nan());
+    let zeros = array![0., 0.];
+    assert_eq!(inf.abs_diff_max(&zeros).unwrap(), Some(f64::INFINITY));
+}
+
+#[test]
+fn close_incompatible_shapes()
+{
+    let a = Array2::<f64>::zeros((2, 3));
+    let b = Array1::<f64>::zeros(2);
+    let kind = ErrorKind::IncompatibleShape;
+    assert_eq!(a.abs_diff_max(&b).unwrap_err().kind(), kind);
+    assert_eq!(b.abs_diff_max(&a).unwrap_err().kind(), kind);
+    assert_eq!(a.allclose(&b, 1., 1., true).unwrap_err().kind(), kind);
+    assert_eq!(b.allclose(&a, 1., 1., true).unwrap_err().kind(), kind);
+    assert_eq!(a.isclose(&b, 1., 1., true).unwrap_err().kind(), kind);
+    assert_eq!(b.isclose(&a, 1., 1., true).unwrap_err().kind(), kind);
+    // even if one of them is empty
+    let empty = Array1::<f64>::zeros(0);
+    assert_eq!(a.abs_diff_max(&empty).unwrap_err().kind(), kind);
+    assert_eq!(a.allclose(&empty, 1., 1., true).unwrap_err().kind(), kind);
+    assert_eq!(a.isclose(&empty, 1., 1., true).unwrap_err().kind(), kind);
+}
+
+#[test]
+fn allclose()
+{
+    let a = array![[1., 2.], [3., 4.]];
+    let b = &a + 1e-9;
+    assert!(a.allclose(&b, 0., 1e-8, false).unwrap());
+    assert!(!a.allclose(&b, 0., 1e-10, false).unwrap());
+    assert!(a.allclose(&b, 2e-9, 0., false).unwrap());
+    assert!(!a.allclose(&b, 1e-10, 0., false).unwrap());
+    // rtol is relative to `other`
+    let x = array![9.];
+    let y = array![10.];
+    assert!(x.allclose(&y, 0.1, 0., false).unwrap());
+    assert!(!y.allclose(&x, 0.1, 0., false).unwrap());
+    // broadcasting
+    let row = array![1., 2.];
+    assert!(array![[1., 2.], [1., 2.]].allclose(&row, 0., 0., false).unwrap());
+    let col = array![[1.], [10.]];
+    assert!(!a.allclose(&col, 0., 1., false).unwrap());
+}
+
+#[test]
+fn allclose_nan_and_infinity()
+{
+    let a = array![1., f64::NAN, f64::INFINITY];
+    assert!(!a.allclose(&a, 1e-5, 1e-8, false).unwrap());
+    assert!(a.allclose(&a, 1e-5, 1e-8, true).unwrap());
+    let b = array![1., 1., f64::INFINITY];
+    assert!(!a.allclose(&b, 1e-5, 1e-8, true).unwrap());
+    let inf = array![f64::INFINITY];
+    let neg_inf = array![f64::NEG_INFINITY];
+    let big = array![f64::MAX];
+    assert!(!inf.allclose(&neg_inf, 1., 1., false).unwrap());
+    assert!(!inf.allclose(&big, 1., 1., false).unwrap());
+    assert!(!big.allclose(&inf, 1., 1., false).unwrap());
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn allclose_consistent_with_approx()
+{
+    fn prop(xs: Vec<(f64, f64)>, eps: f64) -> bool
+    {
+        let eps = eps.abs();
+        if !eps.is_finite() || xs.iter().any(|&(x, y)| !x.is_finite() || !y.is_finite()) {
+            return true;
+        }
+        let a = Array::from_iter(xs.iter().map(|&(x, _)| x));
+        // make about half of the differences smaller than `eps`
+        let b = Array::from_iter(xs.iter().map(|&(x, y)| x + y % (2. * eps + 1.)));
+        let expected = a.abs_diff_eq(&b, eps);
+        a.allclose(&b, 0., eps, false).unwrap() == expected
+            && a.abs_diff_max(&b).unwrap().map_or(true, |max| max <= eps) == expected
+    }
+    quickcheck::quickcheck(prop as fn(Vec<(f64, f64)>, f64) -> bool);
+}
+
+#[test]
+fn isclose_broadcast_scalar()
+{
+    let a = array![[1., 2., 3.], [2., 2.00001, 1.99]];
+    let two = Array::from_elem((), 2.);
+    let mask = a.isclose(&two, 1e-5, 1e-8, false).unwrap();
+    assert_eq!(mask, array![[false, true, false], [true, true, false]]);
+    assert_eq!(mask.len(), 6);
+    let mask = two.isclose(&a, 1e-5, 1e-8, false).unwrap();
+    assert_eq!(mask.shape(), &[2, 3]);
+    // broadcasting in both operands
+    let col = array![[1.], [2.]];
+    let row = array![1., 2., 1.];
+    assert_eq!(col.isclose(&row, 0., 0., false).unwrap(), array![[true, false, true], [false, true, false]]);
+}
+
+#[test]
+fn isclose_nan_and_infinity()
+{
+    let inf = f64::INFINITY;
+    let nan = f64::NAN;
+    let a = array![inf, inf, -inf, nan, nan, 1., f64::MAX];
+    let b = array![inf, -inf, -inf, nan, 1., nan, inf];
+    assert_eq!(a.isclose(&b, 1., 1., false).unwrap(), array![true, false, true, false, false, false, false]);
+    assert_eq!(a.isclose(&b, 1., 1., true).unwrap(), array![true, false, true, true, false, false, false]);
+    let mask = a.isclose(&b, 1e-5, 1e-8, true).unwrap();
+    assert_eq!(mask.iter().all(|&x| x), a.allclose(&b, 1e-5, 1e-8, true).unwrap());
+}
@@ -0,0 +1,59 @@
+use ndarray::prelude::*;
+
+#[test]
+fn scalar_threshold_3d()
+{
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 12 + j * 4 + k) as i32 - 10);
+    let gt = a.gt_scalar(0);
+    assert_eq!(gt.shape(), &[2, 3, 4]);
+    assert_eq!(gt.count_where(|&x| x), 13);
+    assert_eq!(a.ge_scalar(0).count_where(|&x| x), 14);
+    assert_eq!(a.lt_scalar(0), gt.or(&a.eq_scalar(0)).unwrap().not());
+    assert_eq!(a.le_scalar(0), gt.not());
+    assert_eq!(a.eq_scalar(3).count_where(|&x| x), 1);
+    assert_eq!(a.ne_scalar(3), a.eq_scalar(3).not());
+    for ((i, j, k), &x) in gt.indexed_iter() {
+        assert_eq!(x, a[[i, j, k]] > 0);
+    }
+    // the mask follows the layout of the array
+    let t = a.t();
+    assert_eq!(t.gt_scalar(0), gt.t());
+}
+
+#[test]
+fn row_against_matrix()
+{
+    let m = array![[1, 5, 3], [4, 2, 6]];
+    let row = array![2, 5, 7];
+    assert_eq!(m.gt(&row), Ok(array![[false, false, false], [true, false, false]]));
+    assert_eq!(m.ge(&row), Ok(array![[false, true, false], [true, false, false]]));
+    assert_eq!(m.lt(&row), Ok(array![[true, false, true], [false, true, true]]));
+    assert_eq!(m.le(&row), Ok(array![[true, true, true], [false, true, true]]));
+    assert_eq!(m.eq_elem(&row), Ok(array![[false, true, false], [false, false, false]]));
+    assert_eq!(m.ne_elem(&row), Ok(array![[true, false, true], [true, true, true]]));
+    // the row can also be on the left
+    assert_eq!(row.lt(&m), m.gt(&row));
+    let column = array![[2], [4]];
+    assert_eq!(m.ge(&column), Ok(array![[false, true, true], [true, false, true]]));
+    let bad = array![1, 2];
+    assert!(m.gt(&bad).is_err());
+    assert!(m.eq_elem(&bad).is_err());
+}
+
+#[test]
+fn nan_compares_false()
+{
+    let nan = f64::NAN;
+    let a = array![[nan, 1.], [2., nan]];
+    let b = array![1., nan];
+    let gt = a.gt(&b).unwrap();
+    let le = a.le(&b).unwrap();
+    assert_eq!(gt, array![[false, false], [true, false]]);
+    assert_eq!(le, array![[false, false], [false, false]]);
+    assert_eq!(a.gt_scalar(0.), array![[false, true], [true, false]]);
+    assert_eq!(a.le_scalar(5.), array![[false, true], [true, false]]);
+    assert_eq!(a.eq_elem(&a), Ok(array![[false, true], [true, false]]));
+    assert_eq!(a.ne_elem(&a), Ok(array![[true, false], [false, true]]));
+    assert_eq!(a.ge_scalar(nan), Array::from_elem((2, 2), false));
+    assert_eq!(a.lt_scalar(nan), Array::from_elem((2, 2), false));
+}
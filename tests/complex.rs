@@ -23,3 +23,46 @@ fn complex_mat_mul()
         arr1(&[c(1.5, 1.), c(2.5, 0.)])
     );
 }
+
+#[test]
+#[cfg(feature = "std")]
+fn var_complex_matches_parts()
+{
+    let a = Array::from_shape_fn((4, 5, 6), |(i, j, k)| {
+        c(((i * 7 + j * 3 + k * k) % 11) as f64 * 0.5, (i as f64 - j as f64) * 1.25 + k as f64)
+    });
+    let close = |x: f64, y: f64| (x - y).abs() <= 1e-12 * y.abs().max(1.);
+    for ddof in [0., 1., 2.5] {
+        let Complex { re, im } = a.view().split_complex();
+        let expected = re.var(ddof) + im.var(ddof);
+        assert!(close(a.var_complex(ddof), expected));
+        assert!(close(a.std_complex(ddof), expected.sqrt()));
+        for axis in 0..3 {
+            let axis = Axis(axis);
+            let expected = re.var_axis(axis, ddof) + im.var_axis(axis, ddof);
+            let var = a.var_axis_complex(axis, ddof);
+            assert_eq!(var.shape(), expected.shape());
+            assert!(var.iter().zip(&expected).all(|(&v, &e)| close(v, e)));
+            let std = a.std_axis_complex(axis, ddof);
+            assert!(std.iter().zip(&expected).all(|(&s, &e)| close(s, e.sqrt())));
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn var_complex_empty()
+{
+    assert!(Array::<Complex<f64>, _>::zeros(0).var_complex(0.).is_nan());
+    let v = Array::<Complex<f32>, _>::zeros((2, 0)).var_axis_complex(Axis(1), 0.);
+    assert_eq!(v.shape(), &[2]);
+    assert!(v.iter().all(|x| x.is_nan()));
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic]
+fn var_complex_ddof_too_large()
+{
+    arr1(&[c(1., 2.), c(3., 4.)]).var_complex(3.);
+}
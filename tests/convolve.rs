@@ -0,0 +1,219 @@
+#![allow(clippy::float_cmp)]
+
+use ndarray::prelude::*;
+use ndarray::ConvMode;
+#[cfg(any(feature = "std", feature = "libm"))]
+use ndarray::Zip;
+
+/// Return the convolution of `x` with `kernel` in `ConvMode::Full`, from the
+/// definition.
+fn full_convolution(x: &[f64], kernel: &[f64]) -> Vec<f64>
+{
+    let mut res = vec![0.; x.len() + kernel.len() - 1];
+    for (i, &x) in x.iter().enumerate() {
+        for (j, &w) in kernel.iter().enumerate() {
+            res[i + j] += x * w;
+        }
+    }
+    res
+}
+
+#[test]
+fn convolve_impulse()
+{
+    let a = Array::from_shape_fn((3, 5), |(i, j)| (i * 5 + j * j) as f64);
+    let one = array![1.];
+    let odd = array![0., 1., 0.];
+    let even = array![1., 0.];
+    let shift = array![0., 0., 1.];
+    for axis in 0..2 {
+        let axis = Axis(axis);
+        assert_eq!(a.convolve(&one, axis, ConvMode::Valid), a);
+        assert_eq!(a.convolve(&one, axis, ConvMode::Same), a);
+        assert_eq!(a.convolve(&one, axis, ConvMode::Full), a);
+        assert_eq!(a.convolve(&odd, axis, ConvMode::Same), a);
+        assert_eq!(a.convolve(&even, axis, ConvMode::Same), a);
+        assert_eq!(a.correlate(&even.slice(s![..;-1]), axis, ConvMode::Same), a);
+        // a shifted impulse shifts the array
+        let shifted = a.convolve(&shift, axis, ConvMode::Full);
+        assert_eq!(shifted.slice_axis(axis, (2..).into()), a);
+        assert!(shifted.slice_axis(axis, (..2).into()).iter().all(|&x| x == 0.));
+    }
+}
+
+#[test]
+fn convolve_matches_definition()
+{
+    let x = [3., -1., 4., 1., -5., 9., 2.];
+    for m in 1..10 {
+        let kernel: Vec<f64> = (0..m).map(|j| (j * j) as f64 - 2.).collect();
+        let full = full_convolution(&x, &kernel);
+        let a = aview1(&x);
+        let kernel = Array::from(kernel);
+        assert_eq!(a.convolve(&kernel, Axis(0), ConvMode::Full).to_vec(), full);
+        let offset = (m - 1) / 2;
+        assert_eq!(a.convolve(&kernel, Axis(0), ConvMode::Same).to_vec(), &full[offset..offset + x.len()]);
+        let valid = a.convolve(&kernel, Axis(0), ConvMode::Valid);
+        if m <= x.len() {
+            assert_eq!(valid.to_vec(), &full[m - 1..x.len()]);
+        } else {
+            assert_eq!(valid.len(), 0);
+        }
+        // correlation is convolution with the reversed kernel
+        let reversed = kernel.slice(s![..;-1]);
+        for &mode in &[ConvMode::Valid, ConvMode::Same, ConvMode::Full] {
+            assert_eq!(a.correlate(&reversed, Axis(0), mode), a.convolve(&kernel, Axis(0), mode));
+        }
+    }
+}
+
+#[test]
+fn convolve_same_centered()
+{
+    let a = array![1., 2., 3., 4., 5.];
+    // odd length: the middle element of the kernel meets element `i`
+    let ones = Array::ones(3);
+    assert_eq!(a.convolve(&ones, Axis(0), ConvMode::Same), array![3., 6., 9., 12., 9.]);
+    let last = array![0., 0., 1.];
+    assert_eq!(a.convolve(&last, Axis(0), ConvMode::Same), array![0., 1., 2., 3., 4.]);
+    assert_eq!(a.correlate(&last, Axis(0), ConvMode::Same), array![2., 3., 4., 5., 0.]);
+    // even length: the first of the two middle elements meets element `i`
+    let ones = Array::ones(2);
+    assert_eq!(a.convolve(&ones, Axis(0), ConvMode::Same), array![1., 3., 5., 7., 9.]);
+    let ones = Array::ones(4);
+    assert_eq!(a.convolve(&ones, Axis(0), ConvMode::Same), array![3., 6., 10., 14., 12.]);
+    let impulse = array![0., 1., 0., 0.];
+    assert_eq!(a.convolve(&impulse, Axis(0), ConvMode::Same), a);
+    assert_eq!(a.correlate(&impulse.slice(s![..;-1]), Axis(0), ConvMode::Same), a);
+    // the kernel is longer than the array
+    let ones = Array::ones(7);
+    assert_eq!(a.convolve(&ones, Axis(0), ConvMode::Same), array![10., 15., 15., 15., 14.]);
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn convolve_box_kernel_is_rolling_mean()
+{
+    let a = Array::from_shape_fn((4, 9), |(i, j)| ((i * 7 + j * 3) % 11) as f64 - 5.);
+    for window in 1..=9 {
+        let kernel = Array::from_elem(window, 1. / window as f64);
+        let means = a.rolling(Axis(1), window).mean();
+        let convolved = a.convolve(&kernel, Axis(1), ConvMode::Valid);
+        assert_eq!(convolved.shape(), means.shape());
+        Zip::from(&convolved)
+            .and(&means)
+            .for_each(|&x, &y| assert!((x - y).abs() < 1e-12, "{} != {}", x, y));
+    }
+}
+
+#[test]
+fn convolve_strided_lanes()
+{
+    let a = Array::from_shape_fn((5, 6, 7), |(i, j, k)| (i * 42 + j * 7 + k) as i64 % 13 - 6);
+    let v = a.slice(s![..;-1, .., ..;2]);
+    let c = v.to_owned();
+    let kernel = array![2, -1, 3, 1];
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        for &mode in &[ConvMode::Valid, ConvMode::Same, ConvMode::Full] {
+            assert_eq!(v.convolve(&kernel, axis, mode), c.convolve(&kernel, axis, mode));
+            assert_eq!(
+                c.t().correlate(&kernel.slice(s![..;2]), axis, mode),
+                c.t().to_owned().correlate(&kernel.slice(s![..;2]).to_owned(), axis, mode)
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "kernel must not be empty")]
+fn convolve_empty_kernel()
+{
+    array![1., 2.].convolve(&Array1::zeros(0), Axis(0), ConvMode::Same);
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn argmax(a: &Array1<f64>) -> usize
+{
+    (0..a.len()).fold(0, |best, i| if a[i] > a[best] { i } else { best })
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn cross_correlate_peak_lag()
+{
+    let pulse = array![3., 1., -2., 5., 0.5];
+    let m = pulse.len();
+    // the signal is the pulse delayed by 4 elements
+    let mut signal = Array::from_shape_fn(12, |i| ((i * 7) % 5) as f64 * 0.1);
+    signal.slice_mut(s![4..4 + m]).assign(&pulse);
+    let c = signal
+        .cross_correlate(&pulse, ConvMode::Full, false)
+        .unwrap();
+    assert_eq!(c.len(), signal.len() + m - 1);
+    // element `i` of the full correlation is lag `i - (m - 1)`
+    assert_eq!(argmax(&c), 4 + m - 1);
+    let c = signal
+        .cross_correlate(&pulse, ConvMode::Same, false)
+        .unwrap();
+    assert_eq!(argmax(&c), 4 + m / 2);
+    let c = signal
+        .cross_correlate(&pulse, ConvMode::Valid, false)
+        .unwrap();
+    assert_eq!(argmax(&c), 4);
+    // swapping the arrays negates the lags
+    let c = pulse
+        .cross_correlate(&signal, ConvMode::Full, false)
+        .unwrap();
+    assert_eq!(argmax(&c), signal.len() - 1 - 4);
+    // normalized, the windows that overlap partially can correlate
+    // perfectly, but only the delay matches all of the pulse
+    let c = signal
+        .cross_correlate(&pulse, ConvMode::Full, true)
+        .unwrap();
+    assert_eq!(c[c.len() - 1], 1.);
+    let c = signal
+        .cross_correlate(&pulse, ConvMode::Valid, true)
+        .unwrap();
+    assert_eq!(argmax(&c), 4);
+    assert!((c[4] - 1.).abs() < 1e-15);
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn cross_correlate_normalized()
+{
+    let x = Array::from_shape_fn(20, |i| ((i * 37 + 11) % 17) as f64 - 8.);
+    let y = array![2., -1., 0., 4.];
+    let c = x.cross_correlate(&y, ConvMode::Full, false).unwrap();
+    assert_eq!(c, x.correlate(&y, Axis(0), ConvMode::Full));
+    let normalized = x.cross_correlate(&y, ConvMode::Full, true).unwrap();
+    assert!(normalized.iter().all(|&r| (-1. ..=1.).contains(&r)));
+    // the first lag only multiplies `x[0]` with `y[3]`
+    assert_eq!(normalized[0], c[0].signum());
+    // the correlation is invariant to scaling of either array
+    let scaled = (&x * 3.)
+        .cross_correlate(&(&y * 0.5), ConvMode::Full, true)
+        .unwrap();
+    Zip::from(&scaled)
+        .and(&normalized)
+        .for_each(|&r, &s| assert!((r - s).abs() < 1e-12));
+    // windows of zeros have no correlation
+    let c = array![0., 0., 1.]
+        .cross_correlate(&y, ConvMode::Full, true)
+        .unwrap();
+    assert!(c[0].is_nan() && c[1].is_nan());
+    assert!(c.slice(s![2..]).iter().all(|r| !r.is_nan()));
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn cross_correlate_empty()
+{
+    use ndarray::StatsError;
+
+    let x = array![1., 2.];
+    let empty = Array1::<f64>::zeros(0);
+    assert_eq!(x.cross_correlate(&empty, ConvMode::Full, false), Err(StatsError::EmptyInput));
+    assert_eq!(empty.cross_correlate(&x, ConvMode::Same, true), Err(StatsError::EmptyInput));
+}
@@ -0,0 +1,146 @@
+#![allow(clippy::float_cmp)]
+#![cfg(feature = "std")]
+
+use approx::{assert_abs_diff_eq, assert_relative_eq};
+use ndarray::prelude::*;
+
+#[cfg(feature = "approx")]
+fn fixture() -> Array2<f64>
+{
+    array![[1., 2., 4., 7.], [3., 1., 0., -2.], [2.5, 2.5, 3., 4.]]
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn cov()
+{
+    let a = fixture();
+    let sample = array![
+        [7., -16. / 3., 11. / 6.],
+        [-16. / 3., 13. / 3., -4. / 3.],
+        [11. / 6., -4. / 3., 0.5]
+    ];
+    let population = array![[21. / 4., -4., 11. / 8.], [-4., 13. / 4., -1.], [11. / 8., -1., 3. / 8.]];
+    assert_abs_diff_eq!(a.cov(1.), sample, epsilon = 1e-14);
+    assert_abs_diff_eq!(a.cov(0.), population, epsilon = 1e-14);
+    assert_abs_diff_eq!(a.t().cov_of_columns(1.), sample, epsilon = 1e-14);
+    assert_abs_diff_eq!(a.reversed_axes().cov_of_columns(0.), population, epsilon = 1e-14);
+}
+
+#[test]
+fn cov_diagonal_is_var()
+{
+    let a = Array::from_shape_fn((4, 7), |(i, j)| ((i * 7 + j) as f64).sin() * (i + 1) as f64);
+    for &ddof in &[0., 1., 2.5] {
+        let cov = a.cov(ddof);
+        let var = a.var_axis(Axis(1), ddof);
+        for (&c, &v) in cov.diag().iter().zip(&var) {
+            assert_relative_eq!(c, v, max_relative = 1e-14);
+        }
+        assert_eq!(cov, cov.t());
+    }
+}
+
+#[test]
+fn cov_degenerate()
+{
+    let a = array![[1., 2., 3.]];
+    assert_eq!(a.cov(3.), array![[f64::INFINITY]]);
+    assert!(Array2::<f64>::zeros((2, 0)).cov(0.).iter().all(|x| x.is_nan()));
+    assert_eq!(Array2::<f64>::zeros((0, 3)).cov(1.), Array2::zeros((0, 0)));
+}
+
+#[test]
+#[should_panic]
+fn cov_ddof_too_large()
+{
+    array![[1., 2., 3.]].cov(4.);
+}
+
+#[test]
+#[should_panic]
+fn cov_ddof_negative()
+{
+    array![[1., 2., 3.]].cov(-1.);
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn pearson_correlation()
+{
+    let x = Array::linspace(-2., 3., 11);
+    let mut a = Array2::zeros((3, 11));
+    a.row_mut(0).assign(&x);
+    a.row_mut(1).assign(&x.mapv(|x| 0.5 - 3. * x));
+    a.row_mut(2).assign(&x.mapv(|x| 7. * x + 1e3));
+    let corr = a.pearson_correlation();
+    let expected = array![[1., -1., 1.], [-1., 1., -1.], [1., -1., 1.]];
+    assert_abs_diff_eq!(corr, expected, epsilon = 1e-15);
+    assert_abs_diff_eq!(a.t().t().pearson_correlation(), expected, epsilon = 1e-15);
+    assert!(corr.iter().all(|c| (-1. ..=1.).contains(c)));
+
+    let a = fixture();
+    let cov = a.cov(1.);
+    let corr = a.pearson_correlation();
+    for ((i, j), &c) in corr.indexed_iter() {
+        let r = cov[[i, j]] / (cov[[i, i]] * cov[[j, j]]).sqrt();
+        assert_abs_diff_eq!(c, r, epsilon = 1e-15);
+    }
+}
+
+#[test]
+fn pearson_correlation_zero_variance()
+{
+    let a = array![[1., 2., 3.], [5., 5., 5.], [3., 1., 2f64]];
+    let corr = a.pearson_correlation();
+    for i in 0..3 {
+        assert!(corr[[1, i]].is_nan());
+        assert!(corr[[i, 1]].is_nan());
+    }
+    assert_eq!(corr[[0, 0]], 1.);
+    assert_abs_diff_eq!(corr[[0, 2]], -0.5, epsilon = 1e-15);
+    assert!(Array2::<f64>::zeros((2, 0)).pearson_correlation().iter().all(|x| x.is_nan()));
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn spearman_correlation_monotone()
+{
+    let x = Array::linspace(-2., 3., 20);
+    let mut a = Array2::zeros((3, 20));
+    a.row_mut(0).assign(&x);
+    a.row_mut(1).assign(&x.mapv(f64::exp));
+    a.row_mut(2).assign(&x.mapv(|x| -x * x * x));
+    let spearman = a.spearman_correlation();
+    let expected = array![[1., 1., -1.], [1., 1., -1.], [-1., -1., 1.]];
+    assert_abs_diff_eq!(spearman, expected, epsilon = 1e-15);
+    let pearson = a.pearson_correlation();
+    assert!(pearson[[0, 1]] < 0.95);
+    assert!(pearson[[0, 2]] > -0.95);
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn spearman_correlation_ties()
+{
+    // average ranks [1, 2.5, 2.5, 4] and [1, 3, 2, 4]
+    let a = array![[1., 2., 2., 3.], [10., 30., 20., 40.]];
+    let corr = a.spearman_correlation();
+    let r = 3. / 10f64.sqrt();
+    assert_abs_diff_eq!(corr, array![[1., r], [r, 1.]], epsilon = 1e-15);
+
+    // all tied
+    let a = array![[1., 2., 3.], [4., 4., 4f64]];
+    let corr = a.spearman_correlation();
+    assert!(corr.row(1).iter().all(|x| x.is_nan()));
+    assert_eq!(corr[[0, 0]], 1.);
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn spearman_correlation_nan_ranked_last()
+{
+    let a = array![[1., f64::NAN, 3.], [1., 5., 2.]];
+    let corr = a.spearman_correlation();
+    assert_abs_diff_eq!(corr, array![[1., 1.], [1., 1.]], epsilon = 1e-15);
+}
@@ -0,0 +1,92 @@
+#![allow(clippy::float_cmp)]
+
+use ndarray::prelude::*;
+
+#[test]
+fn euclid_i32_signs()
+{
+    let a = array![7, -7, 7, -7, 0, 6, -6];
+    let d = array![3, 3, -3, -3, 3, -3, 3];
+    assert_eq!(a.div_euclid(&d), Ok(array![2, -3, -2, 3, 0, -2, -2]));
+    assert_eq!(a.rem_euclid(&d), Ok(array![1, 2, 1, 2, 0, 0, 0]));
+    let q = a.div_euclid(&d).unwrap();
+    let r = a.rem_euclid(&d).unwrap();
+    assert_eq!(&q * &d + &r, a);
+    assert_eq!(a.rem_euclid_scalar(3), array![1, 2, 1, 2, 0, 0, 0]);
+    assert_eq!(a.rem_euclid_scalar(-3), a.rem_euclid_scalar(3));
+    assert_eq!(a.div_euclid_scalar(-3), array![-2, 3, -2, 3, 0, -2, 2]);
+    assert_eq!(array![i32::MIN].rem_euclid_scalar(i32::MAX), array![i32::MAX - 1]);
+}
+
+#[test]
+fn euclid_f64_signs()
+{
+    let a = array![7.5, -7.5, 7.5, -7.5, -0.];
+    let d = array![2., 2., -2., -2., 2.];
+    assert_eq!(a.div_euclid(&d), Ok(array![3., -4., -3., 4., -0.]));
+    assert_eq!(a.rem_euclid(&d), Ok(array![1.5, 0.5, 1.5, 0.5, 0.]));
+    // a tiny negative remainder can round up to the divisor
+    assert!((0. ..=1.).contains(&array![-1e-20].rem_euclid_scalar(1.)[0]));
+    let nan = f64::NAN;
+    let r = array![1., -1., f64::INFINITY, nan].rem_euclid_scalar(0.);
+    assert!(r.iter().all(|x| x.is_nan()));
+    let q = array![1., -1., 0.].div_euclid_scalar(0.);
+    assert_eq!(q.slice(s![..2]), array![f64::INFINITY, f64::NEG_INFINITY]);
+    assert!(q[2].is_nan());
+    assert!(array![nan].rem_euclid_scalar(2.)[0].is_nan());
+}
+
+#[test]
+fn euclid_broadcast_and_inplace()
+{
+    let a = array![[-5, -1, 4], [9, 10, -12]];
+    let n = array![[4], [5]];
+    let expected = array![[3, 3, 0], [4, 0, 3]];
+    assert_eq!(a.rem_euclid(&n), Ok(expected.clone()));
+    let row = array![2, 3, 4];
+    assert_eq!(n.div_euclid(&row), Ok(array![[2, 1, 1], [2, 1, 1]]));
+    let bad = array![1, 2];
+    assert!(a.rem_euclid(&bad).is_err());
+
+    let mut b = a.t().to_owned();
+    b.rem_euclid_inplace(&n.t()).unwrap();
+    assert_eq!(b, expected.t());
+    let mut c = a.clone();
+    c.div_euclid_inplace(&n).unwrap();
+    assert_eq!(c, array![[-2, -1, 1], [1, 2, -3]]);
+    let mut d = a.clone();
+    assert!(d.div_euclid_inplace(&bad).is_err());
+    assert_eq!(d, a);
+    d.rem_euclid_scalar_inplace(3);
+    assert_eq!(d, array![[1, 2, 1], [0, 1, 0]]);
+    d.div_euclid_scalar_inplace(-1);
+    assert_eq!(d, array![[-1, -2, -1], [0, -1, 0]]);
+    // an empty array can be divided by zero
+    assert_eq!(Array1::<i32>::zeros(0).rem_euclid_scalar(0), Array1::zeros(0));
+}
+
+#[test]
+#[should_panic(expected = "attempt to calculate the remainder with a divisor of zero at index [1, 0]")]
+fn rem_euclid_by_zero()
+{
+    let a = array![[1, 2], [3, 4]];
+    let d = array![[1], [0]];
+    let _ = a.rem_euclid(&d);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with a divisor of zero at index [0, 0]")]
+fn div_euclid_scalar_by_zero()
+{
+    let a = Array::from_elem(vec![2, 2], 7u8);
+    let _ = a.div_euclid_scalar(0);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with a divisor of zero at index [2]")]
+fn div_euclid_inplace_by_zero()
+{
+    let mut a = array![1, 2, 3];
+    let d = array![1, 1, 0];
+    a.div_euclid_inplace(&d).unwrap();
+}
@@ -0,0 +1,85 @@
+#![allow(clippy::float_cmp)]
+
+use ndarray::prelude::*;
+
+#[test]
+fn maximum_minimum_broadcast_column()
+{
+    let a = array![[1, 5, 3], [7, 2, 4]];
+    let column = array![[3], [4]];
+    assert_eq!(a.maximum(&column), Ok(array![[3, 5, 3], [7, 4, 4]]));
+    assert_eq!(a.minimum(&column), Ok(array![[1, 3, 3], [4, 2, 4]]));
+    // broadcasting is symmetric
+    assert_eq!(column.maximum(&a), a.maximum(&column));
+    let row = array![2, 2, 2];
+    assert_eq!(column.minimum(&row), Ok(array![[2, 2, 2], [2, 2, 2]]));
+    assert_eq!(a.t().maximum(&column.t()), Ok(array![[3, 7], [5, 4], [3, 4]]));
+    let bad = array![1, 2];
+    assert!(a.maximum(&bad).is_err());
+    assert!(a.minimum(&bad).is_err());
+}
+
+#[test]
+fn maximum_minimum_nan()
+{
+    let nan = f64::NAN;
+    let a = array![1., nan, nan, 3.];
+    let b = array![nan, 2., nan, 1.];
+    for c in [a.maximum(&b), b.maximum(&a), a.minimum(&b), b.minimum(&a)] {
+        assert!(c.unwrap().slice(s![..3]).iter().all(|x| x.is_nan()));
+    }
+    assert_eq!(a.maximum(&b).unwrap()[3], 3.);
+    assert_eq!(a.minimum(&b).unwrap()[3], 1.);
+    assert!(a.maximum_scalar(0.)[1].is_nan());
+    assert!(array![0.].maximum_scalar(nan)[0].is_nan());
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn fmax_fmin_ignore_nan()
+{
+    let nan = f64::NAN;
+    let a = array![1., nan, nan, 3.];
+    let b = array![nan, 2., nan, 1.];
+    assert_eq!(a.fmax(&b).unwrap().slice(s![..2]), array![1., 2.]);
+    assert_eq!(b.fmin(&a).unwrap().slice(s![..2]), array![1., 2.]);
+    assert!(a.fmax(&b).unwrap()[2].is_nan() && a.fmin(&b).unwrap()[2].is_nan());
+    assert_eq!(a.fmax(&b).unwrap()[3], 3.);
+    assert_eq!(a.fmin(&b).unwrap()[3], 1.);
+    let column = array![[0.], [nan]];
+    let c = column.fmax(&a).unwrap();
+    assert_eq!(c.row(0), array![1., 0., 0., 3.]);
+    assert_eq!(c[[1, 0]], 1.);
+    assert!(c[[1, 1]].is_nan() && c[[1, 2]].is_nan());
+    let bad = array![1., 2.];
+    assert!(a.fmin(&bad).is_err());
+}
+
+#[test]
+fn maximum_minimum_scalar()
+{
+    let a = array![[-2, 1], [0, 3]];
+    assert_eq!(a.maximum_scalar(0), array![[0, 1], [0, 3]]);
+    assert_eq!(a.t().minimum_scalar(1), array![[-2, 0], [1, 1]]);
+}
+
+#[test]
+fn maximum_minimum_assign()
+{
+    let mut a = array![[1., 5., 3.], [7., 2., 4.]];
+    let column = array![[3.], [4.]];
+    a.maximum_assign(&column).unwrap();
+    assert_eq!(a, array![[3., 5., 3.], [7., 4., 4.]]);
+    let row = array![4., 4., 4.];
+    a.minimum_assign(&row).unwrap();
+    assert_eq!(a, array![[3., 4., 3.], [4., 4., 4.]]);
+    let before = a.clone();
+    let bad = array![1., 2.];
+    assert!(a.maximum_assign(&bad).is_err());
+    // only `other` is broadcast
+    assert!(a.slice_mut(s![0..1, ..]).maximum_assign(&before).is_err());
+    assert_eq!(a, before);
+    let nan = array![f64::NAN];
+    a.minimum_assign(&nan).unwrap();
+    assert!(a.iter().all(|x| x.is_nan()));
+}
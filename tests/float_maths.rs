@@ -0,0 +1,442 @@
+#![allow(clippy::float_cmp)]
+
+use ndarray::prelude::*;
+
+#[test]
+fn clamp_boundaries()
+{
+    let a = array![-1., 0., 0.5, 1., 2.];
+    assert_eq!(a.clamp(0., 1.), array![0., 0., 0.5, 1., 1.]);
+    // a single allowed value
+    assert_eq!(a.clamp(0.5, 0.5), array![0.5, 0.5, 0.5, 0.5, 0.5]);
+    let inf = f64::INFINITY;
+    assert_eq!(array![-inf, inf].clamp(-1., 1.), array![-1., 1.]);
+    assert_eq!(a.clamp(-inf, inf), a);
+    // the sign of zero is kept for elements within the bounds
+    assert!(array![-0f64].clamp(-0., 1.)[0].is_sign_negative());
+
+    let mut b = a.clone();
+    b.slice_mut(s![..;-2]).clamp_inplace(0., 1.);
+    assert_eq!(b, array![0., 0., 0.5, 1., 1.]);
+    let mut c = array![[i32::MIN, -3], [3, i32::MAX]];
+    c.clamp_inplace(-3, 3);
+    assert_eq!(c, array![[-3, -3], [3, 3]]);
+}
+
+#[test]
+fn clamp_nan_passes_through()
+{
+    let nan = f64::NAN;
+    let a = array![nan, -2., nan, 2.];
+    let clamped = a.clamp(-1., 1.);
+    assert!(clamped[0].is_nan() && clamped[2].is_nan());
+    assert_eq!(clamped.slice(s![1..;2]), array![-1., 1.]);
+    let mut b = a.clone();
+    b.clamp_inplace(-1., 1.);
+    assert!(b[0].is_nan());
+    assert_eq!(b[3], 1.);
+    let min = array![0.];
+    let max = array![1.];
+    assert!(a.clamp_arrays(&min, &max)[2].is_nan());
+}
+
+#[test]
+#[should_panic(expected = "min must be less than or equal to max")]
+fn clamp_min_greater_than_max()
+{
+    array![1., 2.].clamp(2., 1.);
+}
+
+#[test]
+#[should_panic(expected = "min must be less than or equal to max")]
+fn clamp_inplace_nan_bound()
+{
+    array![1., 2.].clamp_inplace(f64::NAN, 1.);
+}
+
+#[test]
+fn clamp_arrays_broadcast()
+{
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as i32 - 4);
+    let min = array![-2, 0, 1, 3];
+    let max = array![[3], [5], [6]];
+    let expected = Array::from_shape_fn((3, 4), |(i, j)| a[[i, j]].max(min[j]).min(max[[i, 0]]));
+    assert_eq!(a.clamp_arrays(&min, &max), expected);
+    // scalar bounds are zero-dimensional arrays
+    assert_eq!(a.clamp_arrays(&arr0(-1), &arr0(2)), a.clamp(-1, 2));
+    let mut b = a.clone();
+    b.clamp_arrays_inplace(&min, &max);
+    assert_eq!(b, expected);
+    // on a view with a negative stride
+    let mut c = a.clone();
+    c.slice_mut(s![..;-1, ..])
+        .clamp_arrays_inplace(&min, &max.slice(s![..;-1, ..]));
+    assert_eq!(c, expected);
+}
+
+#[test]
+#[should_panic(expected = "min must be less than or equal to max")]
+fn clamp_arrays_min_greater_than_max()
+{
+    let min = array![0., 2.];
+    let max = array![1., 1.];
+    array![[0.5, 1.5]].clamp_arrays(&min, &max);
+}
+
+#[test]
+#[should_panic(expected = "min and max must broadcast to the shape of the array")]
+fn clamp_arrays_incompatible_shape()
+{
+    let min = array![0., 0., 0.];
+    array![[0.5, 1.5]].clamp_arrays(&min, &arr0(1.));
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn nan_to_num_mixed<A: num_traits::Float + std::fmt::Debug>()
+{
+    let (nan, inf) = (A::nan(), A::infinity());
+    let one = A::one();
+    let a = array![[nan, -inf, one], [inf, -one, nan]];
+    let (max, min) = (A::max_value(), A::min_value());
+    let expected = array![[A::zero(), min, one], [max, -one, A::zero()]];
+    assert_eq!(a.nan_to_num(), expected);
+    let mut b = a.clone();
+    b.nan_to_num_inplace();
+    assert_eq!(b, expected);
+    // on a transposed view
+    let mut c = a.clone();
+    c.view_mut().reversed_axes().nan_to_num_inplace();
+    assert_eq!(c, expected);
+
+    let two = one + one;
+    let expected = array![[-one, -two, one], [two, -one, -one]];
+    assert_eq!(a.nan_to_num_with(-one, two, -two), expected);
+    let mut b = a;
+    b.nan_to_num_with_inplace(-one, two, -two);
+    assert_eq!(b, expected);
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn nan_to_num()
+{
+    nan_to_num_mixed::<f32>();
+    nan_to_num_mixed::<f64>();
+    assert_eq!(array![f32::INFINITY].nan_to_num(), array![f32::MAX]);
+    assert_eq!(array![f64::NEG_INFINITY].nan_to_num(), array![f64::MIN]);
+    // finite values, including the extremes and negative zero, are unchanged
+    let a = array![f64::MAX, f64::MIN, f64::MIN_POSITIVE, -0.];
+    assert_eq!(a.nan_to_num(), a);
+    assert!(a.nan_to_num()[3].is_sign_negative());
+}
+
+#[cfg(feature = "std")]
+fn special_values() -> Array3<f64>
+{
+    let values = [1., f64::NAN, -f64::INFINITY, -0., f64::MAX, f64::INFINITY, f64::NAN];
+    Array::from_shape_fn((4, 5, 6), |(i, j, k)| values[(i * 31 + j * 7 + k) % values.len()])
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn masks_on_strided_views()
+{
+    let a = special_values();
+    let f = a.t().to_owned();
+    let views = [a.view(), a.slice(s![..;-1, 1..;2, ..;3]), a.slice(s![.., 2, ..;-4]).insert_axis(Axis(1)), f.t()];
+    for v in &views {
+        let (nan, finite, infinite) = (v.is_nan(), v.is_finite(), v.is_infinite());
+        for mask in [&nan, &finite, &infinite] {
+            assert_eq!(mask.shape(), v.shape());
+            assert!(mask.is_standard_layout());
+        }
+        for (((x, &nan), &finite), &infinite) in v.iter().zip(&nan).zip(&finite).zip(&infinite) {
+            assert_eq!(nan, x.is_nan());
+            assert_eq!(finite, x.is_finite());
+            assert_eq!(infinite, x.is_infinite());
+        }
+        assert_eq!(v.count_nan(), nan.iter().filter(|&&x| x).count());
+    }
+    assert_eq!(f.t().is_nan(), a.is_nan());
+    let nans = (0..a.len())
+        .filter(|i| [1, 6].contains(&((i / 30 * 31 + i / 6 % 5 * 7 + i % 6) % 7)))
+        .count();
+    assert_eq!(a.count_nan(), nans);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn masks_empty()
+{
+    let a = Array2::<f32>::zeros((0, 3));
+    assert_eq!(a.is_finite().shape(), &[0, 3]);
+    assert_eq!(a.count_nan(), 0);
+    assert_eq!(array![f32::NAN, 0.].count_nan(), 1);
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn abs_signum_floats()
+{
+    let nan = f64::NAN;
+    let a = array![-0., 0., -2.5, f64::NEG_INFINITY, f64::MIN, nan];
+    let abs = a.abs();
+    assert_eq!(abs.slice(s![..5]), array![0., 0., 2.5, f64::INFINITY, f64::MAX]);
+    assert!(abs[0].is_sign_positive());
+    assert!(abs[5].is_nan());
+    let signum = a.signum();
+    assert_eq!(signum.slice(s![..5]), array![-1., 1., -1., -1., -1.]);
+    assert!(signum[5].is_nan());
+    let mut b = a.mapv(|x| x as f32);
+    b.signum_inplace();
+    assert_eq!(b.slice(s![..2]), array![-1f32, 1.]);
+    b.abs_inplace();
+    assert_eq!(b.slice(s![..5]), Array::ones(5));
+    assert!(b[5].is_nan());
+}
+
+/// Generic code over floats, which must keep compiling.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn abs_and_signum_of<A: num_traits::Float>(a: &Array1<A>) -> (Array1<A>, Array1<A>)
+{
+    let mut b = a.clone();
+    b.abs_inplace();
+    (a.abs(), b.signum())
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn abs_signum_generic_float()
+{
+    let (abs, signum) = abs_and_signum_of(&array![-2.5f32, -0., 3.]);
+    assert_eq!(abs, array![2.5f32, 0., 3.]);
+    assert!(abs[1].is_sign_positive());
+    assert_eq!(signum, array![1f32, 1., 1.]);
+    let (abs, _) = abs_and_signum_of(&array![f64::MIN_POSITIVE, -1e300]);
+    assert_eq!(abs, array![f64::MIN_POSITIVE, 1e300]);
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn rounding_half_way()
+{
+    let a = array![-2.5, -1.5, -0.5, 0.5, 1.5, 2.5, 3.5];
+    assert_eq!(a.round(), array![-3., -2., -1., 1., 2., 3., 4.]);
+    assert_eq!(a.round_ties_even(), array![-2., -2., -0., 0., 2., 2., 4.]);
+    assert_eq!(a.floor(), array![-3., -2., -1., 0., 1., 2., 3.]);
+    assert_eq!(a.ceil(), array![-2., -1., -0., 1., 2., 3., 4.]);
+    assert_eq!(a.trunc(), array![-2., -1., -0., 0., 1., 2., 3.]);
+    // the sums that round half-way cases to even are not biased
+    let positive = array![0.5, 1.5, 2.5, 3.5];
+    assert_eq!(positive.round_ties_even().sum(), positive.sum());
+    assert_eq!(positive.round().sum(), positive.sum() + 2.);
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn rounding_signs_and_special_values()
+{
+    let a = array![-0.5f32, -0.4, -0., 0.4, 0.49999997, f32::INFINITY, f32::NEG_INFINITY];
+    for r in [a.round_ties_even(), a.ceil(), a.trunc()] {
+        assert!(r.slice(s![..3]).iter().all(|x| *x == 0. && x.is_sign_negative()));
+    }
+    assert_eq!(a.round(), array![-1., -0., -0., 0., 0., f32::INFINITY, f32::NEG_INFINITY]);
+    assert_eq!(a.round_ties_even().slice(s![3..]), array![0., 0., f32::INFINITY, f32::NEG_INFINITY]);
+    assert!(a.floor()[2].is_sign_negative());
+    assert_eq!(a.floor()[1], -1.);
+    for r in [array![f64::NAN].round_ties_even(), array![f64::NAN].round(), array![f64::NAN].floor()] {
+        assert!(r[0].is_nan());
+    }
+    // integers that are too large for a fraction stay the same
+    let large = array![4503599627370497., -9007199254740993., 1e300];
+    assert_eq!(large.round_ties_even(), large);
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn rounding_inplace()
+{
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i as f64 - 1.) * 2.5 + j as f64 * 0.5);
+    let mut b = a.clone();
+    b.round_inplace();
+    assert_eq!(b, a.round());
+    let mut b = a.clone();
+    b.slice_mut(s![..;-1, ..]).round_ties_even_inplace();
+    assert_eq!(b, a.round_ties_even());
+    let mut b = a.clone();
+    b.view_mut().reversed_axes().floor_inplace();
+    assert_eq!(b, a.floor());
+    let mut b = a.clone();
+    b.ceil_inplace();
+    assert_eq!(b, a.ceil());
+    let mut b = a.clone();
+    b.trunc_inplace();
+    assert_eq!(b, a.trunc());
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn powers_of_negative_bases()
+{
+    let a = array![-8f64, -2., -0.5];
+    let half = array![0.5];
+    assert_eq!(a.powi(2), array![64., 4., 0.25]);
+    assert_eq!(a.powi(-3), array![-1. / 512., -0.125, -8.]);
+    assert_eq!(a.powf(2.), array![64., 4., 0.25]);
+    assert_eq!(a.powf(3.), array![-512., -8., -0.125]);
+    assert!(a.powf(1. / 3.).iter().all(|x| x.is_nan()));
+    assert!(a.powf(-0.5).iter().all(|x| x.is_nan()));
+    assert!(a.pow_elem(&half).unwrap().iter().all(|x| x.is_nan()));
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn powers_of_zero()
+{
+    let a = array![0., -0., f64::NAN, f64::INFINITY];
+    let zero = array![0.];
+    assert_eq!(a.powi(0), array![1., 1., 1., 1.]);
+    assert_eq!(a.powf(0.), array![1., 1., 1., 1.]);
+    assert_eq!(a.pow_elem(&zero), Ok(array![1., 1., 1., 1.]));
+    let z = array![0., -0.];
+    assert_eq!(z.powi(-1), array![f64::INFINITY, f64::NEG_INFINITY]);
+    assert_eq!(z.powf(2.), array![0., 0.]);
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn pow_elem_broadcasting()
+{
+    let a = array![[1., 2., 3.], [4., 5., 6.]];
+    let row = array![2., 0., -1.];
+    let expected = array![[1., 1., 1. / 3.], [16., 1., 1. / 6.]];
+    assert_eq!(a.pow_elem(&row), Ok(expected.clone()));
+    // the exponents can also broadcast the base
+    let column = array![[2.], [3.]];
+    assert_eq!(row.pow_elem(&column), Ok(array![[4., 0., 1.], [8., 0., -1.]]));
+    let short = array![1., 2.];
+    assert!(a.pow_elem(&short).is_err());
+
+    let mut b = a.t().to_owned();
+    b.pow_elem_inplace(&row.view().insert_axis(Axis(1)))
+        .unwrap();
+    assert_eq!(b, expected.t());
+    let mut c = a.clone();
+    assert!(c.pow_elem_inplace(&column.t()).is_err());
+    assert!(row.clone().pow_elem_inplace(&a).is_err());
+    assert_eq!(c, a);
+    c.powi_inplace(2);
+    assert_eq!(c, a.powf(2.));
+    c.powf_inplace(0.5);
+    assert_eq!(c, a);
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn math_functions_match_scalars()
+{
+    let a = array![[0., 1e-10, 0.5], [2., f64::INFINITY, f64::NEG_INFINITY]];
+    let check = |res: Array2<f64>, f: fn(f64) -> f64| {
+        for (&x, &y) in a.iter().zip(&res) {
+            let z = f(x);
+            assert!(y == z || y.is_nan() && z.is_nan(), "{} != {} at {}", y, z, x);
+        }
+    };
+    check(a.exp(), f64::exp);
+    check(a.exp_m1(), f64::exp_m1);
+    check(a.ln_1p(), f64::ln_1p);
+    check(a.cbrt(), f64::cbrt);
+    check(a.sin(), f64::sin);
+    check(a.cos(), f64::cos);
+    check(a.tanh(), f64::tanh);
+    // accurate near zero
+    assert_eq!(a.exp_m1()[[0, 1]], 1e-10f64.exp_m1());
+    assert!(a.exp_m1()[[0, 1]] != a.exp()[[0, 1]] - 1.);
+    assert_eq!(a.tanh().row(1), array![2f64.tanh(), 1., -1.]);
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn math_functions_domains()
+{
+    let a = array![-1., -0., 0., 4., f64::INFINITY];
+    let sqrt = a.sqrt();
+    assert!(sqrt[0].is_nan());
+    assert_eq!(sqrt.slice(s![1..]), array![-0., 0., 2., f64::INFINITY]);
+    assert!(sqrt[1].is_sign_negative());
+    let ln = a.ln();
+    assert!(ln[0].is_nan());
+    assert_eq!(ln.slice(s![1..]), array![f64::NEG_INFINITY, f64::NEG_INFINITY, 4f64.ln(), f64::INFINITY]);
+    assert!(a.log2()[0].is_nan() && a.log10()[0].is_nan());
+    assert_eq!(a.log2()[3], 2.);
+    assert_eq!(a.log10().slice(s![1..3]), array![f64::NEG_INFINITY, f64::NEG_INFINITY]);
+    assert_eq!(a.ln_1p()[0], f64::NEG_INFINITY);
+    assert_eq!(a.exp_m1().slice(s![..1]), array![(-1f64).exp_m1()]);
+    assert!(a.tan().slice(s![1..3]).iter().all(|&x| x == 0.));
+    assert!(a.sin()[4].is_nan() && a.cos()[4].is_nan());
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn math_functions_inplace()
+{
+    let a = array![[0.25f32, 1., 9.], [16., 0.5, 100.]];
+    let v = a.t();
+    let mut b = v.to_owned();
+    b.sqrt_inplace();
+    assert_eq!(b, v.sqrt());
+    b.ln_inplace();
+    assert_eq!(b, v.sqrt().ln());
+    b.exp_inplace();
+    assert_eq!(b, v.sqrt().ln().exp());
+    let mut c = a.clone();
+    c.log10_inplace();
+    c.tanh_inplace();
+    c.cbrt_inplace();
+    assert_eq!(c, a.log10().tanh().cbrt());
+    let mut d = a.clone();
+    d.slice_mut(s![.., ..;2]).sin_inplace();
+    assert_eq!(d.column(1), a.column(1));
+    assert_eq!(d.column(2), a.column(2).sin());
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn hypot_near_max()
+{
+    let a = array![2e38f32, -3e38, 1e-30, 0.];
+    let b = array![1e38f32, 1e30, 1e-30, -0.];
+    let naive = (&a * &a + &b * &b).mapv(f32::sqrt);
+    assert_eq!(naive.slice(s![..2]), array![f32::INFINITY, f32::INFINITY]);
+    let h = a.hypot(&b).unwrap();
+    assert!(h.iter().all(|x| x.is_finite()));
+    assert!((h[0] - 5f32.sqrt() * 1e38).abs() <= 1e32);
+    assert_eq!(h[1], 3e38);
+    // the squares of tiny components underflow
+    assert_eq!(naive[2], 0.);
+    assert!((h[2] - 2f32.sqrt() * 1e-30).abs() <= 1e-36);
+    assert_eq!(h[3], 0.);
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn hypot_broadcast_and_inplace()
+{
+    let dx = array![[3., -6.], [0., 1.]];
+    let dy = array![4., 8.];
+    assert_eq!(dx.hypot(&dy), Ok(array![[5., 10.], [4., 8f64.hypot(1.)]]));
+    assert_eq!(dy.hypot(&dx), dx.hypot(&dy));
+    let nan = array![f64::NAN, f64::INFINITY];
+    let h = dx.hypot(&nan).unwrap();
+    assert!(h.column(0).iter().all(|x| x.is_nan()));
+    assert_eq!(h.column(1), array![f64::INFINITY, f64::INFINITY]);
+    let bad = array![1., 2., 3.];
+    assert!(dx.hypot(&bad).is_err());
+
+    let mut m = dx.t().to_owned();
+    m.hypot_inplace(&dy.view().insert_axis(Axis(1))).unwrap();
+    assert_eq!(m, dx.hypot(&dy).unwrap().t());
+    let before = m.clone();
+    assert!(m.hypot_inplace(&bad).is_err());
+    assert_eq!(m, before);
+}
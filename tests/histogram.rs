@@ -0,0 +1,183 @@
+#![allow(clippy::float_cmp)]
+#![cfg(feature = "std")]
+
+use ndarray::prelude::*;
+use ndarray::{histogram2d, Bins, StatsError};
+use ndarray_gen::array_builder::{ArrayBuilder, ElementGenerator};
+
+#[test]
+fn histogram_count()
+{
+    let a = array![[0., 1., 2.], [3., 4., 10.]];
+    let (counts, edges) = a.histogram(Bins::Count(5)).unwrap();
+    assert_eq!(edges, array![0., 2., 4., 6., 8., 10.]);
+    assert_eq!(counts, array![2, 2, 1, 0, 1]);
+    assert_eq!(a.t().histogram(Bins::Count(5)).unwrap(), (counts, edges));
+}
+
+#[test]
+fn histogram_edges()
+{
+    let a = array![0.5, 1., 1.5, 2., 2.5, 3., 3.5];
+    let (counts, edges) = a.histogram(Bins::Edges(array![1., 2., 3.])).unwrap();
+    assert_eq!(edges, array![1., 2., 3.]);
+    // [1, 2) and [2, 3]
+    assert_eq!(counts, array![2, 3]);
+    let (counts, _) = a
+        .histogram_clamped(Bins::Edges(array![1., 2., 3.]))
+        .unwrap();
+    assert_eq!(counts, array![3, 4]);
+
+    // repeated edges make an empty bin
+    let (counts, _) = a.histogram(Bins::Edges(array![1., 2., 2., 3.])).unwrap();
+    assert_eq!(counts, array![2, 0, 3]);
+
+    // non-contiguous edges
+    let e = array![0., 3., 0., 2., 0., 1.].slice_move(s![..;-2]);
+    let (counts, edges) = a.histogram(Bins::Edges(e)).unwrap();
+    assert_eq!(edges, array![1., 2., 3.]);
+    assert_eq!(counts, array![2, 3]);
+}
+
+#[test]
+fn histogram_values_on_edges()
+{
+    let a = array![0., 0.25, 0.5, 0.75, 1.];
+    let (counts, _) = a.histogram(Bins::Count(4)).unwrap();
+    assert_eq!(counts, array![1, 1, 1, 2]);
+    let a = Array::from_shape_fn(101, |i| i as f64 / 10.);
+    let (counts, edges) = a.histogram(Bins::Count(10)).unwrap();
+    assert_eq!(edges, Array::from_shape_fn(11, |i| i as f64));
+    assert_eq!(counts, array![10, 10, 10, 10, 10, 10, 10, 10, 10, 11]);
+}
+
+#[test]
+fn histogram_empty_and_single_value()
+{
+    let (counts, edges) = Array1::<f64>::zeros(0).histogram(Bins::Count(2)).unwrap();
+    assert_eq!(counts, array![0, 0]);
+    assert_eq!(edges, array![0., 0.5, 1.]);
+    let (counts, edges) = array![f64::NAN].histogram(Bins::Count(2)).unwrap();
+    assert_eq!(counts, array![0, 0]);
+    assert_eq!(edges, array![0., 0.5, 1.]);
+
+    let (counts, edges) = array![7., 7.].histogram(Bins::Count(2)).unwrap();
+    assert_eq!(edges, array![6.5, 7., 7.5]);
+    assert_eq!(counts, array![0, 2]);
+    let (counts, _) = array![7.].histogram(Bins::Count(1)).unwrap();
+    assert_eq!(counts, array![1]);
+}
+
+#[test]
+fn histogram_nan_and_infinity()
+{
+    let a = array![1., f64::NAN, 2., f64::INFINITY, f64::NEG_INFINITY];
+    let (counts, _) = a.histogram(Bins::Edges(array![0., 1., 3.])).unwrap();
+    assert_eq!(counts, array![0, 2]);
+    let (counts, _) = a
+        .histogram_clamped(Bins::Edges(array![0., 1., 3.]))
+        .unwrap();
+    assert_eq!(counts, array![1, 3]);
+}
+
+#[test]
+fn histogram_total_count()
+{
+    let a = Array::from_shape_fn((40, 25), |(i, j)| ((i * 25 + j) as f64 * 0.37).sin());
+    for n in 1..20 {
+        let (counts, edges) = a.histogram(Bins::Count(n)).unwrap();
+        assert_eq!(counts.sum(), a.len());
+        assert_eq!(edges.len(), n + 1);
+        for (i, &c) in counts.iter().enumerate() {
+            let expected = a
+                .iter()
+                .filter(|&&x| edges[i] <= x && (x < edges[i + 1] || i == n - 1 && x == edges[n]))
+                .count();
+            assert_eq!(c, expected);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn histogram_zero_bins()
+{
+    let _ = array![1., 2.].histogram(Bins::Count(0));
+}
+
+#[test]
+fn histogram_unsorted_edges()
+{
+    let a = array![1., 2.];
+    let err = Err(StatsError::UnsortedEdges);
+    assert_eq!(a.histogram(Bins::Edges(array![0., 2., 1.])), err);
+    assert_eq!(a.histogram_clamped(Bins::Edges(array![0., 2., 1.])), err);
+    assert_eq!(a.histogram(Bins::Edges(array![0., f64::NAN, 1.])), err);
+    let bins = (Bins::Count(2), Bins::Edges(array![1., 0.]));
+    assert_eq!(histogram2d(&a, &a, bins).map(|_| ()), Err(StatsError::UnsortedEdges));
+}
+
+#[test]
+#[should_panic]
+fn histogram_infinite_range()
+{
+    let _ = array![1., f64::INFINITY].histogram(Bins::Count(2));
+}
+
+/// Return `n` pseudo-random values in `[0, 1)`.
+fn uniform_values(n: usize, seed: u32) -> Array1<f64>
+{
+    ArrayBuilder::new(n)
+        .generator(ElementGenerator::Random(seed))
+        .build::<f64>()
+        .mapv(|x| x / 65536.)
+}
+
+#[test]
+fn histogram2d_total_count()
+{
+    let n = 100_000;
+    let x = uniform_values(n, 1).mapv(|x| 4. * x - 2.);
+    let y = uniform_values(n, 2).mapv(|y| 3. * y);
+    let xbins = Bins::Edges(array![-1., -0.5, 0., 1.5]);
+    let ybins = Bins::Edges(array![0.5, 1., 2.]);
+    let (counts, xedges, yedges) = histogram2d(&x, &y, (xbins, ybins)).unwrap();
+    assert_eq!(counts.shape(), &[3, 2]);
+    assert_eq!(xedges, array![-1., -0.5, 0., 1.5]);
+    assert_eq!(yedges, array![0.5, 1., 2.]);
+    let in_range = x
+        .iter()
+        .zip(&y)
+        .filter(|&(&x, &y)| (-1. ..=1.5).contains(&x) && (0.5..=2.).contains(&y))
+        .count();
+    assert_eq!(counts.sum(), in_range);
+    // 5/8 of the range of x and 1/2 of the range of y
+    assert!((counts.sum() as f64 / n as f64 - 5. / 16.).abs() < 0.01);
+
+    // every point is in range with bins from the data
+    let (counts, _, _) = histogram2d(&x, &y, (Bins::Count(7), Bins::Count(5))).unwrap();
+    assert_eq!(counts.sum(), n);
+    let (xcounts, _) = x.histogram(Bins::Count(7)).unwrap();
+    assert_eq!(counts.sum_axis(Axis(1)), xcounts);
+    let (ycounts, _) = y.histogram(Bins::Count(5)).unwrap();
+    assert_eq!(counts.sum_axis(Axis(0)), ycounts);
+}
+
+#[test]
+fn histogram2d_edges_and_nan()
+{
+    let x = array![0., 1., 2., f64::NAN, 1.];
+    let y = array![2., 1., 0., 1., f64::NAN];
+    let bins = (Bins::Edges(array![0., 1., 2.]), Bins::Edges(array![0., 1., 2.]));
+    let (counts, _, _) = histogram2d(&x, &y, bins).unwrap();
+    assert_eq!(counts, array![[0, 1], [1, 1]]);
+}
+
+#[test]
+fn histogram2d_length_mismatch()
+{
+    let x = array![0., 1.];
+    let y = array![0., 1., 2.];
+    let err = histogram2d(&x, &y, (Bins::Count(2), Bins::Count(2))).map(|_| ());
+    assert!(matches!(err, Err(StatsError::Shape(_))));
+}
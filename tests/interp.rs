@@ -0,0 +1,96 @@
+#![cfg(any(feature = "std", feature = "libm"))]
+#![allow(clippy::float_cmp)]
+
+use ndarray::prelude::*;
+use ndarray::{ErrorKind, ShapeError, StatsError};
+
+#[test]
+fn interp_nodes()
+{
+    let xp = array![-2., 0.1, 0.3, 5., 7.5];
+    let fp = array![1.7, -0.3, 2.9, 1e10, 0.1];
+    // the points themselves, sorted and unsorted, get exactly the values
+    assert_eq!(xp.interp(&xp, &fp, None, None), Ok(fp.clone()));
+    let x = xp.slice(s![..;-1]);
+    assert_eq!(x.interp(&xp, &fp, None, None), Ok(fp.slice(s![..;-1]).to_owned()));
+    // between the points
+    let x = array![-1.95, 0.2, 2.65, 6.25];
+    let y = x.interp(&xp, &fp, None, None).unwrap();
+    let expected: [f64; 4] = [1.7 - 0.05 / 2.1 * 2., 1.3, 0.5e10 + 1.45, 0.5e10 + 0.05];
+    for (&y, &e) in y.iter().zip(&expected) {
+        assert!((y - e).abs() <= 1e-12 * e.abs().max(1.), "{} != {}", y, e);
+    }
+}
+
+#[test]
+fn interp_out_of_range()
+{
+    let xp = array![1., 2., 3.];
+    let fp = array![4., 6., 5.];
+    let x = array![f64::NEG_INFINITY, 0., 1., 3., 3.5, f64::INFINITY];
+    assert_eq!(x.interp(&xp, &fp, None, None), Ok(array![4., 4., 4., 5., 5., 5.]));
+    assert_eq!(x.interp(&xp, &fp, Some(-1.), Some(10.)), Ok(array![-1., -1., 4., 5., 10., 10.]));
+    let y = array![3.5, 0., 2.5]
+        .interp(&xp, &fp, None, Some(f64::NAN))
+        .unwrap();
+    assert!(y[0].is_nan());
+    assert_eq!(y.slice(s![1..]), array![4., 5.5]);
+    // a single point
+    let one = array![2.];
+    let y = array![0., 2., 4.].interp(&one, &one, Some(0.), None);
+    assert_eq!(y, Ok(array![0., 2., 2.]));
+}
+
+#[test]
+fn interp_sorted_matches_unsorted()
+{
+    let xp = Array::from_shape_fn(13, |i| (i as f64 - 6.) * (i as f64 - 6.).abs() / 4.);
+    let fp = xp.mapv(f64::sin);
+    let x = Array::from_shape_fn((7, 11), |(i, j)| ((i * 11 + j) * 37 % 77 / 2) as f64 / 3.5 - 5.5);
+    let mut sorted = x.iter().copied().collect::<Vec<_>>();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let sorted = Array::from(sorted);
+    // a single scan for the sorted elements, binary searches for the others
+    let y = sorted.interp(&xp, &fp, None, None).unwrap();
+    let reversed = sorted
+        .slice(s![..;-1])
+        .interp(&xp, &fp, None, None)
+        .unwrap();
+    assert_eq!(reversed.slice(s![..;-1]), y);
+    let y2 = x.interp(&xp, &fp, None, None).unwrap();
+    for (x, y2) in x.iter().zip(&y2) {
+        let i = sorted.iter().position(|s| s == x).unwrap();
+        assert_eq!(*y2, y[i]);
+    }
+}
+
+#[test]
+fn interp_nan()
+{
+    let xp = array![0., 1.];
+    let fp = array![0., 2.];
+    let y = array![0.5, f64::NAN, 2.]
+        .interp(&xp, &fp, None, None)
+        .unwrap();
+    assert_eq!(y[0], 1.);
+    assert!(y[1].is_nan());
+    assert_eq!(y[2], 2.);
+}
+
+#[test]
+fn interp_errors()
+{
+    let x = array![1., 2.];
+    let xp = array![0., 1., 2.];
+    let fp = array![0., 1.];
+    assert_eq!(
+        x.interp(&xp, &fp, None, None),
+        Err(StatsError::Shape(ShapeError::from_kind(ErrorKind::IncompatibleShape)))
+    );
+    let empty = Array1::<f64>::zeros(0);
+    assert_eq!(x.interp(&empty, &empty, None, None), Err(StatsError::EmptyInput));
+    let unsorted = array![0., 2., 1.];
+    assert_eq!(x.interp(&unsorted, &xp, None, None), Err(StatsError::UnsortedEdges));
+    let nan = array![0., f64::NAN, 1.];
+    assert_eq!(x.interp(&nan, &xp, None, None), Err(StatsError::UnsortedEdges));
+}
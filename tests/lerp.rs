@@ -0,0 +1,119 @@
+#![allow(clippy::float_cmp)]
+#![cfg(any(feature = "std", feature = "libm"))]
+
+use ndarray::prelude::*;
+use ndarray::{ErrorKind, ShapeError};
+
+#[test]
+fn lerp_ends_and_middle()
+{
+    // values of very different magnitudes
+    let a = array![[0.1, -1e20, 3.], [7., f64::MIN_POSITIVE, -0.3]];
+    let b = array![1e-3, 1., 3., -7e15, 0.7, 1e20];
+    let b = b.into_shape_with_order((2, 3)).unwrap();
+    assert_eq!(a.lerp(&b, 0.), Ok(a.clone()));
+    assert_eq!(a.lerp(&b, 1.), Ok(b.clone()));
+    let mid = a.lerp(&b, 0.5).unwrap();
+    for ((&m, &a), &b) in mid.iter().zip(&a).zip(&b) {
+        let e = (a + b) / 2.;
+        assert!((m - e).abs() <= 1e-15 * e.abs(), "{} != {}", m, e);
+    }
+    // equal ends give the end for any weight
+    assert_eq!(a.lerp(&a, 0.3), Ok(a.clone()));
+    assert_eq!(a.lerp(&a, 7.), Ok(a.clone()));
+    // extrapolation
+    let x = array![0., 10.];
+    let y = array![[2.], [4.]];
+    assert_eq!(x.lerp(&y, 2.), Ok(array![[4., -6.], [8., -2.]]));
+    assert_eq!(x.lerp(&y, -1.), Ok(array![[-2., 18.], [-4., 16.]]));
+}
+
+#[test]
+fn lerp_infinite_ends()
+{
+    let inf = f64::INFINITY;
+    let a = array![1., -inf, 0., -inf];
+    let b = array![inf, 5., inf, inf];
+    assert_eq!(a.lerp(&b, 0.), Ok(a.clone()));
+    assert_eq!(a.lerp(&b, 1.), Ok(b.clone()));
+    let mid = a.lerp(&b, 0.5).unwrap();
+    assert_eq!(mid.slice(s![..3]), array![inf, -inf, inf]);
+    assert!(mid[3].is_nan());
+    let mut c = a.clone();
+    let t = array![0., 1., 0., 1.];
+    c.lerp_elem_inplace(&b, &t).unwrap();
+    assert_eq!(c, array![1., 5., 0., inf]);
+}
+
+#[test]
+fn lerp_two_sided_is_exact_and_monotonic()
+{
+    let (a, b) = (array![0.1f32], array![0.7f32]);
+    let ts = Array::from_iter((0..=100).map(|i| i as f32 / 100.));
+    let values = ts.mapv(|t| a.lerp(&b, t).unwrap()[0]);
+    assert_eq!(values[0], 0.1);
+    assert_eq!(values[100], 0.7);
+    assert!(values.windows(2).into_iter().all(|w| w[0] <= w[1]));
+}
+
+#[test]
+fn lerp_weight_array()
+{
+    let a = array![1f32, 2., 3.];
+    let b = array![5f32, 6., 7.];
+    let t = array![[0.], [0.25], [1.]];
+    let c = a.lerp_elem(&b, &t).unwrap();
+    assert_eq!(c, array![[1., 2., 3.], [2., 3., 4.], [5., 6., 7.]]);
+    // per element weights
+    let t = array![1., 0., 0.5];
+    assert_eq!(a.lerp_elem(&b, &t), Ok(array![5., 2., 5.]));
+    // agrees with a scalar weight
+    for &w in &[0., 0.1, 0.5, 1., 1.5] {
+        assert_eq!(a.lerp_elem(&b, &arr0(w)), a.lerp(&b, w));
+    }
+    // the weights broadcast the result
+    let t = Array::from_elem((2, 1, 1), 0.5);
+    assert_eq!(a.lerp_elem(&b, &t).unwrap().shape(), &[2, 1, 3]);
+}
+
+#[test]
+fn lerp_inplace()
+{
+    let a = array![[1., 2.], [3., 4.]];
+    let b = array![[5.], [-4.]];
+    for &t in &[0., 0.5, 1.] {
+        let mut c = a.clone();
+        c.lerp_inplace(&b, t).unwrap();
+        assert_eq!(Ok(c), a.lerp(&b, t));
+    }
+    let t = array![0., 1.];
+    let mut c = a.clone();
+    c.lerp_elem_inplace(&b, &t).unwrap();
+    assert_eq!(c, array![[1., 5.], [3., -4.]]);
+    // a transposed target
+    let mut d = a.t().to_owned();
+    d.view_mut()
+        .reversed_axes()
+        .lerp_elem_inplace(&b, &t)
+        .unwrap();
+    assert_eq!(d.t(), c);
+}
+
+#[test]
+fn lerp_incompatible()
+{
+    let a = array![[1., 2.], [3., 4.]];
+    let b = array![1., 2., 3.];
+    let err = Err(ShapeError::from_kind(ErrorKind::IncompatibleShape));
+    assert_eq!(a.lerp(&b, 0.5).map(|_| ()), err);
+    assert_eq!(a.lerp_elem(&a, &b).map(|_| ()), err);
+    let mut c = a.clone();
+    assert_eq!(c.lerp_inplace(&b, 0.5), err);
+    // `other` can be broadcast but not the weights
+    let t = array![[0.5], [0.5], [0.5]];
+    assert_eq!(c.lerp_elem_inplace(&a, &t), err);
+    // the shape of the array can not grow
+    let row = array![1., 2.];
+    assert_eq!(row.clone().lerp_inplace(&a, 0.5), err);
+    assert_eq!(c, a);
+}
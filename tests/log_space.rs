@@ -0,0 +1,154 @@
+#![allow(clippy::float_cmp)]
+#![cfg(feature = "std")]
+
+use ndarray::prelude::*;
+
+#[test]
+fn logsumexp_large_values()
+{
+    let a = array![1000.0, 1000.1];
+    // the naive formula overflows
+    assert!(a.mapv(f64::exp).sum().ln().is_infinite());
+    let expected = 1000.1 + (1. + (-0.1f64).exp()).ln();
+    assert!((a.logsumexp() - expected).abs() < 1e-12);
+    let expected = -1000. + (1. + (-0.1f64).exp()).ln();
+    assert!((array![-1000.0, -1000.1].logsumexp() - expected).abs() < 1e-12);
+}
+
+#[test]
+fn logsumexp_matches_naive()
+{
+    let a = Array::linspace(-3., 4., 12)
+        .into_shape_with_order((3, 4))
+        .unwrap();
+    let naive = a.mapv(f64::exp).sum().ln();
+    assert!((a.logsumexp() - naive).abs() < 1e-12);
+    assert!((a.t().logsumexp() - naive).abs() < 1e-12);
+    assert!((a.slice(s![.., ..;-1]).logsumexp() - naive).abs() < 1e-12);
+}
+
+#[test]
+fn logsumexp_infinities_and_nan()
+{
+    let inf = f64::INFINITY;
+    assert_eq!(array![-inf, -inf].logsumexp(), -inf);
+    assert_eq!(Array1::<f64>::zeros(0).logsumexp(), -inf);
+    assert_eq!(array![-inf, 0.].logsumexp(), 0.);
+    assert_eq!(array![1., inf].logsumexp(), inf);
+    assert!(array![1., f64::NAN, inf].logsumexp().is_nan());
+    assert!(array![inf, f64::NAN].logsumexp().is_nan());
+}
+
+#[test]
+fn logsumexp_axis()
+{
+    let inf = f64::INFINITY;
+    let a = array![[1000.0, 1000.1, 0.], [-inf, -inf, -inf]];
+    let lse = a.logsumexp_axis(Axis(1));
+    assert_eq!(lse[0], a.row(0).logsumexp());
+    assert_eq!(lse[1], -inf);
+    let lse = a.logsumexp_axis(Axis(0));
+    assert_eq!(lse, array![1000., 1000.1, 0.]);
+    let b = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 12 + j * 4 + k) as f64 / 3.);
+    for axis in 0..3 {
+        let lse = b.logsumexp_axis(Axis(axis));
+        for (lane, &x) in b.lanes(Axis(axis)).into_iter().zip(&lse) {
+            assert!((lane.mapv(f64::exp).sum().ln() - x).abs() < 1e-12);
+        }
+    }
+}
+
+/// Softmax of a lane by the textbook formula, for comparison.
+fn softmax_reference(lane: ArrayView1<'_, f64>) -> Array1<f64>
+{
+    let max = lane.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let exp = lane.mapv(|x| (x - max).exp());
+    let sum: f64 = exp.iter().sum();
+    exp / sum
+}
+
+#[test]
+fn softmax_lanes_sum_to_one()
+{
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| ((i * 31 + j * 17 + k * 7) % 23) as f64 * 40. - 400.);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let p = a.softmax(axis);
+        assert_eq!(p.shape(), a.shape());
+        for sum in p.sum_axis(axis) {
+            assert!((sum - 1.).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn softmax_matches_reference()
+{
+    fn prop(xs: Vec<i16>, axis: u8, scale: u8) -> bool
+    {
+        let n = xs.len() / 6;
+        let a = Array::from_iter(
+            xs.into_iter()
+                .take(6 * n)
+                .map(|x| x as f64 * (scale as f64 + 1.) / 16.),
+        )
+        .into_shape_with_order((2, 3, n))
+        .unwrap();
+        let axis = Axis(axis as usize % 3);
+        let p = a.softmax(axis);
+        let mut inplace = a.t().to_owned();
+        inplace.softmax_inplace(Axis(2 - axis.index()));
+        p.lanes(axis)
+            .into_iter()
+            .zip(a.lanes(axis))
+            .all(|(p, lane)| {
+                p.iter()
+                    .zip(&softmax_reference(lane))
+                    .all(|(x, y)| (x - y).abs() < 1e-12)
+            })
+            && p.t() == inplace
+    }
+    quickcheck::quickcheck(prop as fn(Vec<i16>, u8, u8) -> bool);
+}
+
+#[test]
+fn softmax_infinities()
+{
+    let inf = f64::INFINITY;
+    let a = array![[-inf, 0., -inf], [-inf, -inf, -inf], [1., inf, 2.]];
+    let p = a.softmax(Axis(1));
+    assert_eq!(p.row(0), array![0., 1., 0.]);
+    assert!(p.row(1).iter().all(|x| x.is_nan()));
+    assert!(p.row(2).iter().all(|x| x.is_nan()));
+    let mut b = array![[1e308, -1e308], [-inf, 5.]];
+    b.softmax_inplace(Axis(0));
+    assert_eq!(b, array![[1., 0.], [0., 1.]]);
+}
+
+#[test]
+fn product_log_small_probabilities()
+{
+    let p = Array::from_shape_fn(100_000, |i| 1e-3 * (1. + 0.5 * ((i % 101) as f64 / 100. - 0.5)));
+    assert_eq!(p.product(), 0.);
+    let expected = p.iter().map(|x| x.ln()).sum::<f64>();
+    let (sign, ln_abs) = p.product_log();
+    assert_eq!(sign, 1.);
+    assert!((ln_abs - expected).abs() <= 1e-12 * expected.abs());
+    assert!(ln_abs < -680_000.);
+    let (sign, ln_abs) = p.mapv(|x| -x).slice(s![..99_999]).product_log();
+    assert_eq!(sign, -1.);
+    assert!((ln_abs - (expected - p[99_999].ln())).abs() <= 1e-9);
+}
+
+#[test]
+fn product_log_special_values()
+{
+    assert_eq!(array![2., 0., -3.].product_log(), (0., f64::NEG_INFINITY));
+    assert_eq!(Array1::<f64>::zeros(0).product_log(), (1., 0.));
+    let (sign, ln_abs) = array![1., f64::NAN].product_log();
+    assert!(sign.is_nan() && ln_abs.is_nan());
+    let a = array![[0.5, -4.], [-0.25, 8f64]];
+    let (sign, ln_abs) = a.product_log();
+    assert_eq!(sign, 1.);
+    assert!((ln_abs.exp() - a.product()).abs() < 1e-14);
+}
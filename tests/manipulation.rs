@@ -0,0 +1,292 @@
+use ndarray::prelude::*;
+use ndarray::{concatenate, ErrorKind, RemoveAxis, ShapeError, Slice};
+use ndarray_gen::array_builder::{ArrayBuilder, ElementGenerator};
+
+/// Tile `a` with one element at a time from the definition.
+fn tile_naive<A: Clone>(a: &ArrayD<A>, reps: &[usize]) -> ArrayD<A>
+{
+    let shape: Vec<_> = a.shape().iter().zip(reps).map(|(&n, &r)| n * r).collect();
+    ArrayD::from_shape_fn(shape, |index| {
+        let index: Vec<_> = index
+            .slice()
+            .iter()
+            .zip(a.shape())
+            .map(|(&i, &n)| i % n)
+            .collect();
+        a[&index[..]].clone()
+    })
+}
+
+#[test]
+fn tile_row_into_matrix()
+{
+    let row = array![1, 2, 3];
+    let m = row.tile(&[3, 1]);
+    assert_eq!(m, array![[1, 2, 3], [1, 2, 3], [1, 2, 3]].into_dyn());
+    assert_eq!(row.tile(&[2, 2]), array![[1, 2, 3, 1, 2, 3], [1, 2, 3, 1, 2, 3]].into_dyn());
+    assert_eq!(row.tile(&[]), row.clone().into_dyn());
+    assert_eq!(row.tile(&[1, 1, 2]).shape(), &[1, 1, 6]);
+    // `reps` shorter than the number of axes affects the last axes
+    let column = row.clone().into_shape_with_order((3, 1)).unwrap();
+    assert_eq!(column.tile(&[2]), array![[1, 1], [2, 2], [3, 3]].into_dyn());
+}
+
+#[test]
+fn tile_checkerboard()
+{
+    let block = array![[1u8, 0], [0, 1]];
+    let board = block.tile(&[4, 4]);
+    assert_eq!(board.shape(), &[8, 8]);
+    for ((i, j), &x) in board.into_dimensionality::<Ix2>().unwrap().indexed_iter() {
+        assert_eq!(x, ((i + j) % 2 == 0) as u8);
+    }
+    // a three-dimensional tiling of a non-contiguous view agrees with the
+    // definition
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| format!("{}{}{}", i, j, k));
+    let v = a.slice(s![..;2, 1.., ..;-2]);
+    for reps in [[1, 1, 1], [2, 3, 1], [3, 1, 4], [1, 2, 2]] {
+        assert_eq!(v.tile(&reps), tile_naive(&v.to_owned().into_dyn(), &reps));
+        assert_eq!(v.t().tile(&reps), tile_naive(&v.t().to_owned().into_dyn(), &reps));
+    }
+}
+
+#[test]
+fn tile_zero_repetitions()
+{
+    let a = array![[1, 2], [3, 4]];
+    assert_eq!(a.tile(&[0, 3]).shape(), &[0, 6]);
+    assert_eq!(a.tile(&[2, 0]).shape(), &[4, 0]);
+    assert_eq!(a.tile(&[0, 1, 1]).shape(), &[0, 2, 2]);
+    assert_eq!(Array2::<i32>::zeros((0, 3)).tile(&[5, 2]).shape(), &[0, 6]);
+    assert_eq!(arr0(7).tile(&[0]).shape(), &[0]);
+    assert_eq!(arr0(7).tile(&[2, 3]), Array::from_elem((2, 3), 7).into_dyn());
+}
+
+#[test]
+#[should_panic]
+fn tile_overflow()
+{
+    let _ = array![1, 2].tile(&[usize::MAX / 2, 2]);
+}
+
+#[test]
+fn repeat_elements()
+{
+    let a = array![1, 2, 3];
+    assert_eq!(a.repeat(2, Axis(0)), array![1, 1, 2, 2, 3, 3]);
+    assert_eq!(a.repeat(1, Axis(0)), a);
+    assert_eq!(a.repeat(0, Axis(0)), Array1::<i32>::zeros(0));
+    // non-contiguous views
+    let b = Array::from_shape_fn((3, 4), |(i, j)| format!("{}{}", i, j));
+    let v = b.slice(s![..;-1, ..;2]);
+    assert_eq!(
+        v.repeat(2, Axis(1)),
+        array![["20", "20", "22", "22"], ["10", "10", "12", "12"], ["00", "00", "02", "02"]].mapv(String::from)
+    );
+    assert_eq!(v.t().repeat(3, Axis(1)), v.repeat(3, Axis(0)).reversed_axes());
+    assert_eq!(Array2::<i32>::zeros((0, 3)).repeat(4, Axis(1)).shape(), &[0, 12]);
+}
+
+#[test]
+fn repeat_counts()
+{
+    // run-length decoding with empty runs
+    let values = array![5, 6, 7, 8];
+    let counts = array![0, 3, 0, 1];
+    assert_eq!(values.repeat_counts(&counts, Axis(0)), Ok(array![6, 6, 6, 8]));
+    let zeros = Array1::zeros(4);
+    assert_eq!(values.repeat_counts(&zeros, Axis(0)), Ok(Array1::zeros(0)));
+    // upsampling the rows of a non-contiguous view
+    let labels = array![[1, 2, 3], [4, 5, 6]];
+    let v = labels.t();
+    let counts = array![1, 0, 2];
+    assert_eq!(v.repeat_counts(&counts, Axis(0)), Ok(array![[1, 4], [3, 6], [3, 6]]));
+    let counts = array![2, 1];
+    assert_eq!(v.repeat_counts(&counts, Axis(1)), Ok(array![[1, 1, 4], [2, 2, 5], [3, 3, 6]]));
+    // agrees with `repeat` for equal counts
+    let counts = Array1::from_elem(3, 4);
+    assert_eq!(v.repeat_counts(&counts, Axis(0)), Ok(v.repeat(4, Axis(0))));
+    // the counts must match the axis
+    let err = Err(ShapeError::from_kind(ErrorKind::IncompatibleShape));
+    assert_eq!(v.repeat_counts(&counts, Axis(1)), err);
+}
+
+#[test]
+#[should_panic]
+fn repeat_axis_out_of_bounds()
+{
+    let _ = array![1, 2].repeat(2, Axis(1));
+}
+
+/// Roll `a` along `axis` with slicing and concatenation.
+fn roll_concatenate<A: Clone, D: RemoveAxis>(a: &Array<A, D>, shift: isize, axis: Axis) -> Array<A, D>
+{
+    let n = a.len_of(axis) as isize;
+    let s = shift.rem_euclid(n);
+    let tail = a.slice_axis(axis, Slice::from(n - s..));
+    let head = a.slice_axis(axis, Slice::from(..n - s));
+    concatenate(axis, &[tail, head]).unwrap()
+}
+
+#[test]
+fn roll_shifts()
+{
+    let a = Array::from_shape_fn((4, 5), |(i, j)| 10 * i + j);
+    for axis in [Axis(0), Axis(1)] {
+        let n = a.len_of(axis) as isize;
+        assert_eq!(a.roll(0, axis), a);
+        assert_eq!(a.roll(n, axis), a);
+        assert_eq!(a.roll(-3 * n, axis), a);
+        for shift in [-7, -n, -1, 1, 2, n - 1, n + 2, isize::MIN, isize::MAX] {
+            let expected = roll_concatenate(&a, shift, axis);
+            assert_eq!(a.roll(shift, axis), expected);
+            assert_eq!(a.t().roll(shift, Axis(1 - axis.index())), expected.t());
+            let mut b = a.clone();
+            b.roll_inplace(shift, axis);
+            assert_eq!(b, expected);
+            // in place on a transposed view
+            let mut c = a.t().to_owned();
+            c.view_mut().reversed_axes().roll_inplace(shift, axis);
+            assert_eq!(c.t(), expected);
+        }
+    }
+    // rolling back and forth
+    let mut d = array![1, 2, 3, 4, 5, 6, 7];
+    d.roll_inplace(-2, Axis(0));
+    assert_eq!(d, array![3, 4, 5, 6, 7, 1, 2]);
+    d.roll_inplace(2, Axis(0));
+    assert_eq!(d, array![1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn roll_empty_axis()
+{
+    let a = Array2::<i32>::zeros((3, 0));
+    assert_eq!(a.roll(2, Axis(1)), a);
+    let mut b = a.clone();
+    b.roll_inplace(-1, Axis(1));
+    assert_eq!(b, a);
+    // the other axes are rolled as usual
+    assert_eq!(a.roll(1, Axis(0)).shape(), &[3, 0]);
+    let strings = array![["a".to_string(), "b".to_string()]];
+    assert_eq!(strings.roll(1, Axis(0)), strings);
+    assert_eq!(strings.roll(1, Axis(1)), array![["b".to_string(), "a".to_string()]]);
+}
+
+#[test]
+fn roll_multi_matches_sequential_rolls()
+{
+    let a = ArrayBuilder::new((4, 5, 6))
+        .generator(ElementGenerator::Random(11))
+        .build::<u32>();
+    let cases: &[(&[isize], &[Axis])] = &[
+        (&[], &[]),
+        (&[1], &[Axis(2)]),
+        (&[1, -2, 3], &[Axis(0), Axis(1), Axis(2)]),
+        (&[7, 0, -13], &[Axis(2), Axis(0), Axis(1)]),
+        (&[2, 2], &[Axis(1), Axis(1)]),
+        (&[3, -1, 5, 4], &[Axis(2), Axis(0), Axis(2), Axis(0)]),
+        (&[isize::MAX, isize::MIN, isize::MAX], &[Axis(0), Axis(1), Axis(1)]),
+    ];
+    for &(shifts, axes) in cases {
+        let mut expected = a.clone();
+        for (&shift, &axis) in shifts.iter().zip(axes) {
+            expected = expected.roll(shift, axis);
+        }
+        assert_eq!(a.roll_multi(shifts, axes), Ok(expected.clone()));
+        let t = a.view().permuted_axes([2, 0, 1]);
+        let t_axes = axes
+            .iter()
+            .map(|ax| Axis((ax.index() + 1) % 3))
+            .collect::<Vec<_>>();
+        assert_eq!(t.roll_multi(shifts, &t_axes), Ok(expected.permuted_axes([2, 0, 1])));
+    }
+}
+
+#[test]
+fn roll_multi_empty()
+{
+    let a = Array3::<u8>::zeros((2, 0, 3));
+    assert_eq!(a.roll_multi(&[1, 1, 1], &[Axis(0), Axis(1), Axis(2)]), Ok(a.clone()));
+}
+
+#[test]
+fn roll_multi_length_mismatch()
+{
+    let a = array![[1, 2], [3, 4]];
+    assert_eq!(
+        a.roll_multi(&[1, 2], &[Axis(0)]).unwrap_err().kind(),
+        ErrorKind::IncompatibleShape
+    );
+}
+
+#[test]
+#[should_panic]
+fn roll_multi_axis_out_of_bounds()
+{
+    let _ = array![1, 2].roll_multi(&[1], &[Axis(1)]);
+}
+
+/// Shift `a` along `axis` one element at a time from the definition.
+fn shift_naive(a: &Array2<i32>, offset: isize, axis: Axis, fill: i32) -> Array2<i32>
+{
+    Array::from_shape_fn(a.raw_dim(), |(i, j)| {
+        let mut index = [i, j];
+        let from = index[axis.index()] as i128 - offset as i128;
+        if from < 0 || from >= a.len_of(axis) as i128 {
+            return fill;
+        }
+        index[axis.index()] = from as usize;
+        a[index]
+    })
+}
+
+#[test]
+fn shift_offsets()
+{
+    let a = Array::from_shape_fn((4, 5), |(i, j)| (10 * i + j) as i32);
+    for axis in [Axis(0), Axis(1)] {
+        assert_eq!(a.shift(0, axis, -1), a);
+        for offset in [-7, -5, -4, -1, 0, 1, 2, 4, 5, 9, isize::MIN, isize::MAX] {
+            let expected = shift_naive(&a, offset, axis, -1);
+            assert_eq!(a.shift(offset, axis, -1), expected);
+            let mut b = a.clone();
+            b.shift_inplace(offset, axis, -1);
+            assert_eq!(b, expected);
+            // non-contiguous lanes in place
+            let mut c = a.t().to_owned();
+            c.view_mut().reversed_axes().shift_inplace(offset, axis, -1);
+            assert_eq!(c.t(), expected);
+        }
+    }
+}
+
+#[test]
+fn shift_beyond_length()
+{
+    let a = array![[1, 2, 3], [4, 5, 6]];
+    assert_eq!(a.shift(3, Axis(1), 0), Array2::zeros((2, 3)));
+    assert_eq!(a.shift(-2, Axis(0), 0), Array2::zeros((2, 3)));
+    let mut b = a.clone();
+    b.shift_inplace(-100, Axis(1), 7);
+    assert_eq!(b, Array2::from_elem((2, 3), 7));
+}
+
+#[test]
+fn shift_strings()
+{
+    let a = array!["a".to_string(), "b".to_string(), "c".to_string()];
+    let mut b = a.clone();
+    b.shift_inplace(1, Axis(0), String::new());
+    assert_eq!(b, array![String::new(), "a".to_string(), "b".to_string()]);
+    assert_eq!(a.shift(-1, Axis(0), "-".to_string()), array!["b".to_string(), "c".to_string(), "-".to_string()]);
+}
+
+#[test]
+fn shift_empty_axis()
+{
+    let mut a = Array2::<i32>::zeros((0, 3));
+    assert_eq!(a.shift(2, Axis(0), 1), a);
+    a.shift_inplace(-1, Axis(1), 1);
+    assert_eq!(a.shape(), &[0, 3]);
+}
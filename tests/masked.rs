@@ -0,0 +1,314 @@
+#![allow(clippy::float_cmp)]
+
+use ndarray::prelude::*;
+#[cfg(any(feature = "std", feature = "libm"))]
+use ndarray::StatsError;
+use ndarray::{select_where, select_where_scalar, ErrorKind, ShapeError};
+
+#[test]
+fn sum_mean_where()
+{
+    let a = array![[1., 2., 3.], [4., 5., 6.]];
+    let mask = array![[true, false, false], [true, true, false]];
+    assert_eq!(a.sum_where(&mask), Ok(10.));
+    assert_eq!(a.mean_where(&mask), Ok(Some(10. / 3.)));
+    assert_eq!(a.t().sum_where(&mask.t()), Ok(10.));
+    assert_eq!(a.sum_where(&arr0(true)), Ok(a.sum()));
+    assert_eq!(a.mean_where(&arr0(true)), Ok(a.mean()));
+    // agrees with filtering the elements out
+    let selected: Vec<_> = a
+        .iter()
+        .zip(&mask)
+        .filter(|(_, &m)| m)
+        .map(|(&x, _)| x)
+        .collect();
+    assert_eq!(a.sum_where(&mask), Ok(selected.iter().sum()));
+}
+
+#[test]
+fn sum_mean_where_all_false()
+{
+    let a = array![[1, 2, 3], [4, 5, 6]];
+    let mask = Array2::from_elem((2, 3), false);
+    assert_eq!(a.sum_where(&mask), Ok(0));
+    assert_eq!(a.mean_where(&mask), Ok(None));
+    let row = array![true, true, true];
+    assert_eq!(Array2::<f64>::zeros((0, 3)).mean_where(&row), Ok(None));
+}
+
+#[test]
+fn sum_mean_where_broadcast_row_mask()
+{
+    let a = array![[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]];
+    let row = array![true, false, true];
+    assert_eq!(a.sum_where(&row), Ok(30.));
+    assert_eq!(a.mean_where(&row), Ok(Some(5.)));
+    assert_eq!(a.sum_axis_where(Axis(0), &row), Ok(array![12., 0., 18.]));
+    assert_eq!(a.sum_axis_where(Axis(1), &row), Ok(array![4., 10., 16.]));
+    assert_eq!(a.mean_axis_where(Axis(0), &row), Ok(array![Some(4.), None, Some(6.)]));
+    assert_eq!(a.mean_axis_where(Axis(1), &row), Ok(array![Some(2.), Some(5.), Some(8.)]));
+    // a column mask selects whole rows
+    let column = array![[false], [true], [true]];
+    assert_eq!(a.mean_axis_where(Axis(0), &column), Ok(array![Some(5.5), Some(6.5), Some(7.5)]));
+}
+
+#[test]
+fn mean_axis_where_counts_per_lane()
+{
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i * 20 + j * 5 + k) as f64);
+    let mask = a.mapv(|x| (x as usize) % 3 == 0);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let means = a.mean_axis_where(axis, &mask).unwrap();
+        let sums = a.sum_axis_where(axis, &mask).unwrap();
+        for ((lane, mask), (mean, sum)) in a
+            .lanes(axis)
+            .into_iter()
+            .zip(mask.lanes(axis))
+            .zip(means.iter().zip(&sums))
+        {
+            let selected: Vec<_> = lane
+                .iter()
+                .zip(&mask)
+                .filter(|(_, &m)| m)
+                .map(|(&x, _)| x)
+                .collect();
+            assert_eq!(*sum, selected.iter().sum::<f64>());
+            if selected.is_empty() {
+                assert_eq!(*mean, None);
+            } else {
+                assert_eq!(*mean, Some(*sum / selected.len() as f64));
+            }
+        }
+    }
+}
+
+#[test]
+fn where_incompatible_mask()
+{
+    let a = array![[1., 2., 3.], [4., 5., 6.]];
+    let err = Err(ShapeError::from_kind(ErrorKind::IncompatibleShape));
+    let row = array![true, false];
+    let column = array![[true], [false], [true]];
+    assert_eq!(a.sum_where(&row).map(|_| ()), err);
+    assert_eq!(a.mean_where(&column).map(|_| ()), err);
+    assert_eq!(a.sum_axis_where(Axis(0), &row).map(|_| ()), err);
+    // the mask broadcasts to the shape of the array, not of the result
+    assert_eq!(a.mean_axis_where(Axis(1), &row).map(|_| ()), err);
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn var_where()
+{
+    let a = array![[1., 2., 3.], [4., 5., 6.]];
+    let mask = array![[true, false, true], [false, true, true]];
+    let selected = array![1., 3., 5., 6.];
+    assert_eq!(a.var_where(&mask, 1.), Ok(selected.var(1.)));
+    assert_eq!(a.var_where(&arr0(true), 0.), Ok(a.var(0.)));
+    assert_eq!(a.var_where(&mask, 5.), Err(StatsError::DdofOutOfRange { ddof: 5., n: 4 }));
+    assert_eq!(a.var_where(&arr0(false), 0.), Err(StatsError::EmptyInput));
+    let row = array![true, false];
+    assert_eq!(
+        a.var_where(&row, 0.),
+        Err(StatsError::Shape(ShapeError::from_kind(ErrorKind::IncompatibleShape)))
+    );
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn var_axis_where()
+{
+    let a = array![[1., 2., 3.], [4., 5., 7.], [0., 1., 1f64]];
+    let mask = array![[true, true, true], [true, false, true], [false, false, true]];
+    let var = a.var_axis_where(Axis(1), &mask, 1.).unwrap();
+    assert_eq!(var[0], array![1., 2., 3.].var(1.));
+    assert_eq!(var[1], array![4., 7.].var(1.));
+    // a single element with `ddof = 1` divides by zero
+    assert!(var[2].is_nan());
+    let var = a.var_axis_where(Axis(0), &mask, 0.).unwrap();
+    assert_eq!(var[0], array![1., 4.].var(0.));
+    assert_eq!(var[1], 0.);
+    assert_eq!(var[2], array![3., 7., 1.].var(0.));
+    // lanes without selected elements are NaN
+    let row = array![true, false, true];
+    let var = a.var_axis_where(Axis(0), &row, 2.).unwrap();
+    assert!(var[1].is_nan());
+    assert_eq!(var[0], array![1., 4., 0.].var(2.));
+
+    assert_eq!(a.var_axis_where(Axis(0), &mask, 4.), Err(StatsError::DdofOutOfRange { ddof: 4., n: 3 }));
+    let empty = Array2::<f64>::zeros((2, 0));
+    assert_eq!(empty.var_axis_where(Axis(1), &arr0(true), 0.), Err(StatsError::EmptyInput));
+}
+
+#[test]
+fn select_where_broadcasting()
+{
+    let x = array![[1., -2., 3.], [-4., 5., -6.]];
+    let cond = x.gt_scalar(0.);
+    let neg = -&x;
+    assert_eq!(select_where(&cond, &x, &neg), Ok(array![[1., 2., 3.], [4., 5., 6.]]));
+    // a scalar in either branch
+    assert_eq!(select_where(&cond, &x, &arr0(0.)), Ok(array![[1., 0., 3.], [0., 5., 0.]]));
+    assert_eq!(select_where(&cond, &arr0(0.), &x), Ok(array![[0., -2., 0.], [-4., 0., -6.]]));
+    assert_eq!(select_where_scalar(&cond, 1, -1), array![[1, -1, 1], [-1, 1, -1]]);
+    // a column mask, row and column branches
+    let cond = array![[true], [false], [true]];
+    let row = array![1, 2];
+    let column = array![[10], [20], [30]];
+    assert_eq!(select_where(&cond, &row, &column), Ok(array![[1, 2], [20, 20], [1, 2]]));
+    // only the branches broadcast the mask
+    let cond = arr0(false);
+    assert_eq!(select_where(&cond, &row, &column), Ok(array![[10, 10], [20, 20], [30, 30]]));
+    // all three contribute an axis
+    let cond = array![true, false]
+        .into_shape_with_order((2, 1, 1))
+        .unwrap();
+    let if_true = array![[1], [2], [3]];
+    let if_false = array![-1, -2, -3, -4];
+    let c = select_where(&cond, &if_true, &if_false).unwrap();
+    assert_eq!(c.shape(), &[2, 3, 4]);
+    assert_eq!(c.slice(s![0, .., 0]), array![1, 2, 3]);
+    assert_eq!(c.slice(s![1, 2, ..]), if_false);
+    // views and non-contiguous inputs
+    let cond = array![[true, false], [false, true]];
+    assert_eq!(select_where(&cond.t(), &row, &row.slice(s![..;-1])), Ok(array![[1, 1], [2, 2]]));
+}
+
+#[test]
+fn select_where_not_taken_branch()
+{
+    // the elements of the branch that is not taken are never used
+    let x = array![4., 0., -1., 0.5];
+    let valid = x.gt_scalar(0.);
+    let inv = x.mapv(|x: f64| if x < 0. { f64::NAN } else { 1. / x });
+    assert!(inv.iter().any(|x| x.is_nan()) && inv.iter().any(|x| x.is_infinite()));
+    assert_eq!(select_where(&valid, &inv, &arr0(0.)), Ok(array![0.25, 0., 0., 2.]));
+    let safe = select_where(&valid, &inv, &arr0(f64::NAN)).unwrap();
+    assert!(safe.iter().zip(&valid).all(|(s, &v)| s.is_finite() == v));
+}
+
+#[test]
+fn select_where_incompatible()
+{
+    let cond = array![true, false, true];
+    let a = array![1, 2];
+    let b = array![1, 2, 3];
+    let err = Err(ShapeError::from_kind(ErrorKind::IncompatibleShape));
+    assert_eq!(select_where(&cond, &a, &b), err);
+    assert_eq!(select_where(&cond, &b, &a), err);
+    assert_eq!(select_where(&a.eq_scalar(1), &b, &b), err);
+}
+
+#[test]
+fn set_assign_where()
+{
+    let mut a = array![[1f64, 2., 3.], [4., 5., 6.]];
+    let row = array![true, false, true];
+    a.set_where(&row, -0.).unwrap();
+    assert_eq!(a, array![[-0., 2., -0.], [-0., 5., -0.]]);
+    assert!(a[[0, 0]].is_sign_negative());
+    let not_row = row.mapv(|m| !m);
+    let column = array![[10.], [20.]];
+    a.assign_where(&not_row, &column).unwrap();
+    assert_eq!(a, array![[-0., 10., -0.], [-0., 20., -0.]]);
+    let mut b = a.clone();
+    let src = array![7., 8., 9.];
+    b.assign_where(&arr0(true), &src).unwrap();
+    assert_eq!(b, array![[7., 8., 9.], [7., 8., 9.]]);
+    // a transposed target
+    let mut c = Array2::zeros((3, 2));
+    let src = array![1, 2];
+    c.view_mut()
+        .reversed_axes()
+        .assign_where(&row, &src.insert_axis(Axis(1)))
+        .unwrap();
+    assert_eq!(c, array![[1, 2], [0, 0], [1, 2]]);
+}
+
+#[test]
+fn set_assign_where_untouched()
+{
+    // the elements where the mask is false keep their exact bits
+    let values = [f64::NAN, -f64::NAN, -0., f64::MIN_POSITIVE / 2., f64::INFINITY, 1.];
+    let a = Array::from_iter(values.iter().copied())
+        .into_shape_with_order((2, 3))
+        .unwrap();
+    let mask = array![[false, true, false], [true, false, false]];
+    let mut b = a.clone();
+    b.set_where(&mask, 0.).unwrap();
+    let mut c = a.clone();
+    c.assign_where(&mask, &arr0(0.)).unwrap();
+    for x in [&b, &c] {
+        for ((m, x), y) in mask.iter().zip(x).zip(&a) {
+            if *m {
+                assert_eq!(*x, 0.);
+            } else {
+                assert_eq!(x.to_bits(), y.to_bits());
+            }
+        }
+    }
+    // an empty mask changes nothing
+    let mut d = a.clone();
+    d.set_where(&Array2::from_elem((2, 3), false), 0.).unwrap();
+    d.assign_where(&arr0(false), &arr0(0.)).unwrap();
+    assert_eq!(d.mapv(f64::to_bits), a.mapv(f64::to_bits));
+    // as does an empty array
+    let mut e = Array2::<f64>::zeros((0, 3));
+    let row = array![true, false, true];
+    e.set_where(&row, 1.).unwrap();
+    assert_eq!(e.shape(), &[0, 3]);
+}
+
+#[test]
+fn set_assign_where_incompatible()
+{
+    let a = array![[1, 2, 3], [4, 5, 6]];
+    let err = Err(ShapeError::from_kind(ErrorKind::IncompatibleShape));
+    let mut b = a.clone();
+    let row = array![true, false];
+    let column = array![[true], [false], [true]];
+    assert_eq!(b.set_where(&row, 0), err);
+    assert_eq!(b.assign_where(&column, &arr0(0)), err);
+    // a valid mask with an invalid source does not write anything
+    let src = array![1, 2];
+    assert_eq!(b.assign_where(&arr0(true), &src), err);
+    assert_eq!(b, a);
+}
+
+#[test]
+fn map_where_inplace()
+{
+    let a = array![[1, 8, 3], [9, 2, 7]];
+    let thr = array![[5], [6]];
+    let mask = a.gt(&thr).unwrap();
+    let mut b = a.clone();
+    let mut calls = 0;
+    b.map_where_inplace(&mask, |x| {
+        calls += 1;
+        *x = -*x;
+    })
+    .unwrap();
+    assert_eq!(calls, 3);
+    assert_eq!(b, array![[1, -8, 3], [-9, 2, -7]]);
+    // a contiguous and a transposed target see the same elements
+    let mut c = a.t().to_owned();
+    let mut d = a.clone();
+    c.map_where_inplace(&mask.t(), |x| *x *= 10).unwrap();
+    d.view_mut()
+        .reversed_axes()
+        .map_where_inplace(&mask.t(), |x| *x *= 10)
+        .unwrap();
+    assert_eq!(c, d.t());
+    assert_eq!(d, array![[1, 80, 3], [90, 2, 70]]);
+    // a broadcast mask
+    let mut e = a.clone();
+    let column = array![[false], [true]];
+    e.map_where_inplace(&column, |x| *x = 0).unwrap();
+    assert_eq!(e, array![[1, 8, 3], [0, 0, 0]]);
+    let row = array![true, false];
+    assert_eq!(
+        e.map_where_inplace(&row, |_| panic!()),
+        Err(ShapeError::from_kind(ErrorKind::IncompatibleShape))
+    );
+}
@@ -0,0 +1,114 @@
+#![allow(clippy::float_cmp)]
+#![cfg(feature = "std")]
+
+use approx::assert_relative_eq;
+use ndarray::prelude::*;
+
+#[test]
+fn geomean()
+{
+    let a = array![[2., 8.], [4., 1.]];
+    assert_relative_eq!(a.geomean().unwrap(), 8f64.sqrt(), max_relative = 1e-15);
+    assert_relative_eq!(a.t().geomean().unwrap(), 8f64.sqrt(), max_relative = 1e-15);
+    assert_relative_eq!(a.slice(s![.., ..;-1]).geomean().unwrap(), 8f64.sqrt(), max_relative = 1e-15);
+    assert_eq!(Array1::<f64>::zeros(0).geomean(), None);
+}
+
+#[test]
+fn geomean_zero_negative_nan()
+{
+    assert!(array![1., 0f64].geomean().unwrap().is_nan());
+    assert!(array![1., -4f64].geomean().unwrap().is_nan());
+    assert!(array![1., f64::NAN].geomean().unwrap().is_nan());
+    let g = array![[1., 1.], [-1., 4f64]].geomean_axis(Axis(0)).unwrap();
+    assert!(g[0].is_nan());
+    assert_eq!(g[1], 2.);
+}
+
+#[test]
+fn geomean_does_not_overflow()
+{
+    let a = Array::from_shape_fn(1_000_000, |i| if i % 2 == 0 { 2e30 } else { 0.5e30f64 });
+    assert!(a.product().is_infinite());
+    assert_relative_eq!(a.geomean().unwrap(), 1e30, max_relative = 1e-9);
+}
+
+#[test]
+fn geomean_axis()
+{
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (1 + i + 2 * j + 3 * k) as f64);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let g = a.geomean_axis(axis).unwrap();
+        for (lane, &g) in a.lanes(axis).into_iter().zip(&g) {
+            assert_relative_eq!(g, lane.geomean().unwrap(), max_relative = 1e-14);
+        }
+        let mut f = Array::zeros(a.raw_dim().f());
+        f.assign(&a);
+        let gf = f.geomean_axis(axis).unwrap();
+        g.iter()
+            .zip(&gf)
+            .for_each(|(&x, &y)| assert_relative_eq!(x, y, max_relative = 1e-14));
+    }
+    assert_eq!(Array2::<f64>::zeros((2, 0)).geomean_axis(Axis(1)), None);
+}
+
+#[test]
+fn harmonic_mean()
+{
+    let a = array![[1., 2.], [4., 4.]];
+    assert_eq!(a.harmonic_mean(), Some(2.));
+    assert_eq!(a.t().harmonic_mean(), Some(2.));
+    assert_eq!(Array1::<f64>::zeros(0).harmonic_mean(), None);
+    // a zero is the limit of the mean, not an error
+    assert_eq!(array![3., 0., 5.].harmonic_mean(), Some(0.));
+}
+
+#[test]
+fn harmonic_mean_axis()
+{
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (1 + i + 2 * j + 3 * k) as f64);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let h = a.harmonic_mean_axis(axis).unwrap();
+        for (lane, &h) in a.lanes(axis).into_iter().zip(&h) {
+            assert_relative_eq!(h, lane.harmonic_mean().unwrap(), max_relative = 1e-14);
+            assert!(h <= lane.geomean().unwrap() && h <= lane.mean().unwrap());
+        }
+    }
+    let b = array![[0., 2.], [3., 6.]];
+    assert_eq!(b.harmonic_mean_axis(Axis(1)), Some(array![0., 4.]));
+    assert_eq!(Array2::<f64>::zeros((0, 2)).harmonic_mean_axis(Axis(0)), None);
+}
+
+#[test]
+fn sum_of_squares()
+{
+    let a = Array::from_shape_fn((3, 5, 7), |(i, j, k)| (i as f64 - 1.) * 0.5 + j as f64 - k as f64 * 0.25);
+    let expected = a.mapv(|x| x * x).sum();
+    assert_relative_eq!(a.sum_of_squares(), expected, max_relative = 1e-14);
+    assert_relative_eq!(a.t().sum_of_squares(), expected, max_relative = 1e-14);
+    let v = a.slice(s![.., ..;2, ..;-3]);
+    assert_relative_eq!(v.sum_of_squares(), v.mapv(|x| x * x).sum(), max_relative = 1e-14);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let sums = a.sum_of_squares_axis(axis);
+        for (s, e) in sums.iter().zip(&a.mapv(|x| x * x).sum_axis(axis)) {
+            assert_relative_eq!(*s, *e, max_relative = 1e-14);
+        }
+    }
+    assert_eq!(Array1::<f64>::zeros(0).sum_of_squares(), 0.);
+    assert_eq!(Array2::<f64>::zeros((0, 2)).sum_of_squares_axis(Axis(0)), array![0., 0.]);
+}
+
+#[test]
+fn rms()
+{
+    let a = array![[3., -4.], [-4., 3.]];
+    assert_eq!(a.rms(), Some(12.5f64.sqrt()));
+    assert_eq!(a.rms_axis(Axis(0)), Some(array![5., 5.].mapv(|x: f64| (x * x / 2.).sqrt())));
+    assert_eq!(array![-2., 2., 2.].rms(), Some(2.));
+    assert_eq!(Array1::<f64>::zeros(0).rms(), None);
+    assert_eq!(Array2::<f64>::zeros((2, 0)).rms_axis(Axis(1)), None);
+    assert_eq!(Array2::<f64>::zeros((2, 0)).rms_axis(Axis(0)), Some(Array1::zeros(0)));
+}
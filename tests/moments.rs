@@ -0,0 +1,93 @@
+#![allow(clippy::float_cmp)]
+#![cfg(feature = "std")]
+
+use approx::assert_abs_diff_eq;
+use ndarray::prelude::*;
+
+/// Return the skewness and kurtosis of `a` with a two-pass algorithm.
+fn reference(a: ArrayView1<'_, f64>) -> (f64, f64)
+{
+    let n = a.len() as f64;
+    let mean = a.sum() / n;
+    let m = |k| a.iter().map(|&x| (x - mean).powi(k)).sum::<f64>() / n;
+    (m(3) / m(2).powf(1.5), m(4) / m(2).powi(2) - 3.)
+}
+
+#[test]
+fn skewness_kurtosis_closed_form()
+{
+    // deviations [-3, -2, -1, 0, 6]: m₂ = 10, m₃ = 36, m₄ = 278.8
+    let a = array![1., 2., 3., 4., 10.];
+    assert_abs_diff_eq!(a.skewness(), 36. / 10f64.powf(1.5), epsilon = 1e-15);
+    assert_abs_diff_eq!(a.kurtosis(), 278.8 / 100. - 3., epsilon = 1e-15);
+    assert_abs_diff_eq!(a.slice(s![..;-1]).skewness(), 36. / 10f64.powf(1.5), epsilon = 1e-15);
+    // the moments do not depend on the location or (for positive factors) the scale
+    let b = a.mapv(|x| 1e8 + 3. * x);
+    assert_abs_diff_eq!(b.skewness(), a.skewness(), epsilon = 1e-8);
+    assert_abs_diff_eq!(b.kurtosis(), a.kurtosis(), epsilon = 1e-8);
+    assert_abs_diff_eq!((-&a).skewness(), -a.skewness(), epsilon = 1e-15);
+}
+
+#[test]
+fn skewness_f32_large_values()
+{
+    // 900 values of 1e6 and 100 of 5e6, a scaled Bernoulli distribution with
+    // p = 0.1 and skewness 0.8 / 0.3, where the sum of squared deviations
+    // cubed would overflow `f32`
+    let a = Array::from_shape_fn(1000, |i| if i % 10 == 0 { 5e6f32 } else { 1e6 });
+    assert_abs_diff_eq!(a.skewness(), 0.8 / 0.3, epsilon = 1e-3);
+    assert_abs_diff_eq!(a.mapv(f64::from).skewness(), 0.8 / 0.3, epsilon = 1e-9);
+    let b = Array::from_shape_fn((2, 1000), |(_, j)| if j % 10 == 0 { 5e6f32 } else { 1e6 });
+    assert!(b.skewness_axis(Axis(1)).iter().all(|&s| (s - 0.8 / 0.3).abs() < 1e-3));
+}
+
+#[test]
+fn skewness_symmetric()
+{
+    let a = Array::linspace(-1., 1., 101).mapv(|x: f64| x.powi(3));
+    assert_abs_diff_eq!(a.skewness(), 0., epsilon = 1e-14);
+    // uniform samples have an excess kurtosis of about -1.2
+    let u = Array::linspace(0., 1., 10001);
+    assert_abs_diff_eq!(u.skewness(), 0., epsilon = 1e-12);
+    assert_abs_diff_eq!(u.kurtosis(), -1.2, epsilon = 1e-3);
+}
+
+#[test]
+fn skewness_kurtosis_short_and_constant()
+{
+    assert!(Array1::<f64>::zeros(0).skewness().is_nan());
+    assert!(array![1., 2f64].skewness().is_nan());
+    assert!(!array![1., 2., 4f64].skewness().is_nan());
+    assert!(array![1., 2., 4f64].kurtosis().is_nan());
+    assert!(!array![1., 2., 4., 8f64].kurtosis().is_nan());
+    assert!(array![3., 3., 3., 3f64].skewness().is_nan());
+    assert!(array![3., 3., 3., 3f64].kurtosis().is_nan());
+}
+
+#[test]
+fn skewness_kurtosis_axis()
+{
+    let a = Array::from_shape_fn((5, 6, 7), |(i, j, k)| ((i * 42 + j * 7 + k) as f64 * 0.7).sin().exp());
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let skewness = a.skewness_axis(axis);
+        let kurtosis = a.kurtosis_axis(axis);
+        for ((lane, &s), &k) in a.lanes(axis).into_iter().zip(&skewness).zip(&kurtosis) {
+            let (rs, rk) = reference(lane);
+            assert_abs_diff_eq!(s, rs, epsilon = 1e-12);
+            assert_abs_diff_eq!(k, rk, epsilon = 1e-12);
+            assert_abs_diff_eq!(s, lane.skewness(), epsilon = 1e-15);
+        }
+    }
+    let short = Array2::<f64>::zeros((4, 3));
+    assert!(short.skewness_axis(Axis(1)).iter().all(|x| x.is_nan()));
+    assert!(short.kurtosis_axis(Axis(1)).iter().all(|x| x.is_nan()));
+    assert_eq!(short.kurtosis_axis(Axis(0)).len(), 3);
+}
+
+#[test]
+#[should_panic]
+fn skewness_axis_out_of_bounds()
+{
+    array![1., 2., 3.].skewness_axis(Axis(1));
+}
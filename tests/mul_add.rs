@@ -0,0 +1,51 @@
+#![allow(clippy::float_cmp)]
+
+use ndarray::prelude::*;
+
+#[test]
+fn mul_add_integers()
+{
+    let x = array![[1, 2, 3], [4, 5, 6]];
+    let a = array![[2], [-1]];
+    let b = array![10, 20, 30];
+    let expected = array![[12, 24, 36], [6, 15, 24]];
+    assert_eq!(x.mul_add(&a, &b), Ok(expected.clone()));
+    assert_eq!(x.mul_add(&a, &b).unwrap(), &x * &a + &b);
+    // all three arrays take part in the broadcasting
+    let column = array![[1], [2]];
+    let row = array![1, 2, 3];
+    let scalar = arr0(5);
+    assert_eq!(column.mul_add(&row, &scalar), Ok(array![[6, 7, 8], [7, 9, 11]]));
+    assert_eq!(scalar.mul_add(&column, &row), Ok(array![[6, 7, 8], [11, 12, 13]]));
+    let bad = array![1, 2];
+    assert!(x.mul_add(&bad, &b).is_err());
+    assert!(x.mul_add(&a, &bad).is_err());
+    assert!(column.mul_add(&row, &bad).is_err());
+
+    let mut y = x.t().to_owned();
+    y.mul_add_assign(&a.t(), &b.view().insert_axis(Axis(1)))
+        .unwrap();
+    assert_eq!(y, expected.t());
+    let before = y.clone();
+    assert!(y.mul_add_assign(&a, &b).is_err());
+    assert!(y.mul_add_assign(&arr0(1), &b).is_err());
+    assert_eq!(y, before);
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn mul_add_is_fused()
+{
+    // 0.1 * 10 rounds to exactly 1, but 0.1 is slightly larger than a tenth
+    let x = array![0.1f64, 0.5];
+    let a = array![10.];
+    let b = array![-1.];
+    let unfused = &x * &a + &b;
+    assert_eq!(unfused, array![0., 4.]);
+    let fused = x.mul_add(&a, &b).unwrap();
+    assert_eq!(fused, array![0.1f64.mul_add(10., -1.), 4.]);
+    assert!(fused[0] > 0. && fused[0] < 1e-16);
+    let mut y = x.clone();
+    y.mul_add_assign(&a, &b).unwrap();
+    assert_eq!(y, fused);
+}
@@ -0,0 +1,103 @@
+#![allow(clippy::float_cmp)]
+#![cfg(feature = "std")]
+
+use ndarray::prelude::*;
+use ndarray::NormOrd;
+
+#[test]
+fn norms()
+{
+    let a = array![[3., -4., 0.], [12., 0., 0.]];
+    assert_eq!(a.norm_l1(), 19.);
+    assert_eq!(a.norm_l2(), 13.);
+    assert_eq!(a.norm_max(), 12.);
+    assert_eq!(a.t().norm_l2(), 13.);
+    assert_eq!(a.slice(s![.., ..;-1]).norm_l1(), 19.);
+    let empty = Array2::<f64>::zeros((0, 3));
+    assert_eq!(empty.norm_l1(), 0.);
+    assert_eq!(empty.norm_l2(), 0.);
+    assert_eq!(empty.norm_max(), 0.);
+    assert_eq!(Array1::<f64>::zeros(4).norm_l2(), 0.);
+}
+
+#[test]
+fn norm_l2_does_not_overflow_f32()
+{
+    let a = Array1::from_elem(100, 1e30f32);
+    // the naive sum of squares overflows
+    assert!(a.mapv(|x| x * x).sum().sqrt().is_infinite());
+    assert!((a.norm_l2() / 1e31 - 1.).abs() < 1e-6);
+    let tiny = Array1::from_elem(100, 1e-30f32);
+    // and the naive one underflows
+    assert_eq!(tiny.mapv(|x| x * x).sum(), 0.);
+    assert!((tiny.norm_l2() / 1e-29 - 1.).abs() < 1e-6);
+}
+
+#[test]
+fn norm_l2_matches_naive()
+{
+    let a = Array::linspace(-5., 7f64, 30);
+    let naive = a.mapv(|x| x * x).sum().sqrt();
+    assert!((a.norm_l2() - naive).abs() < 1e-12 * naive);
+}
+
+#[test]
+fn norms_infinity_and_nan()
+{
+    let inf = f64::INFINITY;
+    assert_eq!(array![1., -inf, inf].norm_l2(), inf);
+    assert_eq!(array![1., -inf].norm_max(), inf);
+    assert!(array![1., f64::NAN, 3.].norm_l2().is_nan());
+    assert!(array![f64::NAN, 3.].norm_max().is_nan());
+    assert!(array![1., f64::NAN].norm_l1().is_nan());
+}
+
+#[test]
+fn norm_axis()
+{
+    let a = array![[3., 1., 0.], [-4., 1., 2.]];
+    assert_eq!(a.norm_axis(Axis(0), NormOrd::L1), array![7., 2., 2.]);
+    assert_eq!(a.norm_axis(Axis(0), NormOrd::L2), array![5., 2f64.sqrt(), 2.]);
+    assert_eq!(a.norm_axis(Axis(1), NormOrd::Inf), array![3., 4.]);
+    assert_eq!(a.norm_axis(Axis(1), NormOrd::P(f64::INFINITY)), array![3., 4.]);
+    assert_eq!(a.norm_axis(Axis(1), NormOrd::P(1.)), a.norm_axis(Axis(1), NormOrd::L1));
+    let p2 = a.norm_axis(Axis(0), NormOrd::P(2.));
+    let l2 = a.norm_axis(Axis(0), NormOrd::L2);
+    assert!(p2.iter().zip(&l2).all(|(x, y)| (x - y).abs() < 1e-12));
+    let p3 = a.t().norm_axis(Axis(0), NormOrd::P(3.));
+    assert!((p3[1] - 73f64.cbrt()).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic(expected = "must be greater than zero")]
+fn norm_axis_non_positive_p()
+{
+    array![[1., 2.]].norm_axis(Axis(1), NormOrd::P(0.));
+}
+
+#[test]
+fn normalize_axis()
+{
+    let a = array![[3., 4.], [0., 0.], [-1., 0f64]];
+    let unit = a.normalize_axis(Axis(1), NormOrd::L2);
+    assert_eq!(unit.shape(), a.shape());
+    assert_eq!(unit, array![[0.6, 0.8], [0., 0.], [-1., 0.]]);
+    let cols = a.normalize_axis(Axis(0), NormOrd::Inf);
+    assert_eq!(cols, array![[1., 1.], [0., 0.], [-1. / 3., 0.]]);
+    for ord in [NormOrd::L1, NormOrd::L2, NormOrd::Inf, NormOrd::P(3.)] {
+        let unit = a.t().normalize_axis(Axis(0), ord);
+        let norms = unit.norm_axis(Axis(0), ord);
+        assert!((norms[0] - 1.).abs() < 1e-12 && norms[1] == 0. && (norms[2] - 1.).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn normalize_axis_zero_lanes_stay_zero()
+{
+    let mut a = Array3::<f64>::zeros((2, 3, 4));
+    a[(1, 2, 3)] = -2.;
+    a.normalize_axis_inplace(Axis(2), NormOrd::L1);
+    assert!(a.iter().all(|x| !x.is_nan()));
+    assert_eq!(a[(1, 2, 3)], -1.);
+    assert_eq!(a.iter().filter(|&&x| x != 0.).count(), 1);
+}
@@ -4,7 +4,26 @@
 )]
 
 use approx::assert_abs_diff_eq;
-use ndarray::{arr0, arr1, arr2, array, aview1, Array, Array1, Array2, Array3, Axis};
+#[cfg(any(feature = "std", feature = "libm"))]
+use ndarray::StatsError;
+use ndarray::{
+    arr0,
+    arr1,
+    arr2,
+    array,
+    aview1,
+    s,
+    ArithmeticOp,
+    Array,
+    Array1,
+    Array2,
+    Array3,
+    Axis,
+    DiffMode,
+    ErrorKind,
+    ShapeBuilder,
+    Slice,
+};
 use std::f64;
 
 #[test]
@@ -146,7 +165,63 @@ fn test_cumprod_axis_out_of_bounds()
 }
 
 #[test]
-#[cfg(feature = "std")]
+fn test_cumsum_axis_inplace()
+{
+    let mut a = array![[1, 2, 3], [4, 5, 6]];
+    a.cumsum_axis_inplace(Axis(0));
+    assert_eq!(a, array![[1, 2, 3], [5, 7, 9]]);
+    a.cumsum_axis_inplace(Axis(1));
+    assert_eq!(a, array![[1, 3, 6], [5, 12, 21]]);
+}
+
+#[test]
+fn test_cumsum_axis_inplace_f_order()
+{
+    let mut a = Array2::from_shape_vec((2, 3).f(), vec![1, 4, 2, 5, 3, 6]).unwrap();
+    assert_eq!(a, array![[1, 2, 3], [4, 5, 6]]);
+    a.cumsum_axis_inplace(Axis(1));
+    assert_eq!(a, array![[1, 3, 6], [4, 9, 15]]);
+    a.cumsum_axis_inplace(Axis(0));
+    assert_eq!(a, array![[1, 3, 6], [5, 12, 21]]);
+}
+
+#[test]
+fn test_cumsum_axis_inplace_negative_strides()
+{
+    let mut a = array![[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+    {
+        let mut v = a.slice_mut(s![..;-2, ..;-1]);
+        assert_eq!(v, array![[12, 11, 10, 9], [4, 3, 2, 1]]);
+        v.cumsum_axis_inplace(Axis(1));
+        assert_eq!(v, array![[12, 23, 33, 42], [4, 7, 9, 10]]);
+    }
+    // the middle row is not part of the view and stays as is
+    assert_eq!(a, array![[10, 9, 7, 4], [5, 6, 7, 8], [42, 33, 23, 12]]);
+}
+
+#[test]
+fn test_cumprod_axis_inplace()
+{
+    let mut a = array![[1, 2, 3], [4, 5, 6]];
+    let expected = a.cumprod(Axis(1));
+    a.cumprod_axis_inplace(Axis(1));
+    assert_eq!(a, expected);
+
+    let mut b = Array2::from_shape_vec((2, 3).f(), vec![1, 4, 2, 5, 3, 6]).unwrap();
+    b.slice_mut(s![..;-1, ..]).cumprod_axis_inplace(Axis(0));
+    assert_eq!(b, array![[4, 10, 18], [4, 5, 6]]);
+}
+
+#[test]
+#[should_panic(expected = "axis is out of bounds for array of dimension")]
+fn test_cumsum_axis_inplace_axis_out_of_bounds()
+{
+    let mut a = array![[1, 2], [3, 4]];
+    a.cumsum_axis_inplace(Axis(2));
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn var()
 {
     let a = array![1., -4.32, 1.14, 0.32];
@@ -154,7 +229,7 @@ fn var()
 }
 
 #[test]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 #[should_panic]
 fn var_negative_ddof()
 {
@@ -163,7 +238,7 @@ fn var_negative_ddof()
 }
 
 #[test]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 #[should_panic]
 fn var_too_large_ddof()
 {
@@ -172,7 +247,7 @@ fn var_too_large_ddof()
 }
 
 #[test]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn var_nan_ddof()
 {
     let a = Array2::<f64>::zeros((2, 3));
@@ -181,7 +256,7 @@ fn var_nan_ddof()
 }
 
 #[test]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn var_empty_arr()
 {
     let a: Array1<f64> = array![];
@@ -189,7 +264,228 @@ fn var_empty_arr()
 }
 
 #[test]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn try_var_std()
+{
+    let a = array![1., -4.32, 1.14, 0.32];
+    assert_eq!(a.try_var(1.), Ok(a.var(1.)));
+    assert_eq!(a.try_std(0.), Ok(a.std(0.)));
+    assert_eq!(a.try_var(4.), Ok(f64::INFINITY));
+    assert!(a.try_var(f64::NAN).unwrap().is_nan());
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn try_var_std_errors()
+{
+    let a = array![1., 2., 3.];
+    assert_eq!(a.try_var(-1.), Err(StatsError::DdofOutOfRange { ddof: -1., n: 3 }));
+    assert_eq!(a.try_var(3.5), Err(StatsError::DdofOutOfRange { ddof: 3.5, n: 3 }));
+    assert_eq!(a.try_std(-0.5), Err(StatsError::DdofOutOfRange { ddof: -0.5, n: 3 }));
+    let empty = Array1::<f32>::zeros(0);
+    assert_eq!(empty.try_var(0.), Err(StatsError::EmptyInput));
+    assert_eq!(empty.try_std(0.), Err(StatsError::EmptyInput));
+    assert_eq!(empty.try_var(1.), Err(StatsError::DdofOutOfRange { ddof: 1., n: 0 }));
+    assert_eq!(
+        a.try_var(4.).unwrap_err().to_string(),
+        "StatsError/DdofOutOfRange: `ddof` must not be less than zero or greater than the length 3, but is 4"
+    );
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn try_var_std_axis()
+{
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| ((i * 7 + j * 3 + k * k) % 11) as f64);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        assert_eq!(a.try_var_axis(axis, 1.), Ok(a.var_axis(axis, 1.)));
+        assert_eq!(a.try_std_axis(axis, 0.), Ok(a.std_axis(axis, 0.)));
+        let n = a.len_of(axis);
+        assert_eq!(a.try_var_axis(axis, -1.), Err(StatsError::DdofOutOfRange { ddof: -1., n }));
+        assert_eq!(a.try_std_axis(axis, n as f64 + 1.), Err(StatsError::DdofOutOfRange { ddof: n as f64 + 1., n }));
+    }
+    let empty = Array2::<f64>::zeros((2, 0));
+    assert_eq!(empty.try_var_axis(Axis(1), 0.), Err(StatsError::EmptyInput));
+    assert_eq!(empty.try_std_axis(Axis(1), 0.), Err(StatsError::EmptyInput));
+    assert_eq!(empty.try_var_axis(Axis(0), 1.), Ok(Array1::zeros(0)));
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn var_twopass_accuracy()
+{
+    // A huge offset with comparatively small noise, where the one-pass
+    // algorithm loses precision in `f32`
+    use ndarray_gen::array_builder::{ArrayBuilder, ElementGenerator};
+
+    let a = ArrayBuilder::new(10_000)
+        .generator(ElementGenerator::Random(12345))
+        .build::<f32>()
+        .mapv(|x| 1e9 + x / 65536. * 1e6);
+    let reference = a.mapv(f64::from).var_twopass(1.);
+    assert_abs_diff_eq!(reference, a.mapv(f64::from).var(1.), epsilon = reference * 1e-12);
+    let err_welford = (f64::from(a.var(1.)) - reference).abs();
+    let err_twopass = (f64::from(a.var_twopass(1.)) - reference).abs();
+    assert!(err_twopass < err_welford);
+    assert!(err_twopass < reference * 1e-5);
+
+    let b = a.to_shape((100, 100)).unwrap();
+    let reference = b.mapv(f64::from).var_axis(Axis(0), 1.);
+    let err_welford = (b.var_axis(Axis(0), 1.).mapv(f64::from) - &reference).mapv(f64::abs);
+    let err_twopass = (b.var_axis_twopass(Axis(0), 1.).mapv(f64::from) - &reference).mapv(f64::abs);
+    assert!(err_twopass.sum() < err_welford.sum());
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn var_twopass_matches_var()
+{
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| ((i * 7 + j * 3 + k * k) % 11) as f64);
+    assert_abs_diff_eq!(a.var_twopass(1.), a.var(1.), epsilon = 1e-12);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        for (x, y) in a
+            .var_axis_twopass(axis, 0.)
+            .iter()
+            .zip(&a.var_axis(axis, 0.))
+        {
+            assert_abs_diff_eq!(x, y, epsilon = 1e-12);
+        }
+    }
+    assert!(Array1::<f64>::zeros(0).var_twopass(0.).is_nan());
+    assert!(Array2::<f64>::zeros((2, 0))
+        .var_axis_twopass(Axis(1), 0.)
+        .iter()
+        .all(|v| v.is_nan()));
+    assert_eq!(Array2::<f64>::zeros((2, 0)).var_axis_twopass(Axis(0), 1.), Array1::zeros(0));
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+#[should_panic(expected = "DdofOutOfRange")]
+fn var_twopass_too_large_ddof()
+{
+    array![1., 2.].var_twopass(3.);
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn standardize_axis()
+{
+    let a = Array::from_shape_fn((4, 5, 6), |(i, j, k)| ((i * 7 + j * 3 + k * k) % 11) as f64 * 0.5 - 2.);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        for &ddof in &[0., 1.] {
+            let z = a.standardize_axis(axis, ddof, true);
+            for lane in z.lanes(axis) {
+                assert_abs_diff_eq!(lane.mean().unwrap(), 0., epsilon = 1e-12);
+                assert_abs_diff_eq!(lane.std(ddof), 1., epsilon = 1e-12);
+            }
+            let mut b = a.clone();
+            b.standardize_axis_inplace(axis, ddof, true);
+            assert_eq!(b, z);
+        }
+    }
+
+    // the in-place version also works on views with arbitrary strides
+    let mut b = a.clone();
+    let mut v = b.slice_mut(s![..;-1, 1.., ..;2]);
+    v.standardize_axis_inplace(Axis(2), 0., true);
+    assert_eq!(v, a.slice(s![..;-1, 1.., ..;2]).standardize_axis(Axis(2), 0., true));
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn standardize_axis_constant_lanes()
+{
+    let a = array![[1., 2., 3.], [5., 5., 5f64]];
+    assert_eq!(a.standardize_axis(Axis(1), 0., true).row(1), array![0., 0., 0.]);
+    assert!(a
+        .standardize_axis(Axis(1), 0., false)
+        .row(1)
+        .iter()
+        .all(|x| x.is_nan()));
+    let empty = Array2::<f64>::zeros((3, 0));
+    assert_eq!(empty.standardize_axis(Axis(1), 0., true), empty);
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+#[should_panic(expected = "DdofOutOfRange")]
+fn standardize_axis_too_large_ddof()
+{
+    array![[1., 2.]].standardize_axis(Axis(1), 3., true);
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn ewma()
+{
+    let a = array![2., 4., 8., 6.];
+    assert_eq!(a.ewma(Axis(0), 0.5, false).unwrap(), array![2., 3., 5.5, 5.75]);
+    assert_eq!(a.ewma(Axis(0), 0.5, true).unwrap(), array![2., 10. / 3., 6., 6.]);
+    assert_eq!(a.ewma(Axis(0), 1., false).unwrap(), a);
+    assert_eq!(a.ewma(Axis(0), 1., true).unwrap(), a);
+    // each lane is averaged separately, whatever the memory layout
+    let b = Array::from_shape_vec((4, 2).f(), vec![2., 4., 8., 6., 1., 1., 1., 1.]).unwrap();
+    for &adjust in &[false, true] {
+        let ewma = b.ewma(Axis(0), 0.5, adjust).unwrap();
+        assert_eq!(ewma.column(0), a.ewma(Axis(0), 0.5, adjust).unwrap());
+        assert_eq!(ewma.column(1), array![1., 1., 1., 1.]);
+        assert_eq!(b.t().ewma(Axis(1), 0.5, adjust).unwrap(), ewma.t());
+    }
+    // a NaN propagates to the rest of the lane
+    let ewma = array![2., f64::NAN, 4.].ewma(Axis(0), 0.5, true).unwrap();
+    assert_eq!(ewma[0], 2.);
+    assert!(ewma[1].is_nan() && ewma[2].is_nan());
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn ewma_skipnan()
+{
+    let nan = f64::NAN;
+    let a = array![nan, 2., nan, 4., 8.];
+    let ewma = a.ewma_skipnan(Axis(0), 0.5, false).unwrap();
+    assert!(ewma[0].is_nan());
+    assert_eq!(ewma.slice(s![1..]), array![2., 2., 3., 5.5]);
+    let ewma = a.ewma_skipnan(Axis(0), 0.5, true).unwrap();
+    assert!(ewma[0].is_nan());
+    assert_eq!(ewma.slice(s![1..]), array![2., 2., 10. / 3., 6.]);
+    // without NaNs, the same as `ewma`
+    let b = array![[2., 4., 8., 6.], [1., 3., 5., 7.]];
+    for &adjust in &[false, true] {
+        assert_eq!(b.ewma_skipnan(Axis(1), 0.3, adjust).unwrap(), b.ewma(Axis(1), 0.3, adjust).unwrap());
+    }
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn ewma_alpha_out_of_range()
+{
+    let a = array![1., 2.];
+    for &adjust in &[false, true] {
+        assert_eq!(a.ewma(Axis(0), 0., adjust), Err(StatsError::AlphaOutOfRange { alpha: 0. }));
+        assert_eq!(a.ewma(Axis(0), -0.5, adjust), Err(StatsError::AlphaOutOfRange { alpha: -0.5 }));
+        assert_eq!(a.ewma_skipnan(Axis(0), 1.5, adjust), Err(StatsError::AlphaOutOfRange { alpha: 1.5 }));
+        assert_eq!(
+            a.ewma_skipnan(Axis(0), 1. + f64::EPSILON, adjust),
+            Err(StatsError::AlphaOutOfRange { alpha: 1. + f64::EPSILON })
+        );
+        for result in [a.ewma(Axis(0), f64::NAN, adjust), a.ewma_skipnan(Axis(0), f64::NAN, adjust)] {
+            assert!(matches!(result, Err(StatsError::AlphaOutOfRange { alpha }) if alpha.is_nan()));
+        }
+        // the ends of the interval
+        assert!(a.ewma(Axis(0), f64::MIN_POSITIVE, adjust).is_ok());
+        assert_eq!(a.ewma_skipnan(Axis(0), 1., adjust), Ok(a.clone()));
+    }
+    let f = array![1f32, 2.];
+    assert_eq!(f.ewma(Axis(0), 2., true), Err(StatsError::AlphaOutOfRange { alpha: 2. }));
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn std()
 {
     let a = array![1., -4.32, 1.14, 0.32];
@@ -197,7 +493,7 @@ fn std()
 }
 
 #[test]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 #[should_panic]
 fn std_negative_ddof()
 {
@@ -206,7 +502,7 @@ fn std_negative_ddof()
 }
 
 #[test]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 #[should_panic]
 fn std_too_large_ddof()
 {
@@ -215,7 +511,7 @@ fn std_too_large_ddof()
 }
 
 #[test]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn std_nan_ddof()
 {
     let a = Array2::<f64>::zeros((2, 3));
@@ -224,7 +520,7 @@ fn std_nan_ddof()
 }
 
 #[test]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn std_empty_arr()
 {
     let a: Array1<f64> = array![];
@@ -233,7 +529,7 @@ fn std_empty_arr()
 
 #[test]
 #[cfg(feature = "approx")]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn var_axis()
 {
     use ndarray::{aview0, aview2};
@@ -293,7 +589,7 @@ fn var_axis()
 
 #[test]
 #[cfg(feature = "approx")]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn std_axis()
 {
     use ndarray::aview2;
@@ -354,7 +650,7 @@ fn std_axis()
 
 #[test]
 #[should_panic]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn var_axis_negative_ddof()
 {
     let a = array![1., 2., 3.];
@@ -363,7 +659,7 @@ fn var_axis_negative_ddof()
 
 #[test]
 #[should_panic]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn var_axis_too_large_ddof()
 {
     let a = array![1., 2., 3.];
@@ -371,7 +667,7 @@ fn var_axis_too_large_ddof()
 }
 
 #[test]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn var_axis_nan_ddof()
 {
     let a = Array2::<f64>::zeros((2, 3));
@@ -381,7 +677,7 @@ fn var_axis_nan_ddof()
 }
 
 #[test]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn var_axis_empty_axis()
 {
     let a = Array2::<f64>::zeros((2, 0));
@@ -392,7 +688,7 @@ fn var_axis_empty_axis()
 
 #[test]
 #[should_panic]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn std_axis_bad_dof()
 {
     let a = array![1., 2., 3.];
@@ -400,7 +696,7 @@ fn std_axis_bad_dof()
 }
 
 #[test]
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 fn std_axis_empty_axis()
 {
     let a = Array2::<f64>::zeros((2, 0));
@@ -476,3 +772,1002 @@ fn diff_panic_axis_out_of_bounds()
     let data = array![1, 2, 4, 7];
     data.diff(1, Axis(2));
 }
+
+#[test]
+fn diff_mode_central_linear_ramp()
+{
+    let ramp = Array::from_shape_fn((4, 9), |(i, j)| 0.25 * j as f64 - 3. * i as f64 + 1.);
+    let along_1 = ramp.diff_mode(1, Axis(1), DiffMode::Central);
+    assert_eq!(along_1, Array::from_elem((4, 7), 0.25));
+    let along_0 = ramp.diff_mode(1, Axis(0), DiffMode::Central);
+    assert_eq!(along_0, Array::from_elem((2, 9), -3.));
+    assert_eq!(ramp.t().diff_mode(1, Axis(0), DiffMode::Central), along_1.t());
+    assert_eq!(ramp.diff_mode(2, Axis(1), DiffMode::Central), Array::zeros((4, 5)));
+    let ints = array![0, 3, 6, 9, 12];
+    assert_eq!(ints.diff_mode(1, Axis(0), DiffMode::Central), array![3, 3, 3]);
+    // odd integer differences truncate towards zero
+    let ints = array![0, 1, 3, 0, -4];
+    assert_eq!(ints.diff_mode(1, Axis(0), DiffMode::Central), array![1, 0, -3]);
+}
+
+#[test]
+fn diff_mode_forward_backward()
+{
+    let data = array![[1, 2, 4, 7], [1, 3, 6, 6], [2, 4, 4, 5]];
+    for axis in 0..2 {
+        let axis = Axis(axis);
+        for n in 0..3 {
+            assert_eq!(data.diff_mode(n, axis, DiffMode::Forward), data.diff(n, axis));
+            let backward = data.diff_mode(n, axis, DiffMode::Backward);
+            assert_eq!(backward.shape(), data.shape());
+            assert!(backward.slice_axis(axis, Slice::from(..n)).iter().all(|&x| x == 0));
+            assert_eq!(backward.slice_axis(axis, Slice::from(n..)), data.diff(n, axis));
+        }
+    }
+    assert_eq!(
+        data.diff_mode(1, Axis(1), DiffMode::Backward),
+        array![[0, 1, 2, 3], [0, 2, 3, 0], [0, 2, 0, 1]]
+    );
+    assert_eq!(data.diff_mode(0, Axis(1), DiffMode::Central), data);
+}
+
+#[test]
+#[should_panic(expected = "Central differences of order 2 along axis 1")]
+fn diff_mode_central_panic_too_short()
+{
+    let data = Array2::<f64>::zeros((5, 4));
+    data.diff_mode(2, Axis(1), DiffMode::Central);
+}
+
+#[test]
+#[should_panic(expected = "Backward differences along axis 2")]
+fn diff_mode_panic_axis_out_of_bounds()
+{
+    let data = array![[1, 2], [4, 7]];
+    data.diff_mode(1, Axis(2), DiffMode::Backward);
+}
+
+#[test]
+fn diff_with_padding()
+{
+    let data = array![[1, 2, 4, 7], [1, 3, 6, 6], [2, 4, 4, 5]];
+    let first = array![[0], [0], [1]];
+    let last = array![[10], [10], [10]];
+    assert_eq!(
+        data.diff_with(1, Axis(1), Some(&first), None),
+        Ok(array![[1, 1, 2, 3], [1, 2, 3, 0], [1, 2, 0, 1]])
+    );
+    assert_eq!(
+        data.diff_with(1, Axis(1), None, Some(&last)),
+        Ok(array![[1, 2, 3, 3], [2, 3, 0, 4], [2, 0, 1, 5]])
+    );
+    // scalar-style padding of a single row
+    let row = array![[0, 0, 0, 0]];
+    assert_eq!(data.diff_with(1, Axis(0), Some(&row), Some(&row)).unwrap().shape(), &[4, 4]);
+    assert_eq!(data.diff_with(1, Axis(0), Some(&row), None).unwrap().row(0), data.row(0));
+    assert_eq!(data.diff_with(2, Axis(1), None, None), Ok(data.diff(2, Axis(1))));
+}
+
+#[test]
+fn diff_with_matches_concatenate()
+{
+    let data = Array::from_shape_fn((3, 4, 5), |(i, j, k)| ((i * 7 + j * 3 + k * k) % 11) as i32);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let mut pad_shape = [3, 4, 5];
+        pad_shape[axis.index()] = 2;
+        let before = Array::from_shape_fn(pad_shape, |(i, j, k)| (i + j + k) as i32);
+        let after = Array::from_shape_fn(pad_shape, |(i, j, k)| (i * j * k) as i32);
+        for n in 1..4 {
+            let joined = ndarray::concatenate(axis, &[before.view(), data.view(), after.view()]).unwrap();
+            assert_eq!(data.diff_with(n, axis, Some(&before), Some(&after)), Ok(joined.diff(n, axis)));
+            let joined = ndarray::concatenate(axis, &[before.view(), data.view()]).unwrap();
+            assert_eq!(data.diff_with(n, axis, Some(&before), None), Ok(joined.diff(n, axis)));
+        }
+    }
+}
+
+#[test]
+fn diff_with_shape_error()
+{
+    let data = array![[1., 2., 4.], [1., 3., 6.]];
+    let wrong = array![[0., 0.]];
+    let err = data.diff_with(1, Axis(0), Some(&wrong), None).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    let err = data.diff_with(1, Axis(1), None, Some(&wrong)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    assert!(data.diff_with(1, Axis(1), Some(&wrong.t().to_owned()), None).is_ok());
+}
+
+#[test]
+#[should_panic]
+fn diff_with_panic_axis_out_of_bounds()
+{
+    let data = array![1, 2, 4, 7];
+    let _ = data.diff_with(1, Axis(1), Some(&data), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nansum_nanprod()
+{
+    let a = array![[1., f64::NAN, 3.], [f64::NAN, 5., 6.]];
+    assert_eq!(a.nansum(), 15.);
+    assert_eq!(a.nanprod(), 90.);
+    // non-contiguous, no fast path
+    assert_eq!(a.slice(s![.., ..;2]).nansum(), 10.);
+    assert_eq!(a.t().nanprod(), 90.);
+
+    // long enough to exercise the unrolled fold
+    let mut b = Array1::from_iter((0..37).map(f64::from));
+    b[3] = f64::NAN;
+    b[20] = f64::NAN;
+    assert_eq!(b.nansum(), (0..37).sum::<i32>() as f64 - 23.);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nansum_nanprod_all_nan_or_empty()
+{
+    let a = Array2::<f64>::from_elem((2, 3), f64::NAN);
+    assert_eq!(a.nansum(), 0.);
+    assert_eq!(a.nanprod(), 1.);
+    assert_eq!(a.nansum_axis(Axis(0)), arr1(&[0., 0., 0.]));
+    assert_eq!(a.nanprod_axis(Axis(1)), arr1(&[1., 1.]));
+
+    let e = Array2::<f32>::zeros((0, 3));
+    assert_eq!(e.nansum(), 0.);
+    assert_eq!(e.nanprod(), 1.);
+    assert_eq!(e.nansum_axis(Axis(0)), arr1(&[0., 0., 0.]));
+    assert_eq!(e.nanprod_axis(Axis(0)), arr1(&[1., 1., 1.]));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nansum_nanprod_axis()
+{
+    let a = array![[1., f64::NAN, 3.], [f64::NAN, 5., 6.]];
+    assert_eq!(a.nansum_axis(Axis(0)), arr1(&[1., 5., 9.]));
+    assert_eq!(a.nansum_axis(Axis(1)), arr1(&[4., 11.]));
+    assert_eq!(a.nanprod_axis(Axis(0)), arr1(&[1., 5., 18.]));
+    assert_eq!(a.nanprod_axis(Axis(1)), arr1(&[3., 30.]));
+
+    let f = a.t().to_owned();
+    assert_eq!(f.t().nansum_axis(Axis(0)), arr1(&[1., 5., 9.]));
+    assert_eq!(f.t().nanprod_axis(Axis(1)), arr1(&[3., 30.]));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nanmean()
+{
+    let a = array![[1., f64::NAN, 3.], [f64::NAN, 5., 6.]];
+    assert_eq!(a.nanmean(), Some(15. / 4.));
+    assert_eq!(Array1::<f64>::zeros(0).nanmean(), None);
+    assert_eq!(Array2::<f64>::from_elem((3, 4), f64::NAN).nanmean(), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nanmean_axis_different_nan_counts()
+{
+    let a = array![
+        [1., f64::NAN, f64::NAN, 4.],
+        [f64::NAN, f64::NAN, 7., 8.],
+        [3., f64::NAN, 9., 12.]
+    ];
+    let m = a.nanmean_axis(Axis(0));
+    assert_eq!(m[0], 2.);
+    assert!(m[1].is_nan());
+    assert_eq!(m.slice(s![2..]), aview1(&[8., 8.]));
+    assert_eq!(a.nanmean_axis(Axis(1)), aview1(&[2.5, 7.5, 8.]));
+    assert_eq!(a.t().nanmean_axis(Axis(0)), aview1(&[2.5, 7.5, 8.]));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nanmean_axis_all_nan()
+{
+    let a = Array3::<f32>::from_elem((2, 3, 4), f32::NAN);
+    let m = a.nanmean_axis(Axis(1));
+    assert_eq!(m.shape(), &[2, 4]);
+    assert!(m.iter().all(|x| x.is_nan()));
+
+    let e = Array2::<f64>::zeros((0, 2)).nanmean_axis(Axis(0));
+    assert_eq!(e.shape(), &[2]);
+    assert!(e.iter().all(|x| x.is_nan()));
+}
+
+#[test]
+#[cfg(feature = "approx")]
+#[cfg(feature = "std")]
+fn nanvar_nanstd_agree_without_nan()
+{
+    let a = array![[1., -4.32, 1.14], [0.32, 2.5, -0.7], [3.2, 1e-3, 0.]];
+    for &ddof in &[0., 1., 2.] {
+        assert_abs_diff_eq!(a.nanvar(ddof), a.var(ddof), epsilon = 1e-12);
+        assert_abs_diff_eq!(a.nanstd(ddof), a.std(ddof), epsilon = 1e-12);
+        for axis in 0..2 {
+            assert_abs_diff_eq!(a.nanvar_axis(Axis(axis), ddof), a.var_axis(Axis(axis), ddof), epsilon = 1e-12);
+            assert_abs_diff_eq!(a.nanstd_axis(Axis(axis), ddof), a.std_axis(Axis(axis), ddof), epsilon = 1e-12);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nanvar_axis_mixed_nan_lanes()
+{
+    let a = array![
+        [1., f64::NAN, f64::NAN, 4.],
+        [f64::NAN, f64::NAN, 7., 8.],
+        [3., 5., 9., f64::NAN]
+    ];
+    let v = a.nanvar_axis(Axis(0), 1.);
+    assert_abs_diff_eq!(v[0], array![1., 3.].var(1.));
+    // a single valid element leaves no degrees of freedom
+    assert!(v[1].is_nan());
+    assert_abs_diff_eq!(v[2], array![7., 9.].var(1.));
+    assert_abs_diff_eq!(v[3], array![4., 8.].var(1.));
+
+    let v0 = a.nanvar_axis(Axis(0), 0.);
+    assert_eq!(v0[1], 0.);
+
+    let s = a.nanstd_axis(Axis(1), 1.);
+    assert_abs_diff_eq!(s[0], array![1., 4.].std(1.));
+    assert_abs_diff_eq!(s[2], array![3., 5., 9.].std(1.));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nanvar_too_few_valid_elements()
+{
+    let a = array![f64::NAN, 2., f64::NAN];
+    assert!(a.nanvar(1.).is_nan());
+    assert!(a.nanstd(2.).is_nan());
+    assert_eq!(a.nanvar(0.), 0.);
+    assert!(Array1::<f64>::zeros(0).nanvar(0.).is_nan());
+    assert!(Array2::<f64>::zeros((0, 3))
+        .nanvar_axis(Axis(0), 0.)
+        .iter()
+        .all(|x| x.is_nan()));
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic]
+fn nanvar_negative_ddof()
+{
+    array![1., f64::NAN, 3.].nanvar(-1.);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nancumsum_nancumprod()
+{
+    let a = array![[f64::NAN, 2., 3., f64::NAN], [4., f64::NAN, f64::NAN, 6.]];
+    assert_eq!(a.nancumsum(Axis(1)), array![[0., 2., 5., 5.], [4., 4., 4., 10.]]);
+    assert_eq!(a.nancumprod(Axis(1)), array![[1., 2., 6., 6.], [4., 4., 4., 24.]]);
+    assert_eq!(a.nancumsum(Axis(0)), array![[0., 2., 3., 0.], [4., 2., 3., 6.]]);
+    assert_eq!(a.nancumprod(Axis(0)), array![[1., 2., 3., 1.], [4., 2., 3., 6.]]);
+    assert_eq!(a.t().nancumsum(Axis(0)), a.nancumsum(Axis(1)).t());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nancumsum_matches_cumsum_without_nan()
+{
+    let a = array![[1., 2., 3.], [4., 5., 6.]];
+    let mut expected = a.clone();
+    expected.cumsum_axis_inplace(Axis(0));
+    assert_eq!(a.nancumsum(Axis(0)), expected);
+    assert_eq!(a.nancumprod(Axis(1)), a.cumprod(Axis(1)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic(expected = "axis is out of bounds for array of dimension")]
+fn nancumsum_axis_out_of_bounds()
+{
+    array![1., f64::NAN].nancumsum(Axis(1));
+}
+
+#[test]
+#[cfg(feature = "approx")]
+#[cfg(feature = "std")]
+fn gradient_of_quadratic_is_exact()
+{
+    // f(x) = 3x² - 2x + 1 on a uniform grid, f'(x) = 6x - 2
+    let dx = 0.25;
+    let x = Array::from_shape_fn(9, |i| i as f64 * dx);
+    let f = x.mapv(|x| 3. * x * x - 2. * x + 1.);
+    let g = f.gradient_with_spacing(Axis(0), dx);
+    assert_abs_diff_eq!(g, x.mapv(|x| 6. * x - 2.), epsilon = 1e-12);
+    // non-uniform coordinates
+    let x = array![-1., -0.5, 0.25, 1., 3., 3.5];
+    let f = x.mapv(|x| 3. * x * x - 2. * x + 1.);
+    let g = f.gradient_with_coords(Axis(0), &x);
+    assert_abs_diff_eq!(g, x.mapv(|x| 6. * x - 2.), epsilon = 1e-12);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn gradient_2d()
+{
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * i) as f64 + 10. * j as f64);
+    assert_eq!(a.gradient(Axis(1)), Array::from_elem((3, 4), 10.));
+    let g = a.gradient(Axis(0));
+    for col in g.columns() {
+        assert_eq!(col, array![0., 2., 4.]);
+    }
+    assert_eq!(a.t().gradient(Axis(1)), g.t());
+    assert_eq!(a.slice(s![..;-1, ..]).gradient(Axis(0)), array![-4., -2., 0.].broadcast((4, 3)).unwrap().t());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn gradient_two_elements()
+{
+    assert_eq!(array![1., 4.].gradient(Axis(0)), array![3., 3.]);
+    assert_eq!(array![1., 4.].gradient_with_spacing(Axis(0), 2.), array![1.5, 1.5]);
+    let x = array![0., -1.];
+    assert_eq!(array![1., 4.].gradient_with_coords(Axis(0), &x), array![-3., -3.]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic(expected = "must have length at least 2")]
+fn gradient_too_short()
+{
+    array![[1.], [2.]].gradient(Axis(1));
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic(expected = "same length")]
+fn gradient_with_coords_length_mismatch()
+{
+    let x = array![0., 1.];
+    array![1., 2., 3.].gradient_with_coords(Axis(0), &x);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn trapz_sin()
+{
+    let n = 1001;
+    let x = Array::linspace(0., std::f64::consts::PI, n);
+    let y = x.mapv(f64::sin);
+    assert!((y.trapz(x[1] - x[0]) - 2.).abs() < 1e-4);
+    assert!((y.trapz_with_coords(&x).unwrap() - 2.).abs() < 1e-4);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn trapz_axis()
+{
+    let a = array![[1., 2., 3.], [4., 6., 8.]];
+    assert_eq!(a.trapz_axis(Axis(1), 1.), array![4., 12.]);
+    assert_eq!(a.trapz_axis(Axis(0), 2.), array![5., 8., 11.]);
+    assert_eq!(a.t().trapz_axis(Axis(0), 1.), array![4., 12.]);
+    assert_eq!(a.slice(s![.., ..;-1]).trapz_axis(Axis(1), 1.), array![4., 12.]);
+    let x = array![0., 1., 3.];
+    assert_eq!(a.trapz_axis_with_coords(Axis(1), &x), Ok(array![6.5, 19.]));
+    // a decreasing coordinate flips the sign
+    let x = array![0., -2.];
+    assert_eq!(a.trapz_axis_with_coords(Axis(0), &x), Ok(array![-5., -8., -11.]));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn trapz_short_and_mismatched()
+{
+    assert_eq!(Array1::<f64>::zeros(0).trapz(1.), 0.);
+    assert_eq!(array![5.].trapz(1.), 0.);
+    assert_eq!(Array2::<f64>::zeros((3, 0)).trapz_axis(Axis(1), 1.), array![0., 0., 0.]);
+    let x = array![0., 1.];
+    assert!(matches!(
+        array![1., 2., 3.].trapz_with_coords(&x),
+        Err(ndarray::StatsError::Shape(_))
+    ));
+    assert!(array![[1., 2.]].trapz_axis_with_coords(Axis(0), &x).is_err());
+}
+
+#[test]
+fn bincount()
+{
+    let a = array![[2u8, 0, 2], [5, 2, 0]];
+    assert_eq!(a.bincount(), array![2, 0, 3, 0, 0, 1]);
+    assert_eq!(a.t().bincount(), array![2, 0, 3, 0, 0, 1]);
+    assert_eq!(Array1::<u32>::zeros(0).bincount(), Array1::<usize>::zeros(0));
+    assert_eq!(array![0usize].bincount(), array![1]);
+}
+
+#[test]
+fn bincount_sparse_large_values()
+{
+    let a = Array::from_shape_fn(10, |i| if i % 3 == 0 { 1_000_000u64 } else { i as u64 * 7 });
+    let counts = a.bincount();
+    assert_eq!(counts.len(), 1_000_001);
+    assert_eq!(counts.sum(), 10);
+    assert_eq!(counts[1_000_000], 4);
+    assert_eq!(counts[7], 1);
+    assert_eq!(counts[56], 1);
+    assert_eq!(counts[3 * 7], 0);
+    let w = Array::from_elem(10, 1i32);
+    assert_eq!(a.bincount_weighted(&w, 0).unwrap(), counts.mapv(|c| c as i32));
+}
+
+#[test]
+fn bincount_growing_values()
+{
+    // the output grows with each new largest value
+    let a = Array::from_iter(0..1000u32);
+    assert_eq!(a.bincount(), Array::from_elem(1000, 1));
+    let w = a.mapv(f64::from);
+    assert_eq!(a.bincount_weighted(&w, 0), Ok(w.clone()));
+    assert_eq!(a.bincount_weighted(&w, 1001).unwrap().len(), 1001);
+    let mut b = a.clone();
+    b.invert_axis(Axis(0));
+    assert_eq!(b.bincount(), Array::from_elem(1000, 1));
+}
+
+#[test]
+#[should_panic(expected = "less than `usize::MAX`")]
+fn bincount_usize_max()
+{
+    array![0, usize::MAX].bincount();
+}
+
+#[test]
+fn bincount_weighted()
+{
+    let a = array![[1u16, 3], [1, 0]];
+    let w = array![[1., 2.], [4., 8.]];
+    assert_eq!(a.bincount_weighted(&w, 0), Ok(array![8., 5., 0., 2.]));
+    assert_eq!(a.bincount_weighted(&w, 2), Ok(array![8., 5., 0., 2.]));
+    assert_eq!(a.bincount_weighted(&w, 5), Ok(array![8., 5., 0., 2., 0.]));
+    let empty = Array1::<u8>::zeros(0);
+    assert_eq!(empty.bincount_weighted(&Array1::<f64>::zeros(0), 3), Ok(array![0., 0., 0.]));
+    assert_eq!(a.t().bincount_weighted(&w.t(), 0), Ok(array![8., 5., 0., 2.]));
+    let w = array![[1., 2.]];
+    assert!(a.bincount_weighted(&w, 0).is_err());
+}
+
+#[test]
+fn digitize()
+{
+    let edges = array![1., 2., 4.];
+    let a = array![[0., 1., 1.5], [2., 3., 4.], [5., 4., 0.]];
+    assert_eq!(a.digitize(&edges, false), Ok(array![[0, 1, 1], [2, 2, 3], [3, 3, 0]]));
+    assert_eq!(a.digitize(&edges, true), Ok(array![[0, 0, 1], [1, 2, 2], [3, 2, 0]]));
+    assert_eq!(a.t().digitize(&edges, false), Ok(array![[0, 2, 3], [1, 2, 3], [1, 3, 0]]));
+    let edges = array![1., 1., 2.];
+    let a = array![0., 1., 2., 3.];
+    assert_eq!(a.digitize(&edges, false), Ok(array![0, 2, 3, 3]));
+    assert_eq!(a.digitize(&edges, true), Ok(array![0, 0, 2, 3]));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn digitize_sorted_matches_unsorted()
+{
+    let edges = Array::linspace(-1., 1., 7);
+    let sorted = Array::linspace(-1.5, 1.5, 61);
+    let mut shuffled = sorted.clone();
+    shuffled.as_slice_mut().unwrap().swap(0, 60);
+    for &right in &[false, true] {
+        let expected = sorted.map(|&x| {
+            edges
+                .iter()
+                .filter(|&&e| if right { e < x } else { e <= x })
+                .count()
+        });
+        assert_eq!(sorted.digitize(&edges, right), Ok(expected.clone()));
+        let mut expected_shuffled = expected;
+        expected_shuffled.as_slice_mut().unwrap().swap(0, 60);
+        assert_eq!(shuffled.digitize(&edges, right), Ok(expected_shuffled));
+    }
+}
+
+#[test]
+fn digitize_nan_and_errors()
+{
+    let edges = array![0., 1.];
+    let a = array![f64::NAN, 0.5, 2.];
+    assert_eq!(a.digitize(&edges, false), Ok(array![2, 1, 2]));
+    assert_eq!(a.digitize(&edges, true), Ok(array![2, 1, 2]));
+    let a = array![0.5, 2., f64::NAN];
+    assert_eq!(a.digitize(&edges, false), Ok(array![1, 2, 2]));
+    let empty = Array1::<f64>::zeros(0);
+    assert_eq!(a.digitize(&empty, false), Ok(array![0, 0, 0]));
+    let unsorted = array![1., 0.];
+    assert_eq!(a.digitize(&unsorted, false), Err(ndarray::StatsError::UnsortedEdges));
+    let with_nan = array![0., f64::NAN];
+    assert!(a.digitize(&with_nan, false).is_err());
+}
+
+#[cfg(feature = "std")]
+fn within_one_ulp(x: f32, reference: f64) -> bool
+{
+    let r = reference as f32;
+    let ulp = f32::from_bits(r.to_bits() + 1) - r;
+    (x as f64 - reference).abs() <= ulp as f64
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn sum_compensated_f32()
+{
+    let n = 10_000_000;
+    let reference = 0.1f32 as f64 * n as f64;
+    let a = Array::from_elem(n, 0.1f32);
+    assert!(within_one_ulp(a.sum_compensated(), reference));
+    assert!(!within_one_ulp(a.sum(), reference));
+
+    // strided and non-contiguous rows
+    let b = Array::from_elem((2, n), 0.1f32);
+    assert!(within_one_ulp(b.slice(s![.., ..;2]).sum_compensated(), reference));
+    let sums = b.sum_axis_compensated(Axis(1));
+    assert!(sums.iter().all(|&s| within_one_ulp(s, reference)));
+    let c = Array::from_elem((n / 10, 3), 0.1f32);
+    let sums = c.sum_axis_compensated(Axis(0));
+    assert!(sums.iter().all(|&s| within_one_ulp(s, reference / 10.)));
+    let sums = c.t().sum_axis_compensated(Axis(1));
+    assert!(sums.iter().all(|&s| within_one_ulp(s, reference / 10.)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn sum_compensated_cancellation()
+{
+    let a = array![1e100, 1., -1e100];
+    assert_eq!(a.sum(), 0.);
+    assert_eq!(a.sum_compensated(), 1.);
+    let a = Array::from_shape_fn(99, |i| [1e100, 1., -1e100][i % 3]);
+    assert_eq!(a.sum_compensated(), 33.);
+    assert_eq!(a.slice(s![..;-1]).sum_compensated(), 33.);
+    let a = a.into_shape_with_order((33, 3)).unwrap();
+    assert_eq!(a.sum_axis_compensated(Axis(1)), Array::from_elem(33, 1.));
+    assert_eq!(a.t().sum_axis_compensated(Axis(0)), Array::from_elem(33, 1.));
+    assert_eq!(Array2::<f64>::zeros((0, 3)).sum_compensated(), 0.);
+    assert_eq!(Array2::<f64>::zeros((0, 3)).sum_axis_compensated(Axis(0)), array![0., 0., 0.]);
+}
+
+#[test]
+fn sum_pairwise_f32_accuracy()
+{
+    use ndarray_gen::array_builder::{ArrayBuilder, ElementGenerator};
+
+    let n = 100_000_000;
+    let a = ArrayBuilder::new(n)
+        .generator(ElementGenerator::Random(1))
+        .build::<f32>()
+        .mapv(|x| x / 65536.);
+    let reference = a.iter().map(|&x| x as f64).sum::<f64>();
+    let sequential = a.iter().fold(0f32, |acc, &x| acc + x);
+    let rel_err = |x: f32| (x as f64 - reference).abs() / reference;
+    assert!(rel_err(a.sum()) < 1e-6, "{}", rel_err(a.sum()));
+    assert!(rel_err(sequential) > 1e-3);
+    // non-contiguous arrays are summed pairwise per row
+    let b = a.view().into_shape_with_order((10, n / 10)).unwrap();
+    assert!(rel_err(b.slice(s![..;-1, ..]).sum()) < 1e-6);
+}
+
+#[test]
+fn sum_pairwise_exact_for_integers()
+{
+    for &n in &[0, 1, 7, 8, 127, 128, 129, 1000, 4099] {
+        let a = Array::from_shape_fn(n, |i| i as u64);
+        assert_eq!(a.sum(), (n as u64) * (n as u64).saturating_sub(1) / 2);
+        let f = a.mapv(|x| x as f64);
+        assert_eq!(f.sum(), (n * n.saturating_sub(1) / 2) as f64);
+    }
+}
+
+#[test]
+fn count_where_and_nonzero()
+{
+    let a = Array::from_shape_fn((4, 5, 6), |(i, j, k)| ((i * 7 + j * 3 + k) % 4) as u8);
+    let expected = a.iter().filter(|&&x| x != 0).count();
+    assert_eq!(a.count_nonzero(), expected);
+    assert_eq!(a.count_where(|&x| x == 3), a.iter().filter(|&&x| x == 3).count());
+    // negative strides, transposed and sliced views
+    let odd = a.slice(s![.., .., 1..;2]);
+    assert_eq!(a.slice(s![..;-1, .., ..;-2]).count_nonzero(), odd.iter().filter(|&&x| x != 0).count());
+    assert_eq!(a.t().count_nonzero(), expected);
+    let tail = a.slice(s![.., 1.., ..]);
+    assert_eq!(a.slice(s![.., 1.., ..;-1]).count_where(|&x| x > 1), tail.iter().filter(|&&x| x > 1).count());
+    // broadcast views
+    let row = array![0, 1, 2, 0];
+    assert_eq!(row.broadcast((1000, 4)).unwrap().count_nonzero(), 2000);
+    assert_eq!(row.broadcast((3, 5, 4)).unwrap().t().count_where(|&x| x == 0), 30);
+    assert_eq!(Array2::<f64>::zeros((0, 3)).count_nonzero(), 0);
+    assert_eq!(array![0., -0., f64::NAN, 1.].count_nonzero(), 2);
+}
+
+#[test]
+fn count_nonzero_axis()
+{
+    let a = Array::from_shape_fn((4, 5, 6), |(i, j, k)| ((i * 7 + j * 3 + k) % 4) as i32 - 1);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let expected = a.map_axis(axis, |lane| lane.iter().filter(|&&x| x != 0).count());
+        assert_eq!(a.count_nonzero_axis(axis), expected);
+        let mut f = Array::zeros(a.raw_dim().f());
+        f.assign(&a);
+        assert_eq!(f.count_nonzero_axis(axis), expected);
+        let mut rev = a.clone();
+        rev.invert_axis(axis);
+        assert_eq!(rev.count_nonzero_axis(axis), expected);
+    }
+    let row = array![0, 1, 2, 0];
+    let b = row.broadcast((3, 4)).unwrap();
+    assert_eq!(b.count_nonzero_axis(Axis(0)), array![0, 3, 3, 0]);
+    assert_eq!(b.count_nonzero_axis(Axis(1)), array![2, 2, 2]);
+}
+
+#[test]
+fn any_all_short_circuit()
+{
+    let mut mask = Array::from_elem(1_000_000, false);
+    mask[0] = true;
+    let mut calls = 0;
+    assert!(mask.any_where(|&x| {
+        calls += 1;
+        x
+    }));
+    assert_eq!(calls, 1);
+    let mut calls = 0;
+    assert!(!mask.all_where(|&x| {
+        calls += 1;
+        !x
+    }));
+    assert_eq!(calls, 1);
+    assert!(mask.any());
+    assert!(!mask.all());
+    mask[0] = false;
+    let mut calls = 0;
+    assert!(!mask.any_where(|&x| {
+        calls += 1;
+        x
+    }));
+    assert_eq!(calls, mask.len());
+}
+
+#[test]
+fn any_all()
+{
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i + j * k) % 5 == 0);
+    assert_eq!(a.any(), a.iter().any(|&x| x));
+    assert_eq!(a.all(), a.iter().all(|&x| x));
+    assert!(Array2::<bool>::from_elem((0, 3), false).all());
+    assert!(!Array2::<bool>::from_elem((0, 3), true).any());
+    assert!(array![true, true].broadcast((4, 2)).unwrap().all());
+    assert!(array![3, -1, 2].slice(s![..;-1]).any_where(|&x| x < 0));
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let any = a.map_axis(axis, |lane| lane.iter().any(|&x| x));
+        let all = a.map_axis(axis, |lane| lane.iter().all(|&x| x));
+        assert_eq!(a.any_axis(axis), any);
+        assert_eq!(a.all_axis(axis), all);
+        let mut f = Array::from_elem(a.raw_dim().f(), false);
+        f.assign(&a);
+        assert_eq!(f.any_axis(axis), any);
+        assert_eq!(f.all_axis(axis), all);
+    }
+    let empty = Array2::<bool>::from_elem((0, 3), false);
+    assert_eq!(empty.all_axis(Axis(0)), array![true, true, true]);
+    assert_eq!(empty.any_axis(Axis(0)), array![false, false, false]);
+}
+
+#[test]
+fn logical_ops_three_masks()
+{
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (i + j + k) % 2 == 0);
+    let b = Array::from_shape_fn((3, 1), |(j, _)| j != 1);
+    let c = array![true, false, true, true];
+    let and = a.and(&b).unwrap().and(&c).unwrap();
+    let or = a.or(&b).unwrap().or(&c).unwrap();
+    let xor = a.xor(&b).unwrap().xor(&c).unwrap();
+    assert_eq!(and.shape(), &[2, 3, 4]);
+    for ((i, j, k), &x) in and.indexed_iter() {
+        let (a, b, c) = (a[[i, j, k]], b[[j, 0]], c[k]);
+        assert_eq!(x, a && b && c);
+        assert_eq!(or[[i, j, k]], a || b || c);
+        assert_eq!(xor[[i, j, k]], a ^ b ^ c);
+    }
+    // the order of the operands does not matter
+    assert_eq!(c.and(&b).unwrap().and(&a).unwrap(), and);
+    assert_eq!(and, &(&a & &b) & &c);
+    let column = c.view().into_shape_with_order((4, 1, 1)).unwrap();
+    assert_eq!(a.t().or(&column).unwrap(), or_t(&a, &c));
+    assert_eq!(and.not(), !&and);
+    assert_eq!(and.not().not(), and);
+    let bad = array![true, false];
+    assert!(a.and(&bad).is_err());
+    assert!(b.or(&c.slice(s![..2])).is_ok());
+    assert!(b.xor(&Array2::from_elem((2, 4), true)).is_err());
+    for axis in 0..3 {
+        assert_eq!(and.logical_and_axis(Axis(axis)), and.all_axis(Axis(axis)));
+        assert_eq!(or.logical_or_axis(Axis(axis)), or.any_axis(Axis(axis)));
+    }
+}
+
+fn or_t(a: &Array3<bool>, c: &Array1<bool>) -> Array3<bool>
+{
+    Array::from_shape_fn((4, 3, 2), |(k, j, i)| a[[i, j, k]] || c[k])
+}
+
+#[test]
+fn try_sum_overflow_u8()
+{
+    let a = Array::from_elem((3, 4), 30u8);
+    // 9 * 30 = 270 is the first partial sum above u8::MAX
+    assert_eq!(a.try_sum().unwrap_err().index(), &[2, 0]);
+    assert_eq!(a.t().try_sum().unwrap_err().index(), &[2, 2]);
+    assert_eq!(a.slice(s![.., ..2]).try_sum(), Ok(180));
+    assert_eq!(a.slice(s![..2, ..;-1]).try_sum(), Ok(240));
+    assert_eq!(Array2::<u8>::zeros((0, 3)).try_sum(), Ok(0));
+    assert_eq!(a.try_sum_axis(Axis(0)), Ok(Array::from_elem(4, 90)));
+
+    let mut b = Array::from_elem((4, 3), 50u8);
+    assert_eq!(b.try_sum_axis(Axis(1)), Ok(Array::from_elem(4, 150)));
+    b[[3, 1]] = 200;
+    assert_eq!(b.try_sum_axis(Axis(0)).unwrap_err().index(), &[3, 1]);
+    assert_eq!(b.try_sum_axis(Axis(1)).unwrap_err().index(), &[3, 2]);
+    assert_eq!(b.t().try_sum_axis(Axis(0)).unwrap_err().index(), &[2, 3]);
+}
+
+#[test]
+fn try_sum_overflow_i8()
+{
+    let a = array![100i8, 27, 1, -128, 1];
+    assert_eq!(a.try_sum().unwrap_err().index(), &[2]);
+    assert_eq!(a.slice(s![..2]).try_sum(), Ok(127));
+    // overflows below i8::MIN
+    let b = array![[-100i8, -28, -1], [1, 2, 3]];
+    assert_eq!(b.try_sum().unwrap_err().index(), &[0, 2]);
+    assert_eq!(b.try_sum_axis(Axis(1)).unwrap_err().index(), &[0, 2]);
+    assert_eq!(b.try_sum_axis(Axis(0)), Ok(array![-99, -26, 2]));
+    // the index is in the logical order of the view
+    assert_eq!(b.slice(s![.., ..;-1]).try_sum().unwrap_err().index(), &[0, 2]);
+    let err = b.try_sum().unwrap_err();
+    assert_eq!(err.to_string(), "OverflowError: arithmetic overflow at index [0, 2]");
+}
+
+#[test]
+fn pow_integers()
+{
+    let a = array![[-2i32, 0], [1, 3]];
+    assert_eq!(a.pow(2), array![[4, 0], [1, 9]]);
+    assert_eq!(a.pow(0), array![[1, 1], [1, 1]]);
+    let mut b = a.t().to_owned();
+    b.pow_inplace(3);
+    assert_eq!(b, array![[-8, 1], [0, 27]]);
+    assert_eq!(a.try_pow(31).unwrap_err().index(), &[1, 1]);
+    assert_eq!(array![-2i32].try_pow(31), Ok(array![i32::MIN]));
+    assert_eq!(array![2i32].try_pow(31).unwrap_err().index(), &[0]);
+    let c = array![[1u8, 2, 16], [3, 4, 0]];
+    assert_eq!(c.t().try_pow(2).unwrap_err().index(), &[2, 0]);
+    assert_eq!(c.slice(s![.., ..2]).try_pow(2), Ok(array![[1, 4], [9, 16]]));
+}
+
+#[test]
+fn try_abs()
+{
+    let a = array![[i32::MIN + 1, -7, 0], [1, 7, i32::MAX]];
+    assert_eq!(a.try_abs(), Ok(array![[i32::MAX, 7, 0], [1, 7, i32::MAX]]));
+    assert_eq!(a.t().try_abs(), Ok(array![[i32::MAX, 1], [7, 7], [0, i32::MAX]]));
+    assert_eq!(array![i128::MIN + 1, -1].try_abs(), Ok(array![i128::MAX, 1]));
+    let b = array![[1, i32::MIN], [i32::MIN, 2]];
+    assert_eq!(b.try_abs().unwrap_err().index(), &[0, 1]);
+    assert_eq!(b.t().try_abs().unwrap_err().index(), &[0, 1]);
+    assert_eq!(b.column(0).try_abs().unwrap_err().index(), &[1]);
+    assert_eq!(array![i8::MIN].try_abs().unwrap_err().index(), &[0]);
+    assert_eq!(array![i128::MIN].try_abs().unwrap_err().index(), &[0]);
+}
+
+#[test]
+fn checked_arithmetic_large()
+{
+    // exactly one element overflows in a large array
+    let mut a = Array::from_shape_fn((300, 200), |(i, j)| (i * 200 + j) as i32);
+    a[[217, 43]] = i32::MAX;
+    let zeros = Array::zeros(200);
+    assert_eq!(a.checked_add(&zeros), Ok(a.clone()));
+    let b = Array::from_elem(200, 1);
+    let err = a.checked_add(&b).unwrap_err();
+    assert_eq!((err.index(), err.op()), (&[217, 43][..], ArithmeticOp::Add));
+    assert_eq!(a.checked_add_scalar(1).unwrap_err().index(), &[217, 43]);
+    assert_eq!(a.t().checked_mul_scalar(2).unwrap_err().index(), &[43, 217]);
+    assert_eq!(a.slice(s![..217, ..]).checked_mul_scalar(2).map(|c| c[[1, 0]]), Ok(400));
+    assert_eq!(
+        err.to_string(),
+        "ArithmeticError: attempt to add with overflow at index [217, 43]"
+    );
+    // the first of several overflows in logical order, not in memory order
+    let mut f = Array::zeros((5, 4).f());
+    f[[3, 0]] = u8::MAX;
+    f[[1, 3]] = u8::MAX;
+    assert_eq!(f.checked_add_scalar(1).unwrap_err().index(), &[1, 3]);
+}
+
+#[test]
+fn checked_arithmetic_ops()
+{
+    let a = array![[7i8, -128], [100, 0]];
+    let b = array![[2], [-1]];
+    assert_eq!(a.checked_sub(&b).unwrap_err().index(), &[0, 1]);
+    assert_eq!(a.checked_mul(&b).unwrap_err().index(), &[0, 1]);
+    let e = array![3i8, -4];
+    assert_eq!(e.checked_mul(&b), Ok(array![[6, -8], [-3, 4]]));
+    // division by zero and `MIN / -1`
+    let err = a.checked_div_scalar(0).unwrap_err();
+    assert_eq!((err.index(), err.op()), (&[0, 0][..], ArithmeticOp::Div));
+    assert_eq!(a.checked_div(&b), Ok(array![[3, -64], [-100, 0]]));
+    assert_eq!(a.checked_div(&b.slice(s![..;-1, ..])).unwrap_err().index(), &[0, 1]);
+    assert_eq!(a.checked_div_scalar(-2), Ok(array![[-3, 64], [-50, 0]]));
+    let c = array![5u16, 0, 9];
+    let d = array![[1u16, 2, 3], [1, 0, 1]];
+    assert_eq!(c.checked_div(&d).unwrap_err().index(), &[1, 1]);
+    assert_eq!(c.checked_sub_scalar(1).unwrap_err().index(), &[1]);
+    assert_eq!(c.checked_add(&d), Ok(array![[6, 2, 12], [6, 0, 10]]));
+    assert_eq!(Array2::<u8>::zeros((0, 3)).checked_div_scalar(0), Ok(Array2::zeros((0, 3))));
+}
+
+#[test]
+#[should_panic]
+fn checked_arithmetic_incompatible_shapes()
+{
+    let b = array![1, 2];
+    let _ = array![1, 2, 3].checked_add(&b);
+}
+
+#[test]
+fn sum_as_u8()
+{
+    let a = Array::from_shape_fn((30, 40), |(i, j)| (i * 40 + j) as u8);
+    let expected: u32 = a.iter().map(|&x| x as u32).sum();
+    assert!(expected > u8::MAX as u32);
+    assert_eq!(a.sum_as::<u32>(), expected);
+    assert_eq!(a.t().sum_as::<u32>(), expected);
+    assert_eq!(a.slice(s![..;3, ..;-2]).sum_as::<u64>(), a.slice(s![..;3, ..;-2]).mapv(u64::from).sum());
+    assert_eq!(Array2::<u8>::zeros((0, 4)).sum_as::<u32>(), 0);
+    for axis in 0..2 {
+        let axis = Axis(axis);
+        let expected = a.mapv(u32::from).sum_axis(axis);
+        assert_eq!(a.sum_axis_as::<u32>(axis), expected);
+        assert_eq!(a.t().sum_axis_as::<u32>(Axis(1 - axis.index())), expected);
+    }
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn sum_as_f32_in_f64()
+{
+    let a = Array::from_shape_fn((100, 1000), |(i, j)| ((i * 1000 + j) % 997) as f32 * 0.01);
+    let expected = a.iter().map(|&x| x as f64).fold(0., |acc, x| acc + x);
+    assert_eq!(a.sum_as::<f64>(), expected);
+    let reference = a.mapv(f64::from).sum_axis(Axis(0));
+    assert_abs_diff_eq!(a.sum_axis_as::<f64>(Axis(0)), reference, epsilon = 1e-12);
+    let reference = a.mapv(f64::from).sum_axis(Axis(1));
+    assert_abs_diff_eq!(a.sum_axis_as::<f64>(Axis(1)), reference, epsilon = 1e-9);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn mean_as_integers()
+{
+    let a = Array::from_elem((4, 50), 200u8);
+    assert!(a.try_sum().is_err());
+    assert_eq!(a.mean_as::<f32>(), Some(200.));
+    assert_eq!(a.mean_axis_as::<f64>(Axis(0)), Some(Array::from_elem(50, 200.)));
+    assert_eq!(a.mean_axis_as::<f64>(Axis(1)), Some(Array::from_elem(4, 200.)));
+
+    let b = array![[i32::MAX, i32::MAX, -3], [i32::MIN, i32::MIN, 6]];
+    assert_eq!(b.mean_as::<f64>(), Some(1. / 6.));
+    assert_eq!(b.mean_axis_as::<f64>(Axis(0)), Some(array![-0.5, -0.5, 1.5]));
+    assert_eq!(b.t().mean_axis_as::<f64>(Axis(0)), b.mean_axis_as::<f64>(Axis(1)));
+    let expected = array![(2. * i32::MAX as f64 - 3.) / 3., (2. * i32::MIN as f64 + 6.) / 3.];
+    assert_eq!(b.mean_axis_as::<f64>(Axis(1)), Some(expected));
+    assert_eq!(b.slice(s![.., ..;-1]).mean_axis_as::<f64>(Axis(1)), b.mean_axis_as::<f64>(Axis(1)));
+
+    assert_eq!(Array1::<i64>::zeros(0).mean_as::<f64>(), None);
+    assert_eq!(Array2::<i64>::zeros((0, 3)).mean_axis_as::<f64>(Axis(0)), None);
+    assert_eq!(Array2::<i64>::zeros((0, 3)).mean_axis_as::<f64>(Axis(1)), Some(Array1::zeros(0)));
+}
+
+/// A number that can only be converted from integers up to 100.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Percent(f64);
+
+impl std::ops::Add for Percent
+{
+    type Output = Percent;
+    fn add(self, rhs: Percent) -> Percent
+    {
+        Percent(self.0 + rhs.0)
+    }
+}
+
+impl std::ops::Div for Percent
+{
+    type Output = Percent;
+    fn div(self, rhs: Percent) -> Percent
+    {
+        Percent(self.0 / rhs.0)
+    }
+}
+
+impl num_traits::Zero for Percent
+{
+    fn zero() -> Percent
+    {
+        Percent(0.)
+    }
+    fn is_zero(&self) -> bool
+    {
+        self.0 == 0.
+    }
+}
+
+impl num_traits::FromPrimitive for Percent
+{
+    fn from_i64(n: i64) -> Option<Percent>
+    {
+        if (0..=100).contains(&n) {
+            Some(Percent(n as f64))
+        } else {
+            None
+        }
+    }
+    fn from_u64(n: u64) -> Option<Percent>
+    {
+        Self::from_i64(n as i64)
+    }
+}
+
+#[test]
+fn try_mean_conversion_failure()
+{
+    let a = Array::from_shape_fn((5, 120), |(i, j)| Percent((i + j) as f64));
+    assert_eq!(a.try_mean().unwrap_err().count(), 600);
+    assert_eq!(a.slice(s![.., ..20]).try_mean(), Ok(Some(Percent(11.5))));
+    assert_eq!(a.try_mean_axis(Axis(0)), Ok(a.mean_axis(Axis(0))));
+    assert_eq!(a.try_mean_axis(Axis(0)).unwrap().unwrap()[0], Percent(2.));
+    assert_eq!(a.try_mean_axis(Axis(1)).unwrap_err().count(), 120);
+    assert_eq!(a.t().try_mean_axis(Axis(0)).unwrap_err().count(), 120);
+    assert_eq!(Array2::<Percent>::zeros((0, 200)).try_mean(), Ok(None));
+    assert_eq!(Array2::<Percent>::zeros((0, 200)).try_mean_axis(Axis(0)), Ok(None));
+    let err = a.try_mean().unwrap_err();
+    assert_eq!(err.to_string(), "MeanError: the number of elements 600 can not be converted to the element type");
+}
+
+#[test]
+#[should_panic]
+fn mean_conversion_failure_panics()
+{
+    let a = Array::from_elem(101, Percent(1.));
+    a.mean();
+}
+
+#[test]
+fn try_product_factorial()
+{
+    let a = Array::from_iter(1..=20i32);
+    // 12! is the largest factorial that fits in an i32
+    assert_eq!(a.slice(s![..12]).try_product(), Ok(479_001_600));
+    assert_eq!(a.try_product().unwrap_err().index(), &[12]);
+    assert_eq!(a.slice(s![..;-1]).try_product().unwrap_err().index(), &[7]);
+    assert_eq!(Array1::<i32>::zeros(0).try_product(), Ok(1));
+    assert_eq!(array![0i32, i32::MAX, i32::MAX].try_product(), Ok(0));
+
+    let b = array![[1i32, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12], [100, 100, 100, 100, 100, 100]];
+    assert_eq!(b.try_product_axis(Axis(1)).unwrap_err().index(), &[2, 4]);
+    assert_eq!(b.try_product_axis(Axis(0)), Ok(array![700, 1600, 2700, 4000, 5500, 7200]));
+    assert_eq!(b.t().try_product_axis(Axis(0)).unwrap_err().index(), &[4, 2]);
+    let c = array![[-46341i32, 1], [46341, 1]];
+    assert_eq!(c.try_product_axis(Axis(0)).unwrap_err().index(), &[1, 0]);
+    assert_eq!(array![-46340i32, 46340].try_product(), Ok(-2_147_395_600));
+}
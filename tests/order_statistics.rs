@@ -0,0 +1,878 @@
+#![allow(clippy::float_cmp)]
+
+use ndarray::prelude::*;
+#[cfg(feature = "std")]
+use ndarray::Interpolate;
+use quickcheck::quickcheck;
+
+#[test]
+fn median_odd_even()
+{
+    assert_eq!(array![3, 1, 2].median(), Some(2));
+    assert_eq!(array![4., 1., 3., 2.].median(), Some(2.5));
+    assert_eq!(array![[9., 1.], [5., 7.]].median(), Some(6.));
+    assert_eq!(array![7.].median(), Some(7.));
+}
+
+#[test]
+fn median_repeated_values()
+{
+    assert_eq!(array![2, 2, 2, 2].median(), Some(2));
+    assert_eq!(array![1., 3., 3., 3., 1., 3.].median(), Some(3.));
+    assert_eq!(array![5, 1, 5, 1].median(), Some(3));
+}
+
+#[test]
+fn median_integers_near_max()
+{
+    assert_eq!(array![200u8, 250u8].median(), Some(225));
+    assert_eq!(array![255u8, 1, 255, 254].median(), Some(254));
+    assert_eq!(array![i32::MAX, i32::MAX - 2].median(), Some(i32::MAX - 1));
+    assert_eq!(array![i32::MIN, i32::MIN + 4, 0, i32::MIN + 2].median(), Some(i32::MIN + 3));
+    assert_eq!(array![[u8::MAX, 100], [101, u8::MAX]].median_axis(Axis(1)), Some(array![177, 178]));
+}
+
+#[test]
+fn median_mixed_signs()
+{
+    assert_eq!(array![-100i8, 100].median(), Some(0));
+    assert_eq!(array![i8::MIN, i8::MAX].median(), Some(0));
+    assert_eq!(array![i32::MIN, i32::MAX].median(), Some(0));
+    assert_eq!(array![i32::MAX, i32::MIN, 5, i32::MIN].median(), Some(-1_073_741_821));
+    assert_eq!(array![-f64::MAX, f64::MAX].median(), Some(0.));
+    assert_eq!(array![f64::MAX, f64::MAX].median(), Some(f64::MAX));
+    assert_eq!(array![-f64::MAX, -f64::MAX / 2.].median(), Some(-0.75 * f64::MAX));
+    // rounded towards zero
+    assert_eq!(array![-3, 2].median(), Some(0));
+    assert_eq!(array![-3, -2].median(), Some(-2));
+    assert_eq!(array![i64::MIN, i64::MIN + 1].median(), Some(i64::MIN + 1));
+    assert_eq!(array![f64::NEG_INFINITY, f64::NEG_INFINITY].median(), Some(f64::NEG_INFINITY));
+}
+
+#[test]
+fn median_empty_or_nan()
+{
+    assert_eq!(Array1::<f64>::zeros(0).median(), None);
+    assert_eq!(Array2::<i32>::zeros((3, 0)).median(), None);
+    assert_eq!(array![1., 2., f64::NAN].median(), None);
+}
+
+#[test]
+fn median_non_contiguous()
+{
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((4, 6))
+        .unwrap();
+    let v = a.slice(s![..;-2, 1..;2]);
+    assert_eq!(v.median(), Some(15));
+    assert_eq!(a.t().median(), Some(11));
+}
+
+quickcheck! {
+    fn median_matches_sort(xs: Vec<i32>) -> bool {
+        let a = Array::from(xs.clone());
+        let mut sorted: Vec<i64> = xs.into_iter().map(i64::from).collect();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        let expected = if n == 0 {
+            None
+        } else if n % 2 == 1 {
+            Some(sorted[n / 2])
+        } else {
+            Some((sorted[n / 2 - 1] + sorted[n / 2]) / 2)
+        };
+        a.mapv(i64::from).median() == expected && a.median().map(i64::from) == expected
+    }
+}
+
+#[test]
+fn median_axis()
+{
+    let a = array![[1., 5., 3., 0.], [4., 2., 6., 0.], [9., 8., 7., 0.]];
+    assert_eq!(a.median_axis(Axis(0)).unwrap(), array![4., 5., 6., 0.]);
+    assert_eq!(a.median_axis(Axis(1)).unwrap(), array![2., 3., 7.5]);
+}
+
+#[test]
+fn median_axis_f_order()
+{
+    let a = array![[1., 5., 3., 0.], [4., 2., 6., 0.], [9., 8., 7., 0.]];
+    let f = Array2::from_shape_vec(a.raw_dim().f(), a.t().iter().cloned().collect()).unwrap();
+    assert_eq!(f, a);
+    assert_eq!(f.median_axis(Axis(0)), a.median_axis(Axis(0)));
+    assert_eq!(f.median_axis(Axis(1)), a.median_axis(Axis(1)));
+}
+
+#[test]
+fn median_axis_3d_middle_axis()
+{
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 4, 3))
+        .unwrap();
+    let m = a.median_axis(Axis(1)).unwrap();
+    assert_eq!(m.shape(), &[2, 3]);
+    for ((i, k), &x) in m.indexed_iter() {
+        assert_eq!(Some(x), a.slice(s![i, .., k]).median());
+    }
+    assert_eq!(m, array![[4, 5, 6], [16, 17, 18]]);
+}
+
+#[test]
+fn median_axis_empty_or_nan()
+{
+    assert_eq!(Array2::<f64>::zeros((0, 3)).median_axis(Axis(0)), None);
+    assert_eq!(
+        Array2::<f64>::zeros((0, 3)).median_axis(Axis(1)),
+        Some(Array1::zeros(0))
+    );
+    assert_eq!(array![[1., f64::NAN], [1., 2.]].median_axis(Axis(1)), None);
+}
+
+#[cfg(feature = "std")]
+fn sorted_quantile(xs: &[f64], q: f64, interp: Interpolate) -> f64
+{
+    let mut xs = xs.to_vec();
+    xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let h = q * (xs.len() - 1) as f64;
+    let (i, j) = (h.floor() as usize, h.ceil() as usize);
+    match interp {
+        Interpolate::Linear => xs[i] + (h - i as f64) * (xs[j] - xs[i]),
+        Interpolate::Lower => xs[i],
+        Interpolate::Higher => xs[j],
+        Interpolate::Nearest => {
+            let frac = h - i as f64;
+            if frac < 0.5 || (frac == 0.5 && i % 2 == 0) {
+                xs[i]
+            } else {
+                xs[j]
+            }
+        }
+        Interpolate::Midpoint => (xs[i] + xs[j]) / 2.,
+    }
+}
+
+#[cfg(feature = "std")]
+const INTERPOLATIONS: [Interpolate; 5] = [
+    Interpolate::Linear,
+    Interpolate::Lower,
+    Interpolate::Higher,
+    Interpolate::Nearest,
+    Interpolate::Midpoint,
+];
+
+#[test]
+#[cfg(feature = "std")]
+fn quantile_matches_sorted_reference()
+{
+    let data = [3.5, -1., 7., 2., 2., 9.25, 0., 4., -3., 5.5, 6.];
+    let a = Array::from(data.to_vec());
+    for &interp in &INTERPOLATIONS {
+        for k in 0..=20 {
+            let q = k as f64 / 20.;
+            assert_eq!(a.quantile(q, interp), Some(sorted_quantile(&data, q, interp)), "q={} {:?}", q, interp);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn quantile_median_and_extremes()
+{
+    let a = array![[4., 1., 3.], [2., 6., 5.]];
+    assert_eq!(a.quantile(0.5, Interpolate::Linear), a.median());
+    assert_eq!(a.quantile(0., Interpolate::Linear), Some(1.));
+    assert_eq!(a.quantile(1., Interpolate::Higher), Some(6.));
+    assert_eq!(array![5.].quantile(0.3, Interpolate::Midpoint), Some(5.));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn quantile_empty_or_nan()
+{
+    assert_eq!(Array1::<f64>::zeros(0).quantile(0.5, Interpolate::Linear), None);
+    assert!(array![1., f64::NAN]
+        .quantile(0.5, Interpolate::Lower)
+        .unwrap()
+        .is_nan());
+    assert_eq!(Array2::<f64>::zeros((2, 0)).quantile_axis(Axis(1), 0.5, Interpolate::Linear), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic(expected = "quantile `q` must be between 0 and 1")]
+fn quantile_out_of_range()
+{
+    array![1., 2.].quantile(1.5, Interpolate::Linear);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic(expected = "quantile `q` must be between 0 and 1")]
+fn quantile_axis_nan_q()
+{
+    array![[1., 2.]].quantile_axis(Axis(0), f64::NAN, Interpolate::Linear);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn quantile_axis()
+{
+    let a = array![[1., 5., 3., 0.], [4., 2., f64::NAN, 0.], [9., 8., 7., 0.]];
+    for &interp in &INTERPOLATIONS {
+        let q = a.quantile_axis(Axis(0), 0.3, interp).unwrap();
+        assert_eq!(q.shape(), &[4]);
+        assert_eq!(q[0], sorted_quantile(&[1., 4., 9.], 0.3, interp));
+        assert_eq!(q[1], sorted_quantile(&[5., 2., 8.], 0.3, interp));
+        assert!(q[2].is_nan());
+        assert_eq!(q[3], 0.);
+        assert_eq!(a.t().quantile_axis(Axis(1), 0.3, interp).unwrap()[1], q[1]);
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn quantiles_axis_shape()
+{
+    let a = Array3::<f64>::ones((2, 5, 3));
+    let q = a
+        .quantiles_axis(Axis(1), &[0.1, 0.9], Interpolate::Linear)
+        .unwrap();
+    assert_eq!(q.shape(), &[2, 2, 3]);
+    let q = a.quantiles_axis(Axis(2), &[], Interpolate::Linear).unwrap();
+    assert_eq!(q.shape(), &[0, 2, 5]);
+    assert_eq!(a.quantiles_axis(Axis(0), &[0.5], Interpolate::Linear).unwrap().shape(), &[1, 5, 3]);
+    assert_eq!(Array2::<f64>::zeros((0, 3)).quantiles_axis(Axis(0), &[0.5], Interpolate::Linear), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn quantiles_axis_nan_lane()
+{
+    let a = array![[1., f64::NAN, 3.], [4., 5., 6.]];
+    let q = a
+        .quantiles_axis(Axis(1), &[0., 0.5], Interpolate::Linear)
+        .unwrap();
+    assert!(q.column(0).iter().all(|x| x.is_nan()));
+    assert_eq!(q.column(1), array![4., 5.]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn quantiles_axis_is_bitwise_identical()
+{
+    fn prop(xs: Vec<f64>, rows: u8) -> bool
+    {
+        let xs: Vec<f64> = xs.into_iter().filter(|x| x.is_finite()).collect();
+        let rows = 1 + rows as usize % 4;
+        let cols = xs.len() / rows;
+        if cols == 0 {
+            return true;
+        }
+        let a = Array::from(xs[..rows * cols].to_vec())
+            .into_shape_with_order((rows, cols))
+            .unwrap();
+        let qs = [0., 0.05, 0.25, 1. / 3., 0.5, 0.95, 1.];
+        INTERPOLATIONS.iter().all(|&interp| {
+            (0..2).all(|axis| {
+                let all = a.quantiles_axis(Axis(axis), &qs, interp).unwrap();
+                qs.iter().enumerate().all(|(k, &q)| {
+                    let single = a.quantile_axis(Axis(axis), q, interp).unwrap();
+                    ndarray::Zip::from(all.index_axis(Axis(0), k))
+                        .and(&single)
+                        .all(|x, y| x.to_bits() == y.to_bits())
+                })
+            })
+        })
+    }
+    quickcheck::quickcheck(prop as fn(Vec<f64>, u8) -> bool);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nanquantile()
+{
+    let a = array![[4., f64::NAN], [1., 3.], [f64::NAN, 2.]];
+    for &interp in &INTERPOLATIONS {
+        assert_eq!(a.nanquantile(0.4, interp), Some(sorted_quantile(&[4., 1., 3., 2.], 0.4, interp)));
+    }
+    assert_eq!(array![f64::NAN, f64::NAN].nanquantile(0.5, Interpolate::Linear), None);
+    assert_eq!(Array1::<f64>::zeros(0).nanquantile(0.5, Interpolate::Linear), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nanquantile_axis_lanes_with_0_1_many_nans()
+{
+    let nan = f64::NAN;
+    let a = array![
+        [5., 1., 4., 2., 3.],
+        [5., nan, 4., 2., 3.],
+        [nan, 1., nan, nan, 3.],
+        [nan, nan, nan, nan, nan]
+    ];
+    for &interp in &INTERPOLATIONS {
+        let q = a.nanquantile_axis(Axis(1), 0.6, interp).unwrap();
+        assert_eq!(q[0], sorted_quantile(&[5., 1., 4., 2., 3.], 0.6, interp));
+        assert_eq!(q[1], sorted_quantile(&[5., 4., 2., 3.], 0.6, interp));
+        assert_eq!(q[2], sorted_quantile(&[1., 3.], 0.6, interp));
+        assert!(q[3].is_nan());
+        // agrees with `quantile_axis` on the lane without NaNs
+        assert_eq!(q[0], a.slice(s![..1, ..]).quantile_axis(Axis(1), 0.6, interp).unwrap()[0]);
+    }
+    assert_eq!(a.t().nanquantile_axis(Axis(0), 0., Interpolate::Lower).unwrap().slice(s![..3]), array![1., 2., 1.]);
+    assert_eq!(Array2::<f64>::zeros((3, 0)).nanquantile_axis(Axis(1), 0.5, Interpolate::Linear), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn mode_u8_labels()
+{
+    let labels = array![[0u8, 4, 4, 2], [2, 4, 1, 0]];
+    assert_eq!(labels.mode(), Some(4));
+    assert_eq!(labels.t().mode(), Some(4));
+    assert_eq!(Array1::<u8>::zeros(0).mode(), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn mode_ties_resolve_to_smallest()
+{
+    // 7 occurs first, but 5 is smaller
+    let a = array![7u8, 5, 7, 5, 9];
+    assert_eq!(a.mode(), Some(5));
+    assert_eq!(a.slice(s![..;-1]).mode(), Some(5));
+    assert_eq!(a.mode_first(), Some(7));
+    assert_eq!(a.slice(s![..;-1]).mode_first(), Some(5));
+    assert_eq!(Array1::<u8>::zeros(0).mode_first(), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn mode_axis_unique_lanes()
+{
+    let a = array![[9u8, 8, 7], [1, 2, 3], [4, 4, 5]];
+    // every value unique in the lane: the smallest one wins
+    assert_eq!(a.mode_axis(Axis(1)).unwrap(), array![7, 1, 4]);
+    assert_eq!(a.mode_axis(Axis(0)).unwrap(), array![1, 2, 3]);
+    assert_eq!(a.slice(s![.., ..;-1]).mode_axis(Axis(1)).unwrap(), array![7, 1, 4]);
+    assert_eq!(Array2::<u8>::zeros((2, 0)).mode_axis(Axis(1)), None);
+    // or the first one
+    assert_eq!(a.mode_first_axis(Axis(1)).unwrap(), array![9, 1, 4]);
+    assert_eq!(a.mode_first_axis(Axis(0)).unwrap(), array![9, 8, 7]);
+    assert_eq!(a.slice(s![.., ..;-1]).mode_first_axis(Axis(1)).unwrap(), array![7, 3, 4]);
+    assert_eq!(Array2::<u8>::zeros((2, 0)).mode_first_axis(Axis(1)), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn mode_first_unordered_elements()
+{
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    enum Label
+    {
+        Cat,
+        Dog,
+    }
+    let a = array![Label::Dog, Label::Cat, Label::Cat, Label::Dog];
+    assert_eq!(a.mode_first(), Some(Label::Dog));
+}
+
+#[test]
+fn min_max()
+{
+    let a = array![[3, -7, 5], [2, 9, -7]];
+    assert_eq!(a.min(), Some(&-7));
+    assert_eq!(a.max(), Some(&9));
+    assert_eq!(a.t().min(), Some(&-7));
+    assert_eq!(a.slice(s![.., ..;-2]).max(), Some(&5));
+    assert_eq!(Array2::<i32>::zeros((0, 3)).min(), None);
+    assert_eq!(Array2::<i32>::zeros((0, 3)).max(), None);
+}
+
+#[test]
+fn min_max_ties_return_first_in_memory_order()
+{
+    let a = array![1., -0., 0., 1.0f64];
+    assert!(a.min().unwrap().is_sign_negative());
+    let b = array![0., -0., 1.0f64];
+    assert!(b.min().unwrap().is_sign_positive());
+    assert!(std::ptr::eq(a.max().unwrap(), &a[0]));
+}
+
+#[test]
+fn min_max_nan()
+{
+    let a = array![1.0, f64::NAN, 3.0];
+    assert_eq!(a.min(), None);
+    assert_eq!(a.max(), None);
+    // a single NaN is not comparable either
+    assert_eq!(array![f64::NAN].min(), None);
+    assert_eq!(array![[f64::NAN, 1.], [2., 3.]].t().max(), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn min_max_skipnan()
+{
+    let nan = f64::NAN;
+    let a = array![[nan, 4., nan], [-2., nan, 1.]];
+    assert_eq!(a.min_skipnan(), Some(&-2.));
+    assert_eq!(a.max_skipnan(), Some(&4.));
+    assert_eq!(a.t().min_skipnan(), Some(&-2.));
+    assert_eq!(a.slice(s![.., ..;-2]).max_skipnan(), Some(&1.));
+    assert_eq!(array![nan, nan].min_skipnan(), None);
+    assert_eq!(array![nan, nan].max_skipnan(), None);
+    assert_eq!(Array1::<f64>::zeros(0).max_skipnan(), None);
+    // agrees with `min`/`max` without NaNs
+    let b = array![3., 1., 2.];
+    assert_eq!(b.min_skipnan(), b.min());
+    assert_eq!(b.max_skipnan(), b.max());
+}
+
+#[test]
+fn min_max_axis()
+{
+    let a = array![[3, -7, 5], [2, 9, -7]];
+    assert_eq!(a.min_axis(Axis(0)), array![2, -7, -7]);
+    assert_eq!(a.max_axis(Axis(0)), array![3, 9, 5]);
+    assert_eq!(a.min_axis(Axis(1)), array![-7, -7]);
+    assert_eq!(a.max_axis(Axis(1)), array![5, 9]);
+    // same results for F-order and negatively strided arrays
+    let mut f = Array2::zeros(a.dim().f());
+    f.assign(&a);
+    assert_eq!(f.min_axis(Axis(0)), a.min_axis(Axis(0)));
+    assert_eq!(f.max_axis(Axis(1)), a.max_axis(Axis(1)));
+    assert_eq!(a.slice(s![.., ..;-1]).max_axis(Axis(1)), array![5, 9]);
+    assert_eq!(a.slice(s![..;-1, ..]).min_axis(Axis(0)), array![2, -7, -7]);
+}
+
+#[test]
+fn min_max_axis_3d()
+{
+    let a = Array::from_iter((0..24).map(|x| (x * 7) % 24))
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    for axis in 0..3 {
+        let min = a.min_axis(Axis(axis));
+        let max = a.max_axis(Axis(axis));
+        for (i, lane) in a.lanes(Axis(axis)).into_iter().enumerate() {
+            assert_eq!(min.iter().nth(i), lane.min());
+            assert_eq!(max.iter().nth(i), lane.max());
+        }
+    }
+}
+
+#[test]
+fn min_max_axis_nan_propagates()
+{
+    let a = array![[1., f64::NAN, 3.], [4., 2., 0.]];
+    let min = a.min_axis(Axis(1));
+    assert!(min[0].is_nan());
+    assert_eq!(min[1], 0.);
+    let max = a.max_axis(Axis(0));
+    assert_eq!(max[0], 4.);
+    assert!(max[1].is_nan());
+    assert_eq!(max[2], 3.);
+}
+
+#[test]
+#[should_panic(expected = "axis of length zero")]
+fn min_axis_zero_length()
+{
+    Array2::<i32>::zeros((3, 0)).min_axis(Axis(1));
+}
+
+#[test]
+#[should_panic(expected = "axis of length zero")]
+fn max_axis_zero_length()
+{
+    Array2::<i32>::zeros((0, 3)).max_axis(Axis(0));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn min_max_axis_skipnan()
+{
+    let nan = f64::NAN;
+    let a = array![[nan, 4., nan], [-2., nan, nan]];
+    assert_eq!(a.min_axis_skipnan(Axis(1)), array![4., -2.]);
+    assert_eq!(a.max_axis_skipnan(Axis(1)), array![4., -2.]);
+    let min = a.min_axis_skipnan(Axis(0));
+    assert_eq!(min.slice(s![..2]), array![-2., 4.]);
+    assert!(min[2].is_nan());
+    let max = a.t().max_axis_skipnan(Axis(1));
+    assert_eq!(max.slice(s![..2]), array![-2., 4.]);
+    assert!(max[2].is_nan());
+}
+
+#[test]
+fn argmin_argmax_2d()
+{
+    let a = array![[3, 1, 4], [1, 5, 9], [2, 6, 9]];
+    assert_eq!(a.argmin(), Some((0, 1)));
+    assert_eq!(a.argmax(), Some((1, 2)));
+    assert_eq!(a.t().argmin(), Some((0, 1)));
+    assert_eq!(a.t().argmax(), Some((2, 1)));
+    assert_eq!(a.slice(s![..;-1, ..]).argmax(), Some((0, 2)));
+    assert_eq!(Array2::<i32>::zeros((2, 0)).argmin(), None);
+}
+
+#[test]
+fn argmin_argmax_1d_ties_resolve_to_first()
+{
+    let a = array![2, 0, 7, 0, 7];
+    assert_eq!(a.argmin(), Some(1));
+    assert_eq!(a.argmax(), Some(2));
+    // not contiguous: the first occurrence in logical order still wins
+    let rev = a.slice(s![..;-1]);
+    assert_eq!(rev.argmin(), Some(1));
+    assert_eq!(rev.argmax(), Some(0));
+    assert_eq!(a.slice(s![..;2]).argmax(), Some(1));
+    assert_eq!(Array1::<i32>::zeros(0).argmax(), None);
+}
+
+#[test]
+fn argmin_argmax_dyn()
+{
+    let a = Array::from_iter([4., 8., -1., 8., 3., -1.])
+        .into_shape_with_order(IxDyn(&[3, 1, 2]))
+        .unwrap();
+    assert_eq!(a.argmin(), Some(IxDyn(&[1, 0, 0])));
+    assert_eq!(a.argmax(), Some(IxDyn(&[0, 0, 1])));
+    assert_eq!(a.argmax().map(|i| a[i]), Some(8.));
+}
+
+#[test]
+fn argmin_argmax_nan()
+{
+    let a = array![[1.0, 2.0], [f64::NAN, 0.0]];
+    assert_eq!(a.argmin(), None);
+    assert_eq!(a.argmax(), None);
+    assert_eq!(array![f64::NAN].argmax(), None);
+}
+
+#[test]
+fn argmin_argmax_axis()
+{
+    let a = array![[3, 1, 4], [1, 5, 9], [2, 6, 9]];
+    assert_eq!(a.argmin_axis(Axis(0)), array![1, 0, 0]);
+    assert_eq!(a.argmax_axis(Axis(0)), array![0, 2, 1]);
+    assert_eq!(a.argmin_axis(Axis(1)), array![1, 0, 0]);
+    assert_eq!(a.argmax_axis(Axis(1)), array![2, 2, 2]);
+    assert_eq!(a.t().argmax_axis(Axis(1)), array![0, 2, 1]);
+    assert_eq!(a.slice(s![.., ..;-1]).argmin_axis(Axis(1)), array![1, 2, 2]);
+}
+
+#[test]
+fn argmin_argmax_axis_nan()
+{
+    let a = array![[1., f64::NAN, f64::NAN], [4., 2., 0.]];
+    assert_eq!(a.argmin_axis(Axis(1)), array![1, 2]);
+    assert_eq!(a.argmax_axis(Axis(0)), array![1, 0, 0]);
+}
+
+#[test]
+#[should_panic(expected = "axis of length zero")]
+fn argmax_axis_zero_length()
+{
+    Array2::<i32>::zeros((3, 0)).argmax_axis(Axis(1));
+}
+
+#[test]
+fn argmin_argmax_axis_matches_indexed_iter()
+{
+    fn prop(xs: Vec<i8>, rows: u8, axis: bool, flip: bool) -> bool
+    {
+        let rows = rows as usize % 5 + 1;
+        let cols = xs.len() / rows;
+        if cols == 0 {
+            return true;
+        }
+        let mut a = Array::from_iter(xs.into_iter().take(rows * cols))
+            .into_shape_with_order((rows, cols))
+            .unwrap();
+        if flip {
+            a.invert_axis(Axis(1));
+        }
+        let axis = axis as usize;
+        let lanes = [cols, rows][axis];
+        // brute force: the first occurrence of the extremum along each lane
+        let mut argmin = vec![0; lanes];
+        let mut argmax = vec![0; lanes];
+        for ((i, j), &x) in a.indexed_iter() {
+            let (lane, k) = if axis == 0 { (j, i) } else { (i, j) };
+            let at = |k: usize| if axis == 0 { a[(k, lane)] } else { a[(lane, k)] };
+            if x < at(argmin[lane]) {
+                argmin[lane] = k;
+            }
+            if x > at(argmax[lane]) {
+                argmax[lane] = k;
+            }
+        }
+        a.argmin_axis(Axis(axis)).to_vec() == argmin && a.argmax_axis(Axis(axis)).to_vec() == argmax
+    }
+    quickcheck(prop as fn(Vec<i8>, u8, bool, bool) -> bool);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nanargmin_nanargmax()
+{
+    let nan = f64::NAN;
+    // NaN in the first position
+    assert_eq!(array![nan, 3., 1., 3.].nanargmax(), Some(1));
+    assert_eq!(array![nan, 3., 1., 1.].nanargmin(), Some(2));
+    // NaN in the last position
+    assert_eq!(array![2., 5., 2., nan].nanargmin(), Some(0));
+    assert_eq!(array![2., 5., 2., nan].nanargmax(), Some(1));
+    // all NaN or empty
+    assert_eq!(array![nan, nan].nanargmax(), None);
+    assert_eq!(Array1::<f64>::zeros(0).nanargmin(), None);
+    let a = array![[nan, 1.], [7., nan]];
+    assert_eq!(a.nanargmax(), Some((1, 0)));
+    assert_eq!(a.t().nanargmin(), Some((1, 0)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nanargmin_nanargmax_axis()
+{
+    let nan = f64::NAN;
+    let a = array![[nan, 4., 4.], [-2., 6., nan], [3., nan, 1.]];
+    assert_eq!(a.nanargmin_axis(Axis(1)), array![1, 0, 2]);
+    assert_eq!(a.nanargmax_axis(Axis(1)), array![1, 1, 0]);
+    assert_eq!(a.nanargmin_axis(Axis(0)), array![1, 0, 2]);
+    assert_eq!(a.nanargmax_axis(Axis(0)), array![2, 1, 0]);
+    assert_eq!(a.t().nanargmax_axis(Axis(1)), array![2, 1, 0]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic(expected = "lane 1 along Axis(1) contains only NaN")]
+fn nanargmax_axis_all_nan_lane()
+{
+    let nan = f64::NAN;
+    array![[1., nan], [nan, nan]].nanargmax_axis(Axis(1));
+}
+
+#[test]
+fn ptp()
+{
+    let a = array![[3, -7, 5], [2, 9, -7]];
+    assert_eq!(a.ptp(), Some(16));
+    assert_eq!(a.t().ptp(), Some(16));
+    assert_eq!(a.slice(s![.., 1..;-1]).ptp(), Some(16));
+    assert_eq!(array![4u8].ptp(), Some(0));
+    assert_eq!(Array2::<u8>::zeros((0, 2)).ptp(), None);
+    assert_eq!(array![2., f64::NAN].ptp(), None);
+}
+
+#[test]
+fn ptp_axis()
+{
+    let a = array![[3, -7, 5], [2, 9, -7]];
+    assert_eq!(a.ptp_axis(Axis(0)), array![1, 16, 12]);
+    assert_eq!(a.ptp_axis(Axis(1)), array![12, 16]);
+    let mut f = Array2::zeros(a.dim().f());
+    f.assign(&a);
+    for axis in 0..2 {
+        let axis = Axis(axis);
+        assert_eq!(f.ptp_axis(axis), a.ptp_axis(axis));
+        assert_eq!(a.ptp_axis(axis), &a.max_axis(axis) - &a.min_axis(axis));
+    }
+    let nan = array![[f64::NAN, -7.], [2., 9.]];
+    let ptp = nan.ptp_axis(Axis(0));
+    assert!(ptp[0].is_nan());
+    assert_eq!(ptp[1], 16.);
+}
+
+#[test]
+#[should_panic(expected = "axis of length zero")]
+fn ptp_axis_zero_length()
+{
+    Array2::<i32>::zeros((3, 0)).ptp_axis(Axis(1));
+}
+
+#[test]
+fn min_max_single_pass()
+{
+    let a = array![[3, -7, 5], [2, 9, -7]];
+    assert_eq!(a.min_max(), Some((&-7, &9)));
+    assert_eq!(a.t().min_max(), Some((&-7, &9)));
+    assert_eq!(a.slice(s![.., ..;-2]).min_max(), Some((&-7, &5)));
+    assert_eq!(array![4u8].min_max(), Some((&4, &4)));
+    assert_eq!(Array2::<i32>::zeros((0, 3)).min_max(), None);
+    assert_eq!(array![2., f64::NAN].min_max(), None);
+    assert_eq!(array![f64::NAN].min_max(), None);
+    // ties resolve like `min` and `max`
+    let b = array![1., -0., 0., 1.0f64];
+    let (min, max) = b.min_max().unwrap();
+    assert!(std::ptr::eq(min, b.min().unwrap()));
+    assert!(std::ptr::eq(max, b.max().unwrap()));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn min_max_single_pass_skipnan()
+{
+    let nan = f64::NAN;
+    let a = array![[nan, 4., nan], [-2., nan, 1.]];
+    assert_eq!(a.min_max_skipnan(), Some((&-2., &4.)));
+    assert_eq!(a.t().min_max_skipnan(), Some((&-2., &4.)));
+    assert_eq!(array![nan, nan].min_max_skipnan(), None);
+    assert_eq!(Array1::<f64>::zeros(0).min_max_skipnan(), None);
+}
+
+#[test]
+fn min_max_axis_single_pass()
+{
+    let a = Array::from_iter((0..60).map(|x| (x * 7) % 24 - 12))
+        .into_shape_with_order((3, 4, 5))
+        .unwrap();
+    let mut f = Array::zeros(a.dim().f());
+    f.assign(&a);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let expected = (a.min_axis(axis), a.max_axis(axis));
+        assert_eq!(a.min_max_axis(axis), expected);
+        assert_eq!(f.min_max_axis(axis), expected);
+        assert_eq!(
+            a.slice(s![..;-1, .., ..;2]).min_max_axis(axis),
+            (a.slice(s![..;-1, .., ..;2]).min_axis(axis), a.slice(s![..;-1, .., ..;2]).max_axis(axis))
+        );
+    }
+
+    let nan = array![[1., f64::NAN, 3.], [4., 2., 0.]];
+    let (min, max) = nan.min_max_axis(Axis(0));
+    assert!(min[1].is_nan() && max[1].is_nan());
+    assert_eq!((min[0], max[0], min[2], max[2]), (1., 4., 0., 3.));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn min_max_axis_single_pass_skipnan()
+{
+    let nan = f64::NAN;
+    let a = array![[nan, 4., nan], [-2., nan, nan], [5., 1., nan]];
+    for axis in 0..2 {
+        let axis = Axis(axis);
+        let (min, max) = a.min_max_axis_skipnan(axis);
+        assert_eq!(min.mapv(f64::to_bits), a.min_axis_skipnan(axis).mapv(f64::to_bits));
+        assert_eq!(max.mapv(f64::to_bits), a.max_axis_skipnan(axis).mapv(f64::to_bits));
+    }
+}
+
+#[test]
+#[should_panic(expected = "axis of length zero")]
+fn min_max_axis_zero_length()
+{
+    Array2::<i32>::zeros((3, 0)).min_max_axis(Axis(1));
+}
+
+#[test]
+fn abs_max()
+{
+    let a = array![[3, -7, 5], [2, 6, -1]];
+    assert_eq!(a.abs_max(), Some(7));
+    assert_eq!(a.t().abs_max(), Some(7));
+    assert_eq!(a.slice(s![.., ..;-2]).abs_max(), Some(5));
+    assert_eq!(array![0i32, 0].abs_max(), Some(0));
+    assert_eq!(Array2::<i32>::zeros((0, 3)).abs_max(), None);
+    assert_eq!(array![-2.5, 1., f64::NEG_INFINITY].abs_max(), Some(f64::INFINITY));
+    assert_eq!(array![2., f64::NAN].abs_max(), None);
+    assert_eq!(array![f64::NAN].abs_max(), None);
+}
+
+#[test]
+fn abs_max_i32_min()
+{
+    // the negated absolute values are compared, so only a result that is
+    // not representable overflows
+    assert_eq!(array![i32::MIN + 1, 5, i32::MAX].abs_max(), Some(i32::MAX));
+    assert_eq!(array![[i32::MIN + 1, 5], [-3, 0]].abs_max_axis(Axis(1)), Some(array![i32::MAX, 3]));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "overflow")]
+fn abs_max_i32_min_overflows()
+{
+    array![3, i32::MIN].abs_max();
+}
+
+#[test]
+fn abs_max_axis()
+{
+    let a = Array::from_iter((0..60).map(|x| (x * 7) % 24 - 12))
+        .into_shape_with_order((3, 4, 5))
+        .unwrap();
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        assert_eq!(a.abs_max_axis(axis).unwrap(), a.mapv(i32::abs).max_axis(axis));
+        let v = a.slice(s![..;-1, .., ..;2]);
+        assert_eq!(v.abs_max_axis(axis).unwrap(), v.mapv(i32::abs).max_axis(axis));
+    }
+    assert_eq!(Array2::<i32>::zeros((3, 0)).abs_max_axis(Axis(1)), None);
+    assert_eq!(Array2::<i32>::zeros((3, 0)).abs_max_axis(Axis(0)), Some(Array1::zeros(0)));
+
+    let nan = array![[1., f64::NAN, -3.], [-4., 2., 0.]];
+    let max = nan.abs_max_axis(Axis(0)).unwrap();
+    assert_eq!(max[0], 4.);
+    assert!(max[1].is_nan());
+    assert_eq!(max[2], 3.);
+}
+
+#[test]
+fn cummax_cummin()
+{
+    let a = array![[3, 1, 4, 1], [5, 9, 2, 6]];
+    assert_eq!(a.cummax(Axis(1)), array![[3, 3, 4, 4], [5, 9, 9, 9]]);
+    assert_eq!(a.cummin(Axis(1)), array![[3, 1, 1, 1], [5, 5, 2, 2]]);
+    assert_eq!(a.cummax(Axis(0)), array![[3, 1, 4, 1], [5, 9, 4, 6]]);
+    assert_eq!(a.cummin(Axis(0)), array![[3, 1, 4, 1], [3, 1, 2, 1]]);
+    assert_eq!(a.t().cummax(Axis(0)), a.cummax(Axis(1)).t());
+    assert_eq!(a.slice(s![.., ..;-1]).cummin(Axis(1)), array![[1, 1, 1, 1], [6, 2, 2, 2]]);
+    // the last element of each lane is the extremum of the lane
+    let b = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i * 7 + j * 13 + k * 3) % 11);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let last = b.len_of(axis) - 1;
+        assert_eq!(b.cummax(axis).index_axis(axis, last), b.max_axis(axis));
+        assert_eq!(b.cummin(axis).index_axis(axis, last), b.min_axis(axis));
+    }
+}
+
+#[test]
+fn cummax_cummin_nan_propagates()
+{
+    let nan = f64::NAN;
+    let a = array![2., 1., nan, 5., 0.];
+    let max = a.cummax(Axis(0));
+    let min = a.cummin(Axis(0));
+    assert_eq!(max.slice(s![..2]), array![2., 2.]);
+    assert_eq!(min.slice(s![..2]), array![2., 1.]);
+    assert!(max.slice(s![2..]).iter().all(|x| x.is_nan()));
+    assert!(min.slice(s![2..]).iter().all(|x| x.is_nan()));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn cummax_cummin_skipnan()
+{
+    let nan = f64::NAN;
+    let a = array![[nan, 1., nan, 5., 0.], [2., nan, 3., nan, 1.]];
+    let max = a.cummax_skipnan(Axis(1));
+    let min = a.cummin_skipnan(Axis(1));
+    assert!(max[(0, 0)].is_nan() && min[(0, 0)].is_nan());
+    assert_eq!(max.slice(s![0, 1..]), array![1., 1., 5., 5.]);
+    assert_eq!(min.slice(s![0, 1..]), array![1., 1., 1., 0.]);
+    assert_eq!(max.row(1), array![2., 2., 3., 3., 3.]);
+    assert_eq!(min.row(1), array![2., 2., 2., 2., 1.]);
+}
+
+#[test]
+#[should_panic(expected = "axis is out of bounds")]
+fn cummax_axis_out_of_bounds()
+{
+    array![1, 2].cummax(Axis(1));
+}
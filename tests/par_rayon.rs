@@ -2,6 +2,7 @@
 
 use ndarray::parallel::prelude::*;
 use ndarray::prelude::*;
+use ndarray_gen::array_builder::{ArrayBuilder, ElementGenerator};
 
 const M: usize = 1024 * 10;
 const N: usize = 100;
@@ -92,3 +93,26 @@ fn test_axis_chunks_iter_mut()
     println!("{:?}", a.slice(s![..10, ..5]));
     assert_abs_diff_eq!(a, b, epsilon = 0.001);
 }
+
+#[test]
+fn test_par_sort_axis()
+{
+    let a = ArrayBuilder::new((300, 200))
+        .generator(ElementGenerator::Random(1))
+        .build::<i32>()
+        .mapv(|x| x % 1000);
+    for axis in [Axis(0), Axis(1)] {
+        for layout in [a.clone(), a.t().as_standard_layout().reversed_axes().to_owned()] {
+            let mut serial = layout.clone();
+            serial.sort_axis_inplace(axis);
+            let mut parallel = layout.clone();
+            parallel.par_sort_axis_inplace(axis);
+            assert_eq!(parallel, serial);
+        }
+    }
+    let mut f = a.mapv(|x| x as f64 / 7.);
+    let mut g = f.clone();
+    f.par_sort_axis_by(Axis(1), |x, y| y.total_cmp(x));
+    g.sort_axis_by(Axis(1), |x, y| y.total_cmp(x));
+    assert_eq!(f, g);
+}
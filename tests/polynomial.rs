@@ -0,0 +1,74 @@
+#![allow(clippy::float_cmp)]
+
+use ndarray::prelude::*;
+use ndarray::{polyval, polyval_inplace};
+use ndarray_gen::array_builder::{ArrayBuilder, ElementGenerator};
+
+#[test]
+fn polyval_matches_power_series()
+{
+    let coeffs = array![0.5, -1.25, 3., 0., -2., 0.75];
+    let x = ArrayBuilder::new((9, 13))
+        .generator(ElementGenerator::Random(5))
+        .build::<f64>()
+        .mapv(|x| x / 16384. - 2.);
+    let degree = coeffs.len() - 1;
+    let direct = x.mapv(|x| {
+        coeffs
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| c * x.powi((degree - i) as i32))
+            .sum::<f64>()
+    });
+    let y = polyval(&coeffs, &x);
+    for (&y, &d) in y.iter().zip(&direct) {
+        assert!((y - d).abs() < 1e-12, "{} != {}", y, d);
+    }
+    // in place, on a strided view, with strided coefficients
+    let mut z = x.clone();
+    let strided = array![0.5, 9., -1.25, 9., 3., 9., 0., 9., -2., 9., 0.75];
+    polyval_inplace(&strided.slice(s![..;2]), &mut z.slice_mut(s![.., ..;-3]));
+    assert_eq!(z.slice(s![.., ..;-3]), y.slice(s![.., ..;-3]));
+    assert_eq!(z.slice(s![.., 1..3]), x.slice(s![.., 1..3]));
+}
+
+#[test]
+fn polyval_integers()
+{
+    let coeffs = array![1, 0, -4];
+    let x = array![-2, 0, 3];
+    assert_eq!(polyval(&coeffs, &x), array![0, -4, 5]);
+    let coeffs = array![2, 1];
+    let mut x = array![[1u64, 2], [3, 10]];
+    polyval_inplace(&coeffs, &mut x);
+    assert_eq!(x, array![[3, 5], [7, 21]]);
+}
+
+#[test]
+fn polyval_constant_and_empty()
+{
+    let x = array![1., -7.5, f64::INFINITY];
+    let constant = array![2.5];
+    assert_eq!(polyval(&constant, &x), array![2.5, 2.5, 2.5]);
+    assert_eq!(polyval(&Array1::zeros(0), &x), array![0., 0., 0.]);
+    let mut y = x.clone();
+    polyval_inplace(&Array1::zeros(0), &mut y);
+    assert_eq!(y, array![0., 0., 0.]);
+}
+
+#[test]
+fn polyval_large_x()
+{
+    // (x - 1)⁵, whose terms cancel less and less as |x| grows
+    let coeffs = array![1., -5., 10., -10., 5., -1.];
+    let x = array![1e3, -1e6, 1e12, -1e40];
+    let y = polyval(&coeffs, &x);
+    for (&y, &x) in y.iter().zip(&x) {
+        let exact = (x - 1f64).powi(5);
+        assert!((y - exact).abs() <= 1e-12 * exact.abs(), "{} != {}", y, exact);
+    }
+    // the result overflows to an infinity with the sign of the leading term
+    let x = array![1e70, -1e70];
+    let y = polyval(&coeffs, &x);
+    assert_eq!(y, array![f64::INFINITY, f64::NEG_INFINITY]);
+}
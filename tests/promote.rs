@@ -0,0 +1,81 @@
+#![allow(clippy::float_cmp)]
+
+use ndarray::prelude::*;
+use ndarray::{ErrorKind, Promote, ShapeError};
+
+#[test]
+fn promote_f32_f64()
+{
+    let a = array![[0.1f32, 2.5], [-1., 3e38]];
+    let b = array![0.2f64, 1e300];
+    let c: Array2<f64> = a.add_promote(&b).unwrap();
+    assert_eq!(c, array![[0.1f32 as f64 + 0.2, 2.5 + 1e300], [-0.8, 3e38f32 as f64 + 1e300]]);
+    // the result does not overflow like it would in `f32`
+    let ten = array![[10f64]];
+    assert_eq!(a.mul_promote(&ten).unwrap()[[1, 1]], 3e38f32 as f64 * 10.);
+    // the other way around gives the same type
+    assert_eq!(b.sub_promote(&a), Ok(&b - &a.mapv(|x| x as f64)));
+    assert_eq!(b.div_promote(&a.row(0)), Ok(&b / &a.row(0).mapv(|x| x as f64)));
+}
+
+#[test]
+fn promote_u8_f32()
+{
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 100 + j) as u8);
+    let b = array![0.5f32, -1., 2., 255.];
+    let c: Array2<f32> = a.add_promote(&b).unwrap();
+    assert_eq!(c, a.mapv(f32::from) + &b);
+    let column = b.view().insert_axis(Axis(1));
+    assert_eq!(a.t().mul_promote(&column), Ok(a.t().mapv(f32::from) * column));
+    // no wrapping around in `u8`
+    assert_eq!(a.sub_promote(&b).unwrap()[[0, 0]], -0.5);
+    assert_eq!(a.add_promote(&b).unwrap()[[2, 3]], 458.);
+}
+
+#[test]
+fn promote_integers()
+{
+    let a = array![200u8, 255];
+    let b = array![-100i8, 127];
+    let c: Array1<i16> = a.add_promote(&b).unwrap();
+    assert_eq!(c, array![100, 382]);
+    let rhs = array![-2i16, 7];
+    let d: Array1<i32> = array![60000u16, 0].mul_promote(&rhs).unwrap();
+    assert_eq!(d, array![-120000, 0]);
+    let rhs = array![1i64];
+    let e: Array1<f64> = array![u64::MAX].sub_promote(&rhs).unwrap();
+    assert_eq!(e, array![u64::MAX as f64]);
+    // the same type
+    let rhs = array![2, 3];
+    let f: Array1<i32> = array![7, 8].div_promote(&rhs).unwrap();
+    assert_eq!(f, array![3, 2]);
+    assert_eq!(<u32 as Promote<i32>>::promote_rhs(-1), -1i64);
+}
+
+#[test]
+fn zip_promote_closure()
+{
+    let a = array![1i32, -2, 3];
+    let b = array![[0.5f32], [4.]];
+    let c = a.zip_promote(&b, |x, y| x < y).unwrap();
+    assert_eq!(c, array![[false, true, false], [true, true, true]]);
+    let mut calls = 0;
+    let d = a
+        .zip_promote(&arr0(2u8), |x, y| {
+            calls += 1;
+            x * y
+        })
+        .unwrap();
+    assert_eq!(calls, 3);
+    assert_eq!(d, array![2, -4, 6]);
+}
+
+#[test]
+fn promote_incompatible()
+{
+    let a = array![1u8, 2, 3];
+    let b = array![1f32, 2.];
+    let err = Err(ShapeError::from_kind(ErrorKind::IncompatibleShape));
+    assert_eq!(a.add_promote(&b).map(|_| ()), err);
+    assert_eq!(a.zip_promote(&b, |_, _| ()).map(|_| ()), err);
+}
@@ -0,0 +1,56 @@
+#![allow(clippy::float_cmp)]
+#![cfg(feature = "std")]
+
+use ndarray::prelude::*;
+use ndarray::RankMethod;
+
+#[test]
+fn rank_axis_tie_methods()
+{
+    let a = array![20., 10., 30., 20., 40., 20., 10.];
+    let rank = |method| a.rank_axis(Axis(0), method);
+    assert_eq!(rank(RankMethod::Average), array![4., 1.5, 6., 4., 7., 4., 1.5]);
+    assert_eq!(rank(RankMethod::Min), array![3., 1., 6., 3., 7., 3., 1.]);
+    assert_eq!(rank(RankMethod::Max), array![5., 2., 6., 5., 7., 5., 2.]);
+    assert_eq!(rank(RankMethod::Dense), array![2., 1., 3., 2., 4., 2., 1.]);
+    assert_eq!(rank(RankMethod::Ordinal), array![3., 1., 6., 4., 7., 5., 2.]);
+}
+
+#[test]
+fn rank_axis_nan_last()
+{
+    let a = array![2., f64::NAN, 1., f64::NAN, 3.];
+    assert_eq!(a.rank_axis(Axis(0), RankMethod::Average), array![2., 4.5, 1., 4.5, 3.]);
+    assert_eq!(a.rank_axis(Axis(0), RankMethod::Ordinal), array![2., 4., 1., 5., 3.]);
+    assert_eq!(a.rank_axis(Axis(0), RankMethod::Dense), array![2., 4., 1., 4., 3.]);
+}
+
+#[test]
+fn rank_axis_lanes()
+{
+    let a = array![[[3., 1.], [2., 2.]], [[1., 1.], [0., 5.]]];
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        for &method in &[RankMethod::Average, RankMethod::Min, RankMethod::Max, RankMethod::Dense, RankMethod::Ordinal]
+        {
+            let ranks = a.rank_axis(axis, method);
+            assert_eq!(ranks.shape(), a.shape());
+            for (lane, ranked) in a.lanes(axis).into_iter().zip(ranks.lanes(axis)) {
+                assert_eq!(lane.to_owned().rank_axis(Axis(0), method), ranked);
+            }
+        }
+    }
+    assert_eq!(
+        a.rank_axis(Axis(2), RankMethod::Average),
+        array![[[2., 1.], [1.5, 1.5]], [[1.5, 1.5], [1., 2.]]]
+    );
+    let empty = Array2::<f64>::zeros((0, 3));
+    assert_eq!(empty.rank_axis(Axis(0), RankMethod::Min), empty);
+}
+
+#[test]
+#[should_panic]
+fn rank_axis_out_of_bounds()
+{
+    array![1., 2.].rank_axis(Axis(1), RankMethod::Average);
+}
@@ -0,0 +1,152 @@
+#![allow(clippy::float_cmp)]
+
+use ndarray::prelude::*;
+use ndarray::Zip;
+#[cfg(any(feature = "std", feature = "libm"))]
+use ndarray_gen::array_builder::{ArrayBuilder, ElementGenerator};
+use quickcheck::quickcheck;
+
+/// Return an array of the given shape with pseudo-random elements in
+/// `[-50, 50)`.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn random(shape: (usize, usize, usize), seed: u32) -> Array3<f64>
+{
+    ArrayBuilder::new(shape)
+        .generator(ElementGenerator::Random(seed))
+        .build::<f64>()
+        .mapv(|x| x / 65536. * 100. - 50.)
+}
+
+/// Reduce each window of `window` elements along `axis` with `f`, from
+/// scratch.
+#[cfg(all(feature = "approx", any(feature = "std", feature = "libm")))]
+fn brute_force<F>(a: &Array3<f64>, axis: Axis, window: usize, f: F) -> Array3<f64>
+where F: Fn(&[f64]) -> f64
+{
+    let mut shape = [1, 1, 1];
+    shape[axis.index()] = window;
+    Zip::from(a.windows(shape)).map_collect(|w| f(&w.iter().copied().collect::<Vec<_>>()))
+}
+
+#[test]
+#[cfg(all(feature = "approx", any(feature = "std", feature = "libm")))]
+fn rolling_matches_brute_force()
+{
+    use approx::assert_abs_diff_eq;
+
+    let a = random((6, 7, 8), 7);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        for window in 1..=a.len_of(axis) {
+            let rolling = a.rolling(axis, window);
+            let n = window as f64;
+            let mean = |w: &[f64]| w.iter().sum::<f64>() / n;
+            let var = |w: &[f64]| w.iter().map(|x| (x - mean(w)).powi(2)).sum::<f64>() / n;
+            assert_eq!(rolling.min(), brute_force(&a, axis, window, |w| w.iter().copied().fold(f64::MAX, f64::min)));
+            assert_eq!(rolling.max(), brute_force(&a, axis, window, |w| w.iter().copied().fold(f64::MIN, f64::max)));
+            assert_abs_diff_eq!(rolling.sum(), brute_force(&a, axis, window, |w| w.iter().sum()), epsilon = 1e-9);
+            assert_abs_diff_eq!(rolling.mean(), brute_force(&a, axis, window, mean), epsilon = 1e-9);
+            assert_abs_diff_eq!(rolling.var(0.), brute_force(&a, axis, window, var), epsilon = 1e-9);
+            assert_abs_diff_eq!(rolling.std(0.), brute_force(&a, axis, window, |w| var(w).sqrt()), epsilon = 1e-9);
+            if window > 1 {
+                let sample_var = |w: &[f64]| var(w) * n / (n - 1.);
+                assert_abs_diff_eq!(rolling.var(1.), brute_force(&a, axis, window, sample_var), epsilon = 1e-9);
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn rolling_strided_views()
+{
+    let a = random((5, 6, 7), 3);
+    let v = a.slice(s![..;-1, 1.., ..;2]);
+    let mut f = Array::zeros(v.raw_dim().f());
+    f.assign(&v);
+    let c = v.to_owned();
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        assert_eq!(v.rolling(axis, 2).max(), c.rolling(axis, 2).max());
+        assert_eq!(f.rolling(axis, 3).sum(), c.rolling(axis, 3).sum());
+        assert_eq!(v.rolling(axis, 2).std(1.), c.rolling(axis, 2).std(1.));
+    }
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn rolling_padded()
+{
+    let a = array![[1., 2., 3., 4.], [5., 6., 7., 8.]];
+    let sums = a.rolling(Axis(1), 3).padded(f64::NAN).sum();
+    assert_eq!(sums.shape(), &[2, 4]);
+    assert!(sums.slice(s![.., ..2]).iter().all(|x| x.is_nan()));
+    assert_eq!(sums.slice(s![.., 2..]), array![[6., 9.], [18., 21.]]);
+    assert_eq!(
+        a.rolling(Axis(0), 2).padded(0.).max(),
+        array![[0., 0., 0., 0.], [5., 6., 7., 8.]]
+    );
+    // the window is longer than the axis
+    assert!(a.rolling(Axis(0), 3).padded(f64::NAN).mean().iter().all(|x| x.is_nan()));
+    assert_eq!(a.rolling(Axis(0), 3).mean().shape(), &[0, 4]);
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn rolling_nan()
+{
+    let nan = f64::NAN;
+    let a = array![1., nan, 3., 4., 5., f64::INFINITY, 7., 8.];
+    let sums = a.rolling(Axis(0), 2).sum();
+    assert!(sums[0].is_nan() && sums[1].is_nan());
+    assert_eq!(sums.slice(s![2..4]), array![7., 9.]);
+    assert_eq!(sums.slice(s![4..6]), array![f64::INFINITY, f64::INFINITY]);
+    assert_eq!(sums[6], 15.);
+    let std = a.rolling(Axis(0), 2).std(0.);
+    assert!(std.slice(s![..2]).iter().all(|x| x.is_nan()));
+    assert_eq!(std.slice(s![2..4]), array![0.5, 0.5]);
+    assert!(std.slice(s![4..6]).iter().all(|x| x.is_nan()));
+    assert_eq!(std[6], 0.5);
+    let max = a.rolling(Axis(0), 3).max();
+    assert!(max[0].is_nan() && max[1].is_nan());
+    assert_eq!(max.slice(s![2..]), array![5., f64::INFINITY, f64::INFINITY, f64::INFINITY]);
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn rolling_var_is_not_negative()
+{
+    let mut a = Array::from_elem(100, 0.1);
+    a.slice_mut(s![..50;7]).fill(1e8);
+    assert!(a
+        .rolling(Axis(0), 7)
+        .var(1.)
+        .slice(s![50..])
+        .iter()
+        .all(|&x| (0. ..1e-12).contains(&x)));
+}
+
+#[test]
+#[should_panic(expected = "window must be greater than zero")]
+fn rolling_zero_window()
+{
+    array![1, 2].rolling(Axis(0), 0);
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+#[should_panic(expected = "DdofOutOfRange")]
+fn rolling_too_large_ddof()
+{
+    array![1., 2., 3.].rolling(Axis(0), 2).var(3.);
+}
+
+quickcheck! {
+    fn rolling_min_max_matches_windows(xs: Vec<i32>, window: u8) -> bool {
+        let window = window as usize % 8 + 1;
+        let a = Array::from(xs);
+        let min = Zip::from(a.windows(window)).map_collect(|w| *w.iter().min().unwrap());
+        let max = Zip::from(a.windows(window)).map_collect(|w| *w.iter().max().unwrap());
+        a.rolling(Axis(0), window).min() == min && a.rolling(Axis(0), window).max() == max
+    }
+}
@@ -0,0 +1,100 @@
+use ndarray::prelude::*;
+use ndarray_gen::array_builder::{ArrayBuilder, ElementGenerator};
+
+/// Sort each lane along `axis` by collecting it into a `Vec`.
+fn sort_naive(a: &Array2<i32>, axis: Axis) -> Array2<i32>
+{
+    let mut sorted = a.clone();
+    for mut lane in sorted.lanes_mut(axis) {
+        let mut v = lane.to_vec();
+        v.sort();
+        lane.assign(&Array::from(v));
+    }
+    sorted
+}
+
+/// Return an array of the given shape with pseudo-random elements in
+/// `0..10`, so that there are many duplicates.
+fn random(shape: (usize, usize), seed: u32) -> Array2<i32>
+{
+    ArrayBuilder::new(shape)
+        .generator(ElementGenerator::Random(seed))
+        .build::<i32>()
+        .mapv(|x| x % 10)
+}
+
+#[test]
+fn sort_axis_c_and_f_order()
+{
+    let c = random((7, 9), 3);
+    let f = c.t().as_standard_layout().reversed_axes().to_owned();
+    assert!(f.t().is_standard_layout());
+    for axis in [Axis(0), Axis(1)] {
+        let expected = sort_naive(&c, axis);
+        for a in [&c, &f] {
+            assert_eq!(a.sorted_axis(axis), expected);
+            let mut b = a.clone();
+            b.sort_axis_inplace(axis);
+            assert_eq!(b, expected);
+            // lanes with negative strides
+            let mut b = a.clone();
+            b.invert_axis(axis);
+            b.sort_axis_inplace(axis);
+            assert_eq!(b, expected);
+        }
+    }
+}
+
+#[test]
+fn sort_axis_by_and_key()
+{
+    let a = random((5, 6), 8);
+    let mut b = a.clone();
+    b.sort_axis_by(Axis(1), |x, y| y.cmp(x));
+    let mut expected = sort_naive(&a, Axis(1));
+    expected.invert_axis(Axis(1));
+    assert_eq!(b, expected);
+
+    let mut c = a.t().to_owned();
+    c.sort_axis_by_key(Axis(0), |&x| -x);
+    assert_eq!(c, expected.t());
+}
+
+#[test]
+fn sort_axis_floats()
+{
+    let mut a = array![[2.5, f64::NAN, -1.], [0., f64::INFINITY, -0.]];
+    a.sort_axis_by(Axis(1), |x, y| x.total_cmp(y));
+    assert_eq!(a.slice(s![.., ..2]), array![[-1., 2.5], [-0., 0.]]);
+    assert!(a[[0, 2]].is_nan());
+    assert_eq!(a[[1, 2]], f64::INFINITY);
+    assert!(a[[1, 0]].is_sign_negative());
+}
+
+#[test]
+fn sort_axis_non_copy()
+{
+    let mut a = array![["b".to_string(), "a".to_string()], ["c".to_string(), "a".to_string()]];
+    a.sort_axis_inplace(Axis(0));
+    let expected = array![["b".to_string(), "a".to_string()], ["c".to_string(), "a".to_string()]];
+    assert_eq!(a, expected);
+    a.sort_axis_inplace(Axis(1));
+    assert_eq!(a, array![["a".to_string(), "b".to_string()], ["a".to_string(), "c".to_string()]]);
+}
+
+#[test]
+fn sort_axis_empty()
+{
+    let mut a = Array2::<i32>::zeros((0, 4));
+    a.sort_axis_inplace(Axis(0));
+    a.sort_axis_inplace(Axis(1));
+    assert_eq!(a.sorted_axis(Axis(0)).shape(), &[0, 4]);
+}
+
+#[test]
+#[should_panic]
+fn sort_axis_out_of_bounds()
+{
+    let mut a = array![[1, 2]];
+    a.sort_axis_inplace(Axis(2));
+}
@@ -0,0 +1,192 @@
+use ndarray::prelude::*;
+use ndarray::Unique;
+use ndarray_gen::array_builder::{ArrayBuilder, ElementGenerator};
+
+/// Check the parts of `unique` against the elements of `a` in logical order.
+fn check_unique<D: Dimension>(a: &Array<i32, D>, unique: &Unique<i32>)
+{
+    let elems = a.iter().copied().collect::<Vec<_>>();
+    let values = &unique.values;
+    assert!(values.windows(2).into_iter().all(|w| w[0] < w[1]));
+    assert_eq!(unique.counts.len(), values.len());
+    assert_eq!(unique.first_indices.len(), values.len());
+    assert_eq!(unique.inverse.len(), elems.len());
+    for (k, &v) in values.iter().enumerate() {
+        assert_eq!(unique.counts[k], elems.iter().filter(|&&x| x == v).count());
+        assert_eq!(unique.first_indices[k], elems.iter().position(|&x| x == v).unwrap());
+    }
+    for (i, &x) in elems.iter().enumerate() {
+        assert_eq!(values[unique.inverse[i]], x);
+    }
+}
+
+#[test]
+fn unique_labels()
+{
+    let labels = array![[4, 0, 4, 2], [2, 2, 0, 7], [7, 4, 4, 4]];
+    assert_eq!(labels.unique(), array![0, 2, 4, 7]);
+    let unique = labels.unique_full();
+    assert_eq!(unique.counts, array![2, 3, 5, 2]);
+    assert_eq!(unique.first_indices, array![1, 3, 0, 7]);
+    check_unique(&labels, &unique);
+    // logical order, not memory order
+    let t = labels.t().to_owned();
+    check_unique(&t, &t.unique_full());
+    assert_eq!(t.unique_full().first_indices, array![3, 1, 0, 2]);
+}
+
+#[test]
+fn unique_many_duplicates()
+{
+    let a = ArrayBuilder::new((20, 30))
+        .generator(ElementGenerator::Random(5))
+        .build::<i32>()
+        .mapv(|x| x % 7 - 3);
+    let unique = a.unique_full();
+    assert_eq!(unique.values, Array::from_iter(-3..4));
+    assert_eq!(unique.counts.sum(), a.len());
+    check_unique(&a, &unique);
+
+    let same = Array2::from_elem((4, 5), 9);
+    assert_eq!(same.unique(), array![9]);
+    assert_eq!(same.unique_full().counts, array![20]);
+    assert_eq!(same.unique_full().inverse, Array1::<usize>::zeros(20));
+}
+
+#[test]
+fn unique_empty()
+{
+    let a = Array2::<i32>::zeros((0, 3));
+    assert_eq!(a.unique(), Array1::<i32>::zeros(0));
+    let unique = a.unique_full();
+    assert_eq!(unique.values.len(), 0);
+    assert_eq!(unique.counts.len(), 0);
+    assert_eq!(unique.first_indices.len(), 0);
+    assert_eq!(unique.inverse.len(), 0);
+}
+
+#[test]
+fn unique_floats_total_order()
+{
+    let nan = f64::NAN;
+    let a = array![1.5, -0., nan, 0., 1.5, nan, f64::INFINITY];
+    let values = a.unique_by(f64::total_cmp);
+    assert_eq!(values.len(), 5);
+    assert!(values[0].is_sign_negative() && values[0] == 0.);
+    assert!(values[1].is_sign_positive() && values[1] == 0.);
+    assert_eq!(values.slice(s![2..4]), array![1.5, f64::INFINITY]);
+    assert!(values[4].is_nan());
+    let unique = a.unique_full_by(f64::total_cmp);
+    assert_eq!(unique.counts, array![1, 1, 2, 1, 2]);
+    assert_eq!(unique.first_indices, array![1, 3, 0, 6, 2]);
+    assert_eq!(unique.inverse, array![2, 0, 4, 1, 2, 4, 3]);
+}
+
+#[test]
+fn unique_by_key()
+{
+    // values that compare equal are represented by the first of them
+    let a = array![-3, 1, 3, -1, 2];
+    let unique = a.unique_full_by(|x: &i32, y: &i32| x.abs().cmp(&y.abs()));
+    assert_eq!(unique.values, array![1, 2, -3]);
+    assert_eq!(unique.counts, array![2, 1, 2]);
+    assert_eq!(unique.first_indices, array![1, 4, 0]);
+    assert_eq!(a.unique_by(|x: &i32, y: &i32| x.abs().cmp(&y.abs())), array![1, 2, -3]);
+}
+
+#[test]
+fn unique_axis_rows()
+{
+    // duplicate and near-duplicate rows
+    let a = array![[1, 2, 3], [1, 2, 4], [0, 9, 9], [1, 2, 3], [1, 2, 4], [1, 2, 3]];
+    assert_eq!(a.unique_axis(Axis(0)), array![[0, 9, 9], [1, 2, 3], [1, 2, 4]]);
+    let unique = a.unique_axis_full(Axis(0));
+    assert_eq!(unique.counts, array![1, 3, 2]);
+    assert_eq!(unique.first_indices, array![2, 0, 1]);
+    assert_eq!(unique.inverse, array![1, 2, 0, 1, 2, 1]);
+    assert_eq!(unique.values.select(Axis(0), unique.inverse.as_slice().unwrap()), a);
+    // the same rows in a column-major array
+    let f = a.t().to_owned().reversed_axes();
+    assert_eq!(f.unique_axis_full(Axis(0)), unique);
+}
+
+#[test]
+fn unique_axis_columns()
+{
+    let a = array![[[1, 5, 1, 5], [2, 6, 2, 7]], [[3, 0, 3, 0], [4, 0, 4, 0]]];
+    let unique = a.unique_axis_full(Axis(2));
+    assert_eq!(unique.values, array![[[1, 5, 5], [2, 6, 7]], [[3, 0, 0], [4, 0, 0]]]);
+    assert_eq!(unique.counts, array![2, 1, 1]);
+    assert_eq!(unique.first_indices, array![0, 1, 3]);
+    assert_eq!(unique.inverse, array![0, 1, 0, 2]);
+    assert_eq!(a.unique_axis(Axis(1)), a);
+    assert_eq!(a.unique_axis(Axis(0)), a);
+}
+
+#[test]
+fn unique_axis_empty()
+{
+    let a = Array2::<i32>::zeros((0, 3));
+    let unique = a.unique_axis_full(Axis(0));
+    assert_eq!(unique.values.shape(), &[0, 3]);
+    assert_eq!(unique.counts.len(), 0);
+    assert_eq!(unique.inverse.len(), 0);
+    // all empty subviews are equal
+    let b = Array2::<i32>::zeros((4, 0));
+    let unique = b.unique_axis_full(Axis(0));
+    assert_eq!(unique.values.shape(), &[1, 0]);
+    assert_eq!(unique.counts, array![4]);
+    assert_eq!(unique.inverse, array![0, 0, 0, 0]);
+}
+
+#[test]
+#[should_panic]
+fn unique_axis_out_of_bounds()
+{
+    let _ = array![[1, 2]].unique_axis(Axis(2));
+}
+
+#[test]
+fn dedup_axis_runs()
+{
+    // runs at the start, in the middle and at the end
+    let a = array![[1, 1], [1, 1], [1, 1], [2, 0], [3, 3], [3, 3], [2, 0], [4, 4], [4, 4]];
+    assert_eq!(a.dedup_axis(Axis(0)), array![[1, 1], [2, 0], [3, 3], [2, 0], [4, 4]]);
+    assert_eq!(a.t().dedup_axis(Axis(1)), array![[1, 1], [2, 0], [3, 3], [2, 0], [4, 4]].reversed_axes());
+    // no runs
+    let b = array![[1, 2], [2, 1], [1, 2]];
+    assert_eq!(b.dedup_axis(Axis(0)), b);
+    assert_eq!(b.dedup_axis(Axis(1)), b);
+}
+
+#[test]
+fn dedup_axis_one_repeated_row()
+{
+    let a = Array2::from_shape_fn((6, 3), |(_, j)| j as i32);
+    assert_eq!(a.dedup_axis(Axis(0)), array![[0, 1, 2]]);
+    let e = Array2::<i32>::zeros((0, 3));
+    assert_eq!(e.dedup_axis(Axis(0)), e);
+    assert_eq!(e.dedup_axis(Axis(1)).shape(), &[0, 1]);
+}
+
+#[test]
+fn dedup_axis_by_compares_with_kept()
+{
+    // each subview is compared with the last one kept, not the one before
+    let a = array![0f64, 0.4, 0.8, 1.2, 1.6];
+    let deduped = a.dedup_axis_by(Axis(0), |x, y| (x[()] - y[()]).abs() < 0.5);
+    assert_eq!(deduped, array![0., 0.8, 1.6]);
+    let mut calls = 0;
+    let _ = a.dedup_axis_by(Axis(0), |_, _| {
+        calls += 1;
+        false
+    });
+    assert_eq!(calls, 4);
+}
+
+#[test]
+#[should_panic]
+fn dedup_axis_out_of_bounds()
+{
+    let _ = array![[1, 2]].dedup_axis(Axis(2));
+}
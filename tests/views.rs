@@ -15,3 +15,107 @@ fn cell_view()
     }
     assert_eq!(a, answer);
 }
+
+#[test]
+fn rot90_matrix()
+{
+    let a = array![[1, 2, 3], [4, 5, 6]];
+    assert_eq!(a.rot90(0), a);
+    assert_eq!(a.rot90(1), array![[3, 6], [2, 5], [1, 4]]);
+    assert_eq!(a.rot90(2), array![[6, 5, 4], [3, 2, 1]]);
+    assert_eq!(a.rot90(3), array![[4, 1], [5, 2], [6, 3]]);
+    assert_eq!(a.rot90(-1), a.rot90(3));
+    assert_eq!(a.rot90(-6), a.rot90(2));
+    assert_eq!(a.rot90(isize::MIN), a);
+    // the rotation is a view of the same elements
+    assert!(std::ptr::eq(&a.rot90(1)[[0, 0]], &a[[0, 2]]));
+}
+
+#[test]
+fn rot90_composition()
+{
+    let a = Array::from_shape_fn((3, 4), |(i, j)| 10 * i + j);
+    for k in -5..6 {
+        assert_eq!(a.rot90(k).rot90(1), a.rot90(k + 1));
+        assert_eq!(a.rot90(k).rot90(-k), a);
+    }
+    let r = a.rot90(1);
+    assert_eq!(r.rot90(1).rot90(1).rot90(1), a);
+    assert_eq!(a.t().rot90(1), a.rot90(-1).t());
+}
+
+#[test]
+fn rot90_in_plane_of_3d()
+{
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    for k in -4..5 {
+        let r = a.rot90_in_plane(k, (Axis(0), Axis(2)));
+        for j in 0..3 {
+            assert_eq!(r.index_axis(Axis(1), j), a.index_axis(Axis(1), j).rot90(k));
+        }
+        assert_eq!(a.rot90_in_plane(k, (Axis(2), Axis(0))), a.rot90_in_plane(-k, (Axis(0), Axis(2))));
+    }
+}
+
+#[test]
+#[should_panic]
+fn rot90_in_plane_same_axis()
+{
+    let a = Array3::<f32>::zeros((2, 2, 2));
+    let _ = a.rot90_in_plane(1, (Axis(1), Axis(1)));
+}
+
+#[test]
+#[should_panic]
+fn rot90_in_plane_axis_out_of_bounds()
+{
+    let a = Array2::<f32>::zeros((2, 2));
+    let _ = a.rot90_in_plane(1, (Axis(0), Axis(2)));
+}
+
+#[test]
+fn flipped_views()
+{
+    let a = Array::from_shape_fn((3, 4), |(i, j)| 10 * i + j);
+    for axis in [Axis(0), Axis(1)] {
+        let f = a.flipped(axis);
+        let n = a.len_of(axis);
+        for (i, sub) in f.axis_iter(axis).enumerate() {
+            assert_eq!(sub, a.index_axis(axis, n - 1 - i));
+        }
+        assert_eq!(f.flipped(axis), a);
+        assert!(std::ptr::eq(&f.flipped(axis)[[1, 2]], &a[[1, 2]]));
+    }
+    assert_eq!(a.flipped_all(), a.flipped(Axis(0)).flipped(Axis(1)));
+    assert_eq!(a.flipped_all().flipped_all(), a);
+    assert_eq!(a.t().flipped(Axis(0)), a.flipped(Axis(1)).t());
+    // empty axes and zero dimensions
+    let e = Array2::<i32>::zeros((0, 3));
+    assert_eq!(e.flipped(Axis(0)), e);
+    assert_eq!(e.flipped_all(), e);
+    let z = arr0(5);
+    assert_eq!(z.flipped_all(), z);
+}
+
+#[test]
+fn flipped_mut_aliases_parent()
+{
+    let mut a = Array::from_shape_fn((2, 3), |(i, j)| 3 * i + j);
+    a.flipped_mut(Axis(1))
+        .row_mut(0)
+        .assign(&aview1(&[7, 8, 9]));
+    assert_eq!(a, array![[9, 8, 7], [3, 4, 5]]);
+    {
+        let mut f = a.flipped_mut(Axis(0));
+        f[[0, 0]] = 0;
+        f.flipped_mut(Axis(0))[[0, 0]] = 1;
+    }
+    assert_eq!(a, array![[1, 8, 7], [0, 4, 5]]);
+}
+
+#[test]
+#[should_panic]
+fn flipped_axis_out_of_bounds()
+{
+    let _ = array![1, 2].flipped(Axis(1));
+}
@@ -0,0 +1,157 @@
+#![allow(clippy::float_cmp)]
+
+#[cfg(feature = "std")]
+use approx::assert_abs_diff_eq;
+use ndarray::prelude::*;
+use ndarray::{ErrorKind, ShapeError, StatsError};
+
+#[test]
+fn weighted_mean()
+{
+    let a = array![[1., 2., 3.], [4., 5., 6.]];
+    let w = array![[0., 1., 0.], [0., 0., 3.]];
+    assert_eq!(a.weighted_mean(&w), Ok(5.));
+    assert_eq!(a.t().weighted_mean(&w.t()), Ok(5.));
+    // broadcast a column of row weights
+    let rows = array![[3.], [1.]];
+    assert_eq!(a.weighted_mean(&rows), Ok(2.75));
+    assert_eq!(a.weighted_mean(&arr0(2.)), a.mean().ok_or(StatsError::ZeroWeight));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn weighted_mean_equal_weights_is_mean()
+{
+    let a = Array::linspace(-3., 7., 24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    let w = Array::from_elem(a.raw_dim(), 0.25);
+    assert_abs_diff_eq!(a.weighted_mean(&w).unwrap(), a.mean().unwrap(), epsilon = 1e-12);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let w = Array::from_elem(a.len_of(axis), 7.);
+        let mean = a.mean_axis(axis).unwrap();
+        let weighted = a.weighted_mean_axis(axis, &w).unwrap();
+        assert_eq!(weighted.shape(), mean.shape());
+        weighted
+            .iter()
+            .zip(&mean)
+            .for_each(|(&x, &y)| assert_abs_diff_eq!(x, y, epsilon = 1e-12));
+        let w = Array::from_elem(a.t().len_of(axis), 7.);
+        let f = a.t().weighted_mean_axis(axis, &w).unwrap();
+        f.iter()
+            .zip(&a.t().mean_axis(axis).unwrap())
+            .for_each(|(&x, &y)| assert_abs_diff_eq!(x, y, epsilon = 1e-12));
+    }
+}
+
+#[test]
+fn weighted_mean_axis()
+{
+    let a = array![[1., 2., 3.], [4., 5., 6.]];
+    let w0 = array![1., 3.];
+    let w1 = array![0., 1., 1.];
+    assert_eq!(a.weighted_mean_axis(Axis(0), &w0), Ok(array![3.25, 4.25, 5.25]));
+    assert_eq!(a.weighted_mean_axis(Axis(1), &w1), Ok(array![2.5, 5.5]));
+    assert_eq!(a.slice(s![.., ..;-1]).weighted_mean_axis(Axis(1), &w1), Ok(array![1.5, 4.5]));
+}
+
+#[test]
+fn weighted_mean_errors()
+{
+    let shape_error = StatsError::Shape(ShapeError::from_kind(ErrorKind::IncompatibleShape));
+    let a = array![[1., 2., 3.], [4., 5., 6.]];
+    let w = array![1., 2.];
+    assert_eq!(a.weighted_mean(&w), Err(shape_error.clone()));
+    let w = array![[1., -1., 0.]];
+    assert_eq!(a.weighted_mean(&w), Err(StatsError::ZeroWeight));
+    let w = array![1.];
+    assert_eq!(Array1::<f64>::zeros(0).weighted_mean(&w), Err(StatsError::ZeroWeight));
+    let w = array![1., 2., 3.];
+    assert_eq!(a.weighted_mean_axis(Axis(0), &w), Err(shape_error));
+    let w = array![0., 0., 0.];
+    assert_eq!(a.weighted_mean_axis(Axis(1), &w), Err(StatsError::ZeroWeight));
+    let w = Array1::zeros(0);
+    assert_eq!(Array2::<f64>::zeros((2, 0)).weighted_mean_axis(Axis(1), &w), Err(StatsError::ZeroWeight));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn weighted_var_std()
+{
+    let a = array![[1., 2.], [3., 6.]];
+    let w = array![[2., 1.], [1., 0.]];
+    // mean 1.75, Σw(x - mean)² = 2.75, V₁ = 4, V₂ = 6
+    assert_abs_diff_eq!(a.weighted_var(&w, 0.).unwrap(), 2.75 / 4., epsilon = 1e-12);
+    assert_abs_diff_eq!(a.weighted_var(&w, 1.).unwrap(), 2.75 / 2.5, epsilon = 1e-12);
+    assert_abs_diff_eq!(a.weighted_std(&w, 1.).unwrap(), (2.75f64 / 2.5).sqrt(), epsilon = 1e-12);
+    assert_abs_diff_eq!(a.t().weighted_var(&w.t(), 1.).unwrap(), 2.75 / 2.5, epsilon = 1e-12);
+    // leading zero weights are skipped
+    let w = array![0., 0., 1., 1.];
+    assert_abs_diff_eq!(array![100., -100., 1., 3.].weighted_var(&w, 0.).unwrap(), 1., epsilon = 1e-12);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn weighted_var_non_positive_dof_is_nan()
+{
+    let a = array![1., 2., 3.0f64];
+    let w = array![0., 1., 0.];
+    assert!(a.weighted_var(&w, 1.).unwrap().is_nan());
+    assert!(a.weighted_var_axis(Axis(0), &w, 1.).unwrap()[()].is_nan());
+    assert_eq!(a.weighted_var(&w, 0.), Ok(0.));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn weighted_var_errors()
+{
+    let a = array![[1., 2., 3.], [4., 5., 6.]];
+    let w = array![1., 2.];
+    assert!(matches!(a.weighted_var(&w, 0.), Err(StatsError::Shape(_))));
+    assert!(matches!(a.weighted_std_axis(Axis(1), &w, 0.), Err(StatsError::Shape(_))));
+    let w = array![0., 0.];
+    assert_eq!(a.weighted_var_axis(Axis(0), &w, 0.), Err(StatsError::ZeroWeight));
+    assert_eq!(a.weighted_std(&arr0(0.), 0.), Err(StatsError::ZeroWeight));
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic(expected = "`ddof` must not be less than zero")]
+fn weighted_var_negative_ddof()
+{
+    let w = array![1., 1.];
+    let _ = array![1., 2.].weighted_var(&w, -1.);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn weighted_var_axis_uniform_weights_is_var_axis()
+{
+    fn prop(xs: Vec<i16>, rows: u8, weight: u8, ddof: bool) -> bool
+    {
+        let rows = rows as usize % 4 + 2;
+        let cols = xs.len() / rows;
+        if cols < 2 {
+            return true;
+        }
+        let weight = weight as f64 / 8. + 0.125;
+        let ddof = ddof as u8 as f64;
+        let a = Array::from_iter(xs.into_iter().take(rows * cols).map(f64::from))
+            .into_shape_with_order((rows, cols))
+            .unwrap();
+        (0..2).all(|axis| {
+            let axis = Axis(axis);
+            let w = Array::from_elem(a.len_of(axis), weight);
+            let var = a.var_axis(axis, ddof);
+            let weighted = a.weighted_var_axis(axis, &w, ddof).unwrap();
+            let std = a.weighted_std_axis(axis, &w, ddof).unwrap();
+            let tol = |x: f64| 1e-9 * (1. + x.abs());
+            ndarray::Zip::from(&var)
+                .and(&weighted)
+                .and(&std)
+                .all(|&v, &wv, &ws| (v - wv).abs() < tol(v) && (v.sqrt() - ws).abs() < tol(v))
+        })
+    }
+    quickcheck::quickcheck(prop as fn(Vec<i16>, u8, u8, bool) -> bool);
+}